@@ -0,0 +1,158 @@
+//! JSON-RPC interface to the multisig pallet.
+//!
+//! Wraps the pallet's `MultisigApi` runtime API so wallet front-ends can list proposals and
+//! resolve sovereign accounts without decoding the pallet's storage themselves. Every method
+//! takes an optional block hash to query at, defaulting to the best block.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_multisig::runtime_api::MultisigApi as MultisigRuntimeApi;
+
+/// Error code returned when the runtime API call itself fails.
+pub const RUNTIME_ERROR: i32 = 1;
+
+/// The multisig RPC methods.
+///
+/// Each method queries the block `at`, or the best block if it is omitted.
+#[rpc(server)]
+pub trait MultisigApi<BlockHash, AccountId, MultisigId, ProposalIndex, Hash> {
+	/// Returns the sovereign account of `multisig_id`, or `null` if it does not exist.
+	#[method(name = "multisig_accountOf")]
+	fn account_of(
+		&self,
+		multisig_id: MultisigId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AccountId>>;
+
+	/// Returns the index and call hash of every proposal of `multisig_id` that is neither
+	/// executed nor expired.
+	#[method(name = "multisig_pendingProposals")]
+	fn pending_proposals(
+		&self,
+		multisig_id: MultisigId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(ProposalIndex, Hash)>>;
+
+	/// Returns the index and call hash of every pending proposal of `multisig_id` that has
+	/// gathered the approvals it needs to be executed.
+	#[method(name = "multisig_executableProposals")]
+	fn executable_proposals(
+		&self,
+		multisig_id: MultisigId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(ProposalIndex, Hash)>>;
+
+	/// Returns the multisig and index of every pending proposal of `call_hash`.
+	#[method(name = "multisig_proposalsByCallHash")]
+	fn proposals_by_call_hash(
+		&self,
+		call_hash: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(MultisigId, ProposalIndex)>>;
+}
+
+/// Serves the multisig RPC methods from a client's runtime API.
+///
+/// `Balance` is only named so the runtime API can be resolved; no method returns one.
+pub struct Multisig<C, Block, Balance> {
+	client: Arc<C>,
+	_marker: PhantomData<(Block, Balance)>,
+}
+
+impl<C, Block, Balance> Multisig<C, Block, Balance> {
+	/// Creates the RPC handler over `client`.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: PhantomData }
+	}
+}
+
+impl<C, Block, Balance> Multisig<C, Block, Balance>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	/// Resolves the block to query at, falling back to the best block.
+	fn at(&self, at: Option<Block::Hash>) -> Block::Hash {
+		at.unwrap_or_else(|| self.client.info().best_hash)
+	}
+}
+
+/// Turns a failed runtime API call into an RPC error.
+fn runtime_error(error: sp_api::ApiError) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query the multisig runtime API",
+		Some(error.to_string()),
+	)
+}
+
+impl<C, Block, Balance, AccountId, MultisigId, ProposalIndex, Hash>
+	MultisigApiServer<Block::Hash, AccountId, MultisigId, ProposalIndex, Hash>
+	for Multisig<C, Block, Balance>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: MultisigRuntimeApi<Block, AccountId, Balance, MultisigId, ProposalIndex, Hash>,
+	Balance: Codec + Send + Sync + 'static,
+	AccountId: Codec + Send + Sync + serde::Serialize + 'static,
+	MultisigId: Codec + Send + Sync + serde::Serialize + serde::de::DeserializeOwned + 'static,
+	ProposalIndex: Codec + Send + Sync + serde::Serialize + 'static,
+	Hash: Codec + Send + Sync + serde::Serialize + serde::de::DeserializeOwned + 'static,
+{
+	fn account_of(
+		&self,
+		multisig_id: MultisigId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<AccountId>> {
+		self.client
+			.runtime_api()
+			.account_of(self.at(at), multisig_id)
+			.map_err(runtime_error)
+	}
+
+	fn pending_proposals(
+		&self,
+		multisig_id: MultisigId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(ProposalIndex, Hash)>> {
+		self.client
+			.runtime_api()
+			.pending_proposals(self.at(at), multisig_id)
+			.map_err(runtime_error)
+	}
+
+	fn executable_proposals(
+		&self,
+		multisig_id: MultisigId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(ProposalIndex, Hash)>> {
+		self.client
+			.runtime_api()
+			.executable_proposals(self.at(at), multisig_id)
+			.map_err(runtime_error)
+	}
+
+	fn proposals_by_call_hash(
+		&self,
+		call_hash: Hash,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<(MultisigId, ProposalIndex)>> {
+		self.client
+			.runtime_api()
+			.proposals_by_call_hash(self.at(at), call_hash)
+			.map_err(runtime_error)
+	}
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,140 @@
+use super::*;
+use sp_blockchain::{BlockStatus, Info};
+use sp_runtime::{
+	generic,
+	testing::{Header, H256},
+	traits::NumberFor,
+	OpaqueExtrinsic,
+};
+
+type Block = generic::Block<Header, OpaqueExtrinsic>;
+
+const BEST: H256 = H256::repeat_byte(1);
+const OLDER: H256 = H256::repeat_byte(2);
+
+/// Answers every runtime API call served over RPC with the hash of the block it was made
+/// at, and the others with nothing.
+struct MockApi;
+
+sp_api::mock_impl_runtime_apis! {
+	impl MultisigRuntimeApi<Block, H256, u64, u32, u32, H256> for MockApi {
+		#[advanced]
+		fn account_of(&self, at: H256, _multisig_id: u32) -> Result<Option<H256>, sp_api::ApiError> {
+			Ok(Some(at))
+		}
+
+		#[advanced]
+		fn pending_proposals(
+			&self,
+			at: H256,
+			multisig_id: u32,
+		) -> Result<Vec<(u32, H256)>, sp_api::ApiError> {
+			Ok(vec![(multisig_id, at)])
+		}
+
+		#[advanced]
+		fn executable_proposals(
+			&self,
+			at: H256,
+			multisig_id: u32,
+		) -> Result<Vec<(u32, H256)>, sp_api::ApiError> {
+			Ok(vec![(multisig_id, at)])
+		}
+
+		fn owner_labels(_multisig_id: u32) -> Vec<(H256, Vec<u8>)> {
+			Vec::new()
+		}
+
+		fn export_state(_multisig_id: u32) -> Option<Vec<u8>> {
+			None
+		}
+
+		fn confirmation_payload(_multisig_id: u32, _proposal_index: u32) -> Option<Vec<u8>> {
+			None
+		}
+
+		fn usage_meter(_multisig_id: u32) -> Option<pallet_multisig::UsageMeter<u64>> {
+			None
+		}
+
+		#[advanced]
+		fn proposals_by_call_hash(
+			&self,
+			at: H256,
+			_call_hash: H256,
+		) -> Result<Vec<(u32, u32)>, sp_api::ApiError> {
+			Ok(vec![(at.as_bytes()[0].into(), 0)])
+		}
+	}
+}
+
+/// A client whose best block is `BEST`.
+struct TestClient;
+
+impl ProvideRuntimeApi<Block> for TestClient {
+	type Api = MockApi;
+
+	fn runtime_api(&self) -> sp_api::ApiRef<'_, Self::Api> {
+		MockApi.into()
+	}
+}
+
+impl HeaderBackend<Block> for TestClient {
+	fn header(&self, _hash: H256) -> sp_blockchain::Result<Option<Header>> {
+		Ok(None)
+	}
+
+	fn info(&self) -> Info<Block> {
+		Info {
+			best_hash: BEST,
+			best_number: 2,
+			genesis_hash: H256::zero(),
+			finalized_hash: OLDER,
+			finalized_number: 1,
+			finalized_state: None,
+			number_leaves: 1,
+			block_gap: None,
+		}
+	}
+
+	fn status(&self, _hash: H256) -> sp_blockchain::Result<BlockStatus> {
+		Ok(BlockStatus::InChain)
+	}
+
+	fn number(&self, _hash: H256) -> sp_blockchain::Result<Option<NumberFor<Block>>> {
+		Ok(None)
+	}
+
+	fn hash(&self, _number: NumberFor<Block>) -> sp_blockchain::Result<Option<H256>> {
+		Ok(None)
+	}
+}
+
+/// The RPC handler over `TestClient`, with the runtime API's types pinned.
+fn rpc() -> impl MultisigApiServer<H256, H256, u32, u32, H256> {
+	Multisig::<_, Block, u64>::new(Arc::new(TestClient))
+}
+
+#[test]
+fn omitted_block_defaults_to_the_best_block() {
+	// Arrange
+	let rpc = rpc();
+
+	// Act & Assert
+	assert_eq!(rpc.account_of(7, None).unwrap(), Some(BEST));
+	assert_eq!(rpc.pending_proposals(7, None).unwrap(), vec![(7, BEST)]);
+	assert_eq!(rpc.executable_proposals(7, None).unwrap(), vec![(7, BEST)]);
+	assert_eq!(rpc.proposals_by_call_hash(H256::zero(), None).unwrap(), vec![(1, 0)]);
+}
+
+#[test]
+fn given_block_is_queried() {
+	// Arrange
+	let rpc = rpc();
+
+	// Act & Assert
+	assert_eq!(rpc.account_of(7, Some(OLDER)).unwrap(), Some(OLDER));
+	assert_eq!(rpc.pending_proposals(7, Some(OLDER)).unwrap(), vec![(7, OLDER)]);
+	assert_eq!(rpc.executable_proposals(7, Some(OLDER)).unwrap(), vec![(7, OLDER)]);
+	assert_eq!(rpc.proposals_by_call_hash(H256::zero(), Some(OLDER)).unwrap(), vec![(2, 0)]);
+}
@@ -0,0 +1,103 @@
+//! Derivation of multisig sovereign addresses.
+//!
+//! These functions depend only on SCALE encoding and a pure blake2 implementation, with no
+//! storage or host functions, so wallet front-ends and hardware-wallet firmware can compute and
+//! verify the address of a multisig from its ID without a runtime instance. The pallet derives
+//! its accounts through this module, so the two can never disagree. Every account is namespaced
+//! by the runtime's `Config::PalletId`, which off-chain tooling reads from the metadata.
+//!
+//! Call hashes, by contrast, use the runtime's configured `Hashing` and `Hash` (see
+//! `CallHashOf`). Addresses keep blake2 whatever the runtime's hasher, so that an address never
+//! changes with the runtime configuration and can be computed from the ID alone.
+
+use codec::{Decode, Encode};
+use sp_core::{hashing::blake2_256, H160};
+use sp_runtime::traits::TrailingZeroInput;
+
+/// The tag hashed together with a multisig's ID and a sub-account index to derive one of its
+/// sub-accounts.
+pub const SUB_ACCOUNT_TAG: &[u8] = b"sub";
+
+/// The tag hashed together with an owner set, threshold and salt to derive the sovereign
+/// account of a counterfactual multisig.
+pub const COUNTERFACTUAL_ACCOUNT_TAG: &[u8] = b"counterfactual";
+
+/// Decodes an account from 32 bytes of entropy.
+fn account_from_entropy<AccountId: Decode>(entropy: [u8; 32]) -> AccountId {
+	Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+		.expect("infinite length input; no invalid inputs for type; qed")
+}
+
+/// Returns the 32 bytes of entropy from which the sovereign account of `multisig_id` is decoded.
+///
+/// `pallet_id` is the bytes of the runtime's `Config::PalletId`, which namespaces the accounts
+/// of the pallet, and `salt` the wallet's optional salt.
+pub fn multisig_entropy<MultisigId: Encode>(
+	pallet_id: &[u8; 8],
+	multisig_id: MultisigId,
+	salt: Option<&[u8; 32]>,
+) -> [u8; 32] {
+	(pallet_id, multisig_id, salt).using_encoded(blake2_256)
+}
+
+/// Derives the sovereign account of the multisig `multisig_id`, given the bytes of the
+/// runtime's `Config::PalletId` and the wallet's salt, if any.
+pub fn multisig_account_id<AccountId: Decode, MultisigId: Encode>(
+	pallet_id: &[u8; 8],
+	multisig_id: MultisigId,
+	salt: Option<&[u8; 32]>,
+) -> AccountId {
+	account_from_entropy(multisig_entropy(pallet_id, multisig_id, salt))
+}
+
+/// Derives the sovereign account of a counterfactual multisig with `owners`, `threshold` and
+/// `salt`.
+///
+/// The owners are sorted first, so the order they are listed in does not matter. This lets
+/// funds be sent to a wallet's address before the wallet has been created on chain.
+pub fn counterfactual_account_id<AccountId: Encode + Decode + Ord + Clone>(
+	pallet_id: &[u8; 8],
+	owners: &[AccountId],
+	threshold: u32,
+	salt: &[u8; 32],
+) -> AccountId {
+	let mut owners = owners.to_vec();
+	owners.sort();
+	account_from_entropy(
+		(pallet_id, COUNTERFACTUAL_ACCOUNT_TAG, owners, threshold, salt).using_encoded(blake2_256),
+	)
+}
+
+/// Derives the sub-account `sub_index` of the multisig `multisig_id`.
+///
+/// Sub-accounts are controlled by the multisig through `dispatch_as_sub`, letting one wallet
+/// keep funds for separate purposes apart without creating new multisigs.
+pub fn sub_account_id<AccountId: Decode, MultisigId: Encode>(
+	pallet_id: &[u8; 8],
+	multisig_id: MultisigId,
+	sub_index: u16,
+) -> AccountId {
+	account_from_entropy(
+		(pallet_id, SUB_ACCOUNT_TAG, multisig_id, sub_index).using_encoded(blake2_256),
+	)
+}
+
+/// Returns the EVM address mapped to `account`.
+///
+/// This is the leading 20 bytes of the encoded account ID, matching Frontier's truncated
+/// address mapping.
+pub fn evm_address<AccountId: Encode>(account: &AccountId) -> H160 {
+	let account = account.encode();
+	Decode::decode(&mut TrailingZeroInput::new(&account))
+		.expect("infinite length input; no invalid inputs for type; qed")
+}
+
+/// Returns whether `account` is the sovereign account of the multisig `multisig_id`.
+pub fn verify_multisig_account<AccountId: Decode + PartialEq, MultisigId: Encode>(
+	pallet_id: &[u8; 8],
+	multisig_id: MultisigId,
+	salt: Option<&[u8; 32]>,
+	account: &AccountId,
+) -> bool {
+	multisig_account_id::<AccountId, _>(pallet_id, multisig_id, salt) == *account
+}
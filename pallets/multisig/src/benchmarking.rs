@@ -4,55 +4,1567 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Multisig;
+use codec::{Decode, Encode};
 use frame_benchmarking::v2::*;
+use frame_support::{
+	assert_ok,
+	dispatch::GetDispatchInfo,
+	traits::{Currency, EnsureOrigin, Get, Hooks},
+	weights::Weight,
+	BoundedVec,
+};
 use frame_system::RawOrigin;
+use sp_core::U256;
+use sp_runtime::traits::{Bounded, Hash, IdentifyAccount, One, TrailingZeroInput, Verify, Zero};
 use sp_std::prelude::*;
-use codec::Encode;
 
-// Helper to create a user account
-fn create_user<T: Config>(name: &'static str, index: u32) -> T::AccountId {
-	let user = frame_benchmarking::account(name, index, 0);
-	user
+const SEED: u32 = 0;
+
+// Helper to create `o` owner accounts, the first of which is the whitelisted caller, each
+// funded to cover the deposits the runtime charges.
+fn create_owners<T: Config>(o: u32) -> Vec<T::AccountId> {
+	let mut owners = vec![whitelisted_caller()];
+	owners.extend((1..o).map(|i| account("owner", i, SEED)));
+	for owner in &owners {
+		T::Currency::make_free_balance_be(owner, BalanceOf::<T>::max_value() / 2u32.into());
+	}
+	owners
+}
+
+// Helper to create a multisig of `owners` requiring `threshold` of them, returning its ID.
+fn new_multisig<T: Config>(owners: &[T::AccountId], threshold: u32) -> T::MultisigId {
+	let multisig_id = Multisig::<T>::next_multisig_id();
+	assert_ok!(Multisig::<T>::create_multisig(
+		RawOrigin::Signed(owners[0].clone()).into(),
+		owners.to_vec(),
+		threshold,
+		None,
+	));
+	multisig_id
+}
+
+// Helper to fill the multisig with `p` pending remark proposals, returning the last call and
+// its index.
+fn submit_proposals<T: Config>(
+	owner: &T::AccountId,
+	multisig_id: T::MultisigId,
+	p: u32,
+) -> (<T as Config>::RuntimeCall, T::ProposalIndex)
+where
+	<T as Config>::RuntimeCall: From<frame_system::Call<T>>,
+{
+	let mut last = None;
+	for i in 0..p {
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: i.encode() }.into();
+		let proposal_index = Multisig::<T>::next_proposal_index(multisig_id);
+		assert_ok!(Multisig::<T>::submit_proposal(
+			RawOrigin::Signed(owner.clone()).into(),
+			multisig_id,
+			Box::new(call.clone()),
+		));
+		last = Some((call, proposal_index));
+	}
+	last.expect("at least one proposal is submitted")
+}
+
+// Helper to return the origin of the sovereign account of a multisig.
+fn sovereign<T: Config>(multisig_id: T::MultisigId) -> RawOrigin<T::AccountId> {
+	RawOrigin::Signed(Multisig::<T>::multi_account_id(multisig_id))
+}
+
+// Helper to create `n` accounts that are not owners, such as auditors or guardians.
+fn outsiders<T: Config>(name: &'static str, n: u32) -> Vec<T::AccountId> {
+	(0..n).map(|i| account(name, i, SEED)).collect()
+}
+
+// Helper to build a name of the maximum length.
+fn max_name<T: Config>() -> NameOf<T> {
+	vec![b'n'; T::MaxNameLength::get() as usize].try_into().expect("fits the bound")
 }
 
-#[benchmarks]
+// Helper to label each of `owners` and give each a vote weight of two, as state that copying
+// or replacing the owners has to carry along or clear.
+fn label_and_weigh<T: Config>(multisig_id: T::MultisigId, owners: &[T::AccountId]) {
+	for owner in owners {
+		assert_ok!(Multisig::<T>::set_owner_label(
+			sovereign::<T>(multisig_id).into(),
+			multisig_id,
+			owner.clone(),
+			Some(max_name::<T>()),
+		));
+		assert_ok!(Multisig::<T>::set_owner_weight(
+			sovereign::<T>(multisig_id).into(),
+			multisig_id,
+			owner.clone(),
+			2,
+		));
+	}
+}
+
+// Helper to give every class of calls a threshold of `threshold`.
+fn set_class_thresholds<T: Config>(multisig_id: T::MultisigId, threshold: u32) {
+	for class in [CallClass::Transfer, CallClass::Governance, CallClass::Admin, CallClass::Other] {
+		assert_ok!(Multisig::<T>::set_class_threshold(
+			sovereign::<T>(multisig_id).into(),
+			multisig_id,
+			class,
+			Some(threshold),
+		));
+	}
+}
+
+// Helper to build the maximum number of owner groups, each holding all of `owners`.
+fn full_groups<T: Config>(owners: &[T::AccountId]) -> Vec<OwnerGroupOf<T>> {
+	let members: BoundedVec<_, _> = owners.to_vec().try_into().expect("at most `MaxOwners`");
+	(0..T::MaxGroups::get())
+		.map(|_| OwnerGroup { name: max_name::<T>(), members: members.clone(), threshold: 1 })
+		.collect()
+}
+
+// Helper to return a session scope, the first variant of `SessionScope`, which is assumed to
+// allow remarks.
+fn session_scope<T: Config>() -> T::SessionScope {
+	T::SessionScope::decode(&mut TrailingZeroInput::zeroes()).expect("infinite input")
+}
+
+// Helper to return a remark call.
+fn remark<T: Config>() -> <T as Config>::RuntimeCall
+where
+	<T as Config>::RuntimeCall: From<frame_system::Call<T>>,
+{
+	frame_system::Call::<T>::remark { remark: vec![] }.into()
+}
+
+// Helper to build a velocity limit that caps both executions and value, and that every owner
+// of a multisig of up to `MaxOwners` owners can override.
+fn velocity_limit<T: Config>() -> VelocityLimitOf<T> {
+	VelocityLimit {
+		period: One::one(),
+		max_executions: Some(u32::MAX),
+		max_value: Some(BalanceOf::<T>::max_value()),
+		override_threshold: 1,
+	}
+}
+
+// Helper to put a multisig under the velocity limit of `velocity_limit`.
+fn set_velocity_limit_of<T: Config>(multisig_id: T::MultisigId) {
+	assert_ok!(Multisig::<T>::set_velocity_limit(
+		sovereign::<T>(multisig_id).into(),
+		multisig_id,
+		Some(velocity_limit::<T>()),
+	));
+}
+
+// Helper to register a template with the maximum number of roles, all of which are required
+// and in the sign-off chain, returning its ID.
+fn full_template<T: Config>() -> Result<TemplateId, BenchmarkError> {
+	let origin =
+		T::TemplateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let name = max_name::<T>().to_vec();
+	let indices: Vec<u32> = (0..T::MaxOwners::get()).collect();
+	let template_id = Multisig::<T>::next_template_id();
+	assert_ok!(Multisig::<T>::register_template(
+		origin,
+		name.clone(),
+		vec![name; T::MaxOwners::get() as usize],
+		1,
+		indices.clone(),
+		indices,
+	));
+	Ok(template_id)
+}
+
+// Helper to build the maximum number of payees, each paid the minimum balance.
+fn full_payees<T: Config>() -> Vec<(T::AccountId, BalanceOf<T>)> {
+	outsiders::<T>("payee", T::MaxPayees::get())
+		.into_iter()
+		.map(|payee| (payee, T::Currency::minimum_balance()))
+		.collect()
+}
+
+// Helper to give a multisig a payroll with the maximum number of payees, first paid out in
+// the next block.
+fn set_full_payroll<T: Config>(multisig_id: T::MultisigId) {
+	assert_ok!(Multisig::<T>::set_payroll(
+		sovereign::<T>(multisig_id).into(),
+		multisig_id,
+		full_payees::<T>(),
+		One::one(),
+		frame_system::Pallet::<T>::block_number() + One::one(),
+	));
+}
+
+// Helper to make the maximum number of outsiders the guardians of a multisig, all of whom
+// must approve a recovery, returning them.
+fn set_full_recovery_config<T: Config>(multisig_id: T::MultisigId) -> Vec<T::AccountId> {
+	let guardians = outsiders::<T>("guardian", T::MaxOwners::get());
+	assert_ok!(Multisig::<T>::set_recovery_config(
+		sovereign::<T>(multisig_id).into(),
+		multisig_id,
+		guardians.clone(),
+		guardians.len() as u32,
+		Zero::zero(),
+	));
+	guardians
+}
+
+// Helper to have `guardian` start a recovery of a multisig to the maximum number of new
+// owners, returning them.
+fn initiate_full_recovery<T: Config>(
+	multisig_id: T::MultisigId,
+	guardian: &T::AccountId,
+) -> Vec<T::AccountId> {
+	let new_owners = outsiders::<T>("new_owner", T::MaxOwners::get());
+	assert_ok!(Multisig::<T>::initiate_recovery(
+		RawOrigin::Signed(guardian.clone()).into(),
+		multisig_id,
+		new_owners.clone(),
+		1,
+	));
+	new_owners
+}
+
+// Helper to have each of `owners` confirm a proposal.
+fn confirm_by<T: Config>(
+	owners: &[T::AccountId],
+	multisig_id: T::MultisigId,
+	proposal_index: T::ProposalIndex,
+) {
+	for owner in owners {
+		assert_ok!(Multisig::<T>::confirm_proposal(
+			RawOrigin::Signed(owner.clone()).into(),
+			multisig_id,
+			proposal_index,
+		));
+	}
+}
+
+#[benchmarks(where <T as Config>::RuntimeCall: From<frame_system::Call<T>> + From<Call<T>>)]
 mod benchmarks {
 	use super::*;
 
+	/// Benchmark for creating a multisig with `o` owners, each of whom is indexed as an owner
+	/// of the new wallet.
+	#[benchmark]
+	fn create_multisig(o: Linear<1, { T::MaxOwners::get() }>) {
+		let owners = create_owners::<T>(o);
+		let multisig_id = Multisig::<T>::next_multisig_id();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owners[0].clone()), owners.clone(), o, None);
+
+		assert!(<Multisigs<T>>::contains_key(multisig_id));
+		assert!(Multisig::<T>::owned_multisigs(&owners[owners.len() - 1]).contains(&multisig_id));
+	}
+
+	/// Benchmark for submitting a proposal to a multisig with the maximum number of owners and
+	/// all but one proposal slot taken.
+	///
+	/// The proposer is the last owner, so checking their membership scans the whole owner list.
+	#[benchmark]
+	fn submit_proposal() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, owners.len() as u32);
+		let p = T::MaxPendingProposals::get();
+		if p > 1 {
+			submit_proposals::<T>(&owners[0], multisig_id, p - 1);
+		}
+		let caller = owners[owners.len() - 1].clone();
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: p.encode() }.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, Box::new(call));
+
+		assert_eq!(Multisig::<T>::pending_proposal_count(multisig_id), p);
+	}
+
+	/// Benchmark for the confirmation that brings a proposal to `a` approvals and so meets the
+	/// threshold, on a multisig of at least `o` owners.
+	///
+	/// The multisig has `max(o, a)` owners, as a proposal cannot gather more approvals than its
+	/// multisig has owners.
+	#[benchmark]
+	fn confirm_proposal(
+		o: Linear<2, { T::MaxOwners::get() }>,
+		a: Linear<2, { T::MaxOwners::get() }>,
+	) {
+		let owners = create_owners::<T>(o.max(a));
+		let multisig_id = new_multisig::<T>(&owners, a);
+		let (_, proposal_index) = submit_proposals::<T>(&owners[0], multisig_id, 1);
+		confirm_by::<T>(&owners[1..a as usize - 1], multisig_id, proposal_index);
+		let caller = owners[a as usize - 1].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, proposal_index);
+
+		assert_eq!(Multisig::<T>::approvals(multisig_id, proposal_index).len() as u32, a);
+	}
+
+	/// Benchmark for executing a proposal holding `a` approvals, on a multisig of at least `o`
+	/// owners.
+	///
+	/// The multisig has `max(o, a)` owners, as a proposal cannot gather more approvals than its
+	/// multisig has owners.
+	#[benchmark]
+	fn execute_proposal(
+		o: Linear<1, { T::MaxOwners::get() }>,
+		a: Linear<1, { T::MaxOwners::get() }>,
+	) {
+		let owners = create_owners::<T>(o.max(a));
+		let multisig_id = new_multisig::<T>(&owners, a);
+		let (call, proposal_index) = submit_proposals::<T>(&owners[0], multisig_id, 1);
+		confirm_by::<T>(&owners[1..a as usize], multisig_id, proposal_index);
+		let max_weight = call.get_dispatch_info().call_weight;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owners[0].clone()),
+			multisig_id,
+			proposal_index,
+			Box::new(call),
+			max_weight,
+		);
+
+		assert_eq!(Multisig::<T>::pending_proposal_count(multisig_id), 0);
+	}
+
 	/// Benchmark for the `destroy_multisig` extrinsic, which is called via `execute_proposal`.
-	/// This is the most complex extrinsic because `clear_prefix` depends on the number of
-	/// proposals, `p`, that need to be deleted. We simulate this by creating `p` proposals
-	/// before timing the destruction.
-	#[benchmark(p = 1 .. 100)]
-	fn destroy_multisig(p: u32) {
-		let caller: T::AccountId = whitelisted_caller();
-		let owners = vec![caller.clone(), create_user::<T>("owner", 1)];
-		let threshold = 2;
-		assert_ok!(Multisig::<T>::create_multisig(RawOrigin::Signed(caller.clone()).into(), owners.clone(), threshold));
-		let multisig_id = Multisig::<T>::next_multisig_id() - 1;
-
-		// Setup: Create `p` dummy proposals to fill up storage, simulating the worst case.
-		for i in 0..p {
-			let call: <T as Config>::RuntimeCall = frame_system::Call::remark { remark: i.encode() }.into();
-			assert_ok!(Multisig::<T>::submit_proposal(RawOrigin::Signed(owners[0].clone()).into(), multisig_id, Box::new(call)));
+	/// `clear_prefix` depends on the number of proposals that need to be deleted, so we create
+	/// `p - 1` proposals before the destruction proposal itself, on a multisig of `o` owners.
+	///
+	/// We are benchmarking `execute_proposal` here because `destroy_multisig` can only be
+	/// called by the multisig's sovereign account. This setup correctly measures the weight of
+	/// the entire self-governed destruction process.
+	#[benchmark]
+	fn destroy_multisig(
+		o: Linear<2, { T::MaxOwners::get() }>,
+		p: Linear<1, { T::MaxPendingProposals::get() }>,
+	) {
+		let owners = create_owners::<T>(o);
+		let multisig_id = new_multisig::<T>(&owners, o);
+		if p > 1 {
+			submit_proposals::<T>(&owners[0], multisig_id, p - 1);
 		}
 
 		// Now create the actual proposal to destroy the multisig.
-		let destroy_call: <T as Config>::RuntimeCall = crate::Call::destroy_multisig { multisig_id }.into();
-		assert_ok!(Multisig::<T>::submit_proposal(RawOrigin::Signed(owners[0].clone()).into(), multisig_id, Box::new(destroy_call.clone())));
-		let proposal_index = p;
-		assert_ok!(Multisig::<T>::confirm_proposal(RawOrigin::Signed(owners[1].clone()).into(), multisig_id, proposal_index));
+		let destroy_call: <T as Config>::RuntimeCall =
+			Call::<T>::destroy_multisig { multisig_id }.into();
+		let proposal_index = Multisig::<T>::next_proposal_index(multisig_id);
+		assert_ok!(Multisig::<T>::submit_proposal(
+			RawOrigin::Signed(owners[0].clone()).into(),
+			multisig_id,
+			Box::new(destroy_call.clone()),
+		));
+		confirm_by::<T>(&owners[1..], multisig_id, proposal_index);
+		let max_weight = destroy_call.get_dispatch_info().call_weight;
 
-		// The benchmarked action is the final execution of the destruction proposal.
-		// We are benchmarking `execute_proposal` here because `destroy_multisig`
-		// can only be called by the multisig's sovereign account. This setup correctly
-		// measures the weight of the entire self-governed destruction process.
 		#[extrinsic_call]
-		execute_proposal(RawOrigin::Signed(caller), multisig_id, proposal_index, Box::new(destroy_call));
+		execute_proposal(
+			RawOrigin::Signed(owners[0].clone()),
+			multisig_id,
+			proposal_index,
+			Box::new(destroy_call),
+			max_weight,
+		);
 
 		// Verify that the multisig no longer exists.
 		assert!(!<Multisigs<T>>::contains_key(multisig_id));
 	}
 
+	/// Benchmark for submitting a call of `b` encoded bytes uploaded in chunks, to a multisig
+	/// with the maximum number of owners and all but one proposal slot taken.
+	#[benchmark]
+	fn finalize_call(b: Linear<8, { T::MaxCallSize::get() }>) {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, owners.len() as u32);
+		let p = T::MaxPendingProposals::get();
+		if p > 1 {
+			submit_proposals::<T>(&owners[0], multisig_id, p - 1);
+		}
+		let caller = owners[owners.len() - 1].clone();
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![0; (b - 8) as usize] }.into();
+		let chunk: CallDataOf<T> = call.encode().try_into().expect("the call fits in `b` bytes");
+		assert_ok!(Multisig::<T>::note_call_chunk(
+			RawOrigin::Signed(caller.clone()).into(),
+			multisig_id,
+			chunk,
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id);
+
+		assert_eq!(Multisig::<T>::pending_proposal_count(multisig_id), p);
+	}
+
+	/// Benchmark for cancelling a proposal approved by all but one of the `o` owners, each of
+	/// whose approval deposits is refunded.
+	#[benchmark]
+	fn cancel_proposal(o: Linear<2, { T::MaxOwners::get() }>) {
+		let owners = create_owners::<T>(o);
+		let multisig_id = new_multisig::<T>(&owners, o);
+		let (_, proposal_index) = submit_proposals::<T>(&owners[0], multisig_id, 1);
+		confirm_by::<T>(&owners[1..o as usize - 1], multisig_id, proposal_index);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owners[0].clone()), multisig_id, proposal_index);
+
+		assert_eq!(Multisig::<T>::pending_proposal_count(multisig_id), 0);
+	}
+
+	/// Benchmark for the rejection that stops a proposal approved by all but one of the `o`
+	/// owners from passing, so it is removed and every deposit refunded.
+	///
+	/// The rejecting owner had approved the proposal, so their approval is withdrawn first.
+	#[benchmark]
+	fn reject_proposal(o: Linear<2, { T::MaxOwners::get() }>) {
+		let owners = create_owners::<T>(o);
+		let multisig_id = new_multisig::<T>(&owners, o);
+		let (_, proposal_index) = submit_proposals::<T>(&owners[0], multisig_id, 1);
+		confirm_by::<T>(&owners[1..o as usize - 1], multisig_id, proposal_index);
+		let caller = owners[o as usize - 2].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, proposal_index);
+
+		assert_eq!(Multisig::<T>::pending_proposal_count(multisig_id), 0);
+	}
+
+	/// Benchmark for changing the owners of a multisig of `o` owners, measured on
+	/// `swap_owner`, which both removes and adds an owner.
+	///
+	/// The replaced owner is the last one, so finding them scans the whole owner list.
+	#[benchmark]
+	fn change_owners(o: Linear<2, { T::MaxOwners::get() }>) {
+		let owners = create_owners::<T>(o);
+		let multisig_id = new_multisig::<T>(&owners, o);
+		let sovereign = Multisig::<T>::multi_account_id(multisig_id);
+		let old = owners[o as usize - 1].clone();
+		let new: T::AccountId = account("new_owner", 0, SEED);
+
+		#[extrinsic_call]
+		swap_owner(RawOrigin::Signed(sovereign), multisig_id, old, new.clone());
+
+		assert!(Multisig::<T>::multisigs(multisig_id).unwrap().owners.contains(&new));
+	}
+
+	/// Benchmark for bumping the signing nonce of a multisig holding `p` pre-approved call
+	/// hashes, all of which are cleared.
+	#[benchmark]
+	fn bump_signing_nonce(p: Linear<0, { T::MaxPendingProposals::get() }>) {
+		let owners = create_owners::<T>(2);
+		let multisig_id = new_multisig::<T>(&owners, 2);
+		for i in 0..p {
+			assert_ok!(Multisig::<T>::pre_approve(
+				RawOrigin::Signed(owners[0].clone()).into(),
+				multisig_id,
+				T::Hashing::hash_of(&i),
+			));
+		}
+		let sovereign = Multisig::<T>::multi_account_id(multisig_id);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(sovereign), multisig_id);
+
+		assert_eq!(Multisig::<T>::pre_approval_count(multisig_id), 0);
+	}
+
+	/// Benchmark for paying out `n` payrolls of `p` payees each, all falling due in the same
+	/// block.
+	#[benchmark]
+	fn pay_payrolls(
+		n: Linear<0, { T::MaxPayrollsPerBlock::get() }>,
+		p: Linear<1, { T::MaxPayees::get() }>,
+	) {
+		let owners = create_owners::<T>(1);
+		let due = frame_system::Pallet::<T>::block_number() + One::one();
+		let amount = T::Currency::minimum_balance() * 10u32.into();
+		let payees: Vec<_> = (0..p).map(|i| (account("payee", i, SEED), amount)).collect();
+		for _ in 0..n {
+			let multisig_id = new_multisig::<T>(&owners, 1);
+			let sovereign = Multisig::<T>::multi_account_id(multisig_id);
+			T::Currency::make_free_balance_be(
+				&sovereign,
+				T::Currency::minimum_balance() * (20 * p).into(),
+			);
+			assert_ok!(Multisig::<T>::set_payroll(
+				RawOrigin::Signed(sovereign).into(),
+				multisig_id,
+				payees.clone(),
+				One::one(),
+				due,
+			));
+		}
+
+		#[block]
+		{
+			Multisig::<T>::on_initialize(due);
+		}
+
+		if n > 0 {
+			assert_eq!(T::Currency::free_balance(&payees[0].0), amount * n.into());
+		}
+	}
+
+	/// Benchmark for executing `n` proposals at once, each approved by every owner of a
+	/// multisig with the maximum number of owners.
+	#[benchmark]
+	fn batch_execute(n: Linear<1, { T::MaxPendingProposals::get() }>) {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, owners.len() as u32);
+		let mut proposals = Vec::new();
+		for _ in 0..n {
+			let (call, proposal_index) = submit_proposals::<T>(&owners[0], multisig_id, 1);
+			confirm_by::<T>(&owners[1..], multisig_id, proposal_index);
+			proposals.push((proposal_index, Box::new(call)));
+		}
+		let proposals: BatchCallsOf<T> = proposals.try_into().expect("at most one per slot");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owners[0].clone()), multisig_id, proposals);
+
+		assert_eq!(Multisig::<T>::pending_proposal_count(multisig_id), 0);
+	}
+
+	/// Benchmark for pre-approving a call hash that all but one owner of a multisig with the
+	/// maximum number of owners have already pre-approved.
+	#[benchmark]
+	fn pre_approve() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let call_hash = T::Hashing::hash_of(&0u32);
+		let (caller, others) = owners.split_last().expect("at least one owner");
+		for owner in others {
+			assert_ok!(Multisig::<T>::pre_approve(
+				RawOrigin::Signed(owner.clone()).into(),
+				multisig_id,
+				call_hash,
+			));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), multisig_id, call_hash);
+
+		assert_eq!(Multisig::<T>::pre_approvals(multisig_id, call_hash).len(), owners.len());
+	}
+
+	/// Benchmark for withdrawing the last of the maximum number of pre-approvals of a call
+	/// hash.
+	#[benchmark]
+	fn cancel_pre_approval() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let call_hash = T::Hashing::hash_of(&0u32);
+		for owner in &owners {
+			assert_ok!(Multisig::<T>::pre_approve(
+				RawOrigin::Signed(owner.clone()).into(),
+				multisig_id,
+				call_hash,
+			));
+		}
+		let caller = owners[owners.len() - 1].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, call_hash);
+
+		assert_eq!(Multisig::<T>::pre_approvals(multisig_id, call_hash).len(), owners.len() - 1);
+	}
+
+	/// Benchmark for submitting a typed transfer, which also earmarks the funds it will spend,
+	/// to a multisig with the maximum number of owners and all but one proposal slot taken.
+	#[benchmark]
+	fn submit_typed_proposal() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, owners.len() as u32);
+		let p = T::MaxPendingProposals::get();
+		if p > 1 {
+			submit_proposals::<T>(&owners[0], multisig_id, p - 1);
+		}
+		let amount = T::Currency::minimum_balance() * 10u32.into();
+		T::Currency::make_free_balance_be(
+			&Multisig::<T>::multi_account_id(multisig_id),
+			amount * 2u32.into(),
+		);
+		let proposal = TypedProposal::Transfer { dest: account("dest", 0, SEED), amount };
+		let caller = owners[owners.len() - 1].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, proposal);
+
+		assert_eq!(Multisig::<T>::pending_proposal_count(multisig_id), p);
+	}
+
+	/// Benchmark for granting a standing approval to an owner holding all but one of the
+	/// standing approvals they may have.
+	#[benchmark]
+	fn grant_standing_approval() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let caller = owners[owners.len() - 1].clone();
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let max_amount = T::Currency::minimum_balance();
+		let expires_at = frame_system::Pallet::<T>::block_number() + One::one();
+		for _ in 1..T::MaxStandingApprovals::get() {
+			assert_ok!(Multisig::<T>::grant_standing_approval(
+				RawOrigin::Signed(caller.clone()).into(),
+				multisig_id,
+				dest.clone(),
+				max_amount,
+				expires_at,
+			));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), multisig_id, dest, max_amount, expires_at);
+
+		assert_eq!(
+			Multisig::<T>::standing_approvals(multisig_id, &caller).len() as u32,
+			T::MaxStandingApprovals::get()
+		);
+	}
+
+	/// Benchmark for revoking the first of the maximum number of standing approvals, which
+	/// shifts all the others.
+	#[benchmark]
+	fn revoke_standing_approval() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let caller = owners[0].clone();
+		let expires_at = frame_system::Pallet::<T>::block_number() + One::one();
+		for _ in 0..T::MaxStandingApprovals::get() {
+			assert_ok!(Multisig::<T>::grant_standing_approval(
+				RawOrigin::Signed(caller.clone()).into(),
+				multisig_id,
+				account("dest", 0, SEED),
+				T::Currency::minimum_balance(),
+				expires_at,
+			));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), multisig_id, 0);
+
+		assert_eq!(
+			Multisig::<T>::standing_approvals(multisig_id, &caller).len() as u32,
+			T::MaxStandingApprovals::get() - 1
+		);
+	}
+
+	/// Benchmark for requiring every owner of a multisig with the maximum number of owners to
+	/// approve.
+	#[benchmark]
+	fn set_required_approvers() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, owners.clone());
+
+		assert_eq!(Multisig::<T>::multisigs(multisig_id).unwrap().required_approvers, owners);
+	}
+
+	/// Benchmark for putting every owner of a multisig with the maximum number of owners into
+	/// the sign-off chain.
+	#[benchmark]
+	fn set_approval_order() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, owners.clone());
+
+		assert_eq!(Multisig::<T>::multisigs(multisig_id).unwrap().approval_order, owners);
+	}
+
+	/// Benchmark for setting the maximum number of owner groups, each holding every owner of a
+	/// multisig with the maximum number of owners.
+	#[benchmark]
+	fn set_group_quorum() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let groups = full_groups::<T>(&owners);
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, GroupRule::All, groups);
+
+		assert!(Multisig::<T>::group_quorums(multisig_id).is_some());
+	}
+
+	/// Benchmark for cloning a multisig with the maximum number of owners, each labelled and
+	/// weighted, a full group quorum and a threshold for every class of calls.
+	#[benchmark]
+	fn clone_multisig() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let source_id = new_multisig::<T>(&owners, 1);
+		label_and_weigh::<T>(source_id, &owners);
+		set_class_thresholds::<T>(source_id, 1);
+		assert_ok!(Multisig::<T>::set_group_quorum(
+			sovereign::<T>(source_id).into(),
+			source_id,
+			GroupRule::All,
+			full_groups::<T>(&owners),
+		));
+		let multisig_id = Multisig::<T>::next_multisig_id();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owners[0].clone()), source_id);
+
+		assert!(Multisig::<T>::current_config_commitment(multisig_id).is_some());
+	}
+
+	/// Benchmark for registering a template with the maximum number of roles, all of which are
+	/// required and in the sign-off chain.
+	#[benchmark]
+	fn register_template() -> Result<(), BenchmarkError> {
+		let origin =
+			T::TemplateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let name = max_name::<T>().to_vec();
+		let roles = vec![name.clone(); T::MaxOwners::get() as usize];
+		let indices: Vec<u32> = (0..T::MaxOwners::get()).collect();
+		let template_id = Multisig::<T>::next_template_id();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, name, roles, 1, indices.clone(), indices);
+
+		assert!(Multisig::<T>::templates(template_id).is_some());
+		Ok(())
+	}
+
+	/// Benchmark for removing a template with the maximum number of roles.
+	#[benchmark]
+	fn remove_template() -> Result<(), BenchmarkError> {
+		let origin =
+			T::TemplateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let template_id = full_template::<T>()?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, template_id);
+
+		assert!(Multisig::<T>::templates(template_id).is_none());
+		Ok(())
+	}
+
+	/// Benchmark for creating a multisig from a template with the maximum number of roles, all
+	/// of which are required and in the sign-off chain.
+	#[benchmark]
+	fn create_from_template() -> Result<(), BenchmarkError> {
+		let template_id = full_template::<T>()?;
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = Multisig::<T>::next_multisig_id();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owners[0].clone()), template_id, owners.clone());
+
+		assert_eq!(Multisig::<T>::multisigs(multisig_id).unwrap().required_approvers, owners);
+		Ok(())
+	}
+
+	/// Benchmark for submitting a proposal with a content reference of the maximum length, to
+	/// a multisig with the maximum number of owners and all but one proposal slot taken.
+	#[benchmark]
+	fn submit_proposal_with_content() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, owners.len() as u32);
+		let p = T::MaxPendingProposals::get();
+		if p > 1 {
+			submit_proposals::<T>(&owners[0], multisig_id, p - 1);
+		}
+		let caller = owners[owners.len() - 1].clone();
+		let content_ref = vec![0; T::MaxContentRefLength::get() as usize];
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, Box::new(remark::<T>()), content_ref);
+
+		assert_eq!(Multisig::<T>::pending_proposal_count(multisig_id), p);
+	}
+
+	/// Benchmark for capping what the contract proposals of a multisig may spend.
+	#[benchmark]
+	fn set_contract_limits() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let limits =
+			ContractLimits { max_value: T::Currency::minimum_balance(), max_gas: Weight::MAX };
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, Some(limits));
+
+		assert!(Multisig::<T>::contract_limits(multisig_id).is_some());
+	}
+
+	/// Benchmark for capping what the EVM proposals of a multisig may spend.
+	#[benchmark]
+	fn set_evm_limits() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let limits = EvmLimits { max_value: U256::MAX, max_gas: u64::MAX };
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, Some(limits));
+
+		assert!(Multisig::<T>::evm_limits(multisig_id).is_some());
+	}
+
+	/// Benchmark for tracking a treasury spend by the last owner of a multisig with the
+	/// maximum number of owners.
+	#[benchmark]
+	fn track_inbound_spend() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let caller = owners[owners.len() - 1].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, 0, T::Currency::minimum_balance());
+
+		assert!(Multisig::<T>::inbound_spends(multisig_id, 0).is_some());
+	}
+
+	/// Benchmark for untracking a treasury spend by the last owner of a multisig with the
+	/// maximum number of owners.
+	#[benchmark]
+	fn untrack_inbound_spend() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let caller = owners[owners.len() - 1].clone();
+		assert_ok!(Multisig::<T>::track_inbound_spend(
+			RawOrigin::Signed(caller.clone()).into(),
+			multisig_id,
+			0,
+			T::Currency::minimum_balance(),
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, 0);
+
+		assert!(Multisig::<T>::inbound_spends(multisig_id, 0).is_none());
+	}
+
+	/// Benchmark for appointing the maximum number of auditors.
+	#[benchmark]
+	fn set_auditors() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let auditors = outsiders::<T>("auditor", T::MaxOwners::get());
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, auditors.clone());
+
+		assert_eq!(Multisig::<T>::auditors(multisig_id), auditors);
+	}
+
+	/// Benchmark for the last of the maximum number of auditors flagging a proposal every
+	/// other auditor has flagged, with a reason of the maximum length.
+	#[benchmark]
+	fn flag_proposal() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let auditors = outsiders::<T>("auditor", T::MaxOwners::get());
+		assert_ok!(Multisig::<T>::set_auditors(
+			sovereign::<T>(multisig_id).into(),
+			multisig_id,
+			auditors.clone(),
+		));
+		let (_, proposal_index) = submit_proposals::<T>(&owners[0], multisig_id, 1);
+		let reason = vec![0; T::MaxFlagReasonLength::get() as usize];
+		let (caller, others) = auditors.split_last().expect("at least one auditor");
+		for auditor in others {
+			assert_ok!(Multisig::<T>::flag_proposal(
+				RawOrigin::Signed(auditor.clone()).into(),
+				multisig_id,
+				proposal_index,
+				reason.clone(),
+			));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), multisig_id, proposal_index, reason);
+
+		assert_eq!(
+			Multisig::<T>::proposal_flags(multisig_id, proposal_index).len(),
+			auditors.len()
+		);
+	}
+
+	/// Benchmark for the overhead of dispatching a call as a multisig, on top of the call's
+	/// own weight.
+	#[benchmark]
+	fn dispatch_as_multisig() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, multisig_id, Box::new(remark::<T>()));
+	}
+
+	/// Benchmark for protecting a reserve of a multisig's balance.
+	#[benchmark]
+	fn set_protected_reserve() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let amount = T::Currency::minimum_balance();
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, Some(amount));
+
+		assert_eq!(Multisig::<T>::protected_reserve(multisig_id), Some(amount));
+	}
+
+	/// Benchmark for setting a payroll with the maximum number of payees.
+	#[benchmark]
+	fn set_payroll() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let payees = full_payees::<T>();
+		// Clear of the block the benchmark itself may be run in.
+		let first_payout = frame_system::Pallet::<T>::block_number() + 2u32.into();
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, payees, One::one(), first_payout);
+
+		assert!(Multisig::<T>::payrolls(multisig_id).is_some());
+	}
+
+	/// Benchmark for pausing a payroll with the maximum number of payees.
+	#[benchmark]
+	fn set_payroll_paused() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		set_full_payroll::<T>(multisig_id);
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, true);
+
+		assert!(Multisig::<T>::payrolls(multisig_id).unwrap().paused);
+	}
+
+	/// Benchmark for cancelling a payroll with the maximum number of payees.
+	#[benchmark]
+	fn cancel_payroll() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		set_full_payroll::<T>(multisig_id);
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id);
+
+		assert!(Multisig::<T>::payrolls(multisig_id).is_none());
+	}
+
+	/// Benchmark for granting an operator session.
+	#[benchmark]
+	fn grant_session() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let operator: T::AccountId = account("operator", 0, SEED);
+
+		#[extrinsic_call]
+		_(
+			sovereign::<T>(multisig_id),
+			multisig_id,
+			operator.clone(),
+			session_scope::<T>(),
+			One::one(),
+		);
+
+		assert!(Multisig::<T>::operator_sessions(multisig_id, &operator).is_some());
+	}
+
+	/// Benchmark for the last owner of a multisig with the maximum number of owners revoking
+	/// an operator session.
+	#[benchmark]
+	fn revoke_session() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let operator: T::AccountId = account("operator", 0, SEED);
+		assert_ok!(Multisig::<T>::grant_session(
+			sovereign::<T>(multisig_id).into(),
+			multisig_id,
+			operator.clone(),
+			session_scope::<T>(),
+			One::one(),
+		));
+		let caller = owners[owners.len() - 1].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, operator.clone());
+
+		assert!(Multisig::<T>::operator_sessions(multisig_id, &operator).is_none());
+	}
+
+	/// Benchmark for the overhead of an operator dispatching a call under a velocity limit, on
+	/// top of the call's own weight.
+	#[benchmark]
+	fn operator_execute() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let operator: T::AccountId = account("operator", 0, SEED);
+		assert_ok!(Multisig::<T>::grant_session(
+			sovereign::<T>(multisig_id).into(),
+			multisig_id,
+			operator.clone(),
+			session_scope::<T>(),
+			One::one(),
+		));
+		set_velocity_limit_of::<T>(multisig_id);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(operator), multisig_id, Box::new(remark::<T>()));
+	}
+
+	/// Benchmark for submitting a proposal and funding the multisig, to a multisig with the
+	/// maximum number of owners and all but one proposal slot taken.
+	#[benchmark]
+	fn submit_proposal_with_funds() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, owners.len() as u32);
+		let p = T::MaxPendingProposals::get();
+		if p > 1 {
+			submit_proposals::<T>(&owners[0], multisig_id, p - 1);
+		}
+		let caller = owners[owners.len() - 1].clone();
+		let amount = T::Currency::minimum_balance() * 10u32.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, Box::new(remark::<T>()), amount);
+
+		assert_eq!(Multisig::<T>::pending_proposal_count(multisig_id), p);
+	}
+
+	/// Benchmark for labelling the last owner of a multisig with the maximum number of owners
+	/// with a name of the maximum length.
+	#[benchmark]
+	fn set_owner_label() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let owner = owners[owners.len() - 1].clone();
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, owner.clone(), Some(max_name::<T>()));
+
+		assert!(Multisig::<T>::owner_labels(multisig_id).iter().any(|(who, _)| *who == owner));
+	}
+
+	/// Benchmark for suspending an owner of a multisig with the maximum number of owners, all
+	/// but two of them suspended already, while a full group quorum has to stay reachable.
+	#[benchmark]
+	fn suspend_owner() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		assert_ok!(Multisig::<T>::set_group_quorum(
+			sovereign::<T>(multisig_id).into(),
+			multisig_id,
+			GroupRule::All,
+			full_groups::<T>(&owners),
+		));
+		set_class_thresholds::<T>(multisig_id, 1);
+		let o = owners.len();
+		for owner in owners.iter().take(o.saturating_sub(2)) {
+			assert_ok!(Multisig::<T>::suspend_owner(
+				sovereign::<T>(multisig_id).into(),
+				multisig_id,
+				owner.clone(),
+			));
+		}
+		let owner = owners[o.saturating_sub(2)].clone();
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, owner.clone());
+
+		assert!(Multisig::<T>::suspended_owners(multisig_id).contains(&owner));
+	}
+
+	/// Benchmark for reinstating the last of all but one suspended owners of a multisig with
+	/// the maximum number of owners.
+	#[benchmark]
+	fn reinstate_owner() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let suspended = &owners[..owners.len() - 1];
+		for owner in suspended {
+			assert_ok!(Multisig::<T>::suspend_owner(
+				sovereign::<T>(multisig_id).into(),
+				multisig_id,
+				owner.clone(),
+			));
+		}
+		let owner = suspended[suspended.len().saturating_sub(1)].clone();
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, owner.clone());
+
+		assert!(!Multisig::<T>::suspended_owners(multisig_id).contains(&owner));
+	}
+
+	/// Benchmark for setting the maximum number of backup owners.
+	#[benchmark]
+	fn set_backup_owners() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let backup = outsiders::<T>("backup", T::MaxOwners::get());
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, backup, 1, One::one());
+
+		assert!(Multisig::<T>::backup_owners(multisig_id).is_some());
+	}
+
+	/// Benchmark for clearing the maximum number of backup owners.
+	#[benchmark]
+	fn clear_backup_owners() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		assert_ok!(Multisig::<T>::set_backup_owners(
+			sovereign::<T>(multisig_id).into(),
+			multisig_id,
+			outsiders::<T>("backup", T::MaxOwners::get()),
+			1,
+			One::one(),
+		));
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id);
+
+		assert!(Multisig::<T>::backup_owners(multisig_id).is_none());
+	}
+
+	/// Benchmark for handing a multisig with the maximum number of labelled and weighted owners
+	/// over to the maximum number of backup owners.
+	#[benchmark]
+	fn activate_backup_owners() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		label_and_weigh::<T>(multisig_id, &owners);
+		set_class_thresholds::<T>(multisig_id, 1);
+		let backup = outsiders::<T>("backup", T::MaxOwners::get());
+		assert_ok!(Multisig::<T>::set_backup_owners(
+			sovereign::<T>(multisig_id).into(),
+			multisig_id,
+			backup.clone(),
+			1,
+			One::one(),
+		));
+		let now = frame_system::Pallet::<T>::block_number();
+		frame_system::Pallet::<T>::set_block_number(now + One::one());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owners[0].clone()), multisig_id);
+
+		assert_eq!(Multisig::<T>::multisigs(multisig_id).unwrap().owners, backup);
+	}
+
+	/// Benchmark for exempting a multisig from deposits, measured on
+	/// `set_multisig_deposit_exemption`, which also checks that the multisig exists.
+	#[benchmark]
+	fn set_deposit_exemption() -> Result<(), BenchmarkError> {
+		let origin = T::DepositExemptionOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+
+		#[extrinsic_call]
+		set_multisig_deposit_exemption(origin as T::RuntimeOrigin, multisig_id, true);
+
+		Ok(())
+	}
+
+	/// Benchmark for setting the velocity limit of a multisig with the maximum number of
+	/// owners, whose total weight bounds the override threshold.
+	#[benchmark]
+	fn set_velocity_limit() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, Some(velocity_limit::<T>()));
+
+		assert!(Multisig::<T>::velocity_limit(multisig_id).is_some());
+	}
+
+	/// Benchmark for capping the number of active multisigs.
+	#[benchmark]
+	fn set_max_active_multisigs() -> Result<(), BenchmarkError> {
+		let origin =
+			T::WalletCapOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Some(u32::MAX));
+
+		assert_eq!(Multisig::<T>::max_active_multisigs(), Some(u32::MAX));
+		Ok(())
+	}
+
+	/// Benchmark for uploading a chunk of `b` bytes of a call, by the last owner of a multisig
+	/// with the maximum number of owners.
+	#[benchmark]
+	fn note_call_chunk(b: Linear<1, { T::MaxCallSize::get() }>) {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let caller = owners[owners.len() - 1].clone();
+		let chunk: CallDataOf<T> = vec![0; b as usize].try_into().expect("at most `MaxCallSize`");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), multisig_id, chunk);
+
+		assert_eq!(Multisig::<T>::call_uploads(multisig_id, &caller).unwrap().data.len() as u32, b);
+	}
+
+	/// Benchmark for discarding an upload of the maximum call size.
+	#[benchmark]
+	fn discard_call_upload() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let caller = owners[0].clone();
+		let chunk: CallDataOf<T> = vec![0; T::MaxCallSize::get() as usize]
+			.try_into()
+			.expect("exactly `MaxCallSize`");
+		assert_ok!(Multisig::<T>::note_call_chunk(
+			RawOrigin::Signed(caller.clone()).into(),
+			multisig_id,
+			chunk,
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), multisig_id);
+
+		assert!(Multisig::<T>::call_uploads(multisig_id, &caller).is_none());
+	}
+
+	/// Benchmark for the last of `o` owners withdrawing their approval of a proposal all of
+	/// them have approved, so their approval deposit is refunded.
+	#[benchmark]
+	fn revoke_approval(o: Linear<2, { T::MaxOwners::get() }>) {
+		let owners = create_owners::<T>(o);
+		let multisig_id = new_multisig::<T>(&owners, o);
+		let (_, proposal_index) = submit_proposals::<T>(&owners[0], multisig_id, 1);
+		confirm_by::<T>(&owners[1..], multisig_id, proposal_index);
+		let caller = owners[o as usize - 1].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, proposal_index);
+
+		assert_eq!(Multisig::<T>::approvals(multisig_id, proposal_index).len() as u32, o - 1);
+	}
+
+	/// Benchmark for an owner delegating their confirmations while each of the other `o - 1`
+	/// owners has a delegation, all of which are searched for the new delegate.
+	#[benchmark]
+	fn delegate_confirmations(o: Linear<1, { T::MaxOwners::get() }>) {
+		let owners = create_owners::<T>(o);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let delegates = outsiders::<T>("delegate", o);
+		for (owner, delegate) in owners.iter().zip(&delegates).skip(1) {
+			assert_ok!(Multisig::<T>::delegate_confirmations(
+				RawOrigin::Signed(owner.clone()).into(),
+				multisig_id,
+				delegate.clone(),
+				One::one(),
+			));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owners[0].clone()), multisig_id, delegates[0].clone(), One::one());
+
+		assert_eq!(Multisig::<T>::delegator_of(multisig_id, delegates[0].clone()), owners[0]);
+	}
+
+	/// Benchmark for weighting an owner of a multisig with the maximum number of owners, half
+	/// of them suspended, a threshold for every class of calls and a velocity limit, all of
+	/// which must stay reachable.
+	#[benchmark]
+	fn set_owner_weight() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		set_class_thresholds::<T>(multisig_id, 1);
+		set_velocity_limit_of::<T>(multisig_id);
+		for owner in owners.iter().skip(1).step_by(2) {
+			assert_ok!(Multisig::<T>::suspend_owner(
+				sovereign::<T>(multisig_id).into(),
+				multisig_id,
+				owner.clone(),
+			));
+		}
+		let owner = owners[owners.len() - 1].clone();
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, owner.clone(), 2);
+
+		assert_eq!(Multisig::<T>::vote_weight(multisig_id, &owner), 2);
+	}
+
+	/// Benchmark for setting a class threshold of a multisig with the maximum number of
+	/// owners, whose total weight bounds it.
+	#[benchmark]
+	fn set_class_threshold() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, CallClass::Admin, Some(owners.len() as u32));
+
+		assert!(Multisig::<T>::class_threshold(multisig_id, CallClass::Admin).is_some());
+	}
+
+	/// Benchmark for setting a call filter with the maximum number of patterns.
+	#[benchmark]
+	fn set_call_filter() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let patterns = (0..T::MaxCallFilterEntries::get())
+			.map(|i| CallPattern { pallet_index: i as u8, call_index: Some(0) })
+			.collect();
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, Some((FilterMode::Allow, patterns)));
+
+		assert!(Multisig::<T>::call_filter(multisig_id).is_some());
+	}
+
+	/// Benchmark for setting the spending limit of a multisig.
+	#[benchmark]
+	fn set_spending_limit() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let limit = SpendingLimit { period: One::one(), amount: BalanceOf::<T>::max_value() };
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, Some(limit));
+
+		assert!(Multisig::<T>::spending_limit(multisig_id).is_some());
+	}
+
+	/// Benchmark for the last owner of a multisig with the maximum number of owners spending
+	/// from its allowance, checked against a protected reserve and a velocity limit.
+	#[benchmark]
+	fn spend_allowance() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let minimum = T::Currency::minimum_balance();
+		T::Currency::make_free_balance_be(
+			&Multisig::<T>::multi_account_id(multisig_id),
+			minimum * 100u32.into(),
+		);
+		let limit = SpendingLimit { period: One::one(), amount: BalanceOf::<T>::max_value() };
+		assert_ok!(Multisig::<T>::set_spending_limit(
+			sovereign::<T>(multisig_id).into(),
+			multisig_id,
+			Some(limit),
+		));
+		assert_ok!(Multisig::<T>::set_protected_reserve(
+			sovereign::<T>(multisig_id).into(),
+			multisig_id,
+			Some(minimum),
+		));
+		set_velocity_limit_of::<T>(multisig_id);
+		let caller = owners[owners.len() - 1].clone();
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let amount = minimum * 10u32.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, dest.clone(), amount);
+
+		assert_eq!(T::Currency::free_balance(&dest), amount);
+	}
+
+	/// Benchmark for setting the execution delay of a multisig.
+	#[benchmark]
+	fn set_execution_delay() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, Some(One::one()));
+
+		assert!(<ExecutionDelays<T>>::contains_key(multisig_id));
+	}
+
+	/// Benchmark for appointing the maximum number of vetoers.
+	#[benchmark]
+	fn set_vetoers() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let vetoers = outsiders::<T>("vetoer", T::MaxOwners::get());
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, vetoers.clone());
+
+		assert_eq!(Multisig::<T>::vetoers(multisig_id), vetoers);
+	}
+
+	/// Benchmark for configuring recovery by the maximum number of guardians.
+	#[benchmark]
+	fn set_recovery_config() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let guardians = outsiders::<T>("guardian", T::MaxOwners::get());
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, guardians, 1, Zero::zero());
+
+		assert!(Multisig::<T>::recovery_config(multisig_id).is_some());
+	}
+
+	/// Benchmark for removing the recovery configuration of a multisig, along with a recovery
+	/// in progress to the maximum number of owners.
+	#[benchmark]
+	fn remove_recovery_config() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let guardians = set_full_recovery_config::<T>(multisig_id);
+		initiate_full_recovery::<T>(multisig_id, &guardians[0]);
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id);
+
+		assert!(Multisig::<T>::active_recovery(multisig_id).is_none());
+	}
+
+	/// Benchmark for the last of the maximum number of guardians starting a recovery to the
+	/// maximum number of owners.
+	#[benchmark]
+	fn initiate_recovery() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let guardians = set_full_recovery_config::<T>(multisig_id);
+		let caller = guardians[guardians.len() - 1].clone();
+		let new_owners = outsiders::<T>("new_owner", T::MaxOwners::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id, new_owners, 1);
+
+		assert!(Multisig::<T>::active_recovery(multisig_id).is_some());
+	}
+
+	/// Benchmark for the last of the maximum number of guardians approving a recovery every
+	/// other guardian has approved.
+	#[benchmark]
+	fn approve_recovery() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let guardians = set_full_recovery_config::<T>(multisig_id);
+		let (caller, others) = guardians.split_last().expect("at least one guardian");
+		initiate_full_recovery::<T>(multisig_id, &others[0]);
+		for guardian in &others[1..] {
+			assert_ok!(Multisig::<T>::approve_recovery(
+				RawOrigin::Signed(guardian.clone()).into(),
+				multisig_id,
+			));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), multisig_id);
+
+		assert_eq!(
+			Multisig::<T>::active_recovery(multisig_id).unwrap().approvals.len(),
+			guardians.len()
+		);
+	}
+
+	/// Benchmark for handing a multisig with the maximum number of labelled and weighted owners
+	/// over to the maximum number of new owners, approved by every one of the maximum number of
+	/// guardians.
+	#[benchmark]
+	fn claim_recovery() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		label_and_weigh::<T>(multisig_id, &owners);
+		set_class_thresholds::<T>(multisig_id, 1);
+		let guardians = set_full_recovery_config::<T>(multisig_id);
+		let new_owners = initiate_full_recovery::<T>(multisig_id, &guardians[0]);
+		for guardian in &guardians[1..] {
+			assert_ok!(Multisig::<T>::approve_recovery(
+				RawOrigin::Signed(guardian.clone()).into(),
+				multisig_id,
+			));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owners[0].clone()), multisig_id);
+
+		assert_eq!(Multisig::<T>::multisigs(multisig_id).unwrap().owners, new_owners);
+	}
+
+	/// Benchmark for the last owner of a multisig with the maximum number of owners cancelling
+	/// a recovery to the maximum number of owners.
+	#[benchmark]
+	fn cancel_recovery() {
+		let owners = create_owners::<T>(T::MaxOwners::get());
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let guardians = set_full_recovery_config::<T>(multisig_id);
+		initiate_full_recovery::<T>(multisig_id, &guardians[0]);
+		let caller = owners[owners.len() - 1].clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id);
+
+		assert!(Multisig::<T>::active_recovery(multisig_id).is_none());
+	}
+
+	/// Benchmark for setting the threshold a multisig decays to when inactive.
+	#[benchmark]
+	fn set_threshold_decay() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let decay = ThresholdDecay { inactivity_period: One::one(), threshold: 1 };
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, Some(decay));
+
+		assert!(Multisig::<T>::threshold_decay(multisig_id).is_some());
+	}
+
+	/// Benchmark for revoking a delegation of confirmations.
+	#[benchmark]
+	fn revoke_delegation() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let caller = owners[0].clone();
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		assert_ok!(Multisig::<T>::delegate_confirmations(
+			RawOrigin::Signed(caller.clone()).into(),
+			multisig_id,
+			delegate.clone(),
+			One::one(),
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), multisig_id);
+
+		assert_eq!(Multisig::<T>::delegator_of(multisig_id, delegate.clone()), delegate);
+	}
+
+	/// Benchmark for the overhead of dispatching a call from a sub-account of a multisig, on
+	/// top of the call's own weight.
+	#[benchmark]
+	fn dispatch_as_sub() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+
+		#[extrinsic_call]
+		_(sovereign::<T>(multisig_id), multisig_id, 0, Box::new(remark::<T>()));
+	}
+
+	/// Benchmark for checking an owner's signature over the confirmation payload of a
+	/// proposal.
+	#[benchmark]
+	fn verify_signature() {
+		let owners = create_owners::<T>(1);
+		let multisig_id = new_multisig::<T>(&owners, 1);
+		let (_, proposal_index) = submit_proposals::<T>(&owners[0], multisig_id, 1);
+		let payload = Multisig::<T>::confirmation_payload(multisig_id, proposal_index)
+			.expect("the proposal exists");
+		let (signer, signature) = T::BenchmarkHelper::sign_message(&payload);
+		let owner = signer.into_account();
+		let verified;
+
+		#[block]
+		{
+			verified = signature.verify(&payload[..], &owner);
+		}
+
+		assert!(verified);
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::mock::new_test_ext(), crate::mock::Test);
-}
\ No newline at end of file
+}
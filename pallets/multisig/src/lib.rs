@@ -9,14 +9,30 @@ mod mock;
 mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
-
 mod benchmarking;
 
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+
+pub mod address;
+
+pub mod members;
+
+pub mod migrations;
+pub use members::*;
+
+pub mod origin;
+pub use origin::*;
+
+pub mod runtime_api;
+
+pub mod traits;
+pub use traits::*;
+
 pub mod weight;
 pub use weight::WeightInfo;
 
 /// A pallet for creating and managing stateful, on-chain multisig wallets.
-
 ///
 /// The core lifecycle is as follows:
 /// 1. **Creation:** A user creates a wallet with a set of owners and an approval threshold.
@@ -27,23 +43,43 @@ pub use weight::WeightInfo;
 /// 5. **Destruction:** The wallet can be safely destroyed through a self-governed proposal,
 ///    ensuring all associated storage is cleaned up.
 #[frame_support::pallet]
+// The call macro converts the `PostDispatchInfo` returned by calls into itself.
+#[allow(clippy::useless_conversion)]
 pub mod pallet {
 	use super::*;
+	use codec::DecodeLimit;
 	use frame_support::{
-		dispatch::GetDispatchInfo,
+		dispatch::{
+			extract_actual_weight, GetDispatchInfo, PostDispatchInfo, WithPostDispatchInfo,
+		},
 		pallet_prelude::*,
-		traits::{Currency, ReservableCurrency},
+		storage::with_storage_layer,
+		traits::{
+			BalanceStatus, Contains, Currency, ExistenceRequirement, InstanceFilter,
+			ReservableCurrency,
+		},
+		PalletId,
 	};
 	use frame_system::{pallet_prelude::*, RawOrigin};
-	use sp_io::hashing::blake2_256;
-	use sp_runtime::traits::{Dispatchable, TrailingZeroInput};
+	use sp_core::{H160, U256};
+	use sp_runtime::{
+		traits::{
+			AtLeast32BitUnsigned, Convert, Dispatchable, Hash, IdentifyAccount, One, Saturating,
+			Verify,
+		},
+		Perbill,
+	};
 	use sp_std::prelude::*;
 
+	/// The in-code storage version, bumped with each migration in `migrations`.
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config<RuntimeOrigin: From<Origin<Self>>> {
 		/// The overarching event type for the runtime.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -56,7 +92,7 @@ pub mod pallet {
 		/// The overarching call type for the runtime.
 		/// This allows a multisig to propose and dispatch calls from any other pallet.
 		type RuntimeCall: Parameter
-			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
 			+ GetDispatchInfo;
 
 		/// A configurable constant for the maximum number of owners a multisig wallet can have.
@@ -64,17 +100,438 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxOwners: Get<u32>;
 
+		/// The maximum number of unexecuted proposals a multisig can hold at once.
+		///
+		/// This bounds the cost of destroying a multisig, which is weighed against both the owner
+		/// count and the number of pending proposals.
+		#[pallet::constant]
+		type MaxPendingProposals: Get<u32>;
+
+		/// The maximum number of standing approvals a single owner can hold per multisig.
+		#[pallet::constant]
+		type MaxStandingApprovals: Get<u32>;
+
+		/// The maximum number of owner groups a multisig's quorum rule can reference.
+		#[pallet::constant]
+		type MaxGroups: Get<u32>;
+
+		/// The maximum length, in bytes, of a human-readable name stored by this pallet.
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+
+		/// The maximum length, in bytes, of an off-chain content reference attached to a
+		/// proposal, such as an IPFS CID or a document hash.
+		#[pallet::constant]
+		type MaxContentRefLength: Get<u32>;
+
+		/// The maximum length, in bytes, of the input data of a contract proposal.
+		#[pallet::constant]
+		type MaxContractDataLength: Get<u32>;
+
+		/// The maximum length, in bytes, of the reason attached to an auditor's flag.
+		#[pallet::constant]
+		type MaxFlagReasonLength: Get<u32>;
+
+		/// The length, in bytes, of the runtime's encoded session keys, against which session key
+		/// proposals are validated.
+		#[pallet::constant]
+		type SessionKeysLength: Get<u32>;
+
+		/// The maximum number of payees on a multisig's payroll.
+		#[pallet::constant]
+		type MaxPayees: Get<u32>;
+
+		/// The maximum number of payrolls that can fall due in the same block.
+		#[pallet::constant]
+		type MaxPayrollsPerBlock: Get<u32>;
+
+		/// The scopes an operator session can grant, each admitting a category of calls, such as
+		/// staking management.
+		type SessionScope: Parameter
+			+ Member
+			+ MaxEncodedLen
+			+ InstanceFilter<<Self as Config>::RuntimeCall>;
+
+		/// Decides whether a proposal has collected enough support to be executed.
+		type VoteTally: VoteTally<Self::AccountId>;
+
+		/// Computes the deposits reserved for creating multisigs, submitting proposals and
+		/// confirming them.
+		///
+		/// The calculator may read its amounts from storage, for example from
+		/// `pallet-parameters`, so a chain can adjust them through governance without a runtime
+		/// upgrade.
+		type DepositCalculator: DepositCalculator<BalanceOf<Self>>;
+
+		/// The origin allowed to exempt accounts and multisigs from deposits.
+		type DepositExemptionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin allowed to cap the number of active multisigs.
+		type WalletCapOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Estimates the fee charged for a given weight, for the usage meters.
+		type FeeEstimator: Convert<Weight, BalanceOf<Self>>;
+
+		/// The maximum size, in bytes, of a call uploaded in chunks.
+		#[pallet::constant]
+		type MaxCallSize: Get<u32>;
+
+		/// The maximum number of calls a multisig's call filter may list.
+		#[pallet::constant]
+		type MaxCallFilterEntries: Get<u32>;
+
+		/// The number of blocks a proposal stays open for, unless its proposer chose another
+		/// lifetime when submitting it.
+		#[pallet::constant]
+		type ProposalLifetime: Get<BlockNumberFor<Self>>;
+
+		/// The share of a proposer's submission deposit forfeited when their proposal expires
+		/// without being executed.
+		///
+		/// It is paid to whoever removes the expired proposal, funding its cleanup. Zero refunds
+		/// the whole deposit.
+		#[pallet::constant]
+		type ExpiredDepositSlash: Get<Perbill>;
+
+		/// The number of blocks a proposal must wait after meeting its threshold before it can
+		/// be executed, for multisigs that have not set their own delay.
+		///
+		/// The delay gives owners a window to spot a compromised quorum and revoke their
+		/// approvals. Proposals approved by every owner are never delayed.
+		#[pallet::constant]
+		type DefaultExecutionDelay: Get<BlockNumberFor<Self>>;
+
+		/// The identifier namespacing the accounts the pallet derives, such as wallets' sovereign
+		/// accounts, from those of other pallets.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum number of multisigs listed in an account's `OwnerToMultisigs` index.
+		#[pallet::constant]
+		type MaxMultisigsPerOwner: Get<u32>;
+
+		/// Called after each proposal is executed.
+		type OnProposalExecuted: OnProposalExecuted<Self>;
+
+		/// Called after each multisig is created.
+		type OnMultisigCreated: OnMultisigCreated<Self>;
+
+		/// Called after each multisig is destroyed.
+		type OnMultisigDestroyed: OnMultisigDestroyed<Self>;
+
+		/// The signature an owner makes off-chain over a signing payload, see
+		/// `confirmation_payload`.
+		type OffchainSignature: Verify<Signer = Self::SigningPublicKey> + Parameter;
+
+		/// The public key that verifies an `OffchainSignature`, identifying the owner who made
+		/// it.
+		type SigningPublicKey: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Signs confirmation payloads for the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::SigningPublicKey, Self::OffchainSignature>;
+
+		/// The origin allowed to register and remove wallet templates.
+		type TemplateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Converts typed proposals into the runtime calls they stand for.
+		type CallBuilder: TypedCallBuilder<Self>;
+
+		/// Reports non-fungible items held by a multisig's sovereign account.
+		type ItemCustody: ItemCustody<Self::AccountId>;
+
+		/// An external policy every proposal must satisfy before it is executed.
+		type PolicyCheck: PolicyCheck<Self>;
+
+		/// Sorts proposed calls into the classes a multisig may set separate thresholds for.
+		type CallClassifier: CallClassifier<Self>;
+
+		/// Looks up the proxies that may submit and confirm proposals on an owner's behalf.
+		type OwnerProxies: OwnerProxies<Self::AccountId>;
+
+		/// The accounts typed transfer proposals may pay out to, such as KYC'd addresses
+		/// maintained by a compliance pallet.
+		type RecipientGate: Contains<Self::AccountId>;
+
+		/// The critical calls, such as runtime upgrades, that every owner must approve whatever
+		/// the multisig's threshold.
+		type CriticalCalls: Contains<<Self as Config>::RuntimeCall>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// A unique identifier for a multisig wallet.
+		///
+		/// Wider types let long-lived deployments create wallets without ever exhausting the
+		/// counter.
+		type MultisigId: Parameter + Member + MaxEncodedLen + AtLeast32BitUnsigned + Copy + Default;
+
+		/// A unique identifier for a proposal within a specific multisig.
+		type ProposalIndex: Parameter
+			+ Member
+			+ MaxEncodedLen
+			+ AtLeast32BitUnsigned
+			+ Copy
+			+ Default;
 	}
 
 	// Custom Types
 
-	/// A unique identifier for a multisig wallet.
-	pub type MultisigId = u32;
+	/// A unique identifier for a wallet template.
+	pub type TemplateId = u32;
+
+	/// A bounded, human-readable name.
+	pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+	/// The hash of an encoded call, computed with the runtime's configured `Hashing`.
+	pub type CallHashOf<T> = <T as frame_system::Config>::Hash;
+
+	/// A bounded reference to off-chain content, such as an IPFS CID.
+	pub type ContentRefOf<T> = BoundedVec<u8, <T as Config>::MaxContentRefLength>;
+
+	/// A `Proposal` instantiated with the runtime's types.
+	pub type ProposalOf<T> = Proposal<
+		<T as frame_system::Config>::AccountId,
+		CallHashOf<T>,
+		ContentRefOf<T>,
+		BlockNumberFor<T>,
+		<T as Config>::MaxOwners,
+	>;
+
+	/// An auditor's flag on a proposal, with the reason for the objection.
+	pub type FlagOf<T> = (
+		<T as frame_system::Config>::AccountId,
+		BoundedVec<u8, <T as Config>::MaxFlagReasonLength>,
+	);
+
+	/// A `WalletTemplate` instantiated with the runtime's types.
+	pub type WalletTemplateOf<T> = WalletTemplate<NameOf<T>, <T as Config>::MaxOwners>;
+
+	/// A `Multisig` instantiated with the runtime's types.
+	pub type MultisigOf<T> =
+		Multisig<<T as frame_system::Config>::AccountId, <T as Config>::MaxOwners>;
+
+	/// The balance type of the configured `Currency`.
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// An `OwnerGroup` instantiated with the runtime's types.
+	pub type OwnerGroupOf<T> = OwnerGroup<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::MaxOwners,
+		<T as Config>::MaxNameLength,
+	>;
+
+	/// A `GroupQuorum` instantiated with the runtime's types.
+	pub type GroupQuorumOf<T> = GroupQuorum<OwnerGroupOf<T>, <T as Config>::MaxGroups>;
+
+	/// Bounded input data for a contract constructor or message.
+	pub type ContractDataOf<T> = BoundedVec<u8, <T as Config>::MaxContractDataLength>;
+
+	/// A `TypedProposal` instantiated with the runtime's types.
+	pub type TypedProposalOf<T> = TypedProposal<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::Hash,
+		ContractDataOf<T>,
+		BlockNumberFor<T>,
+	>;
+
+	/// A `ContractLimits` instantiated with the runtime's balance type.
+	pub type ContractLimitsOf<T> = ContractLimits<BalanceOf<T>>;
+
+	/// An `OperatorSession` instantiated with the runtime's types.
+	pub type OperatorSessionOf<T> = OperatorSession<<T as Config>::SessionScope, BlockNumberFor<T>>;
+
+	/// The deposits held for a proposal, with the account each was reserved from.
+	pub type ProposalDepositsOf<T> = BoundedVec<
+		(<T as frame_system::Config>::AccountId, BalanceOf<T>),
+		<T as Config>::MaxOwners,
+	>;
+
+	/// A `Payroll` instantiated with the runtime's types.
+	pub type PayrollOf<T> = Payroll<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		BlockNumberFor<T>,
+		<T as Config>::MaxPayees,
+	>;
+
+	/// A pending proposal as exported in a `WalletSnapshot`: its index, the proposal with its
+	/// approvals, its earmarked funds and the deposits held for it.
+	pub type OpenProposalOf<T> =
+		(<T as Config>::ProposalIndex, ProposalOf<T>, Option<BalanceOf<T>>, ProposalDepositsOf<T>);
+
+	/// The domain tag that opens every payload owners sign off-chain, so the signature can never
+	/// be replayed as anything other than a multisig approval.
+	pub const SIGNING_DOMAIN: &[u8] = b"pba/multisig/approval";
+
+	/// What an off-chain approval signature approves.
+	///
+	/// Only confirmations of pending proposals can be signed off-chain: a signed pre-approval
+	/// could be relayed again once consumed, and so counted for every later proposal of the
+	/// same call.
+	#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub enum ApprovalTarget<ProposalIndex, Hash> {
+		/// A pending proposal, confirmed with `confirm_proposal_with_signature`.
+		Confirmation {
+			/// The index of the proposal.
+			proposal_index: ProposalIndex,
+			/// The hash of the proposal's call.
+			call_hash: Hash,
+		},
+	}
+
+	/// The depth to which uploaded calls are decoded, matching the limit applied to extrinsics.
+	pub const MAX_CALL_DECODE_DEPTH: u32 = 256;
+
+	/// An `ApprovalTarget` instantiated with the runtime's types.
+	pub type ApprovalTargetOf<T> = ApprovalTarget<<T as Config>::ProposalIndex, CallHashOf<T>>;
+
+	/// The version of the `WalletSnapshot` encoding, bumped whenever its layout changes.
+	pub const SNAPSHOT_VERSION: u16 = 1;
+
+	/// A point-in-time export of everything the pallet stores about one multisig, for audits.
+	///
+	/// Auditors decode it with `version` to pick the matching layout.
+	#[derive(Encode, Decode, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct WalletSnapshot<T: Config> {
+		/// The layout version of this snapshot, see `SNAPSHOT_VERSION`.
+		pub version: u16,
+		/// The block at which the snapshot was taken.
+		pub block_number: BlockNumberFor<T>,
+		/// The sovereign account of the multisig.
+		pub account: T::AccountId,
+		/// The free and reserved balance of the sovereign account.
+		pub balance: (BalanceOf<T>, BalanceOf<T>),
+		/// The owners, threshold, mandatory approvers and sign-off order.
+		pub config: MultisigOf<T>,
+		/// The cross-group quorum rule, if any.
+		pub group_quorum: Option<GroupQuorumOf<T>>,
+		/// The contract proposal limits, if any.
+		pub contract_limits: Option<ContractLimitsOf<T>>,
+		/// The EVM proposal limits, if any.
+		pub evm_limits: Option<EvmLimits>,
+		/// The protected reserve, if any.
+		pub protected_reserve: Option<BalanceOf<T>>,
+		/// The read-only auditors.
+		pub auditors: Vec<T::AccountId>,
+		/// The veto accounts.
+		pub vetoers: Vec<T::AccountId>,
+		/// The suspended owners.
+		pub suspended_owners: Vec<T::AccountId>,
+		/// The labelled owners and their labels.
+		pub owner_labels: Vec<(T::AccountId, NameOf<T>)>,
+		/// The owners who have delegated their confirmation right, with their delegations.
+		pub delegations: Vec<(T::AccountId, DelegationOf<T>)>,
+		/// The owners whose vote weight is not one, with their weight.
+		pub owner_weights: Vec<(T::AccountId, u32)>,
+		/// The thresholds of the classes of calls that have their own.
+		pub class_thresholds: Vec<(CallClass, u32)>,
+		/// The filter restricting the calls the multisig may propose.
+		pub call_filter: Option<CallFilterOf<T>>,
+		/// The backup owner set, if any.
+		pub backup_owners: Option<BackupOwnersOf<T>>,
+		/// The guardian configuration, if any.
+		pub recovery: Option<RecoveryConfigOf<T>>,
+		/// The payroll, if any.
+		pub payroll: Option<PayrollOf<T>>,
+		/// The velocity limit, if any, and the executions made in its current period.
+		pub velocity: Option<(VelocityLimitOf<T>, ExecutionWindowOf<T>)>,
+		/// The spending limit, if any, and what has been spent in its current period.
+		pub spending: Option<(SpendingLimitOf<T>, SpendingWindowOf<T>)>,
+		/// The threshold decay policy, if any.
+		pub threshold_decay: Option<ThresholdDecayOf<T>>,
+		/// The block in which the multisig last executed a proposal, if it has.
+		pub last_executed_at: Option<BlockNumberFor<T>>,
+		/// The execution delay the multisig applies.
+		pub execution_delay: BlockNumberFor<T>,
+		/// The signing nonce.
+		pub signing_nonce: u64,
+		/// The usage meter.
+		pub usage: UsageMeterOf<T>,
+		/// The calls owners are uploading in chunks.
+		pub call_uploads: Vec<CallUploadOf<T>>,
+		/// The calls uploaded for pending proposals, by proposal index.
+		pub uploaded_calls: Vec<(T::ProposalIndex, CallUploadOf<T>)>,
+		/// The number of pending proposals.
+		pub pending_proposal_count: u32,
+		/// The index the next proposal will be given.
+		pub next_proposal_index: T::ProposalIndex,
+		/// Every pending proposal, with its approvals, earmarked funds and deposits.
+		pub open_proposals: Vec<OpenProposalOf<T>>,
+	}
+
+	/// A `VelocityLimit` instantiated with the runtime's types.
+	pub type VelocityLimitOf<T> = VelocityLimit<BalanceOf<T>, BlockNumberFor<T>>;
+
+	/// An `ExecutionWindow` instantiated with the runtime's types.
+	pub type ExecutionWindowOf<T> = ExecutionWindow<BalanceOf<T>, BlockNumberFor<T>>;
+
+	/// A `SpendingLimit` instantiated with the runtime's types.
+	pub type SpendingLimitOf<T> = SpendingLimit<BalanceOf<T>, BlockNumberFor<T>>;
+
+	/// A `Delegation` instantiated with the runtime's types.
+	pub type DelegationOf<T> =
+		Delegation<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+	/// A `ThresholdDecay` instantiated with the runtime's types.
+	pub type ThresholdDecayOf<T> = ThresholdDecay<BlockNumberFor<T>>;
+
+	/// A `SpendingWindow` instantiated with the runtime's types.
+	pub type SpendingWindowOf<T> = SpendingWindow<BalanceOf<T>, BlockNumberFor<T>>;
+
+	/// A `CallUpload` instantiated with the runtime's types.
+	pub type CallUploadOf<T> = CallUpload<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as Config>::MaxCallSize,
+	>;
+
+	/// A chunk of call data, or a whole uploaded call.
+	pub type CallDataOf<T> = BoundedVec<u8, <T as Config>::MaxCallSize>;
+
+	/// A `CallFilter` instantiated with the runtime's bound.
+	pub type CallFilterOf<T> = CallFilter<<T as Config>::MaxCallFilterEntries>;
+
+	/// The proposals of a batch execution, each with its full call.
+	pub type BatchCallsOf<T> = BoundedVec<
+		(<T as Config>::ProposalIndex, Box<<T as Config>::RuntimeCall>),
+		<T as Config>::MaxPendingProposals,
+	>;
+
+	/// A `UsageMeter` instantiated with the runtime's types.
+	pub type UsageMeterOf<T> = UsageMeter<BalanceOf<T>>;
+
+	/// The owners, threshold and optional endowment of a multisig created at genesis.
+	pub type GenesisMultisigOf<T> =
+		(Vec<<T as frame_system::Config>::AccountId>, u32, Option<BalanceOf<T>>);
+
+	/// A `BackupOwners` set instantiated with the runtime's types.
+	pub type BackupOwnersOf<T> = BackupOwners<
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T>,
+		<T as Config>::MaxOwners,
+	>;
+
+	/// A `RecoveryConfig` instantiated with the runtime's types.
+	pub type RecoveryConfigOf<T> = RecoveryConfig<
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T>,
+		<T as Config>::MaxOwners,
+	>;
+
+	/// An `ActiveRecovery` instantiated with the runtime's types.
+	pub type ActiveRecoveryOf<T> = ActiveRecovery<
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T>,
+		<T as Config>::MaxOwners,
+	>;
 
-	/// A unique identifier for a proposal within a specific multisig.
-	pub type ProposalIndex = u32;
+	/// A `StandingApproval` instantiated with the runtime's types.
+	pub type StandingApprovalOf<T> =
+		StandingApproval<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
 
 	/// Represents the on-chain configuration of a multisig wallet.
 	///
@@ -87,425 +544,7323 @@ pub mod pallet {
 	pub struct Multisig<AccountId, MaxOwners: Get<u32>> {
 		/// The list of accounts that are owners of this multisig.
 		pub owners: BoundedVec<AccountId, MaxOwners>,
-		/// The number of owner approvals required to execute a proposal.
+		/// The total vote weight of the owner approvals required to execute a proposal. Owners
+		/// have a weight of one unless given another.
 		pub threshold: u32,
+		/// Owners whose approval must be present for any proposal to execute, regardless of
+		/// how many other approvals it has collected. A suspended owner is not required.
+		pub required_approvers: BoundedVec<AccountId, MaxOwners>,
+		/// An optional sign-off chain. Owners listed here may only approve once everyone ahead
+		/// of them in the list has approved or is suspended; an empty list means approvals are
+		/// unordered.
+		pub approval_order: BoundedVec<AccountId, MaxOwners>,
+	}
+
+	impl<AccountId: PartialEq, MaxOwners: Get<u32>> Multisig<AccountId, MaxOwners> {
+		/// Returns whether `who` may add their approval on top of the existing `approvals`
+		/// without skipping anyone ahead of them in the sign-off chain.
+		///
+		/// Members ahead of `who` who are `suspended` are passed over.
+		pub fn approval_in_order(
+			&self,
+			approvals: &[AccountId],
+			suspended: &[AccountId],
+			who: &AccountId,
+		) -> bool {
+			match self.approval_order.iter().position(|member| member == who) {
+				Some(position) => self.approval_order[..position]
+					.iter()
+					.all(|member| approvals.contains(member) || suspended.contains(member)),
+				None => true,
+			}
+		}
+
+		/// Returns whether every owner is among `approvals`.
+		///
+		/// A unanimous proposal is the wallet's emergency path: it may spend into the protected
+		/// reserve, and it may be executed as soon as every owner has approved it, waiving the
+		/// timelock meant for contested decisions.
+		pub fn is_unanimous(&self, approvals: &[AccountId]) -> bool {
+			self.owners.iter().all(|owner| approvals.contains(owner))
+		}
 	}
 
 	/// Represents a pending proposal that owners can confirm.
 	///
-	/// This tracks the state of a proposed action.
+	/// This tracks the state of a proposed action, including its approval tally, so that
+	/// confirming or executing a proposal needs a single storage read.
 	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
-	pub struct Proposal {
+	#[scale_info(skip_type_params(MaxOwners))]
+	pub struct Proposal<AccountId, CallHash, ContentRef, BlockNumber, MaxOwners: Get<u32>> {
 		/// The hash of the call to be executed.
 		///
 		///    Storing only the hash of the call is a significant storage
 		/// optimization. The full call data is provided by the user again during the
 		/// execution phase, where its hash is verified against this stored value.
-		pub call_hash: [u8; 32],
-		/// A flag to track whether the proposal has been successfully executed,
-		/// preventing re-execution.
-		pub executed: bool,
+		pub call_hash: CallHash,
+		/// Where the proposal is in its lifecycle. Once it is `Executed` it cannot be executed
+		/// again.
+		pub status: ProposalStatus,
+		/// The owner who submitted the proposal.
+		pub proposer: AccountId,
+		/// The owners who have approved the proposal, in the order they approved it.
+		pub approvals: BoundedVec<AccountId, MaxOwners>,
+		/// The owners who have voted against the proposal.
+		pub rejections: BoundedVec<AccountId, MaxOwners>,
+		/// An optional reference to off-chain paperwork backing the proposal, such as an
+		/// invoice or legal agreement. It is fixed at submission, so every approval is bound
+		/// to it.
+		pub content_ref: Option<ContentRef>,
+		/// The last block at which the proposal may be confirmed or executed.
+		pub expires_at: BlockNumber,
+		/// The block at which the proposal's approvals first met the threshold, from which its
+		/// execution delay runs, or `None` while they fall short of it.
+		pub threshold_met_at: Option<BlockNumber>,
 	}
 
-	// STORAGE
-
-	/// A counter for generating unique multisig IDs.
-	#[pallet::storage]
-	#[pallet::getter(fn next_multisig_id)]
-	pub type NextMultisigId<T> = StorageValue<_, MultisigId, ValueQuery>;
-
-	/// A map from a `MultisigId` to its on-chain `Multisig` configuration.
+	/// Where a proposal is in its lifecycle.
 	///
-	/// This is the primary storage item for the wallets themselves.
-	#[pallet::storage]
-	#[pallet::getter(fn multisigs)]
-	pub type Multisigs<T: Config> =
-		StorageMap<_, Blake2_128Concat, MultisigId, Multisig<T::AccountId, T::MaxOwners>>;
+	/// Only open and executed proposals are kept in storage; a cancelled or expired proposal
+	/// is removed, and its final status is announced in `ProposalStatusChanged` and recorded in
+	/// its off-chain history. `Pending` and `Executed` encode as the `false` and `true` of the
+	/// `executed` flag this replaced, so proposals stored before it still decode.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+	)]
+	pub enum ProposalStatus {
+		/// The proposal is awaiting confirmations or execution.
+		Pending,
+		/// The proposal's call has been dispatched successfully.
+		Executed,
+		/// The proposal's last execution failed with `error`. It stays open and may be
+		/// executed again.
+		Failed {
+			/// The error the call failed with.
+			error: DispatchError,
+		},
+		/// The proposal was withdrawn by its proposer, vetoed or rejected by the owners.
+		Cancelled,
+		/// The proposal expired without being executed.
+		Expired,
+	}
 
-	/// A map to store pending proposals, keyed by the multisig ID and a unique proposal index.
-	#[pallet::storage]
-	#[pallet::getter(fn proposals)]
-	pub type Proposals<T: Config> = StorageDoubleMap<
-		_,
-		Blake2_128Concat,
-		MultisigId,
-		Blake2_128Concat,
-		ProposalIndex,
-		Proposal,
-	>;
+	impl ProposalStatus {
+		/// Returns whether a proposal in this status may still be confirmed and executed.
+		pub fn is_open(&self) -> bool {
+			matches!(self, Self::Pending | Self::Failed { .. })
+		}
+	}
 
-	/// A counter for generating unique proposal indices for each multisig.
-	///
-	/// Each multisig maintains its own separate proposal count to keep indices small and
-	/// manageable.
-	#[pallet::storage]
-	#[pallet::getter(fn next_proposal_index)]
-	pub type NextProposalIndex<T: Config> =
-		StorageMap<_, Blake2_128Concat, MultisigId, ProposalIndex, ValueQuery>;
+	/// A named subset of a multisig's owners with its own approval threshold.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(MaxOwners, MaxNameLength))]
+	pub struct OwnerGroup<
+		AccountId: Clone + PartialEq + Eq + core::fmt::Debug,
+		MaxOwners: Get<u32>,
+		MaxNameLength: Get<u32>,
+	> {
+		/// A human-readable name for the group, such as `Engineering` or `Legal`.
+		pub name: BoundedVec<u8, MaxNameLength>,
+		/// The owners belonging to the group.
+		pub members: BoundedVec<AccountId, MaxOwners>,
+		/// The number of members that must approve for the group to be satisfied.
+		pub threshold: u32,
+	}
 
-	/// A map to store the set of accounts that have approved a specific proposal.
-	#[pallet::storage]
-	#[pallet::getter(fn approvals)]
-	pub type Approvals<T: Config> = StorageDoubleMap<
-		_,
-		Blake2_128Concat,
-		MultisigId,
-		Blake2_128Concat,
-		ProposalIndex,
-		BoundedVec<T::AccountId, T::MaxOwners>,
-		ValueQuery,
-	>;
+	impl<
+			AccountId: Clone + PartialEq + Eq + core::fmt::Debug,
+			MaxOwners: Get<u32>,
+			MaxNameLength: Get<u32>,
+		> OwnerGroup<AccountId, MaxOwners, MaxNameLength>
+	{
+		/// Returns whether enough of the group's members are among `approvals`.
+		pub fn is_met(&self, approvals: &[AccountId]) -> bool {
+			let approved = self.members.iter().filter(|member| approvals.contains(member)).count();
+			approved as u32 >= self.threshold
+		}
+	}
 
-	//EVENTS
-	/// Events emitted by this pallet.
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
-		/// A new multisig wallet has been created.
-		MultisigCreated {
-			/// The account that created the multisig.
-			creator: T::AccountId,
-			/// The unique ID of the new multisig.
-			multisig_id: MultisigId,
-			/// The sovereign account address of the new multisig.
-			multisig_account: T::AccountId,
-		},
-		/// A new proposal has been submitted.
-		ProposalSubmitted {
-			/// The ID of the multisig the proposal belongs to.
-			multisig_id: MultisigId,
-			/// The unique index of the new proposal.
-			proposal_index: ProposalIndex,
-			/// The hash of the proposed call.
-			call_hash: [u8; 32],
-		},
-		/// An owner has confirmed a proposal.
-		Confirmation {
-			/// The owner who cast the confirmation vote.
-			who: T::AccountId,
-			/// The ID of the multisig the proposal belongs to.
-			multisig_id: MultisigId,
-			/// The index of the proposal being confirmed.
-			proposal_index: ProposalIndex,
-		},
-		/// A proposal has been executed.
-		ProposalExecuted {
-			/// The ID of the multisig the proposal belonged to.
-			multisig_id: MultisigId,
-			/// The index of the proposal that was executed.
-			proposal_index: ProposalIndex,
-			/// The result of the dispatched call.
-			result: DispatchResult,
-		},
-		/// A multisig wallet has been destroyed.
-		MultisigDestroyed {
-			/// The ID of the multisig that was destroyed.
-			multisig_id: MultisigId,
-		},
+	/// The class of a proposed call, each of which a multisig may give its own threshold.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		PartialOrd,
+		Ord,
+		RuntimeDebug,
+	)]
+	pub enum CallClass {
+		/// Routine payments, such as balance transfers.
+		Transfer,
+		/// Changes to the multisig itself, such as its owners or thresholds.
+		Governance,
+		/// Privileged chain administration, such as runtime upgrades.
+		Admin,
+		/// Any other call.
+		Other,
 	}
 
-	#[pallet::error]
-	pub enum Error<T> {
-		/// An operation caused a number to overflow.
-		StorageOverflow,
-		/// The number of owners submitted is greater than the allowed maximum.
-		TooManyOwners,
-		/// The provided threshold is invalid; it must be greater than 0 and less
-		/// than or equal to the number of owners.
-		InvalidThreshold,
-		/// The specified multisig wallet does not exist.
-		MultisigNotFound,
-		/// The caller is not an owner of the multisig.
-		NotAnOwner,
-		/// The specified proposal does not exist.
-		ProposalNotFound,
-		/// The proposal has already been executed.
-		AlreadyExecuted,
-		/// The caller has already confirmed this proposal.
-		AlreadyConfirmed,
-		/// The origin of the call is not the multisig's own sovereign account.
-		MustBeMultisig,
-		/// The hash of the provided call does not match the hash of the proposal.
-		CallHashMismatch,
-		/// The proposal does not have enough approvals to be executed.
-		NotEnoughApprovals,
-		/// The multisig cannot be destroyed because it still holds a balance.
-		NonZeroBalance,
+	/// A pallet, or a single call of a pallet, identified by its index in the runtime.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+	)]
+	pub struct CallPattern {
+		/// The index of the pallet in the runtime.
+		pub pallet_index: u8,
+		/// The index of the call within the pallet, or `None` for every call of the pallet.
+		pub call_index: Option<u8>,
 	}
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Creates a new, persistent multisig wallet.
+	impl CallPattern {
+		/// Returns whether the SCALE-encoded runtime call `call` matches the pattern.
 		///
-		/// This extrinsic allows a signed user to create a new multisig wallet with a
-		/// specified set of owners and an approval threshold. Upon successful creation,
-		/// the wallet is assigned a unique `MultisigId` and a deterministic, sovereign
-		/// `AccountId` is derived. This sovereign account can hold funds and dispatch
-		/// calls on behalf of the multisig owners.
+		/// A runtime call encodes as its pallet index followed by its call index.
+		pub fn matches(&self, call: &[u8]) -> bool {
+			call.first() == Some(&self.pallet_index) &&
+				self.call_index.is_none_or(|index| call.get(1) == Some(&index))
+		}
+	}
+
+	/// Whether a `CallFilter` lists the only calls allowed or the calls denied.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+	)]
+	pub enum FilterMode {
+		/// Only calls matching a pattern may be proposed.
+		Allow,
+		/// Calls matching a pattern may not be proposed.
+		Deny,
+	}
+
+	/// The calls a multisig may propose.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	#[scale_info(skip_type_params(MaxEntries))]
+	pub struct CallFilter<MaxEntries: Get<u32>> {
+		/// Whether `patterns` are allowed or denied.
+		pub mode: FilterMode,
+		/// The calls the filter lists.
+		pub patterns: BoundedVec<CallPattern, MaxEntries>,
+	}
+
+	impl<MaxEntries: Get<u32>> CallFilter<MaxEntries> {
+		/// Returns whether the SCALE-encoded runtime call `call` passes the filter.
+		pub fn permits(&self, call: &[u8]) -> bool {
+			let listed = self.patterns.iter().any(|pattern| pattern.matches(call));
+			match self.mode {
+				FilterMode::Allow => listed,
+				FilterMode::Deny => !listed,
+			}
+		}
+	}
+
+	/// How the groups of a `GroupQuorum` are combined.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		Clone,
+		Copy,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+	)]
+	pub enum GroupRule {
+		/// Every group must reach its threshold, e.g. 2-of-Engineering AND 1-of-Legal.
+		All,
+		/// At least one group must reach its threshold.
+		Any,
+	}
+
+	/// A quorum requirement over named owner groups, evaluated at execution time on top of
+	/// the multisig's overall threshold.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	#[scale_info(skip_type_params(MaxGroups))]
+	pub struct GroupQuorum<Group, MaxGroups: Get<u32>> {
+		/// How the groups are combined.
+		pub rule: GroupRule,
+		/// The groups taking part in the quorum.
+		pub groups: BoundedVec<Group, MaxGroups>,
+	}
+
+	/// A named wallet configuration expressed in terms of owner roles rather than accounts.
+	///
+	/// Instantiating a template assigns one account to each role, in order, and derives the
+	/// wallet's mandatory approvers and sign-off chain from the roles referenced here.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	#[scale_info(skip_type_params(MaxOwners))]
+	pub struct WalletTemplate<Name, MaxOwners: Get<u32>> {
+		/// A human-readable name for the template.
+		pub name: Name,
+		/// The owner roles, such as `CFO` or `Treasurer`, one per owner.
+		pub roles: BoundedVec<Name, MaxOwners>,
+		/// The number of owner approvals required to execute a proposal.
+		pub threshold: u32,
+		/// Indices into `roles` whose approval is mandatory.
+		pub required_roles: BoundedVec<u32, MaxOwners>,
+		/// Indices into `roles` forming the sign-off chain, in order.
+		pub approval_order: BoundedVec<u32, MaxOwners>,
+	}
+
+	/// A time-boxed grant letting an operator execute calls of one category for a multisig
+	/// without a per-action approval.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct OperatorSession<Scope, BlockNumber> {
+		/// The category of calls the operator may execute.
+		pub scope: Scope,
+		/// The last block at which the session can be used.
+		pub expires_at: BlockNumber,
+	}
+
+	/// A recurring set of payments made automatically from a multisig's sovereign account.
+	///
+	/// The owners approve the payroll once; every `period` blocks from `next_payout` onwards,
+	/// each payee is paid their amount without a further proposal.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	#[scale_info(skip_type_params(MaxPayees))]
+	pub struct Payroll<AccountId, Balance, BlockNumber, MaxPayees: Get<u32>> {
+		/// The accounts paid each period, with the amount each receives.
+		pub payees: BoundedVec<(AccountId, Balance), MaxPayees>,
+		/// The number of blocks between payouts.
+		pub period: BlockNumber,
+		/// The block at which the next payout is made.
+		pub next_payout: BlockNumber,
+		/// Whether payouts are suspended. A paused payroll skips the payouts that fall due.
+		pub paused: bool,
+	}
+
+	/// A dormant owner set that takes over a multisig once its owners have been inactive for
+	/// `inactivity_period` blocks.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	#[scale_info(skip_type_params(MaxOwners))]
+	pub struct BackupOwners<AccountId, BlockNumber, MaxOwners: Get<u32>> {
+		/// The accounts that become the owners.
+		pub owners: BoundedVec<AccountId, MaxOwners>,
+		/// The approval threshold that applies once they do.
+		pub threshold: u32,
+		/// The number of blocks without owner activity after which the backup set may take over.
+		pub inactivity_period: BlockNumber,
+		/// The block of the last proposal, confirmation or execution by an owner.
+		pub last_activity: BlockNumber,
+	}
+
+	/// A lower threshold that applies once a multisig has executed no proposal for
+	/// `inactivity_period` blocks, so the remaining signers can still move funds after some
+	/// keys are lost.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		Clone,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+	)]
+	pub struct ThresholdDecay<BlockNumber> {
+		/// The number of blocks without an execution after which the threshold decays.
+		pub inactivity_period: BlockNumber,
+		/// The threshold that applies once it has.
+		pub threshold: u32,
+	}
+
+	/// An owner's confirmation right, temporarily lent to another account.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct Delegation<AccountId, BlockNumber> {
+		/// The account that may confirm proposals on the owner's behalf.
+		pub delegate: AccountId,
+		/// The block from which the delegate may no longer do so.
+		pub expires_at: BlockNumber,
+	}
+
+	/// The guardians who may jointly replace a multisig's owners if the owners lose their keys.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	#[scale_info(skip_type_params(MaxOwners))]
+	pub struct RecoveryConfig<AccountId, BlockNumber, MaxOwners: Get<u32>> {
+		/// The guardian accounts.
+		pub guardians: BoundedVec<AccountId, MaxOwners>,
+		/// The number of guardians who must approve a recovery.
+		pub threshold: u32,
+		/// The number of blocks between a recovery's initiation and when it may be claimed.
+		pub delay: BlockNumber,
+	}
+
+	/// A recovery of a multisig initiated by one of its guardians.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	#[scale_info(skip_type_params(MaxOwners))]
+	pub struct ActiveRecovery<AccountId, BlockNumber, MaxOwners: Get<u32>> {
+		/// The accounts that become the owners once the recovery is claimed.
+		pub new_owners: BoundedVec<AccountId, MaxOwners>,
+		/// The approval threshold that applies once they do.
+		pub new_threshold: u32,
+		/// The block the recovery was initiated in.
+		pub initiated_at: BlockNumber,
+		/// The guardians who have approved the recovery.
+		pub approvals: BoundedVec<AccountId, MaxOwners>,
+	}
+
+	/// A proposal whose intent the pallet can decode, as opposed to an opaque `RuntimeCall`.
+	///
+	/// The runtime turns each variant into a concrete call through `Config::CallBuilder`.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		Clone,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+	)]
+	pub enum TypedProposal<AccountId, Balance, Hash, Data, BlockNumber> {
+		/// A transfer of the native currency from the multisig's sovereign account.
+		Transfer {
+			/// The account receiving the funds.
+			dest: AccountId,
+			/// The amount to transfer.
+			amount: Balance,
+		},
+		/// Instantiates a contract from already uploaded code, owned by the sovereign account.
+		InstantiateContract {
+			/// The hash of the contract code to instantiate.
+			code_hash: Hash,
+			/// The encoded constructor selector and arguments.
+			data: Data,
+			/// The salt used to derive the contract's address.
+			salt: Data,
+			/// The balance transferred to the new contract.
+			value: Balance,
+			/// The maximum weight the instantiation may consume.
+			gas_limit: Weight,
+		},
+		/// Calls a message on a deployed contract from the sovereign account.
+		CallContract {
+			/// The address of the contract.
+			dest: AccountId,
+			/// The encoded message selector and arguments.
+			data: Data,
+			/// The balance transferred to the contract.
+			value: Balance,
+			/// The maximum weight the call may consume.
+			gas_limit: Weight,
+		},
+		/// Transfers a non-fungible item, such as a `pallet-nfts` item, held by the sovereign
+		/// account.
+		TransferItem {
+			/// The collection the item belongs to.
+			collection: u32,
+			/// The item within the collection.
+			item: u32,
+			/// The account receiving the item.
+			dest: AccountId,
+		},
+		/// Proposes a `pallet-treasury` spend of the native currency, for wallets that hold the
+		/// treasury's spend origin.
+		TreasurySpend {
+			/// The amount to spend.
+			amount: Balance,
+			/// The account receiving the spend.
+			beneficiary: AccountId,
+		},
+		/// Claims a `pallet-treasury` spend that has been approved for the wallet.
+		TreasuryPayout {
+			/// The index of the approved spend.
+			spend_index: u32,
+		},
+		/// Accepts the curator role of a `pallet-bounties` bounty for the sovereign account.
+		AcceptCurator {
+			/// The index of the bounty.
+			bounty_id: u32,
+		},
+		/// Awards a bounty curated by the sovereign account to its beneficiary.
+		AwardBounty {
+			/// The index of the bounty.
+			bounty_id: u32,
+			/// The account the bounty is awarded to.
+			beneficiary: AccountId,
+		},
+		/// Claims the payout of a bounty awarded to the sovereign account.
+		ClaimBounty {
+			/// The index of the bounty.
+			bounty_id: u32,
+		},
+		/// Sends an EVM transaction from the sovereign account's mapped address, see
+		/// `Pallet::evm_address`.
+		EvmCall {
+			/// The EVM address being called.
+			target: H160,
+			/// The transaction's input data.
+			input: Data,
+			/// The amount of the EVM-side native currency transferred, in wei.
+			value: U256,
+			/// The maximum amount of EVM gas the transaction may consume.
+			gas_limit: u64,
+		},
+		/// A transfer of the native currency that vests to the recipient over time, through
+		/// `pallet-vesting`.
+		VestedTransfer {
+			/// The account receiving the grant.
+			dest: AccountId,
+			/// The amount transferred, all of which is initially locked.
+			locked: Balance,
+			/// The amount unlocked every block from `starting_block`.
+			per_block: Balance,
+			/// The block from which the grant starts to vest.
+			starting_block: BlockNumber,
+		},
+		/// Joins a `pallet-nomination-pools` pool with the sovereign account, bonding `amount`.
+		JoinPool {
+			/// The ID of the pool to join.
+			pool_id: u32,
+			/// The amount to bond.
+			amount: Balance,
+		},
+		/// Bonds more of the sovereign account's free balance into the pool it is a member of.
+		BondExtraToPool {
+			/// The additional amount to bond.
+			amount: Balance,
+		},
+		/// Claims the sovereign account's pending rewards from its pool.
+		ClaimPoolPayout,
+		/// Sets the `pallet-session` keys of the sovereign account, for wallets that operate a
+		/// validator or collator.
+		SetSessionKeys {
+			/// The encoded session keys, which must be `Config::SessionKeysLength` bytes long.
+			keys: Data,
+			/// The proof of ownership of the keys.
+			proof: Data,
+		},
+		/// Removes the `pallet-session` keys of the sovereign account.
+		PurgeSessionKeys,
+	}
+
+	/// A cap on how much a multisig may execute within each period.
+	///
+	/// Proposals beyond the cap need approvals carrying `override_threshold` vote weight, so a
+	/// compromised set of `threshold` keys can only do limited damage before the other owners
+	/// notice.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		Clone,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+	)]
+	pub struct VelocityLimit<Balance, BlockNumber> {
+		/// The number of blocks each period lasts.
+		pub period: BlockNumber,
+		/// The most proposals that may be executed in a period, if capped.
+		pub max_executions: Option<u32>,
+		/// The most the sovereign account's balance may fall in a period, if capped.
+		pub max_value: Option<Balance>,
+		/// The total vote weight of approvals with which a proposal may exceed the caps.
+		pub override_threshold: u32,
+	}
+
+	/// The executions a multisig has made in its current velocity period.
+	#[derive(
+		Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug, Default,
+	)]
+	pub struct ExecutionWindow<Balance, BlockNumber> {
+		/// The block at which the period started.
+		pub started_at: BlockNumber,
+		/// The number of proposals executed in the period.
+		pub executions: u32,
+		/// The total amount by which the sovereign account's balance has fallen in the period.
+		pub spent: Balance,
+	}
+
+	/// An allowance any single owner of a multisig may spend from its sovereign account in each
+	/// period, without a proposal.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		Clone,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+	)]
+	pub struct SpendingLimit<Balance, BlockNumber> {
+		/// The number of blocks each period lasts.
+		pub period: BlockNumber,
+		/// The most the owners may spend together in a period.
+		pub amount: Balance,
+	}
+
+	/// What the owners of a multisig have spent of its allowance in the current period.
+	#[derive(
+		Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug, Default,
+	)]
+	pub struct SpendingWindow<Balance, BlockNumber> {
+		/// The block at which the period started.
+		pub started_at: BlockNumber,
+		/// The total spent in the period.
+		pub spent: Balance,
+	}
+
+	/// The encoded bytes of a call uploaded in chunks, with the deposit reserved for them.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	#[scale_info(skip_type_params(MaxCallSize))]
+	pub struct CallUpload<AccountId, Balance, MaxCallSize: Get<u32>> {
+		/// The owner who uploaded the call and paid its deposit.
+		pub depositor: AccountId,
+		/// The bytes uploaded so far.
+		pub data: BoundedVec<u8, MaxCallSize>,
+		/// The deposit reserved for the bytes.
+		pub deposit: Balance,
+	}
+
+	/// The operations a multisig's owners have made, with the weight and fees they consumed.
+	#[derive(
+		Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug, Default,
+	)]
+	pub struct UsageMeter<Balance> {
+		/// The number of proposals submitted.
+		pub submissions: u32,
+		/// The number of confirmations given.
+		pub confirmations: u32,
+		/// The number of proposals executed, including the calls they dispatched.
+		pub executions: u32,
+		/// The total weight consumed.
+		pub weight: Weight,
+		/// The total fees consumed, estimated from `weight`.
+		pub fees: Balance,
+	}
+
+	/// How close a pending proposal is to being executed, as reported by `approval_status`.
+	#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct ApprovalStatus {
+		/// The number of approvals that count towards execution.
+		pub approvals: u32,
+		/// The number of rejections that count against execution.
+		pub rejections: u32,
+		/// The threshold in force, after any inactivity decay.
+		pub threshold: u32,
+		/// Whether the proposal is listed by `executable_proposals`.
+		pub executable: bool,
+	}
+
+	/// The kinds of operation metered in a `UsageMeter`.
+	#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+	pub enum MeteredOperation {
+		/// Submitting a proposal.
+		Submission,
+		/// Confirming a proposal.
+		Confirmation,
+		/// Executing a proposal.
+		Execution,
+	}
+
+	/// A multisig's limits on the contract proposals its owners may submit.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		Clone,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+	)]
+	pub struct ContractLimits<Balance> {
+		/// The largest balance a single contract proposal may transfer.
+		pub max_value: Balance,
+		/// The largest gas limit a single contract proposal may set.
+		pub max_gas: Weight,
+	}
+
+	/// A multisig's limits on the EVM transactions its owners may submit.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		Clone,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+	)]
+	pub struct EvmLimits {
+		/// The largest value, in wei, a single EVM proposal may transfer.
+		pub max_value: U256,
+		/// The largest gas limit a single EVM proposal may set.
+		pub max_gas: u64,
+	}
+
+	impl<AccountId, Balance, Hash, Data, BlockNumber>
+		TypedProposal<AccountId, Balance, Hash, Data, BlockNumber>
+	{
+		/// Returns the account that assets leave the multisig for, if this is an outbound
+		/// transfer.
+		pub fn recipient(&self) -> Option<&AccountId> {
+			match self {
+				TypedProposal::Transfer { dest, .. } |
+				TypedProposal::TransferItem { dest, .. } |
+				TypedProposal::VestedTransfer { dest, .. } => Some(dest),
+				_ => None,
+			}
+		}
+
+		/// Returns the amount of the native currency this proposal moves out of the multisig,
+		/// if it is a transfer or bonds funds into a pool.
+		pub fn transfer_amount(&self) -> Option<&Balance> {
+			match self {
+				TypedProposal::Transfer { amount, .. } |
+				TypedProposal::JoinPool { amount, .. } |
+				TypedProposal::BondExtraToPool { amount } => Some(amount),
+				TypedProposal::VestedTransfer { locked, .. } => Some(locked),
+				_ => None,
+			}
+		}
+	}
+
+	impl<Balance: PartialOrd> ContractLimits<Balance> {
+		/// Returns whether a contract proposal transferring `value` with `gas_limit` is within
+		/// these limits.
+		pub fn allows(&self, value: &Balance, gas_limit: &Weight) -> bool {
+			value <= &self.max_value && gas_limit.all_lte(self.max_gas)
+		}
+	}
+
+	/// An owner's standing approval for a recurring, known-good payment.
+	///
+	/// Any typed transfer proposal to `dest` of at most `max_amount`, submitted at or before
+	/// block `expires_at`, counts the owner's approval automatically.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		Clone,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+	)]
+	pub struct StandingApproval<AccountId, Balance, BlockNumber> {
+		/// The only recipient this approval applies to.
+		pub dest: AccountId,
+		/// The largest transfer amount this approval covers.
+		pub max_amount: Balance,
+		/// The last block at which this approval is counted.
+		pub expires_at: BlockNumber,
+	}
+
+	impl<AccountId: PartialEq, Balance: PartialOrd, BlockNumber: PartialOrd>
+		StandingApproval<AccountId, Balance, BlockNumber>
+	{
+		/// Returns whether this approval covers `proposal` at block `now`.
+		///
+		/// Standing approvals only ever cover plain transfers.
+		pub fn matches<Hash, Data>(
+			&self,
+			proposal: &TypedProposal<AccountId, Balance, Hash, Data, BlockNumber>,
+			now: &BlockNumber,
+		) -> bool {
+			match proposal {
+				TypedProposal::Transfer { dest, amount } =>
+					dest == &self.dest && amount <= &self.max_amount && now <= &self.expires_at,
+				_ => false,
+			}
+		}
+	}
+
+	// STORAGE
+
+	/// A counter for generating unique multisig IDs.
+	#[pallet::storage]
+	#[pallet::getter(fn next_multisig_id)]
+	pub type NextMultisigId<T: Config> = StorageValue<_, T::MultisigId, ValueQuery>;
+
+	/// The number of multisigs that currently exist.
+	#[pallet::storage]
+	#[pallet::getter(fn active_multisig_count)]
+	pub type ActiveMultisigCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The maximum number of multisigs that may exist at once, if capped by governance.
+	#[pallet::storage]
+	#[pallet::getter(fn max_active_multisigs)]
+	pub type MaxActiveMultisigs<T: Config> = StorageValue<_, u32>;
+
+	/// A map from a `MultisigId` to its on-chain `Multisig` configuration.
+	///
+	/// This is the primary storage item for the wallets themselves.
+	#[pallet::storage]
+	#[pallet::getter(fn multisigs)]
+	pub type Multisigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, Multisig<T::AccountId, T::MaxOwners>>;
+
+	/// A map to store pending proposals, keyed by the multisig ID and a unique proposal index.
+	#[pallet::storage]
+	#[pallet::getter(fn proposals)]
+	pub type Proposals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		Blake2_128Concat,
+		T::ProposalIndex,
+		ProposalOf<T>,
+	>;
+
+	/// The pending proposals of every multisig, keyed by the hash of their call, so tools that
+	/// only know a call's hash, such as from a hardware wallet's display, can find its proposal.
+	#[pallet::storage]
+	pub type ProposalsByCallHash<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CallHashOf<T>,
+		Blake2_128Concat,
+		(T::MultisigId, T::ProposalIndex),
+		(),
+	>;
+
+	/// A counter for generating unique proposal indices for each multisig.
+	///
+	/// Each multisig maintains its own separate proposal count to keep indices small and
+	/// manageable.
+	#[pallet::storage]
+	#[pallet::getter(fn next_proposal_index)]
+	pub type NextProposalIndex<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, T::ProposalIndex, ValueQuery>;
+
+	/// The number of unexecuted proposals of each multisig.
+	#[pallet::storage]
+	pub type PendingProposalCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, u32, ValueQuery>;
+
+	/// Approvals registered by owners for a call hash before a matching proposal exists.
+	///
+	/// When a proposal with this call hash is submitted, the recorded owners are counted as
+	/// having confirmed it and their entries are consumed. Owners who cannot be counted yet,
+	/// because they are suspended or out of turn, are kept and counted on a later confirmation.
+	#[pallet::storage]
+	#[pallet::getter(fn pre_approvals)]
+	pub type PreApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		Identity,
+		CallHashOf<T>,
+		BoundedVec<T::AccountId, T::MaxOwners>,
+		ValueQuery,
+	>;
+
+	/// The number of call hashes each multisig holds pre-approvals for.
+	///
+	/// This is capped at `MaxPendingProposals`, which bounds the cost of dropping them all.
+	#[pallet::storage]
+	#[pallet::getter(fn pre_approval_count)]
+	pub type PreApprovalCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, u32, ValueQuery>;
+
+	/// The standing approvals each owner has granted within a multisig.
+	#[pallet::storage]
+	#[pallet::getter(fn standing_approvals)]
+	pub type StandingApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<StandingApprovalOf<T>, T::MaxStandingApprovals>,
+		ValueQuery,
+	>;
+
+	/// The cross-group quorum rule of each multisig that has one.
+	#[pallet::storage]
+	#[pallet::getter(fn group_quorums)]
+	pub type GroupQuorums<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, GroupQuorumOf<T>>;
+
+	/// The contract proposal limits of each multisig that has set them.
+	#[pallet::storage]
+	#[pallet::getter(fn contract_limits)]
+	pub type MultisigContractLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, ContractLimitsOf<T>>;
+
+	/// The EVM proposal limits of each multisig that has set them.
+	#[pallet::storage]
+	#[pallet::getter(fn evm_limits)]
+	pub type MultisigEvmLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, EvmLimits>;
+
+	/// The treasury spends each multisig expects to receive, keyed by the treasury's spend
+	/// index and holding the expected amount.
+	#[pallet::storage]
+	#[pallet::getter(fn inbound_spends)]
+	pub type InboundSpends<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::MultisigId, Twox64Concat, u32, BalanceOf<T>>;
+
+	/// The read-only auditors of each multisig, who may flag proposals but not approve them.
+	#[pallet::storage]
+	#[pallet::getter(fn auditors)]
+	pub type Auditors<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		BoundedVec<T::AccountId, T::MaxOwners>,
+		ValueQuery,
+	>;
+
+	/// The veto accounts of each multisig, who may cancel any pending proposal but not approve
+	/// one.
+	#[pallet::storage]
+	#[pallet::getter(fn vetoers)]
+	pub type Vetoers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		BoundedVec<T::AccountId, T::MaxOwners>,
+		ValueQuery,
+	>;
+
+	/// The auditor flags raised against each proposal, at most one per auditor.
+	///
+	/// Flags do not block execution; they make objections visible on-chain.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_flags)]
+	pub type ProposalFlags<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		Blake2_128Concat,
+		T::ProposalIndex,
+		BoundedVec<FlagOf<T>, T::MaxOwners>,
+		ValueQuery,
+	>;
+
+	/// The proposals that are dispatched with the pallet's `Origin::Multisig` rather than
+	/// signed by the sovereign account, as chosen at submission.
+	#[pallet::storage]
+	#[pallet::getter(fn uses_multisig_origin)]
+	pub type MultisigOriginProposals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		Blake2_128Concat,
+		T::ProposalIndex,
+		(),
+		OptionQuery,
+	>;
+
+	/// The balance below which each multisig may only spend with the approval of every owner.
+	#[pallet::storage]
+	#[pallet::getter(fn protected_reserve)]
+	pub type ProtectedReserves<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, BalanceOf<T>>;
+
+	/// The sovereign account of each counterfactual or salted multisig, which is derived with a
+	/// salt rather than from its ID alone.
+	#[pallet::storage]
+	pub type SovereignAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, T::AccountId>;
+
+	/// The multisig that uses each sovereign account, so an origin can be traced back to its
+	/// multisig whether the account was derived from the ID or from a salt.
+	#[pallet::storage]
+	#[pallet::getter(fn multisig_of_account)]
+	pub type MultisigAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::MultisigId>;
+
+	/// The multisigs each account is an owner of, so wallets can list an account's multisigs
+	/// without scanning every one.
+	///
+	/// At most `MaxMultisigsPerOwner` multisigs are listed per account; further ones are left
+	/// out rather than refused, so a full index never blocks a wallet.
+	#[pallet::storage]
+	#[pallet::getter(fn owned_multisigs)]
+	pub type OwnerToMultisigs<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<T::MultisigId, T::MaxMultisigsPerOwner>,
+		ValueQuery,
+	>;
+
+	/// The deposit reserved from the creator of each multisig.
+	#[pallet::storage]
+	#[pallet::getter(fn creation_deposit)]
+	pub type CreationDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, (T::AccountId, BalanceOf<T>)>;
+
+	/// The deposits reserved from the proposer and confirming owners of each proposal.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_deposits)]
+	pub type ProposalDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		Blake2_128Concat,
+		T::ProposalIndex,
+		ProposalDepositsOf<T>,
+		ValueQuery,
+	>;
+
+	/// The human-readable label of each owner of a multisig that has one, such as
+	/// "CFO - ledger key".
+	#[pallet::storage]
+	#[pallet::getter(fn owner_label)]
+	pub type OwnerLabels<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		Blake2_128Concat,
+		T::AccountId,
+		NameOf<T>,
+	>;
+
+	/// The delegation of each owner of a multisig who has lent their confirmation right.
+	#[pallet::storage]
+	#[pallet::getter(fn delegation)]
+	pub type Delegations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		Blake2_128Concat,
+		T::AccountId,
+		DelegationOf<T>,
+	>;
+
+	/// The vote weight of each owner of a multisig whose vote does not count exactly once.
+	///
+	/// An owner without an entry has a weight of one. A multisig's threshold is the total weight
+	/// of the approvals a proposal needs, so with no entries it is a head-count.
+	#[pallet::storage]
+	#[pallet::getter(fn owner_weight)]
+	pub type OwnerWeights<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::MultisigId, Blake2_128Concat, T::AccountId, u32>;
+
+	/// The thresholds a multisig applies to calls of particular classes instead of its own.
+	#[pallet::storage]
+	#[pallet::getter(fn class_threshold)]
+	pub type ClassThresholds<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::MultisigId, Blake2_128Concat, CallClass, u32>;
+
+	/// The filter restricting the calls each multisig may propose, if it has one.
+	#[pallet::storage]
+	#[pallet::getter(fn call_filter)]
+	pub type CallFilters<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, CallFilterOf<T>>;
+
+	/// The owners of each multisig who are currently suspended.
+	///
+	/// A suspended owner remains an owner, but cannot propose or confirm, and their existing
+	/// approvals do not count towards execution.
+	#[pallet::storage]
+	#[pallet::getter(fn suspended_owners)]
+	pub type SuspendedOwners<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		BoundedVec<T::AccountId, T::MaxOwners>,
+		ValueQuery,
+	>;
+
+	/// The backup owner set registered for each multisig that has one.
+	#[pallet::storage]
+	#[pallet::getter(fn backup_owners)]
+	pub type BackupOwnerSets<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, BackupOwnersOf<T>>;
+
+	/// The guardian configuration of each multisig that has one.
+	#[pallet::storage]
+	#[pallet::getter(fn recovery_config)]
+	pub type RecoveryConfigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, RecoveryConfigOf<T>>;
+
+	/// The recovery in progress for each multisig that has one.
+	#[pallet::storage]
+	#[pallet::getter(fn active_recovery)]
+	pub type ActiveRecoveries<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, ActiveRecoveryOf<T>>;
+
+	/// The accounts exempt from paying creation, proposal and approval deposits.
+	#[pallet::storage]
+	#[pallet::getter(fn is_exempt_account)]
+	pub type DepositExemptAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// The multisigs whose proposals and approvals require no deposits.
+	#[pallet::storage]
+	#[pallet::getter(fn is_exempt_multisig)]
+	pub type DepositExemptMultisigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, (), OptionQuery>;
+
+	/// The velocity limit of each multisig that has one.
+	#[pallet::storage]
+	#[pallet::getter(fn velocity_limit)]
+	pub type VelocityLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, VelocityLimitOf<T>>;
+
+	/// The executions each multisig with a velocity limit has made in its current period.
+	#[pallet::storage]
+	#[pallet::getter(fn execution_window)]
+	pub type ExecutionWindows<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, ExecutionWindowOf<T>, ValueQuery>;
+
+	/// The execution delay of each multisig that has set its own, overriding
+	/// `DefaultExecutionDelay`.
+	#[pallet::storage]
+	pub type ExecutionDelays<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, BlockNumberFor<T>>;
+
+	/// The spending limit of each multisig that has one.
+	#[pallet::storage]
+	#[pallet::getter(fn spending_limit)]
+	pub type SpendingLimits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, SpendingLimitOf<T>>;
+
+	/// The threshold decay policy of each multisig that has one.
+	#[pallet::storage]
+	#[pallet::getter(fn threshold_decay)]
+	pub type ThresholdDecays<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, ThresholdDecayOf<T>>;
+
+	/// The block in which each multisig last executed a proposal, if it has.
+	#[pallet::storage]
+	#[pallet::getter(fn last_executed_at)]
+	pub type LastExecutedAt<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, BlockNumberFor<T>>;
+
+	/// What each multisig with a spending limit has spent in its current period.
+	#[pallet::storage]
+	#[pallet::getter(fn spending_window)]
+	pub type SpendingWindows<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, SpendingWindowOf<T>, ValueQuery>;
+
+	/// The calls each owner of a multisig is uploading in chunks, not yet proposed.
+	#[pallet::storage]
+	#[pallet::getter(fn call_uploads)]
+	pub type CallUploads<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		Blake2_128Concat,
+		T::AccountId,
+		CallUploadOf<T>,
+	>;
+
+	/// The uploaded calls of pending proposals, from which they can be executed.
+	#[pallet::storage]
+	#[pallet::getter(fn uploaded_calls)]
+	pub type UploadedCalls<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		Blake2_128Concat,
+		T::ProposalIndex,
+		CallUploadOf<T>,
+	>;
+
+	/// The usage meter of each multisig, for chargeback of the weight and fees its operations
+	/// consume.
+	#[pallet::storage]
+	#[pallet::getter(fn usage_meter)]
+	pub type UsageMeters<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, UsageMeterOf<T>, ValueQuery>;
+
+	/// The signing nonce of each multisig, included in every payload its owners sign off-chain.
+	///
+	/// Bumping it invalidates every approval signed against an earlier nonce.
+	#[pallet::storage]
+	#[pallet::getter(fn signing_nonce)]
+	pub type SigningNonces<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::MultisigId, u64, ValueQuery>;
+
+	/// The operator sessions of each multisig, keyed by operator.
+	#[pallet::storage]
+	#[pallet::getter(fn operator_sessions)]
+	pub type OperatorSessions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		Blake2_128Concat,
+		T::AccountId,
+		OperatorSessionOf<T>,
+	>;
+
+	/// The payroll of each multisig.
+	#[pallet::storage]
+	#[pallet::getter(fn payrolls)]
+	pub type Payrolls<T: Config> = StorageMap<_, Blake2_128Concat, T::MultisigId, PayrollOf<T>>;
+
+	/// The multisigs whose payroll falls due at each block.
+	///
+	/// Entries are not removed when a payroll is changed or cancelled; a payroll is only paid
+	/// if its `next_payout` is still the block being processed.
+	#[pallet::storage]
+	#[pallet::getter(fn payroll_agenda)]
+	pub type PayrollAgenda<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<T::MultisigId, T::MaxPayrollsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The funds reserved in a multisig's sovereign account for each pending typed transfer.
+	///
+	/// Reserving the amount when the transfer is proposed stops concurrent proposals from
+	/// collectively promising more than the wallet holds.
+	#[pallet::storage]
+	#[pallet::getter(fn earmarks)]
+	pub type Earmarks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::MultisigId,
+		Blake2_128Concat,
+		T::ProposalIndex,
+		BalanceOf<T>,
+	>;
+
+	/// A counter for generating unique template IDs.
+	#[pallet::storage]
+	#[pallet::getter(fn next_template_id)]
+	pub type NextTemplateId<T> = StorageValue<_, TemplateId, ValueQuery>;
+
+	/// The registry of wallet templates that new multisigs can be instantiated from.
+	#[pallet::storage]
+	#[pallet::getter(fn templates)]
+	pub type Templates<T: Config> =
+		StorageMap<_, Blake2_128Concat, TemplateId, WalletTemplateOf<T>>;
+
+	/// The origin a proposal is dispatched with when it opts into carrying its approvals.
+	///
+	/// Pallets that accept it can check how a multisig approved a call, for example that at
+	/// least four owners of wallet 7 did, rather than only seeing its sovereign account.
+	#[pallet::origin]
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		TypeInfo,
+		MaxEncodedLen,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub enum Origin<T: Config> {
+		/// A call approved by the owners of a multisig.
+		Multisig {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The number of approvals that counted towards executing the call.
+			approvals: u32,
+			/// The threshold the approvals had to meet.
+			threshold: u32,
+		},
+	}
+
+	//EVENTS
+	/// Events emitted by this pallet.
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new multisig wallet has been created.
+		MultisigCreated {
+			/// The account that created the multisig.
+			creator: T::AccountId,
+			/// The unique ID of the new multisig.
+			multisig_id: T::MultisigId,
+			/// The sovereign account address of the new multisig.
+			multisig_account: T::AccountId,
+			/// A hash commitment to the wallet's configuration, see `config_commitment`.
+			config_hash: T::Hash,
+		},
+		/// A new proposal has been submitted.
+		ProposalSubmitted {
+			/// The ID of the multisig the proposal belongs to.
+			multisig_id: T::MultisigId,
+			/// The unique index of the new proposal.
+			proposal_index: T::ProposalIndex,
+			/// The hash of the proposed call.
+			call_hash: CallHashOf<T>,
+			/// The off-chain content reference attached to the proposal, if any.
+			content_ref: Option<Vec<u8>>,
+		},
+		/// An owner has confirmed a proposal.
+		Confirmation {
+			/// The owner who cast the confirmation vote.
+			who: T::AccountId,
+			/// The ID of the multisig the proposal belongs to.
+			multisig_id: T::MultisigId,
+			/// The index of the proposal being confirmed.
+			proposal_index: T::ProposalIndex,
+		},
+		/// A proposal has been executed.
+		ProposalExecuted {
+			/// The ID of the multisig the proposal belonged to.
+			multisig_id: T::MultisigId,
+			/// The index of the proposal that was executed.
+			proposal_index: T::ProposalIndex,
+			/// The result of the dispatched call.
+			result: DispatchResult,
+		},
+		/// A proposal has moved to a new status in its lifecycle.
+		ProposalStatusChanged {
+			/// The ID of the multisig the proposal belongs to.
+			multisig_id: T::MultisigId,
+			/// The index of the proposal.
+			proposal_index: T::ProposalIndex,
+			/// The status the proposal has moved to.
+			status: ProposalStatus,
+		},
+		/// A multisig wallet has been destroyed.
+		MultisigDestroyed {
+			/// The ID of the multisig that was destroyed.
+			multisig_id: T::MultisigId,
+		},
+		/// An owner has pre-approved a call hash ahead of its proposal.
+		PreApproved {
+			/// The owner who registered the pre-approval.
+			who: T::AccountId,
+			/// The ID of the multisig the pre-approval belongs to.
+			multisig_id: T::MultisigId,
+			/// The hash of the call being pre-approved.
+			call_hash: CallHashOf<T>,
+		},
+		/// An owner has withdrawn a pre-approval.
+		PreApprovalCancelled {
+			/// The owner who withdrew the pre-approval.
+			who: T::AccountId,
+			/// The ID of the multisig the pre-approval belonged to.
+			multisig_id: T::MultisigId,
+			/// The hash of the call that is no longer pre-approved.
+			call_hash: CallHashOf<T>,
+		},
+		/// An owner has granted a standing approval.
+		StandingApprovalGranted {
+			/// The owner granting the approval.
+			who: T::AccountId,
+			/// The ID of the multisig the approval applies to.
+			multisig_id: T::MultisigId,
+			/// The pattern of proposals that will be approved automatically.
+			approval: StandingApprovalOf<T>,
+		},
+		/// An owner has revoked a standing approval.
+		StandingApprovalRevoked {
+			/// The owner revoking the approval.
+			who: T::AccountId,
+			/// The ID of the multisig the approval applied to.
+			multisig_id: T::MultisigId,
+			/// The pattern that is no longer approved automatically.
+			approval: StandingApprovalOf<T>,
+		},
+		/// The set of mandatory approvers of a multisig has been replaced.
+		RequiredApproversSet {
+			/// The ID of the multisig whose configuration changed.
+			multisig_id: T::MultisigId,
+			/// The owners whose approval is now mandatory.
+			approvers: Vec<T::AccountId>,
+		},
+		/// The sign-off chain of a multisig has been replaced.
+		ApprovalOrderSet {
+			/// The ID of the multisig whose configuration changed.
+			multisig_id: T::MultisigId,
+			/// The owners, in the order in which they must approve.
+			order: Vec<T::AccountId>,
+		},
+		/// The cross-group quorum rule of a multisig has been replaced.
+		GroupQuorumSet {
+			/// The ID of the multisig whose configuration changed.
+			multisig_id: T::MultisigId,
+			/// How the groups are combined.
+			rule: GroupRule,
+			/// The number of groups in the rule; zero means the rule was removed.
+			groups: u32,
+		},
+		/// A multisig wallet has been created as a copy of another one.
+		MultisigCloned {
+			/// The ID of the multisig whose configuration was copied.
+			source_id: T::MultisigId,
+			/// The ID of the new multisig.
+			multisig_id: T::MultisigId,
+			/// A hash commitment to the copied configuration, see `config_commitment`.
+			config_hash: T::Hash,
+		},
+		/// A wallet template has been registered.
+		TemplateRegistered {
+			/// The ID of the new template.
+			template_id: TemplateId,
+			/// The name of the template.
+			name: Vec<u8>,
+		},
+		/// A wallet template has been removed from the registry.
+		TemplateRemoved {
+			/// The ID of the removed template.
+			template_id: TemplateId,
+		},
+		/// A multisig wallet has been instantiated from a template.
+		MultisigCreatedFromTemplate {
+			/// The ID of the template that was used.
+			template_id: TemplateId,
+			/// The ID of the new multisig.
+			multisig_id: T::MultisigId,
+		},
+		/// A multisig's contract proposal limits have been set or removed.
+		ContractLimitsSet {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The new limits, or `None` if they were removed.
+			limits: Option<ContractLimitsOf<T>>,
+		},
+		/// A multisig's EVM proposal limits have been set or removed.
+		EvmLimitsSet {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The new limits, or `None` if they were removed.
+			limits: Option<EvmLimits>,
+		},
+		/// An owner has recorded a treasury spend the multisig expects to receive.
+		InboundSpendTracked {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The treasury's index of the spend.
+			spend_index: u32,
+			/// The expected amount.
+			amount: BalanceOf<T>,
+		},
+		/// An owner has stopped tracking an expected treasury spend.
+		InboundSpendUntracked {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The treasury's index of the spend.
+			spend_index: u32,
+		},
+		/// A multisig's auditors have been set.
+		AuditorsSet {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The new auditors.
+			auditors: Vec<T::AccountId>,
+		},
+		/// An auditor has flagged a proposal.
+		ProposalFlagged {
+			/// The auditor who raised the flag.
+			auditor: T::AccountId,
+			/// The ID of the multisig the proposal belongs to.
+			multisig_id: T::MultisigId,
+			/// The index of the flagged proposal.
+			proposal_index: T::ProposalIndex,
+			/// The reason given for the flag.
+			reason: Vec<u8>,
+		},
+		/// Chain governance has dispatched a call as a multisig's sovereign account, bypassing
+		/// its owners.
+		DispatchedAsMultisig {
+			/// The ID of the multisig whose sovereign account was used.
+			multisig_id: T::MultisigId,
+			/// The result of the dispatched call.
+			result: DispatchResult,
+		},
+		/// Funds have been reserved for a pending transfer proposal.
+		FundsEarmarked {
+			/// The ID of the multisig holding the funds.
+			multisig_id: T::MultisigId,
+			/// The index of the transfer proposal.
+			proposal_index: T::ProposalIndex,
+			/// The amount reserved.
+			amount: BalanceOf<T>,
+		},
+		/// The funds reserved for a transfer proposal have been released.
+		EarmarkReleased {
+			/// The ID of the multisig holding the funds.
+			multisig_id: T::MultisigId,
+			/// The index of the transfer proposal.
+			proposal_index: T::ProposalIndex,
+			/// The amount released.
+			amount: BalanceOf<T>,
+		},
+		/// A multisig's protected reserve has been set or removed.
+		ProtectedReserveSet {
+			/// The ID of the multisig that was configured.
+			multisig_id: T::MultisigId,
+			/// The new protected reserve, if any.
+			amount: Option<BalanceOf<T>>,
+		},
+		/// A multisig's payroll has been set.
+		PayrollSet {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The block of the first payout.
+			first_payout: BlockNumberFor<T>,
+		},
+		/// A multisig's payroll has been paused or resumed.
+		PayrollPaused {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// Whether the payroll is now paused.
+			paused: bool,
+		},
+		/// A multisig's payroll has been cancelled.
+		PayrollCancelled {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+		},
+		/// A multisig's payroll has been paid out.
+		PayrollPaid {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The total amount paid.
+			total: BalanceOf<T>,
+		},
+		/// A payroll payment could not be made and was skipped.
+		PayrollPaymentFailed {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The payee who was not paid.
+			payee: T::AccountId,
+			/// The amount that was due.
+			amount: BalanceOf<T>,
+		},
+		/// An operator session has been granted.
+		SessionGranted {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The operator the session was granted to.
+			operator: T::AccountId,
+			/// The category of calls the operator may execute.
+			scope: T::SessionScope,
+			/// The last block at which the session can be used.
+			expires_at: BlockNumberFor<T>,
+		},
+		/// An operator session has been revoked.
+		SessionRevoked {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The operator whose session was revoked.
+			operator: T::AccountId,
+		},
+		/// An operator has executed a call through their session.
+		OperatorExecuted {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The operator who executed the call.
+			operator: T::AccountId,
+			/// The result of the call's dispatch.
+			result: DispatchResult,
+		},
+		/// A proposer has topped up the multisig along with their proposal.
+		ProposalFunded {
+			/// The ID of the multisig that received the funds.
+			multisig_id: T::MultisigId,
+			/// The index of the proposal the funds were attached to.
+			proposal_index: T::ProposalIndex,
+			/// The proposer who sent the funds.
+			who: T::AccountId,
+			/// The amount sent.
+			amount: BalanceOf<T>,
+		},
+		/// Inconsistent storage of a multisig has been repaired.
+		Reconciled {
+			/// The ID of the multisig that was reconciled.
+			multisig_id: T::MultisigId,
+			/// The number of entries repaired or removed.
+			repaired: u32,
+		},
+		/// An owner's label has been set or removed.
+		OwnerLabelSet {
+			/// The ID of the multisig that was configured.
+			multisig_id: T::MultisigId,
+			/// The owner whose label changed.
+			owner: T::AccountId,
+			/// The new label, if any.
+			label: Option<NameOf<T>>,
+		},
+		/// An owner has been suspended.
+		OwnerSuspended {
+			/// The ID of the multisig that was configured.
+			multisig_id: T::MultisigId,
+			/// The suspended owner.
+			owner: T::AccountId,
+		},
+		/// A suspended owner has been reinstated.
+		OwnerReinstated {
+			/// The ID of the multisig that was configured.
+			multisig_id: T::MultisigId,
+			/// The reinstated owner.
+			owner: T::AccountId,
+		},
+		/// A backup owner set has been registered, replacing any previous one.
+		BackupOwnersSet {
+			/// The ID of the multisig that was configured.
+			multisig_id: T::MultisigId,
+			/// The number of blocks of owner inactivity after which the set may take over.
+			inactivity_period: BlockNumberFor<T>,
+		},
+		/// A multisig's backup owner set has been removed.
+		BackupOwnersCleared {
+			/// The ID of the multisig that was configured.
+			multisig_id: T::MultisigId,
+		},
+		/// A multisig's backup owner set has replaced its inactive owners.
+		BackupOwnersActivated {
+			/// The ID of the multisig whose owners were replaced.
+			multisig_id: T::MultisigId,
+		},
+		/// An account's exemption from deposits has been granted or revoked.
+		AccountDepositExemptionSet {
+			/// The account concerned.
+			who: T::AccountId,
+			/// Whether the account is now exempt.
+			exempt: bool,
+		},
+		/// A multisig's exemption from deposits has been granted or revoked.
+		MultisigDepositExemptionSet {
+			/// The ID of the multisig concerned.
+			multisig_id: T::MultisigId,
+			/// Whether the multisig is now exempt.
+			exempt: bool,
+		},
+		/// A multisig's velocity limit has been set or removed.
+		VelocityLimitSet {
+			/// The ID of the multisig that was configured.
+			multisig_id: T::MultisigId,
+			/// The new velocity limit, if any.
+			limit: Option<VelocityLimitOf<T>>,
+		},
+		/// A multisig's signing nonce has been bumped, invalidating its outstanding off-chain
+		/// approvals.
+		SigningNonceBumped {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The new signing nonce.
+			nonce: u64,
+		},
+		/// The cap on the number of active multisigs has been set or lifted.
+		MaxActiveMultisigsSet {
+			/// The new cap, if any.
+			max: Option<u32>,
+		},
+		/// A chunk of call data has been uploaded.
+		CallChunkNoted {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The owner uploading the call.
+			who: T::AccountId,
+			/// The number of bytes uploaded so far.
+			len: u32,
+		},
+		/// An owner's call upload has been discarded and its deposit refunded.
+		CallUploadDiscarded {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The owner who was uploading the call.
+			who: T::AccountId,
+		},
+		/// An owner has been added to a multisig.
+		OwnerAdded {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The new owner.
+			owner: T::AccountId,
+			/// The multisig's threshold after the change.
+			threshold: u32,
+		},
+		/// An owner has been removed from a multisig.
+		OwnerRemoved {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The removed owner.
+			owner: T::AccountId,
+			/// The multisig's threshold after the change.
+			threshold: u32,
+		},
+		/// An owner of a multisig has been replaced by another account.
+		OwnerSwapped {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The replaced owner.
+			old: T::AccountId,
+			/// The owner taking their place.
+			new: T::AccountId,
+		},
+		/// An owner has withdrawn their approval of a proposal.
+		ApprovalRevoked {
+			/// The owner who withdrew their approval.
+			who: T::AccountId,
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the proposal.
+			proposal_index: T::ProposalIndex,
+		},
+		/// A pending proposal has been withdrawn by its proposer.
+		ProposalCancelled {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the cancelled proposal.
+			proposal_index: T::ProposalIndex,
+			/// The proposer who cancelled it.
+			who: T::AccountId,
+		},
+		/// An expired proposal has been removed.
+		ProposalExpired {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the expired proposal.
+			proposal_index: T::ProposalIndex,
+		},
+		/// An owner has voted against a proposal.
+		Rejection {
+			/// The owner who voted against the proposal.
+			who: T::AccountId,
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the proposal.
+			proposal_index: T::ProposalIndex,
+		},
+		/// A proposal has been rejected by so many owners that it can no longer pass, and has
+		/// been removed.
+		ProposalRejected {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the rejected proposal.
+			proposal_index: T::ProposalIndex,
+		},
+		/// Part of a proposer's deposit has been forfeited to whoever removed their expired
+		/// proposal.
+		DepositSlashed {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the expired proposal.
+			proposal_index: T::ProposalIndex,
+			/// The proposer who forfeited part of their deposit.
+			who: T::AccountId,
+			/// The account the forfeited amount was paid to.
+			beneficiary: T::AccountId,
+			/// The amount forfeited.
+			amount: BalanceOf<T>,
+		},
+		/// An owner's confirmation of one of the proposals in a batch failed.
+		BatchConfirmationFailed {
+			/// The owner who tried to confirm the proposal.
+			who: T::AccountId,
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the proposal that was not confirmed.
+			proposal_index: T::ProposalIndex,
+			/// Why the confirmation failed.
+			error: DispatchError,
+		},
+		/// The execution of one of the proposals in a batch failed before its call was
+		/// dispatched.
+		BatchExecutionFailed {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the proposal that was not executed.
+			proposal_index: T::ProposalIndex,
+			/// Why the execution failed.
+			error: DispatchError,
+		},
+		/// The vote weight of an owner has been changed.
+		OwnerWeightSet {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The owner.
+			owner: T::AccountId,
+			/// The owner's new vote weight.
+			weight: u32,
+		},
+		/// The threshold a multisig applies to a class of calls has been set or cleared.
+		ClassThresholdSet {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The class of calls.
+			class: CallClass,
+			/// The class's threshold, or `None` if it now uses the multisig's threshold.
+			threshold: Option<u32>,
+		},
+		/// The filter restricting the calls a multisig may propose has been set or cleared.
+		CallFilterSet {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// Whether the filter allows or denies the calls it lists, or `None` if it was
+			/// cleared.
+			mode: Option<FilterMode>,
+		},
+		/// The spending limit of a multisig has been set or removed.
+		SpendingLimitSet {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The new spending limit, if any.
+			limit: Option<SpendingLimitOf<T>>,
+		},
+		/// An owner has spent from a multisig's allowance without a proposal.
+		AllowanceSpent {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The owner who made the transfer.
+			who: T::AccountId,
+			/// The recipient of the transfer.
+			dest: T::AccountId,
+			/// The amount transferred.
+			amount: BalanceOf<T>,
+		},
+		/// The execution delay of a multisig has been set or reset to the default.
+		ExecutionDelaySet {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The new delay, or `None` if the multisig uses `DefaultExecutionDelay`.
+			delay: Option<BlockNumberFor<T>>,
+		},
+		/// A unanimous proposal has been executed before its timelock elapsed.
+		TimelockWaived {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the executed proposal.
+			proposal_index: T::ProposalIndex,
+		},
+		/// The veto accounts of a multisig have been replaced.
+		VetoersSet {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The new veto accounts.
+			vetoers: Vec<T::AccountId>,
+		},
+		/// A veto account has cancelled a pending proposal.
+		ProposalVetoed {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the vetoed proposal.
+			proposal_index: T::ProposalIndex,
+			/// The veto account that cancelled it.
+			who: T::AccountId,
+		},
+		/// A multisig's guardians have been configured, replacing any previous configuration.
+		RecoveryConfigSet {
+			/// The ID of the multisig that was configured.
+			multisig_id: T::MultisigId,
+			/// The number of blocks a recovery must wait before it may be claimed.
+			delay: BlockNumberFor<T>,
+		},
+		/// A multisig's guardian configuration has been removed.
+		RecoveryConfigRemoved {
+			/// The ID of the multisig that was configured.
+			multisig_id: T::MultisigId,
+		},
+		/// A guardian has started a recovery of a multisig.
+		RecoveryInitiated {
+			/// The ID of the multisig being recovered.
+			multisig_id: T::MultisigId,
+			/// The guardian who started the recovery.
+			who: T::AccountId,
+		},
+		/// A guardian has approved a multisig's recovery.
+		RecoveryApproved {
+			/// The ID of the multisig being recovered.
+			multisig_id: T::MultisigId,
+			/// The approving guardian.
+			who: T::AccountId,
+		},
+		/// A recovery has replaced a multisig's owners.
+		RecoveryClaimed {
+			/// The ID of the recovered multisig.
+			multisig_id: T::MultisigId,
+		},
+		/// An owner has cancelled a recovery of their multisig.
+		RecoveryCancelled {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The owner who cancelled the recovery.
+			who: T::AccountId,
+		},
+		/// A multisig's threshold decay policy has been set or removed.
+		ThresholdDecaySet {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The new policy, if any.
+			decay: Option<ThresholdDecayOf<T>>,
+		},
+		/// An owner has delegated their confirmation right.
+		ConfirmationsDelegated {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The delegating owner.
+			owner: T::AccountId,
+			/// The account that may confirm on the owner's behalf.
+			delegate: T::AccountId,
+			/// The block from which the delegation no longer applies.
+			expires_at: BlockNumberFor<T>,
+		},
+		/// An owner has revoked the delegation of their confirmation right.
+		DelegationRevoked {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The owner who revoked their delegation.
+			owner: T::AccountId,
+		},
+		/// A proxy has submitted or confirmed a proposal on an owner's behalf.
+		ProxyVoted {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the proposal.
+			proposal_index: T::ProposalIndex,
+			/// The owner the proxy acted for.
+			owner: T::AccountId,
+			/// The proxy.
+			proxy: T::AccountId,
+		},
+		/// A multisig has dispatched a call from one of its sub-accounts.
+		DispatchedAsSub {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the sub-account used.
+			sub_index: u16,
+			/// The result of the dispatched call.
+			result: DispatchResult,
+		},
+		/// An owner's off-chain confirmation of a proposal has been relayed.
+		SignedConfirmationRelayed {
+			/// The ID of the multisig.
+			multisig_id: T::MultisigId,
+			/// The index of the proposal.
+			proposal_index: T::ProposalIndex,
+			/// The owner who signed the confirmation.
+			owner: T::AccountId,
+			/// The account that relayed it.
+			relayer: T::AccountId,
+		},
+		/// A multisig has been left out of an owner's `OwnerToMultisigs` index, which is full.
+		OwnerIndexFull {
+			/// The owner whose index is full.
+			owner: T::AccountId,
+			/// The ID of the multisig left out.
+			multisig_id: T::MultisigId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// An operation caused a number to overflow.
+		StorageOverflow,
+		/// The number of owners submitted is greater than the allowed maximum.
+		TooManyOwners,
+		/// The provided threshold is invalid; it must be greater than 0 and less
+		/// than or equal to the number of owners.
+		InvalidThreshold,
+		/// The specified multisig wallet does not exist.
+		MultisigNotFound,
+		/// The caller is not an owner of the multisig.
+		NotAnOwner,
+		/// The specified proposal does not exist.
+		ProposalNotFound,
+		/// The proposal has already been executed.
+		AlreadyExecuted,
+		/// The caller has already confirmed this proposal.
+		AlreadyConfirmed,
+		/// The origin of the call is not the multisig's own sovereign account.
+		MustBeMultisig,
+		/// The hash of the provided call does not match the hash of the proposal.
+		CallHashMismatch,
+		/// The proposal does not have enough approvals to be executed.
+		NotEnoughApprovals,
+		/// The multisig cannot be destroyed because it still holds a balance.
+		NonZeroBalance,
+		/// The caller has already pre-approved this call hash.
+		AlreadyPreApproved,
+		/// The caller has no pre-approval registered for this call hash.
+		PreApprovalNotFound,
+		/// The runtime does not support this kind of typed proposal.
+		UnsupportedProposal,
+		/// The owner already holds the maximum number of standing approvals for this multisig.
+		TooManyStandingApprovals,
+		/// The standing approval would already be expired.
+		StandingApprovalExpired,
+		/// No standing approval exists at the given index.
+		StandingApprovalNotFound,
+		/// The same account was listed more than once.
+		DuplicateAccount,
+		/// A mandatory approver has not approved the proposal.
+		MissingRequiredApproval,
+		/// An owner ahead of the caller in the sign-off chain has not approved yet.
+		OutOfOrderApproval,
+		/// More owner groups were supplied than the allowed maximum.
+		TooManyGroups,
+		/// A group has a threshold of zero or larger than its number of members.
+		InvalidGroupThreshold,
+		/// The approvals do not satisfy the multisig's cross-group quorum rule.
+		GroupQuorumNotMet,
+		/// A name is longer than the allowed maximum.
+		NameTooLong,
+		/// The specified wallet template does not exist.
+		TemplateNotFound,
+		/// A template references a role that does not exist or references one twice.
+		InvalidTemplate,
+		/// The number of owners does not match the number of roles in the template.
+		OwnersDoNotMatchTemplate,
+		/// The off-chain content reference is longer than the allowed maximum.
+		ContentRefTooLong,
+		/// A contract proposal transfers more value or sets a higher gas limit than the
+		/// multisig's contract limits allow.
+		ContractLimitsExceeded,
+		/// An EVM proposal transfers more value or sets a higher gas limit than the multisig's
+		/// EVM limits allow.
+		EvmLimitsExceeded,
+		/// The multisig's sovereign account still holds non-fungible items.
+		ItemsHeld,
+		/// The treasury spend is already being tracked for this multisig.
+		SpendAlreadyTracked,
+		/// The treasury spend is not being tracked for this multisig.
+		SpendNotTracked,
+		/// The caller is not an auditor of this multisig.
+		NotAnAuditor,
+		/// The flag's reason is longer than the allowed maximum.
+		ReasonTooLong,
+		/// The runtime's external policy check rejected the proposal.
+		PolicyCheckFailed,
+		/// The recipient of a typed transfer is not allowed by the runtime's recipient gate.
+		RecipientNotAllowed,
+		/// The multisig already holds the maximum number of pending proposals.
+		TooManyPendingProposals,
+		/// The multisig's unreserved balance cannot cover the proposed transfer.
+		InsufficientBalance,
+		/// The proposal would spend into the protected reserve without unanimous approval.
+		ReserveBreached,
+		/// The payroll has no payees, a zero period or a first payout that is not in the future.
+		InvalidPayroll,
+		/// The payroll has more payees than allowed.
+		TooManyPayees,
+		/// Too many payrolls already fall due in the requested block.
+		PayrollAgendaFull,
+		/// The multisig has no payroll.
+		PayrollNotFound,
+		/// The vesting schedule of a vested transfer proposal is not allowed.
+		InvalidVestingSchedule,
+		/// An operator session must last at least one block.
+		InvalidSession,
+		/// The account has no operator session for this multisig.
+		SessionNotFound,
+		/// The operator session has expired.
+		SessionExpired,
+		/// The call is outside the scope of the operator session.
+		CallOutsideSession,
+		/// The account cannot reserve the required deposit.
+		InsufficientDeposit,
+		/// The multisig's storage is already consistent.
+		NothingToReconcile,
+		/// The owner is suspended and cannot propose or confirm.
+		SuspendedOwner,
+		/// The owner is already suspended.
+		AlreadySuspended,
+		/// The owner is not suspended.
+		NotSuspended,
+		/// The multisig has no backup owner set.
+		BackupOwnersNotFound,
+		/// The owners have been active within the backup owner set's inactivity period.
+		OwnersStillActive,
+		/// The velocity limit is invalid: its period is zero, or its override threshold is
+		/// below the multisig's threshold or above the total vote weight of its owners.
+		InvalidVelocityLimit,
+		/// The proposal would exceed the multisig's velocity limit for this period and does not
+		/// have the approvals to override it.
+		VelocityLimitExceeded,
+		/// A pool proposal must bond a non-zero amount.
+		InvalidPoolBond,
+		/// The session keys are not the length of the runtime's session keys.
+		InvalidSessionKeys,
+		/// The maximum number of active multisigs has been reached.
+		TooManyMultisigs,
+		/// The uploaded call would exceed `MaxCallSize`.
+		CallTooLarge,
+		/// There is no call being uploaded, or uploaded for the proposal.
+		CallUploadNotFound,
+		/// The uploaded bytes do not decode to a call.
+		UndecodableCall,
+		/// The call is critical and every owner must approve it.
+		UnanimityRequired,
+		/// The caller has not approved this proposal.
+		NotApproved,
+		/// Only the proposer may do this.
+		NotProposer,
+		/// The proposal has expired and can no longer be confirmed or executed.
+		Expired,
+		/// The proposal has not expired yet.
+		NotExpired,
+		/// A proposal's lifetime must be at least one block.
+		InvalidLifetime,
+		/// The caller has already voted against this proposal.
+		AlreadyRejected,
+		/// An owner's vote weight must be at least one.
+		InvalidVoteWeight,
+		/// The multisig's call filter does not permit proposing this call.
+		CallFiltered,
+		/// The call filter lists more than `MaxCallFilterEntries` calls.
+		TooManyFilterEntries,
+		/// A spending limit's period must be at least one block.
+		InvalidSpendingLimit,
+		/// The multisig has no spending limit.
+		NoSpendingLimit,
+		/// The transfer would exceed the multisig's allowance for the current period.
+		SpendingLimitExceeded,
+		/// The proposal's execution delay has not yet elapsed since it met its threshold.
+		TimelockActive,
+		/// The caller is not a veto account of the multisig.
+		NotAVetoer,
+		/// The multisig has no guardian configuration.
+		RecoveryNotConfigured,
+		/// The caller is not a guardian of the multisig.
+		NotAGuardian,
+		/// A recovery of the multisig is already in progress.
+		RecoveryAlreadyActive,
+		/// No recovery of the multisig is in progress.
+		NoActiveRecovery,
+		/// The guardian has already approved the recovery.
+		RecoveryAlreadyApproved,
+		/// Too few guardians have approved the recovery.
+		RecoveryNotApproved,
+		/// The recovery's delay has not yet passed.
+		RecoveryDelayActive,
+		/// The delegation is for no blocks, or its delegate is an owner or already a delegate
+		/// of another owner.
+		InvalidDelegation,
+		/// The owner has not delegated their confirmation right.
+		NoDelegation,
+		/// The caller is not a proxy of the owner.
+		NotAProxy,
+		/// The sovereign account is already used by another multisig.
+		AccountInUse,
+		/// The weight of the call exceeds the `max_weight` given for its execution.
+		MaxWeightTooLow,
+		/// The multisig already holds pre-approvals for the maximum number of call hashes.
+		TooManyPreApprovals,
+		/// The signature does not verify against the payload and the given owner.
+		InvalidSignature,
+		/// The dispatch class given for an uploaded call is not the call's own.
+		WrongDispatchClass,
+	}
+
+	/// The multisigs a chain launches with, such as its treasury or council wallets.
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The owners, threshold and optional endowment of each multisig, created in order
+		/// from ID zero.
+		///
+		/// The first owner is recorded as the creator and pays any creation deposit. The
+		/// endowment is minted into the sovereign account.
+		pub multisigs: Vec<GenesisMultisigOf<T>>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (owners, threshold, endowment) in &self.multisigs {
+				let creator = owners.first().cloned().expect("a genesis multisig has no owners");
+				let owners = Pallet::<T>::bounded_owner_set(owners.clone(), *threshold)
+					.expect("a genesis multisig has an invalid owner set or threshold");
+				let multisig = Multisig {
+					owners,
+					threshold: *threshold,
+					required_approvers: BoundedVec::new(),
+					approval_order: BoundedVec::new(),
+				};
+				let multisig_id = Pallet::<T>::do_create_multisig(creator, multisig, None)
+					.expect("a genesis multisig could not be created");
+				if let Some(amount) = endowment {
+					let _ = T::Currency::deposit_creating(
+						&Pallet::<T>::multi_account_id(multisig_id),
+						*amount,
+					);
+				}
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Pays out the payrolls falling due in this block.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let agenda = <PayrollAgenda<T>>::take(now);
+			let count = agenda.len() as u32;
+			for multisig_id in agenda {
+				Self::pay_payroll(multisig_id, now);
+			}
+			T::WeightInfo::pay_payrolls(count, T::MaxPayees::get())
+		}
+
+		/// Checks that the runtime configures the pallet so wallets can actually be used.
+		fn integrity_test() {
+			assert!(T::MaxOwners::get() > 0, "`MaxOwners` must allow at least one owner");
+			assert!(
+				T::MaxPendingProposals::get() > 0,
+				"`MaxPendingProposals` must allow at least one pending proposal"
+			);
+			assert!(
+				T::MaxMultisigsPerOwner::get() > 0,
+				"`MaxMultisigsPerOwner` must allow owning at least one multisig"
+			);
+			assert!(
+				!T::ProposalLifetime::get().is_zero(),
+				"`ProposalLifetime` must keep proposals open for at least one block"
+			);
+			assert!(
+				T::DefaultExecutionDelay::get() < T::ProposalLifetime::get(),
+				"`DefaultExecutionDelay` must be shorter than `ProposalLifetime`, or proposals \
+				 expire before they can be executed"
+			);
+			// The forfeited share of an expired proposal's deposit pays for its removal.
+			assert!(
+				T::ExpiredDepositSlash::get().is_zero() ||
+					!T::DepositCalculator::proposal_deposit(0, 0).is_zero(),
+				"`ExpiredDepositSlash` requires the `DepositCalculator` to charge a proposal \
+				 deposit"
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Creates a new, persistent multisig wallet.
+		///
+		/// This extrinsic allows a signed user to create a new multisig wallet with a
+		/// specified set of owners and an approval threshold. Upon successful creation,
+		/// the wallet is assigned a unique `MultisigId` and a deterministic, sovereign
+		/// `AccountId` is derived. This sovereign account can hold funds and dispatch
+		/// calls on behalf of the multisig owners.
+		///
+		/// If an `endowment` is given, it is transferred from the creator to the sovereign
+		/// account in the same transaction, so the wallet is funded above the existential
+		/// deposit from the start. If the transfer fails, the wallet is not created.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the user creating the multisig.
+		/// - `owners`: A vector of `AccountId`s who will be the owners of the new wallet.
+		/// - `threshold`: The number of owner approvals required to execute a proposal.
+		/// - `endowment`: The amount to fund the sovereign account with, if any.
+		///
+		/// ### Emits:
+		/// - `MultisigCreated` on successful creation.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_multisig(owners.len() as u32))]
+		pub fn create_multisig(
+			origin: OriginFor<T>,
+			owners: Vec<T::AccountId>,
+			threshold: u32,
+			endowment: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			//   Convert the user-provided `Vec` into a `BoundedVec` to prevent a
+			// potential DoS attack where a user provides an extremely large number of owners,
+			// bloating storage and computation. This fails if `owners.len() > MaxOwners`.
+			let bounded_owners: BoundedVec<_, _> =
+				owners.try_into().map_err(|_| Error::<T>::TooManyOwners)?;
+
+			// Ensure the threshold is a sensible value.
+			ensure!(
+				threshold > 0 && threshold <= bounded_owners.len() as u32,
+				Error::<T>::InvalidThreshold
+			);
+
+			// Create and store the new multisig's configuration.
+			let new_multisig = Multisig {
+				owners: bounded_owners,
+				threshold,
+				required_approvers: BoundedVec::new(),
+				approval_order: BoundedVec::new(),
+			};
+			let multisig_id = Self::do_create_multisig(who.clone(), new_multisig, None)?;
+
+			if let Some(amount) = endowment {
+				T::Currency::transfer(
+					&who,
+					&Self::multi_account_id(multisig_id),
+					amount,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			}
+
+			Ok(())
+		}
+
+		/// Submits a new proposal for a multisig wallet to execute.
+		///
+		/// This extrinsic can only be called by an owner of the specified multisig.
+		/// It creates a new proposal record, storing the hash of the `call` to be
+		/// executed. The submitter's account is automatically added as the first
+		/// confirmation for the proposal.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the multisig owner submitting the proposal.
+		/// - `multisig_id`: The ID of the multisig for which the proposal is being made.
+		/// - `call`: The `RuntimeCall` that the multisig owners will vote on to execute.
+		///
+		/// ### Emits:
+		/// - `ProposalSubmitted` on successful submission.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::submit_proposal())]
+		pub fn submit_proposal(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_submit_proposal(who, multisig_id, &call, None, None, None)?;
+			Self::meter_usage(
+				multisig_id,
+				MeteredOperation::Submission,
+				T::WeightInfo::submit_proposal(),
+			);
+			Ok(())
+		}
+
+		/// Confirms a pending proposal.
+		///
+		/// This extrinsic can only be called by an owner of the specified multisig who has not
+		/// yet confirmed the proposal, or by an account the owner has delegated their
+		/// confirmation right to, in which case the confirmation counts as the owner's.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner confirming the proposal, or their delegate.
+		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
+		/// - `proposal_index`: The index of the proposal being confirmed.
+		///
+		/// ### Emits:
+		/// - `Confirmation` on successful confirmation.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::confirm_proposal(
+			T::MaxOwners::get(),
+			T::MaxOwners::get()
+		))]
+		pub fn confirm_proposal(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = Self::delegator_of(multisig_id, ensure_signed(origin)?);
+			let weight = Self::do_confirm_proposal(who, multisig_id, proposal_index)?;
+			Ok(Some(weight).into())
+		}
+
+		/// Executes a proposal that has met its confirmation threshold.
+		///
+		/// This extrinsic can be called by any signed account, as the authorization is
+		/// based on the on-chain confirmation state, not the caller's identity.
+		///
+		/// ### Parameters:
+		/// - `origin`: Any signed account.
+		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
+		/// - `proposal_index`: The index of the proposal to be executed.
+		/// - `call`: The full `RuntimeCall` corresponding to the proposal's stored hash.
+		/// - `max_weight`: The most weight the call may have, which must be at least its weight. It
+		///   is charged up front, in the call's dispatch class, so blockspace is reserved for what
+		///   the proposal dispatches; any weight the call does not use is refunded.
+		///
+		/// ### Emits:
+		/// - `ProposalExecuted` with the result of the dispatched call.
+		#[pallet::call_index(3)]
+		#[pallet::weight((
+			T::WeightInfo::execute_proposal(T::MaxOwners::get(), T::MaxOwners::get())
+				.saturating_add(*max_weight),
+			call.get_dispatch_info().class,
+		))]
+		pub fn execute_proposal(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				call.get_dispatch_info().call_weight.all_lte(max_weight),
+				Error::<T>::MaxWeightTooLow
+			);
+			let weight = Self::do_execute_proposal(who, multisig_id, proposal_index, call)?;
+			Ok(Some(weight).into())
+		}
+
+		/// Destroys a multisig wallet and cleans up all associated storage.
+		///
+		///  This is a sovereign action. It can only be called successfully
+		/// if the `origin` is the multisig's own derived `AccountId`. This means that
+		/// to destroy a wallet, the owners must first propose, confirm, and execute a
+		/// call to this very extrinsic.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig being destroyed.
+		/// - `multisig_id`: The ID of the multisig to destroy.
+		///
+		/// ### Emits:
+		/// - `MultisigDestroyed` on successful destruction.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::destroy_multisig(
+			T::MaxOwners::get(),
+			T::MaxPendingProposals::get(),
+		))]
+		pub fn destroy_multisig(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let multisig_account = Self::multi_account_id(multisig_id);
+
+			// The core security check for this extrinsic.
+			ensure!(who == multisig_account, Error::<T>::MustBeMultisig);
+
+			// A check to ensure we are not trying to destroy a non-existent multisig.
+			let multisig = <Multisigs<T>>::get(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+
+			// Funds earmarked for other pending transfers still belong to the wallet, so they
+			// are released and then count towards the balance check below, and the deposits
+			// paid for the storage about to be cleaned up are refunded.
+			Self::release_multisig_reserves(multisig_id);
+
+			//   This is a critical safety net to prevent the accidental, irreversible
+			// loss of funds. It forces the owners to first empty the wallet's balance
+			// via a separate proposal before they can destroy it.
+			let balance = T::Currency::free_balance(&multisig_account);
+			ensure!(balance.is_zero(), Error::<T>::NonZeroBalance);
+			// The same applies to collectibles, which would otherwise be stranded.
+			ensure!(!T::ItemCustody::holds_items(&multisig_account), Error::<T>::ItemsHeld);
+
+			// Clean up all storage associated with the multisig.
+			//    `clear_prefix` is used for convenience to clean up all proposals,
+			// along with their approvals, in a single action. While this has a variable weight, the
+			// sovereign security model ensures this potentially expensive operation is a
+			// deliberate, multi-approved decision.
+			let weight = T::WeightInfo::destroy_multisig(
+				multisig.owners.len() as u32,
+				Self::pending_proposal_count(multisig_id),
+			);
+			<Multisigs<T>>::remove(multisig_id);
+			Self::reindex_owners(multisig_id, &multisig.owners, &[]);
+			ActiveMultisigCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			Self::clear_multisig_storage(multisig_id);
+
+			Self::deposit_event(Event::MultisigDestroyed { multisig_id });
+			T::OnMultisigDestroyed::on_multisig_destroyed(multisig_id);
+			Ok(Some(weight).into())
+		}
+
+		/// Registers an approval for a call hash before a proposal for it has been submitted.
+		///
+		/// This supports air-gapped signers who review upcoming transactions offline: when a
+		/// proposal with a matching `call_hash` is later submitted to this multisig, the
+		/// caller is counted as having confirmed it automatically.
+		///
+		/// A multisig holds pre-approvals for at most `MaxPendingProposals` call hashes at once.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner registering the pre-approval.
+		/// - `multisig_id`: The ID of the multisig the future proposal will belong to.
+		/// - `call_hash`: The hash of the encoded call being pre-approved, see `call_hash`.
+		///
+		/// ### Emits:
+		/// - `PreApproved` on success.
+		///
+		/// ### Errors:
+		/// - `TooManyPreApprovals` if this would pre-approve a call hash beyond the cap.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::pre_approve())]
+		pub fn pre_approve(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			call_hash: CallHashOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			ensure!(multisig.owners.contains(&who), Error::<T>::NotAnOwner);
+
+			<PreApprovals<T>>::try_mutate(multisig_id, call_hash, |approvers| -> DispatchResult {
+				ensure!(!approvers.contains(&who), Error::<T>::AlreadyPreApproved);
+				if approvers.is_empty() {
+					let count = Self::pre_approval_count(multisig_id);
+					ensure!(count < T::MaxPendingProposals::get(), Error::<T>::TooManyPreApprovals);
+					<PreApprovalCount<T>>::insert(multisig_id, count + 1);
+				}
+				approvers.try_push(who.clone()).map_err(|_| Error::<T>::TooManyOwners)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PreApproved { who, multisig_id, call_hash });
+			Ok(())
+		}
+
+		/// Withdraws a pre-approval previously registered with `pre_approve`.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner withdrawing the pre-approval.
+		/// - `multisig_id`: The ID of the multisig the pre-approval belongs to.
+		/// - `call_hash`: The call hash that should no longer be pre-approved.
+		///
+		/// ### Emits:
+		/// - `PreApprovalCancelled` on success.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::cancel_pre_approval())]
+		pub fn cancel_pre_approval(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			call_hash: CallHashOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<PreApprovals<T>>::try_mutate_exists(
+				multisig_id,
+				call_hash,
+				|maybe_approvers| -> DispatchResult {
+					let approvers =
+						maybe_approvers.as_mut().ok_or(Error::<T>::PreApprovalNotFound)?;
+					let position = approvers
+						.iter()
+						.position(|a| a == &who)
+						.ok_or(Error::<T>::PreApprovalNotFound)?;
+					approvers.remove(position);
+					if approvers.is_empty() {
+						*maybe_approvers = None;
+						Self::note_pre_approval_removed(multisig_id);
+					}
+					Ok(())
+				},
+			)?;
+
+			Self::deposit_event(Event::PreApprovalCancelled { who, multisig_id, call_hash });
+			Ok(())
+		}
+
+		/// Submits a typed proposal for a multisig wallet to execute.
+		///
+		/// This behaves like `submit_proposal`, except that the call is built by the runtime
+		/// from a structured description. Because the pallet understands what a typed proposal
+		/// does, owners' standing approvals that match it are counted automatically. The amount
+		/// of a transfer is reserved in the sovereign account until the proposal executes, so
+		/// pending transfers can never collectively exceed the wallet's balance.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the multisig owner submitting the proposal.
+		/// - `multisig_id`: The ID of the multisig for which the proposal is being made.
+		/// - `proposal`: The typed description of the action to propose.
+		///
+		/// ### Emits:
+		/// - `ProposalSubmitted` on successful submission.
+		/// - `Confirmation` for each owner whose standing approval matched.
+		/// - `FundsEarmarked` for a transfer.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::submit_typed_proposal())]
+		pub fn submit_typed_proposal(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal: TypedProposalOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if let Some(recipient) = proposal.recipient() {
+				ensure!(T::RecipientGate::contains(recipient), Error::<T>::RecipientNotAllowed);
+			}
+			Self::ensure_within_limits(multisig_id, &proposal)?;
+			let sovereign = Self::multi_account_id(multisig_id);
+			let call = T::CallBuilder::build(&sovereign, &proposal)
+				.ok_or(Error::<T>::UnsupportedProposal)?;
+			let proposal_index =
+				Self::do_submit_proposal(who, multisig_id, &call, Some(&proposal), None, None)?;
+
+			// Earmark the funds a transfer will spend, so other proposals cannot promise them.
+			if let Some(&amount) = proposal.transfer_amount() {
+				T::Currency::reserve(&sovereign, amount)
+					.map_err(|_| Error::<T>::InsufficientBalance)?;
+				<Earmarks<T>>::insert(multisig_id, proposal_index, amount);
+				Self::deposit_event(Event::FundsEarmarked { multisig_id, proposal_index, amount });
+			}
+			Ok(())
+		}
+
+		/// Grants a standing approval for typed transfers matching a pattern.
+		///
+		/// Until `expires_at`, every typed transfer proposal to `dest` of at most `max_amount`
+		/// counts the caller's approval without a separate confirmation, reducing signer
+		/// fatigue for recurring, known-good payments.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner granting the approval.
+		/// - `multisig_id`: The ID of the multisig the approval applies to.
+		/// - `dest`: The only recipient the approval covers.
+		/// - `max_amount`: The largest transfer amount the approval covers.
+		/// - `expires_at`: The last block at which the approval is counted.
+		///
+		/// ### Emits:
+		/// - `StandingApprovalGranted` on success.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::grant_standing_approval())]
+		pub fn grant_standing_approval(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			dest: T::AccountId,
+			max_amount: BalanceOf<T>,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			ensure!(multisig.owners.contains(&who), Error::<T>::NotAnOwner);
+			ensure!(
+				expires_at >= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::StandingApprovalExpired
+			);
+
+			let approval = StandingApproval { dest, max_amount, expires_at };
+			<StandingApprovals<T>>::try_mutate(multisig_id, &who, |approvals| {
+				approvals
+					.try_push(approval.clone())
+					.map_err(|_| Error::<T>::TooManyStandingApprovals)
+			})?;
+
+			Self::deposit_event(Event::StandingApprovalGranted { who, multisig_id, approval });
+			Ok(())
+		}
+
+		/// Revokes one of the caller's standing approvals.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner revoking the approval.
+		/// - `multisig_id`: The ID of the multisig the approval applies to.
+		/// - `index`: The position of the approval in the caller's list of standing approvals.
+		///
+		/// ### Emits:
+		/// - `StandingApprovalRevoked` on success.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::revoke_standing_approval())]
+		pub fn revoke_standing_approval(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let approval = <StandingApprovals<T>>::try_mutate_exists(
+				multisig_id,
+				&who,
+				|maybe_approvals| -> Result<StandingApprovalOf<T>, DispatchError> {
+					let approvals =
+						maybe_approvals.as_mut().ok_or(Error::<T>::StandingApprovalNotFound)?;
+					ensure!(
+						(index as usize) < approvals.len(),
+						Error::<T>::StandingApprovalNotFound
+					);
+					let approval = approvals.remove(index as usize);
+					if approvals.is_empty() {
+						*maybe_approvals = None;
+					}
+					Ok(approval)
+				},
+			)?;
+
+			Self::deposit_event(Event::StandingApprovalRevoked { who, multisig_id, approval });
+			Ok(())
+		}
+
+		/// Designates the owners whose approval is mandatory for every proposal.
+		///
+		/// A proposal can only be executed once all of these owners have approved it, in
+		/// addition to meeting the numeric threshold (e.g. the CFO must always sign). Passing
+		/// an empty list removes the requirement. Like `destroy_multisig`, this must be
+		/// dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `approvers`: The owners whose approval becomes mandatory.
+		///
+		/// ### Emits:
+		/// - `RequiredApproversSet` on success.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_required_approvers())]
+		pub fn set_required_approvers(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			approvers: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let mut multisig = Self::ensure_sovereign(origin, multisig_id)?;
+
+			let bounded: BoundedVec<_, _> =
+				approvers.clone().try_into().map_err(|_| Error::<T>::TooManyOwners)?;
+			for (i, approver) in bounded.iter().enumerate() {
+				ensure!(multisig.owners.contains(approver), Error::<T>::NotAnOwner);
+				ensure!(!bounded[..i].contains(approver), Error::<T>::DuplicateAccount);
+			}
+			multisig.required_approvers = bounded;
+			<Multisigs<T>>::insert(multisig_id, multisig);
+
+			Self::deposit_event(Event::RequiredApproversSet { multisig_id, approvers });
+			Ok(())
+		}
+
+		/// Configures an ordered sign-off chain for the multisig.
+		///
+		/// Owners in `order` may only approve a proposal (including by submitting it) once
+		/// every owner ahead of them in the list has approved, matching corporate sign-off
+		/// chains such as analyst, then manager, then director. Owners not listed may approve
+		/// at any time. Passing an empty list makes approvals unordered again. This must be
+		/// dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `order`: The owners, in the order in which they must approve.
+		///
+		/// ### Emits:
+		/// - `ApprovalOrderSet` on success.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_approval_order())]
+		pub fn set_approval_order(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			order: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let mut multisig = Self::ensure_sovereign(origin, multisig_id)?;
+
+			let bounded: BoundedVec<_, _> =
+				order.clone().try_into().map_err(|_| Error::<T>::TooManyOwners)?;
+			for (i, member) in bounded.iter().enumerate() {
+				ensure!(multisig.owners.contains(member), Error::<T>::NotAnOwner);
+				ensure!(!bounded[..i].contains(member), Error::<T>::DuplicateAccount);
+			}
+			multisig.approval_order = bounded;
+			<Multisigs<T>>::insert(multisig_id, multisig);
+
+			Self::deposit_event(Event::ApprovalOrderSet { multisig_id, order });
+			Ok(())
+		}
+
+		/// Sets a quorum rule over named owner groups, such as requiring 2-of-Engineering AND
+		/// 1-of-Legal.
+		///
+		/// The rule is evaluated at execution time in addition to the multisig's overall
+		/// threshold. Passing no groups removes the rule. This must be dispatched by the
+		/// multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `rule`: Whether all groups or any single group must reach its threshold.
+		/// - `groups`: The owner groups, each with its own threshold.
+		///
+		/// ### Emits:
+		/// - `GroupQuorumSet` on success.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::set_group_quorum())]
+		pub fn set_group_quorum(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			rule: GroupRule,
+			groups: Vec<OwnerGroupOf<T>>,
+		) -> DispatchResult {
+			let multisig = Self::ensure_sovereign(origin, multisig_id)?;
+
+			let groups: BoundedVec<_, T::MaxGroups> =
+				groups.try_into().map_err(|_| Error::<T>::TooManyGroups)?;
+			for group in groups.iter() {
+				ensure!(
+					group.threshold > 0 && group.threshold <= group.members.len() as u32,
+					Error::<T>::InvalidGroupThreshold
+				);
+				for (i, member) in group.members.iter().enumerate() {
+					ensure!(multisig.owners.contains(member), Error::<T>::NotAnOwner);
+					ensure!(!group.members[..i].contains(member), Error::<T>::DuplicateAccount);
+				}
+			}
+
+			let count = groups.len() as u32;
+			if groups.is_empty() {
+				<GroupQuorums<T>>::remove(multisig_id);
+			} else {
+				<GroupQuorums<T>>::insert(multisig_id, GroupQuorum { rule, groups });
+			}
+
+			Self::deposit_event(Event::GroupQuorumSet { multisig_id, rule, groups: count });
+			Ok(())
+		}
+
+		/// Creates a new multisig wallet with the same governance as an existing one.
+		///
+		/// The owners with their labels and vote weights, the threshold and every policy of
+		/// `source_id` are copied to a wallet with a fresh ID and sovereign account. The policies
+		/// are the mandatory approvers, sign-off order, group quorum, class thresholds, call
+		/// filter, contract, EVM, velocity and spending limits, protected reserve, threshold
+		/// decay and execution delay. The source's auditors, vetoers and recovery guardians are
+		/// copied too, so they keep the same powers over the clone. Owners' personal
+		/// pre-approvals and standing approvals are not copied. This saves organizations from
+		/// re-entering the configuration for every identically-governed project wallet.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the user creating the multisig.
+		/// - `source_id`: The ID of the multisig whose configuration is copied.
+		///
+		/// ### Emits:
+		/// - `MultisigCreated` for the new wallet.
+		/// - `MultisigCloned` linking it to its source.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::clone_multisig())]
+		pub fn clone_multisig(origin: OriginFor<T>, source_id: T::MultisigId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let source = Self::multisigs(source_id).ok_or(Error::<T>::MultisigNotFound)?;
+
+			let multisig_id = Self::do_create_multisig(who, source, None)?;
+			if let Some(quorum) = Self::group_quorums(source_id) {
+				<GroupQuorums<T>>::insert(multisig_id, quorum);
+			}
+			if let Some(limits) = Self::contract_limits(source_id) {
+				<MultisigContractLimits<T>>::insert(multisig_id, limits);
+			}
+			if let Some(limits) = Self::evm_limits(source_id) {
+				<MultisigEvmLimits<T>>::insert(multisig_id, limits);
+			}
+			if let Some(amount) = Self::protected_reserve(source_id) {
+				<ProtectedReserves<T>>::insert(multisig_id, amount);
+			}
+			if let Some(limit) = Self::velocity_limit(source_id) {
+				<VelocityLimits<T>>::insert(multisig_id, limit);
+			}
+			if let Some(limit) = Self::spending_limit(source_id) {
+				<SpendingLimits<T>>::insert(multisig_id, limit);
+			}
+			if let Some(decay) = Self::threshold_decay(source_id) {
+				<ThresholdDecays<T>>::insert(multisig_id, decay);
+				<LastExecutedAt<T>>::insert(multisig_id, frame_system::Pallet::<T>::block_number());
+			}
+			if let Some(delay) = <ExecutionDelays<T>>::get(source_id) {
+				<ExecutionDelays<T>>::insert(multisig_id, delay);
+			}
+			let auditors = Self::auditors(source_id);
+			if !auditors.is_empty() {
+				<Auditors<T>>::insert(multisig_id, auditors);
+			}
+			let vetoers = Self::vetoers(source_id);
+			if !vetoers.is_empty() {
+				<Vetoers<T>>::insert(multisig_id, vetoers);
+			}
+			if let Some(recovery) = Self::recovery_config(source_id) {
+				<RecoveryConfigs<T>>::insert(multisig_id, recovery);
+			}
+			for (owner, label) in <OwnerLabels<T>>::iter_prefix(source_id) {
+				<OwnerLabels<T>>::insert(multisig_id, owner, label);
+			}
+			for (owner, weight) in <OwnerWeights<T>>::iter_prefix(source_id) {
+				<OwnerWeights<T>>::insert(multisig_id, owner, weight);
+			}
+			for (class, threshold) in <ClassThresholds<T>>::iter_prefix(source_id) {
+				<ClassThresholds<T>>::insert(multisig_id, class, threshold);
+			}
+			if let Some(filter) = Self::call_filter(source_id) {
+				<CallFilters<T>>::insert(multisig_id, filter);
+			}
+
+			let config_hash =
+				Self::current_config_commitment(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			Self::deposit_event(Event::MultisigCloned { source_id, multisig_id, config_hash });
+			Ok(())
+		}
+
+		/// Submits a proposal that references off-chain supporting documentation.
+		///
+		/// This behaves like `submit_proposal`, but also stores `content_ref`, typically the
+		/// CID or hash of an invoice or legal agreement, alongside the proposal. Because the
+		/// reference cannot be changed afterwards, owners who approve the proposal approve it
+		/// against that exact paperwork.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner submitting the proposal.
+		/// - `multisig_id`: The ID of the multisig the proposal is for.
+		/// - `call`: The runtime call to be executed upon approval.
+		/// - `content_ref`: The reference to the off-chain content.
+		///
+		/// ### Emits:
+		/// - `ProposalSubmitted`, carrying the content reference, on successful submission.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::submit_proposal_with_content())]
+		pub fn submit_proposal_with_content(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			call: Box<<T as Config>::RuntimeCall>,
+			content_ref: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let content_ref: ContentRefOf<T> =
+				content_ref.try_into().map_err(|_| Error::<T>::ContentRefTooLong)?;
+			Self::do_submit_proposal(who, multisig_id, &call, None, Some(content_ref), None)?;
+			Ok(())
+		}
+
+		/// Registers a named wallet template.
+		///
+		/// Templates standardize custody policy across an organization's wallets: each wallet
+		/// created from one gets the same threshold, mandatory approvers and sign-off chain,
+		/// expressed in terms of owner roles. Only `TemplateOrigin` may register templates.
+		///
+		/// ### Parameters:
+		/// - `origin`: The `TemplateOrigin`, typically governance.
+		/// - `name`: A human-readable name for the template.
+		/// - `roles`: The owner roles, one per owner of an instantiated wallet.
+		/// - `threshold`: The number of owner approvals required to execute a proposal.
+		/// - `required_roles`: Indices into `roles` whose approval is mandatory.
+		/// - `approval_order`: Indices into `roles` forming the sign-off chain, in order.
+		///
+		/// ### Emits:
+		/// - `TemplateRegistered` on success.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::register_template())]
+		pub fn register_template(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			roles: Vec<Vec<u8>>,
+			threshold: u32,
+			required_roles: Vec<u32>,
+			approval_order: Vec<u32>,
+		) -> DispatchResult {
+			T::TemplateOrigin::ensure_origin(origin)?;
+
+			let bounded_name: NameOf<T> =
+				name.clone().try_into().map_err(|_| Error::<T>::NameTooLong)?;
+			let roles = roles
+				.into_iter()
+				.map(|role| role.try_into().map_err(|_| Error::<T>::NameTooLong))
+				.collect::<Result<Vec<NameOf<T>>, _>>()?;
+			let roles: BoundedVec<_, _> =
+				roles.try_into().map_err(|_| Error::<T>::TooManyOwners)?;
+			ensure!(threshold > 0 && threshold <= roles.len() as u32, Error::<T>::InvalidThreshold);
+
+			let required_roles: BoundedVec<_, _> =
+				required_roles.try_into().map_err(|_| Error::<T>::InvalidTemplate)?;
+			let approval_order: BoundedVec<_, _> =
+				approval_order.try_into().map_err(|_| Error::<T>::InvalidTemplate)?;
+			for indices in [&required_roles, &approval_order] {
+				for (i, index) in indices.iter().enumerate() {
+					ensure!((*index as usize) < roles.len(), Error::<T>::InvalidTemplate);
+					ensure!(!indices[..i].contains(index), Error::<T>::InvalidTemplate);
+				}
+			}
+
+			let template_id = Self::next_template_id();
+			NextTemplateId::<T>::put(
+				template_id.checked_add(1).ok_or(Error::<T>::StorageOverflow)?,
+			);
+			let template = WalletTemplate {
+				name: bounded_name,
+				roles,
+				threshold,
+				required_roles,
+				approval_order,
+			};
+			<Templates<T>>::insert(template_id, template);
+
+			Self::deposit_event(Event::TemplateRegistered { template_id, name });
+			Ok(())
+		}
+
+		/// Removes a wallet template from the registry.
+		///
+		/// Wallets already created from the template are unaffected.
+		///
+		/// ### Parameters:
+		/// - `origin`: The `TemplateOrigin`, typically governance.
+		/// - `template_id`: The ID of the template to remove.
+		///
+		/// ### Emits:
+		/// - `TemplateRemoved` on success.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::remove_template())]
+		pub fn remove_template(origin: OriginFor<T>, template_id: TemplateId) -> DispatchResult {
+			T::TemplateOrigin::ensure_origin(origin)?;
+			ensure!(<Templates<T>>::contains_key(template_id), Error::<T>::TemplateNotFound);
+			<Templates<T>>::remove(template_id);
+
+			Self::deposit_event(Event::TemplateRemoved { template_id });
+			Ok(())
+		}
+
+		/// Creates a new multisig wallet from a registered template.
+		///
+		/// Each account in `owners` takes the template role at the same position, and the
+		/// wallet's mandatory approvers and sign-off chain are derived from those roles.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the user creating the multisig.
+		/// - `template_id`: The ID of the template to instantiate.
+		/// - `owners`: One distinct account per template role, in role order.
+		///
+		/// ### Emits:
+		/// - `MultisigCreated` for the new wallet.
+		/// - `MultisigCreatedFromTemplate` linking it to its template.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::create_from_template())]
+		pub fn create_from_template(
+			origin: OriginFor<T>,
+			template_id: TemplateId,
+			owners: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let template = Self::templates(template_id).ok_or(Error::<T>::TemplateNotFound)?;
+
+			ensure!(owners.len() == template.roles.len(), Error::<T>::OwnersDoNotMatchTemplate);
+			for (i, owner) in owners.iter().enumerate() {
+				ensure!(!owners[..i].contains(owner), Error::<T>::DuplicateAccount);
+			}
+			let owners: BoundedVec<_, _> =
+				owners.try_into().map_err(|_| Error::<T>::TooManyOwners)?;
+			let by_role = |indices: &BoundedVec<u32, T::MaxOwners>| {
+				indices.iter().map(|index| owners[*index as usize].clone()).collect::<Vec<_>>()
+			};
+			let required_approvers = by_role(&template.required_roles)
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyOwners)?;
+			let approval_order = by_role(&template.approval_order)
+				.try_into()
+				.map_err(|_| Error::<T>::TooManyOwners)?;
+
+			let multisig = Multisig {
+				owners,
+				threshold: template.threshold,
+				required_approvers,
+				approval_order,
+			};
+			let multisig_id = Self::do_create_multisig(who, multisig, None)?;
+
+			Self::deposit_event(Event::MultisigCreatedFromTemplate { template_id, multisig_id });
+			Ok(())
+		}
+
+		/// Sets or removes the limits on contract proposals for a multisig.
+		///
+		/// Once set, every typed contract instantiation or call proposal must stay within the
+		/// value and gas caps, so a single compromised owner cannot even put an outsized contract
+		/// interaction up for a vote. This must be dispatched by the multisig's own sovereign
+		/// account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `limits`: The new limits, or `None` to lift them.
+		///
+		/// ### Emits:
+		/// - `ContractLimitsSet` on success.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_contract_limits())]
+		pub fn set_contract_limits(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			limits: Option<ContractLimitsOf<T>>,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			<MultisigContractLimits<T>>::set(multisig_id, limits.clone());
+
+			Self::deposit_event(Event::ContractLimitsSet { multisig_id, limits });
+			Ok(())
+		}
+
+		/// Sets or removes the limits on EVM proposals for a multisig.
+		///
+		/// Once set, every typed EVM proposal must stay within the value and gas caps. This must
+		/// be dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `limits`: The new limits, or `None` to lift them.
+		///
+		/// ### Emits:
+		/// - `EvmLimitsSet` on success.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::set_evm_limits())]
+		pub fn set_evm_limits(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			limits: Option<EvmLimits>,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			<MultisigEvmLimits<T>>::set(multisig_id, limits.clone());
+
+			Self::deposit_event(Event::EvmLimitsSet { multisig_id, limits });
+			Ok(())
+		}
+
+		/// Records a treasury spend that has been approved in favour of the multisig.
+		///
+		/// This gives grant programs paid out to the wallet a first-class record of what is
+		/// owed to it, so owners know which `TreasuryPayout` proposals to make.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of an owner of the multisig.
+		/// - `multisig_id`: The ID of the multisig the spend is for.
+		/// - `spend_index`: The treasury's index of the spend.
+		/// - `amount`: The amount the multisig expects to receive.
+		///
+		/// ### Emits:
+		/// - `InboundSpendTracked` on success.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::track_inbound_spend())]
+		pub fn track_inbound_spend(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			spend_index: u32,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			ensure!(multisig.owners.contains(&who), Error::<T>::NotAnOwner);
+			ensure!(
+				!<InboundSpends<T>>::contains_key(multisig_id, spend_index),
+				Error::<T>::SpendAlreadyTracked
+			);
+			<InboundSpends<T>>::insert(multisig_id, spend_index, amount);
+
+			Self::deposit_event(Event::InboundSpendTracked { multisig_id, spend_index, amount });
+			Ok(())
+		}
+
+		/// Stops tracking an expected treasury spend, typically once it has been paid out.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of an owner of the multisig.
+		/// - `multisig_id`: The ID of the multisig the spend is for.
+		/// - `spend_index`: The treasury's index of the spend.
+		///
+		/// ### Emits:
+		/// - `InboundSpendUntracked` on success.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::untrack_inbound_spend())]
+		pub fn untrack_inbound_spend(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			spend_index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			ensure!(multisig.owners.contains(&who), Error::<T>::NotAnOwner);
+			<InboundSpends<T>>::take(multisig_id, spend_index)
+				.ok_or(Error::<T>::SpendNotTracked)?;
+
+			Self::deposit_event(Event::InboundSpendUntracked { multisig_id, spend_index });
+			Ok(())
+		}
+
+		/// Sets the read-only auditors of a multisig.
+		///
+		/// Auditors cannot submit or approve proposals, but may flag any open proposal with a
+		/// reason, giving compliance teams a visible objection channel. Passing an empty list
+		/// removes all auditors. This must be dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `auditors`: The auditor accounts.
+		///
+		/// ### Emits:
+		/// - `AuditorsSet` on success.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::set_auditors())]
+		pub fn set_auditors(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			auditors: Vec<T::AccountId>,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+
+			let bounded: BoundedVec<_, _> =
+				auditors.clone().try_into().map_err(|_| Error::<T>::TooManyOwners)?;
+			for (i, auditor) in bounded.iter().enumerate() {
+				ensure!(!bounded[..i].contains(auditor), Error::<T>::DuplicateAccount);
+			}
+			<Auditors<T>>::insert(multisig_id, bounded);
+
+			Self::deposit_event(Event::AuditorsSet { multisig_id, auditors });
+			Ok(())
+		}
+
+		/// Flags an open proposal with a reason for objecting to it.
+		///
+		/// Flags do not block execution; they are recorded on-chain and announced so that
+		/// owners and observers can see the objection before the proposal is executed. Flagging
+		/// a proposal again replaces the auditor's earlier reason.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of an auditor of the multisig.
+		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
+		/// - `proposal_index`: The index of the proposal to flag.
+		/// - `reason`: The reason for the flag.
+		///
+		/// ### Emits:
+		/// - `ProposalFlagged` on success.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::flag_proposal())]
+		pub fn flag_proposal(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+			reason: Vec<u8>,
+		) -> DispatchResult {
+			let auditor = ensure_signed(origin)?;
+			ensure!(Self::auditors(multisig_id).contains(&auditor), Error::<T>::NotAnAuditor);
+			let proposal =
+				Self::proposals(multisig_id, proposal_index).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(proposal.status.is_open(), Error::<T>::AlreadyExecuted);
+			let bounded_reason: BoundedVec<_, _> =
+				reason.clone().try_into().map_err(|_| Error::<T>::ReasonTooLong)?;
+
+			<ProposalFlags<T>>::try_mutate(multisig_id, proposal_index, |flags| {
+				match flags.iter_mut().find(|(who, _)| who == &auditor) {
+					Some(flag) => flag.1 = bounded_reason,
+					None => flags
+						.try_push((auditor.clone(), bounded_reason))
+						.map_err(|_| Error::<T>::TooManyOwners)?,
+				}
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::ProposalFlagged {
+				auditor,
+				multisig_id,
+				proposal_index,
+				reason,
+			});
+			Ok(())
+		}
+
+		/// Dispatches a call from a multisig's sovereign account on the authority of chain
+		/// governance.
+		///
+		/// This is a rescue mechanism for catastrophic scenarios, such as every owner having
+		/// lost their keys, and bypasses the owners entirely. It can only be dispatched by root,
+		/// and always emits `DispatchedAsMultisig` so the intervention is visible.
+		///
+		/// ### Parameters:
+		/// - `origin`: Must be root.
+		/// - `multisig_id`: The ID of the multisig whose sovereign account dispatches the call.
+		/// - `call`: The call to dispatch.
+		///
+		/// ### Emits:
+		/// - `DispatchedAsMultisig` with the result of the dispatched call.
+		#[pallet::call_index(24)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			T::WeightInfo::dispatch_as_multisig().saturating_add(dispatch_info.call_weight)
+		})]
+		pub fn dispatch_as_multisig(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(<Multisigs<T>>::contains_key(multisig_id), Error::<T>::MultisigNotFound);
+
+			let multisig_account = Self::multi_account_id(multisig_id);
+			let info = call.get_dispatch_info();
+			let result = call.dispatch(RawOrigin::Signed(multisig_account).into());
+			let weight = T::WeightInfo::dispatch_as_multisig()
+				.saturating_add(extract_actual_weight(&result, &info));
+
+			Self::deposit_event(Event::DispatchedAsMultisig {
+				multisig_id,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+			Ok(Some(weight).into())
+		}
+
+		/// Sets or removes the protected reserve of a multisig.
+		///
+		/// Once set, a proposal that leaves the sovereign account's total balance below `amount`
+		/// fails unless every owner has approved it, giving treasuries an on-chain rule against
+		/// spending their runway. Proposals that do not reduce the balance are unaffected. This
+		/// must be dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `amount`: The new protected reserve, or `None` to remove it.
+		///
+		/// ### Emits:
+		/// - `ProtectedReserveSet` on success.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::set_protected_reserve())]
+		pub fn set_protected_reserve(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			amount: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			<ProtectedReserves<T>>::set(multisig_id, amount);
+
+			Self::deposit_event(Event::ProtectedReserveSet { multisig_id, amount });
+			Ok(())
+		}
+
+		/// Sets the payroll of a multisig, replacing any existing one.
+		///
+		/// From `first_payout`, and every `period` blocks after, each payee is paid their amount
+		/// from the sovereign account, so recurring payments need only be approved once. A
+		/// payment that would fail, or dip into the protected reserve, is skipped. This must be
+		/// dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `payees`: The accounts to pay each period, with the amount each receives.
+		/// - `period`: The number of blocks between payouts.
+		/// - `first_payout`: The block of the first payout, which must be in the future.
+		///
+		/// ### Emits:
+		/// - `PayrollSet` on success.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_payroll())]
+		pub fn set_payroll(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			payees: Vec<(T::AccountId, BalanceOf<T>)>,
+			period: BlockNumberFor<T>,
+			first_payout: BlockNumberFor<T>,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			ensure!(
+				!payees.is_empty() &&
+					!period.is_zero() &&
+					first_payout > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidPayroll
+			);
+			let payees: BoundedVec<_, _> =
+				payees.try_into().map_err(|_| Error::<T>::TooManyPayees)?;
+
+			Self::schedule_payroll(multisig_id, first_payout)?;
+			<Payrolls<T>>::insert(
+				multisig_id,
+				Payroll { payees, period, next_payout: first_payout, paused: false },
+			);
+
+			Self::deposit_event(Event::PayrollSet { multisig_id, first_payout });
+			Ok(())
+		}
+
+		/// Pauses or resumes the payroll of a multisig.
+		///
+		/// Payouts falling due while the payroll is paused are skipped, not deferred. This must
+		/// be dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig whose payroll to change.
+		/// - `paused`: Whether the payroll should be paused.
+		///
+		/// ### Emits:
+		/// - `PayrollPaused` on success.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_payroll_paused())]
+		pub fn set_payroll_paused(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			paused: bool,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			<Payrolls<T>>::try_mutate(multisig_id, |maybe_payroll| -> DispatchResult {
+				let payroll = maybe_payroll.as_mut().ok_or(Error::<T>::PayrollNotFound)?;
+				payroll.paused = paused;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PayrollPaused { multisig_id, paused });
+			Ok(())
+		}
+
+		/// Cancels the payroll of a multisig.
+		///
+		/// This must be dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig whose payroll to cancel.
+		///
+		/// ### Emits:
+		/// - `PayrollCancelled` on success.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::cancel_payroll())]
+		pub fn cancel_payroll(origin: OriginFor<T>, multisig_id: T::MultisigId) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			<Payrolls<T>>::take(multisig_id).ok_or(Error::<T>::PayrollNotFound)?;
+
+			Self::deposit_event(Event::PayrollCancelled { multisig_id });
+			Ok(())
+		}
+
+		/// Grants an operator session for a multisig, replacing any existing one.
+		///
+		/// For `duration` blocks, `operator` may execute any call admitted by `scope` from the
+		/// sovereign account without a proposal, such as day-to-day staking management. Any
+		/// owner can revoke the session early. This must be dispatched by the multisig's own
+		/// sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to grant the session for.
+		/// - `operator`: The account receiving the session.
+		/// - `scope`: The category of calls the operator may execute.
+		/// - `duration`: The number of blocks the session lasts.
+		///
+		/// ### Emits:
+		/// - `SessionGranted` on success.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::grant_session())]
+		pub fn grant_session(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			operator: T::AccountId,
+			scope: T::SessionScope,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			ensure!(!duration.is_zero(), Error::<T>::InvalidSession);
+
+			let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			<OperatorSessions<T>>::insert(
+				multisig_id,
+				&operator,
+				OperatorSession { scope: scope.clone(), expires_at },
+			);
+
+			Self::deposit_event(Event::SessionGranted { multisig_id, operator, scope, expires_at });
+			Ok(())
+		}
+
+		/// Revokes an operator session before it expires.
+		///
+		/// Any single owner may revoke a session, so a compromised or misbehaving operator can be
+		/// stopped without gathering a threshold.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of an owner of the multisig.
+		/// - `multisig_id`: The ID of the multisig the session belongs to.
+		/// - `operator`: The operator whose session to revoke.
+		///
+		/// ### Emits:
+		/// - `SessionRevoked` on success.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::revoke_session())]
+		pub fn revoke_session(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			operator: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			ensure!(multisig.owners.contains(&who), Error::<T>::NotAnOwner);
+			<OperatorSessions<T>>::take(multisig_id, &operator)
+				.ok_or(Error::<T>::SessionNotFound)?;
+
+			Self::deposit_event(Event::SessionRevoked { multisig_id, operator });
+			Ok(())
+		}
+
+		/// Executes a call from a multisig's sovereign account under an operator session.
+		///
+		/// The call must be admitted by the scope of the caller's unexpired session. Like a
+		/// proposal without unanimous approval, it may not spend into the protected reserve.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the operator.
+		/// - `multisig_id`: The ID of the multisig to act for.
+		/// - `call`: The call to dispatch.
+		///
+		/// ### Emits:
+		/// - `OperatorExecuted` with the result of the dispatched call.
+		#[pallet::call_index(31)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				T::WeightInfo::operator_execute().saturating_add(dispatch_info.call_weight),
+				dispatch_info.class,
+			)
+		})]
+		pub fn operator_execute(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			let operator = ensure_signed(origin)?;
+			ensure!(<Multisigs<T>>::contains_key(multisig_id), Error::<T>::MultisigNotFound);
+			let session = Self::operator_sessions(multisig_id, &operator)
+				.ok_or(Error::<T>::SessionNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= session.expires_at,
+				Error::<T>::SessionExpired
+			);
+			ensure!(session.scope.filter(&call), Error::<T>::CallOutsideSession);
+			ensure!(!T::CriticalCalls::contains(&call), Error::<T>::UnanimityRequired);
+
+			// Whatever the call spends counts against the velocity limit, and the call is
+			// undone if it exceeds it.
+			let info = call.get_dispatch_info();
+			let multisig_account = Self::multi_account_id(multisig_id);
+			let balance_before = T::Currency::total_balance(&multisig_account);
+			let result = with_storage_layer(|| {
+				let post_info = Self::dispatch_as_sovereign(multisig_id, *call, false, None)?;
+				let spent =
+					balance_before.saturating_sub(T::Currency::total_balance(&multisig_account));
+				Self::record_spend(multisig_id, spent).map_err(|error| {
+					error.with_weight(post_info.actual_weight.unwrap_or(info.call_weight))
+				})?;
+				Ok(post_info)
+			});
+			let weight = T::WeightInfo::operator_execute()
+				.saturating_add(extract_actual_weight(&result, &info));
+
+			Self::deposit_event(Event::OperatorExecuted {
+				multisig_id,
+				operator,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+			Ok(Some(weight).into())
+		}
+
+		/// Submits a proposal and tops up the multisig's sovereign account in the same
+		/// transaction.
+		///
+		/// This behaves like `submit_proposal`, but first transfers `amount` from the proposer
+		/// to the sovereign account, for example to cover the payment being proposed. Either
+		/// both the transfer and the submission happen, or neither does.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner submitting the proposal.
+		/// - `multisig_id`: The ID of the multisig the proposal is for.
+		/// - `call`: The runtime call to be executed upon approval.
+		/// - `amount`: The amount to transfer from the proposer to the multisig.
+		///
+		/// ### Emits:
+		/// - `ProposalSubmitted` on successful submission.
+		/// - `ProposalFunded` for the attached funds.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::submit_proposal_with_funds())]
+		pub fn submit_proposal_with_funds(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			call: Box<<T as Config>::RuntimeCall>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let proposal_index =
+				Self::do_submit_proposal(who.clone(), multisig_id, &call, None, None, None)?;
+
+			T::Currency::transfer(
+				&who,
+				&Self::multi_account_id(multisig_id),
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			Self::deposit_event(Event::ProposalFunded { multisig_id, proposal_index, who, amount });
+			Ok(())
+		}
+
+		/// Repairs inconsistent storage left behind for a multisig.
+		///
+		/// Anyone may call this. If the multisig no longer exists, every leftover entry kept
+		/// for it is removed, refunding its deposits and releasing its earmarked funds. If it
+		/// does exist, its bookkeeping is repaired against its proposals; see
+		/// `reconcile_multisig`. This cleans up after partial destructions and historical bugs.
+		///
+		/// ### Parameters:
+		/// - `origin`: Any signed account.
+		/// - `multisig_id`: The ID of the multisig to reconcile.
+		///
+		/// ### Emits:
+		/// - `Reconciled` with the number of repairs made.
+		///
+		/// ### Errors:
+		/// - `NothingToReconcile` if the multisig's storage is already consistent.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::destroy_multisig(
+			T::MaxOwners::get(),
+			T::MaxPendingProposals::get(),
+		))]
+		pub fn reconcile(origin: OriginFor<T>, multisig_id: T::MultisigId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let repaired = match Self::multisigs(multisig_id) {
+				Some(multisig) => Self::reconcile_multisig(multisig_id, &multisig),
+				None =>
+					Self::release_multisig_reserves(multisig_id) +
+						Self::clear_multisig_storage(multisig_id),
+			};
+			ensure!(repaired > 0, Error::<T>::NothingToReconcile);
+
+			Self::deposit_event(Event::Reconciled { multisig_id, repaired });
+			Ok(())
+		}
+
+		/// Confirms a proposal and executes it in the same transaction.
+		///
+		/// This is for the owner whose confirmation meets the threshold: it saves a second
+		/// transaction and leaves no window in which another account could execute the proposal
+		/// at an inopportune moment. If the proposal cannot be executed after the confirmation,
+		/// the confirmation is not recorded either.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner giving the final confirmation.
+		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
+		/// - `proposal_index`: The index of the proposal being confirmed and executed.
+		/// - `call`: The full `RuntimeCall` corresponding to the proposal's stored hash.
+		/// - `max_weight`: The most weight the call may have, charged up front as by
+		///   `execute_proposal`.
+		///
+		/// ### Emits:
+		/// - `Confirmation` on successful confirmation.
+		/// - `ProposalExecuted` with the result of the dispatched call.
+		#[pallet::call_index(34)]
+		#[pallet::weight((
+			T::WeightInfo::confirm_proposal(T::MaxOwners::get(), T::MaxOwners::get())
+				.saturating_add(T::WeightInfo::execute_proposal(
+					T::MaxOwners::get(),
+					T::MaxOwners::get(),
+				))
+				.saturating_add(*max_weight),
+			call.get_dispatch_info().class,
+		))]
+		pub fn confirm_and_execute(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+			call: Box<<T as Config>::RuntimeCall>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let confirmed = Self::confirm_proposal(origin.clone(), multisig_id, proposal_index)?;
+			let executed =
+				Self::execute_proposal(origin, multisig_id, proposal_index, call, max_weight)?;
+			Ok(Some(
+				confirmed
+					.actual_weight
+					.unwrap_or_default()
+					.saturating_add(executed.actual_weight.unwrap_or_default()),
+			)
+			.into())
+		}
+
+		/// Sets or removes the human-readable label of an owner.
+		///
+		/// Labels identify the person or device behind each owner key to signer interfaces and
+		/// auditors, and are returned by the pallet's runtime API. This must be dispatched by the
+		/// multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `owner`: The owner to label.
+		/// - `label`: The new label, or `None` to remove it.
+		///
+		/// ### Emits:
+		/// - `OwnerLabelSet` on success.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::set_owner_label())]
+		pub fn set_owner_label(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			owner: T::AccountId,
+			label: Option<NameOf<T>>,
+		) -> DispatchResult {
+			let multisig = Self::ensure_sovereign(origin, multisig_id)?;
+			ensure!(multisig.owners.contains(&owner), Error::<T>::NotAnOwner);
+			<OwnerLabels<T>>::set(multisig_id, &owner, label.clone());
+
+			Self::deposit_event(Event::OwnerLabelSet { multisig_id, owner, label });
+			Ok(())
+		}
+
+		/// Suspends an owner without removing them.
+		///
+		/// Until reinstated, the owner cannot submit or confirm proposals, and any approval they
+		/// have already given does not count towards execution. A suspended mandatory approver
+		/// is not required, and a suspended member of the sign-off chain is passed over. This
+		/// suits an owner on leave, or a key whose integrity is being investigated. The owners
+		/// who remain active must still be able to meet the threshold and the group quorum, so
+		/// the wallet can always still reinstate them. This must be dispatched by the multisig's
+		/// own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `owner`: The owner to suspend.
+		///
+		/// ### Emits:
+		/// - `OwnerSuspended` on success.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::suspend_owner())]
+		pub fn suspend_owner(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			owner: T::AccountId,
+		) -> DispatchResult {
+			let multisig = Self::ensure_sovereign(origin, multisig_id)?;
+			ensure!(multisig.owners.contains(&owner), Error::<T>::NotAnOwner);
+			<SuspendedOwners<T>>::try_mutate(multisig_id, |suspended| -> DispatchResult {
+				ensure!(!suspended.contains(&owner), Error::<T>::AlreadySuspended);
+				suspended.try_push(owner.clone()).map_err(|_| Error::<T>::TooManyOwners)?;
+				let active = Self::total_weight(multisig_id, &multisig.owners)
+					.saturating_sub(Self::total_weight(multisig_id, suspended));
+				ensure!(active >= multisig.threshold, Error::<T>::InvalidThreshold);
+				Self::ensure_class_thresholds_reachable(multisig_id, active)?;
+				if let Some(quorum) = Self::group_quorums(multisig_id) {
+					let active: Vec<_> = multisig
+						.owners
+						.iter()
+						.filter(|owner| !suspended.contains(owner))
+						.cloned()
+						.collect();
+					let mut groups = quorum.groups.iter();
+					let reachable = match quorum.rule {
+						GroupRule::All => groups.all(|group| group.is_met(&active)),
+						GroupRule::Any => groups.any(|group| group.is_met(&active)),
+					};
+					ensure!(reachable, Error::<T>::InvalidGroupThreshold);
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::OwnerSuspended { multisig_id, owner });
+			Ok(())
+		}
+
+		/// Reinstates a suspended owner, whose existing approvals count again.
+		///
+		/// This must be dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `owner`: The owner to reinstate.
+		///
+		/// ### Emits:
+		/// - `OwnerReinstated` on success.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::reinstate_owner())]
+		pub fn reinstate_owner(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			owner: T::AccountId,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			<SuspendedOwners<T>>::try_mutate_exists(multisig_id, |maybe_suspended| {
+				let suspended = maybe_suspended.as_mut().ok_or(Error::<T>::NotSuspended)?;
+				let position = suspended
+					.iter()
+					.position(|suspended| suspended == &owner)
+					.ok_or(Error::<T>::NotSuspended)?;
+				suspended.remove(position);
+				if suspended.is_empty() {
+					*maybe_suspended = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::OwnerReinstated { multisig_id, owner });
+			Ok(())
+		}
+
+		/// Registers a backup owner set, such as the legal department's keys, replacing any
+		/// previous one.
+		///
+		/// If the owners neither submit, confirm nor execute a proposal for `inactivity_period`
+		/// blocks, anyone may call `activate_backup_owners` to make the backup set the owners,
+		/// with `threshold`. This keeps the wallet governable if its owners lose their keys or
+		/// disappear. This must be dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `owners`: The backup owners.
+		/// - `threshold`: The approval threshold for the backup owners.
+		/// - `inactivity_period`: The number of blocks of inactivity before they may take over.
+		///
+		/// ### Emits:
+		/// - `BackupOwnersSet` on success.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::set_backup_owners())]
+		pub fn set_backup_owners(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			owners: Vec<T::AccountId>,
+			threshold: u32,
+			inactivity_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			let owners = Self::bounded_owner_set(owners, threshold)?;
+
+			let last_activity = frame_system::Pallet::<T>::block_number();
+			<BackupOwnerSets<T>>::insert(
+				multisig_id,
+				BackupOwners { owners, threshold, inactivity_period, last_activity },
+			);
+
+			Self::deposit_event(Event::BackupOwnersSet { multisig_id, inactivity_period });
+			Ok(())
+		}
+
+		/// Removes a multisig's backup owner set.
+		///
+		/// This must be dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		///
+		/// ### Emits:
+		/// - `BackupOwnersCleared` on success.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::clear_backup_owners())]
+		pub fn clear_backup_owners(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			<BackupOwnerSets<T>>::take(multisig_id).ok_or(Error::<T>::BackupOwnersNotFound)?;
+
+			Self::deposit_event(Event::BackupOwnersCleared { multisig_id });
+			Ok(())
+		}
+
+		/// Makes a multisig's backup owner set its owners, once the current owners have been
+		/// inactive for the set's inactivity period.
+		///
+		/// Anyone may call this. The backup owners and their threshold replace the owners, and
+		/// every policy that names the previous owners is cleared: mandatory approvers, the
+		/// sign-off order, the group quorum, suspensions and owner labels. Approvals the
+		/// previous owners gave to pending proposals no longer count.
+		///
+		/// ### Parameters:
+		/// - `origin`: Any signed account.
+		/// - `multisig_id`: The ID of the multisig to hand over.
+		///
+		/// ### Emits:
+		/// - `BackupOwnersActivated` on success.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::activate_backup_owners())]
+		pub fn activate_backup_owners(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			let backup =
+				Self::backup_owners(multisig_id).ok_or(Error::<T>::BackupOwnersNotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now >= backup.last_activity.saturating_add(backup.inactivity_period),
+				Error::<T>::OwnersStillActive
+			);
+
+			Self::replace_owners(multisig_id, multisig, backup.owners, backup.threshold)?;
+			<BackupOwnerSets<T>>::remove(multisig_id);
+
+			Self::deposit_event(Event::BackupOwnersActivated { multisig_id });
+			Ok(())
+		}
+
+		/// Grants or revokes an account's exemption from deposits.
+		///
+		/// An exempt account pays no deposit when it creates a multisig, submits a proposal or
+		/// confirms one, for example a chain's official council. Deposits already reserved are
+		/// unaffected. Only `DepositExemptionOrigin` may call this.
+		///
+		/// ### Parameters:
+		/// - `origin`: The `DepositExemptionOrigin`, typically governance.
+		/// - `who`: The account to exempt or stop exempting.
+		/// - `exempt`: Whether the account is exempt.
+		///
+		/// ### Emits:
+		/// - `AccountDepositExemptionSet` on success.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::set_deposit_exemption())]
+		pub fn set_account_deposit_exemption(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			exempt: bool,
+		) -> DispatchResult {
+			T::DepositExemptionOrigin::ensure_origin(origin)?;
+			<DepositExemptAccounts<T>>::set(&who, exempt.then_some(()));
+
+			Self::deposit_event(Event::AccountDepositExemptionSet { who, exempt });
+			Ok(())
+		}
+
+		/// Grants or revokes a multisig's exemption from deposits.
+		///
+		/// No deposit is reserved for proposals submitted to, or confirmations given on, an
+		/// exempt multisig, whoever its owners are. Only `DepositExemptionOrigin` may call this.
+		///
+		/// ### Parameters:
+		/// - `origin`: The `DepositExemptionOrigin`, typically governance.
+		/// - `multisig_id`: The ID of the multisig to exempt or stop exempting.
+		/// - `exempt`: Whether the multisig is exempt.
+		///
+		/// ### Emits:
+		/// - `MultisigDepositExemptionSet` on success.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::set_deposit_exemption())]
+		pub fn set_multisig_deposit_exemption(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			exempt: bool,
+		) -> DispatchResult {
+			T::DepositExemptionOrigin::ensure_origin(origin)?;
+			ensure!(<Multisigs<T>>::contains_key(multisig_id), Error::<T>::MultisigNotFound);
+			<DepositExemptMultisigs<T>>::set(multisig_id, exempt.then_some(()));
+
+			Self::deposit_event(Event::MultisigDepositExemptionSet { multisig_id, exempt });
+			Ok(())
+		}
+
+		/// Sets or removes the velocity limit of a multisig.
+		///
+		/// Once set, the multisig may execute at most `max_executions` proposals, and let its
+		/// sovereign account's balance fall by at most `max_value`, in each `period` blocks.
+		/// A proposal that would exceed either cap fails unless its approvals carry a vote
+		/// weight of at least `override_threshold`. Funds spent by operators or from the spending
+		/// allowance count towards `max_value` too, and such a spend fails if it would exceed it.
+		/// This must be dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `limit`: The new velocity limit, or `None` to remove it.
+		///
+		/// ### Emits:
+		/// - `VelocityLimitSet` on success.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::set_velocity_limit())]
+		pub fn set_velocity_limit(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			limit: Option<VelocityLimitOf<T>>,
+		) -> DispatchResult {
+			let multisig = Self::ensure_sovereign(origin, multisig_id)?;
+			if let Some(limit) = &limit {
+				ensure!(
+					!limit.period.is_zero() &&
+						limit.override_threshold >= multisig.threshold &&
+						limit.override_threshold <=
+							Self::total_weight(multisig_id, &multisig.owners),
+					Error::<T>::InvalidVelocityLimit
+				);
+			}
+			<VelocityLimits<T>>::set(multisig_id, limit.clone());
+			<ExecutionWindows<T>>::remove(multisig_id);
+
+			Self::deposit_event(Event::VelocityLimitSet { multisig_id, limit });
+			Ok(())
+		}
+
+		/// Bumps the signing nonce of a multisig, invalidating its outstanding off-chain
+		/// approvals wholesale.
+		///
+		/// Every confirmation owners sign off-chain commits to the current nonce, so signatures
+		/// made before the bump are refused by `confirm_proposal_with_signature`. Pre-approvals
+		/// registered ahead of their proposals are dropped as well; there are at most
+		/// `MaxPendingProposals` of them. Use this when an owner's signing device may have been
+		/// compromised, or to withdraw approvals that have been superseded. This must be
+		/// dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig.
+		///
+		/// ### Emits:
+		/// - `SigningNonceBumped` with the new nonce.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::bump_signing_nonce(T::MaxPendingProposals::get()))]
+		pub fn bump_signing_nonce(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			let nonce = Self::signing_nonce(multisig_id).saturating_add(1);
+			<SigningNonces<T>>::insert(multisig_id, nonce);
+			let _ =
+				<PreApprovals<T>>::clear_prefix(multisig_id, T::MaxPendingProposals::get(), None);
+			<PreApprovalCount<T>>::remove(multisig_id);
+
+			Self::deposit_event(Event::SigningNonceBumped { multisig_id, nonce });
+			Ok(())
+		}
+
+		/// Sets or lifts the cap on the number of multisigs that may exist at once.
+		///
+		/// This lets a chain bound the pallet's total state footprint, for example during its
+		/// early phases. Lowering the cap below the current count destroys nothing; it only
+		/// prevents new multisigs from being created. Only `WalletCapOrigin` may call this.
+		///
+		/// ### Parameters:
+		/// - `origin`: The `WalletCapOrigin`, typically governance.
+		/// - `max`: The new cap, or `None` to lift it.
+		///
+		/// ### Emits:
+		/// - `MaxActiveMultisigsSet` on success.
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::set_max_active_multisigs())]
+		pub fn set_max_active_multisigs(origin: OriginFor<T>, max: Option<u32>) -> DispatchResult {
+			T::WalletCapOrigin::ensure_origin(origin)?;
+			<MaxActiveMultisigs<T>>::set(max);
+
+			Self::deposit_event(Event::MaxActiveMultisigsSet { max });
+			Ok(())
+		}
+
+		/// Appends a chunk to the call the caller is uploading for a multisig.
+		///
+		/// Calls too large for a single extrinsic are uploaded over several transactions and
+		/// then proposed with `finalize_call`. A deposit is reserved for every byte, and
+		/// refunded once the proposal is executed or the upload discarded.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of an owner of the multisig.
+		/// - `multisig_id`: The ID of the multisig.
+		/// - `chunk`: The next bytes of the encoded call.
+		///
+		/// ### Emits:
+		/// - `CallChunkNoted` with the number of bytes uploaded so far.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::note_call_chunk(chunk.len() as u32))]
+		pub fn note_call_chunk(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			chunk: CallDataOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			ensure!(multisig.owners.contains(&who), Error::<T>::NotAnOwner);
+
+			let mut upload = Self::call_uploads(multisig_id, &who).unwrap_or(CallUpload {
+				depositor: who.clone(),
+				data: BoundedVec::new(),
+				deposit: Zero::zero(),
+			});
+			upload
+				.data
+				.try_extend(chunk.iter().copied())
+				.map_err(|_| Error::<T>::CallTooLarge)?;
+			let deposit = Self::reserve_call_data_deposit(multisig_id, &who, chunk.len() as u32)?;
+			upload.deposit = upload.deposit.saturating_add(deposit);
+			let len = upload.data.len() as u32;
+			<CallUploads<T>>::insert(multisig_id, &who, upload);
+
+			Self::deposit_event(Event::CallChunkNoted { multisig_id, who, len });
+			Ok(())
+		}
+
+		/// Submits the call the caller has uploaded in chunks as a proposal.
+		///
+		/// The uploaded bytes must decode to a call. They are kept with the proposal, so it can
+		/// be executed with `execute_uploaded_proposal` without resending them.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner who uploaded the call.
+		/// - `multisig_id`: The ID of the multisig.
+		///
+		/// ### Emits:
+		/// - `ProposalSubmitted` on successful submission.
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::finalize_call(T::MaxCallSize::get()))]
+		pub fn finalize_call(origin: OriginFor<T>, multisig_id: T::MultisigId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let upload =
+				Self::call_uploads(multisig_id, &who).ok_or(Error::<T>::CallUploadNotFound)?;
+			let call = Self::decode_uploaded_call(&upload)?;
+
+			let proposal_index =
+				Self::do_submit_proposal(who.clone(), multisig_id, &call, None, None, None)?;
+			<CallUploads<T>>::remove(multisig_id, &who);
+			<UploadedCalls<T>>::insert(multisig_id, proposal_index, upload);
+			Self::meter_usage(
+				multisig_id,
+				MeteredOperation::Submission,
+				T::WeightInfo::finalize_call(T::MaxCallSize::get()),
+			);
+			Ok(())
+		}
+
+		/// Discards the call the caller is uploading for a multisig, refunding its deposit.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner uploading the call.
+		/// - `multisig_id`: The ID of the multisig.
+		///
+		/// ### Emits:
+		/// - `CallUploadDiscarded` on success.
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::discard_call_upload())]
+		pub fn discard_call_upload(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let upload =
+				<CallUploads<T>>::take(multisig_id, &who).ok_or(Error::<T>::CallUploadNotFound)?;
+			T::Currency::unreserve(&upload.depositor, upload.deposit);
+
+			Self::deposit_event(Event::CallUploadDiscarded { multisig_id, who });
+			Ok(())
+		}
+
+		/// Executes a proposal submitted with `finalize_call` or `submit_proposal_with_preimage`,
+		/// using the call stored for it.
+		///
+		/// This behaves exactly like `execute_proposal`. The uploaded bytes and their deposit
+		/// are released once the proposal is executed.
+		///
+		/// ### Parameters:
+		/// - `origin`: Any signed account.
+		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
+		/// - `proposal_index`: The index of the proposal to be executed.
+		/// - `max_weight`: The most weight the stored call may have, which must be at least its
+		///   weight. It is charged up front, in `class`.
+		/// - `class`: The dispatch class of the stored call. Passing it in keeps weighing the
+		///   extrinsic from reading and decoding the stored bytes.
+		///
+		/// ### Emits:
+		/// - `ProposalExecuted` with the result of the dispatched call.
+		#[pallet::call_index(49)]
+		#[pallet::weight((
+			T::WeightInfo::execute_proposal(T::MaxOwners::get(), T::MaxOwners::get())
+				.saturating_add(T::WeightInfo::finalize_call(T::MaxCallSize::get()))
+				.saturating_add(*max_weight),
+			*class,
+		))]
+		pub fn execute_uploaded_proposal(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+			max_weight: Weight,
+			class: DispatchClass,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let upload = Self::uploaded_calls(multisig_id, proposal_index)
+				.ok_or(Error::<T>::CallUploadNotFound)?;
+			let call = Self::decode_uploaded_call(&upload)?;
+			let info = call.get_dispatch_info();
+			ensure!(info.call_weight.all_lte(max_weight), Error::<T>::MaxWeightTooLow);
+			ensure!(info.class == class, Error::<T>::WrongDispatchClass);
+			let weight =
+				Self::do_execute_proposal(who, multisig_id, proposal_index, Box::new(call))?;
+			Ok(Some(weight.saturating_add(T::WeightInfo::finalize_call(upload.data.len() as u32)))
+				.into())
+		}
+
+		/// Adds an owner to a multisig, optionally changing its threshold.
+		///
+		/// This must be dispatched by the multisig's own sovereign account, so the owners
+		/// rotate membership through the usual propose, confirm and execute flow.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig.
+		/// - `owner`: The account to add.
+		/// - `new_threshold`: The threshold to apply, or `None` to keep the current one.
+		///
+		/// ### Emits:
+		/// - `OwnerAdded` with the resulting threshold.
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::change_owners(T::MaxOwners::get()))]
+		pub fn add_owner(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			owner: T::AccountId,
+			new_threshold: Option<u32>,
+		) -> DispatchResult {
+			let multisig = Self::ensure_sovereign(origin, multisig_id)?;
+			let threshold = Self::change_owners(
+				multisig_id,
+				multisig,
+				None,
+				Some(owner.clone()),
+				new_threshold,
+			)?;
+
+			Self::deposit_event(Event::OwnerAdded { multisig_id, owner, threshold });
+			Ok(())
+		}
+
+		/// Removes an owner from a multisig, optionally changing its threshold.
+		///
+		/// The owner's approvals on pending proposals stop counting, and their label,
+		/// suspension and standing approvals are dropped. The change is refused if it would
+		/// leave the threshold, a group quorum or the velocity limit unreachable. This must be
+		/// dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig.
+		/// - `owner`: The owner to remove.
+		/// - `new_threshold`: The threshold to apply, or `None` to keep the current one.
+		///
+		/// ### Emits:
+		/// - `OwnerRemoved` with the resulting threshold.
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::change_owners(T::MaxOwners::get()))]
+		pub fn remove_owner(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			owner: T::AccountId,
+			new_threshold: Option<u32>,
+		) -> DispatchResult {
+			let multisig = Self::ensure_sovereign(origin, multisig_id)?;
+			let threshold =
+				Self::change_owners(multisig_id, multisig, Some(&owner), None, new_threshold)?;
+
+			Self::deposit_event(Event::OwnerRemoved { multisig_id, owner, threshold });
+			Ok(())
+		}
+
+		/// Replaces an owner of a multisig with another account.
+		///
+		/// The new owner takes the old one's place among the mandatory approvers, in the
+		/// sign-off chain and in any owner groups. Approvals the old owner gave stop counting,
+		/// and their label, suspension and standing approvals are dropped. This must be
+		/// dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig.
+		/// - `old`: The owner to replace.
+		/// - `new`: The account taking their place.
+		///
+		/// ### Emits:
+		/// - `OwnerSwapped` on success.
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::change_owners(T::MaxOwners::get()))]
+		pub fn swap_owner(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			old: T::AccountId,
+			new: T::AccountId,
+		) -> DispatchResult {
+			let multisig = Self::ensure_sovereign(origin, multisig_id)?;
+			Self::change_owners(multisig_id, multisig, Some(&old), Some(new.clone()), None)?;
+
+			Self::deposit_event(Event::OwnerSwapped { multisig_id, old, new });
+			Ok(())
+		}
+
+		/// Withdraws the caller's approval of a pending proposal.
+		///
+		/// An owner who changes their mind, or spots a malicious call, can take back their
+		/// consent as long as the proposal has not been executed. The approval deposit they
+		/// paid is refunded; a proposer's submission deposit stays reserved while the proposal
+		/// is pending.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner withdrawing their approval.
+		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
+		/// - `proposal_index`: The index of the proposal.
+		///
+		/// ### Emits:
+		/// - `ApprovalRevoked` on success.
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::revoke_approval(T::MaxOwners::get()))]
+		pub fn revoke_approval(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut proposal =
+				Self::proposals(multisig_id, proposal_index).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(proposal.status.is_open(), Error::<T>::AlreadyExecuted);
+			let position = proposal
+				.approvals
+				.iter()
+				.position(|approver| approver == &who)
+				.ok_or(Error::<T>::NotApproved)?;
+
+			proposal.approvals.remove(position);
+			if who != proposal.proposer {
+				Self::refund_approval_deposit(multisig_id, proposal_index, &who);
+			}
+			Self::track_threshold(multisig_id, &mut proposal);
+			Self::index_proposal(multisig_id, proposal_index, &proposal);
+			<Proposals<T>>::insert(multisig_id, proposal_index, proposal);
+
+			Self::deposit_event(Event::ApprovalRevoked { who, multisig_id, proposal_index });
+			Ok(())
+		}
+
+		/// Withdraws a pending proposal, removing it and its approvals.
+		///
+		/// Only the owner who submitted the proposal may cancel it, and only before it is
+		/// executed. Its deposits are refunded and any funds earmarked for it released.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the proposer.
+		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
+		/// - `proposal_index`: The index of the proposal to cancel.
+		///
+		/// ### Emits:
+		/// - `ProposalCancelled` on success.
+		/// - `EarmarkReleased` if funds were earmarked for the proposal.
+		#[pallet::call_index(54)]
+		#[pallet::weight(T::WeightInfo::cancel_proposal(T::MaxOwners::get()))]
+		pub fn cancel_proposal(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let proposal =
+				Self::proposals(multisig_id, proposal_index).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(proposal.status.is_open(), Error::<T>::AlreadyExecuted);
+			ensure!(proposal.proposer == who, Error::<T>::NotProposer);
+
+			Self::remove_pending_proposal(multisig_id, proposal_index, ProposalStatus::Cancelled);
+
+			Self::deposit_event(Event::ProposalCancelled { multisig_id, proposal_index, who });
+			Ok(())
+		}
+
+		/// Submits a proposal that stays open for `lifetime` blocks instead of the default
+		/// `ProposalLifetime`.
+		///
+		/// This behaves like `submit_proposal`. A short lifetime suits time-sensitive actions
+		/// that must not be executed late; a long one suits slow-moving approvals.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner submitting the proposal.
+		/// - `multisig_id`: The ID of the multisig the proposal is for.
+		/// - `call`: The runtime call to be executed upon approval.
+		/// - `lifetime`: The number of blocks the proposal stays open for.
+		///
+		/// ### Emits:
+		/// - `ProposalSubmitted` on successful submission.
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::submit_proposal())]
+		pub fn submit_proposal_with_lifetime(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			call: Box<<T as Config>::RuntimeCall>,
+			lifetime: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!lifetime.is_zero(), Error::<T>::InvalidLifetime);
+			Self::do_submit_proposal(who, multisig_id, &call, None, None, Some(lifetime))?;
+			Self::meter_usage(
+				multisig_id,
+				MeteredOperation::Submission,
+				T::WeightInfo::submit_proposal(),
+			);
+			Ok(())
+		}
+
+		/// Removes a proposal that expired without being executed.
+		///
+		/// Anyone may call this once the proposal's `expires_at` block has passed. The caller is
+		/// paid the `ExpiredDepositSlash` share of the proposer's submission deposit, the rest of
+		/// its deposits are refunded and any funds earmarked for it released.
+		///
+		/// ### Parameters:
+		/// - `origin`: Any signed account.
+		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
+		/// - `proposal_index`: The index of the expired proposal.
+		///
+		/// ### Emits:
+		/// - `ProposalExpired` on success.
+		/// - `DepositSlashed` if part of the proposer's deposit was forfeited.
+		/// - `EarmarkReleased` if funds were earmarked for the proposal.
+		#[pallet::call_index(56)]
+		#[pallet::weight(T::WeightInfo::cancel_proposal(T::MaxOwners::get()))]
+		pub fn remove_expired_proposal(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let proposal =
+				Self::proposals(multisig_id, proposal_index).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(proposal.status.is_open(), Error::<T>::AlreadyExecuted);
+			ensure!(Self::is_expired(&proposal), Error::<T>::NotExpired);
+
+			Self::slash_expired_deposit(multisig_id, proposal_index, &proposal.proposer, &who);
+			Self::remove_pending_proposal(multisig_id, proposal_index, ProposalStatus::Expired);
+
+			Self::deposit_event(Event::ProposalExpired { multisig_id, proposal_index });
+			Ok(())
+		}
+
+		/// Votes against a pending proposal.
+		///
+		/// An owner who approved the proposal changes their vote, and their approval deposit is
+		/// refunded. Once so many owners have voted against the proposal that the threshold can
+		/// no longer be met, that is once their vote weight exceeds the owners' total weight
+		/// minus the threshold, the proposal is removed, its deposits refunded and any funds
+		/// earmarked for it released. An owner who voted against a proposal may still change
+		/// their mind by confirming it.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner voting against the proposal.
+		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
+		/// - `proposal_index`: The index of the proposal being rejected.
+		///
+		/// ### Emits:
+		/// - `Rejection` on success.
+		/// - `ProposalRejected` if the proposal can no longer pass.
+		/// - `EarmarkReleased` if funds were earmarked for a rejected proposal.
+		#[pallet::call_index(57)]
+		#[pallet::weight(T::WeightInfo::reject_proposal(T::MaxOwners::get()))]
+		pub fn reject_proposal(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			ensure!(multisig.owners.contains(&who), Error::<T>::NotAnOwner);
+			ensure!(
+				!Self::suspended_owners(multisig_id).contains(&who),
+				Error::<T>::SuspendedOwner
+			);
+			let mut proposal =
+				Self::proposals(multisig_id, proposal_index).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(proposal.status.is_open(), Error::<T>::AlreadyExecuted);
+			ensure!(!Self::is_expired(&proposal), Error::<T>::Expired);
+			ensure!(!proposal.rejections.contains(&who), Error::<T>::AlreadyRejected);
+
+			if let Some(position) = proposal.approvals.iter().position(|approver| approver == &who)
+			{
+				proposal.approvals.remove(position);
+				if who != proposal.proposer {
+					Self::refund_approval_deposit(multisig_id, proposal_index, &who);
+				}
+			}
+			proposal
+				.rejections
+				.try_push(who.clone())
+				.map_err(|_| Error::<T>::TooManyOwners)?;
+			Self::note_owner_activity(multisig_id);
+			Self::deposit_event(Event::Rejection { who, multisig_id, proposal_index });
+
+			let rejections = Self::counted_approvals(multisig_id, &multisig, &proposal.rejections);
+			let total = Self::total_weight(multisig_id, &multisig.owners);
+			if Self::total_weight(multisig_id, &rejections) >
+				total.saturating_sub(multisig.threshold)
+			{
+				Self::remove_pending_proposal(
+					multisig_id,
+					proposal_index,
+					ProposalStatus::Cancelled,
+				);
+				Self::deposit_event(Event::ProposalRejected { multisig_id, proposal_index });
+			} else {
+				Self::track_threshold(multisig_id, &mut proposal);
+				Self::index_proposal(multisig_id, proposal_index, &proposal);
+				<Proposals<T>>::insert(multisig_id, proposal_index, proposal);
+			}
+			Ok(())
+		}
+
+		/// Submits a proposal and stores its full encoded call on chain, not only its hash.
+		///
+		/// This behaves like `submit_proposal`, but the proposal can then be executed with
+		/// `execute_uploaded_proposal` by anyone, without resending the call. A deposit is
+		/// reserved for every byte of the call, and refunded once the proposal is executed or
+		/// removed.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner submitting the proposal.
+		/// - `multisig_id`: The ID of the multisig the proposal is for.
+		/// - `call`: The runtime call to be executed upon approval, of at most `MaxCallSize`
+		///   encoded bytes.
+		///
+		/// ### Emits:
+		/// - `ProposalSubmitted` on successful submission.
+		#[pallet::call_index(58)]
+		#[pallet::weight(T::WeightInfo::finalize_call(T::MaxCallSize::get()))]
+		pub fn submit_proposal_with_preimage(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let data: CallDataOf<T> =
+				call.encode().try_into().map_err(|_| Error::<T>::CallTooLarge)?;
+
+			let proposal_index =
+				Self::do_submit_proposal(who.clone(), multisig_id, &call, None, None, None)?;
+			let deposit = Self::reserve_call_data_deposit(multisig_id, &who, data.len() as u32)?;
+			<UploadedCalls<T>>::insert(
+				multisig_id,
+				proposal_index,
+				CallUpload { depositor: who, data, deposit },
+			);
+			Self::meter_usage(
+				multisig_id,
+				MeteredOperation::Submission,
+				T::WeightInfo::finalize_call(T::MaxCallSize::get()),
+			);
+			Ok(())
+		}
+
+		/// Confirms several pending proposals of a multisig at once.
+		///
+		/// Each proposal is confirmed as by `confirm_proposal`. A proposal that cannot be
+		/// confirmed, for example because it was already executed, is skipped without affecting
+		/// the others.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner confirming the proposals.
+		/// - `multisig_id`: The ID of the multisig the proposals belong to.
+		/// - `proposal_indices`: The indices of the proposals to confirm.
+		///
+		/// ### Emits:
+		/// - `Confirmation` for every proposal confirmed.
+		/// - `BatchConfirmationFailed` for every proposal that could not be confirmed.
+		#[pallet::call_index(59)]
+		#[pallet::weight(
+			T::WeightInfo::confirm_proposal(T::MaxOwners::get(), T::MaxOwners::get())
+				.saturating_mul(proposal_indices.len() as u64)
+		)]
+		pub fn batch_confirm(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal_indices: BoundedVec<T::ProposalIndex, T::MaxPendingProposals>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mut weight = Weight::zero();
+			for proposal_index in proposal_indices {
+				let result = with_storage_layer(|| {
+					Self::do_confirm_proposal(who.clone(), multisig_id, proposal_index)
+				});
+				match result {
+					Ok(confirmed) => weight.saturating_accrue(confirmed),
+					Err(error) => {
+						// How far a failed confirmation got is unknown, so it is charged in full.
+						weight.saturating_accrue(T::WeightInfo::confirm_proposal(
+							T::MaxOwners::get(),
+							T::MaxOwners::get(),
+						));
+						Self::deposit_event(Event::BatchConfirmationFailed {
+							who: who.clone(),
+							multisig_id,
+							proposal_index,
+							error,
+						});
+					},
+				}
+			}
+			Ok(Some(weight).into())
+		}
+
+		/// Executes several approved proposals of a multisig at once.
+		///
+		/// Each proposal is executed as by `execute_proposal`. The weight charged covers every
+		/// proposal's execution and its call, in the highest dispatch class among the calls. A
+		/// proposal that cannot be executed, for example because it lacks approvals, is skipped
+		/// without affecting the others.
+		///
+		/// ### Parameters:
+		/// - `origin`: Any signed account.
+		/// - `multisig_id`: The ID of the multisig the proposals belong to.
+		/// - `proposals`: The index of each proposal to execute with its full call.
+		///
+		/// ### Emits:
+		/// - `ProposalExecuted` for every proposal executed, with the result of its call.
+		/// - `BatchExecutionFailed` for every proposal that could not be executed.
+		#[pallet::call_index(60)]
+		#[pallet::weight(proposals.iter().fold(
+			(T::WeightInfo::batch_execute(proposals.len() as u32), DispatchClass::Normal),
+			|(total, class), (_, call)| {
+				let dispatch_info = call.get_dispatch_info();
+				(
+					total.saturating_add(dispatch_info.call_weight),
+					Pallet::<T>::higher_class(class, dispatch_info.class),
+				)
+			},
+		))]
+		pub fn batch_execute(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposals: BatchCallsOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mut weight = Weight::zero();
+			for (proposal_index, call) in proposals {
+				// How far a failed execution got is unknown, so it is charged in full.
+				let max_weight =
+					T::WeightInfo::execute_proposal(T::MaxOwners::get(), T::MaxOwners::get())
+						.saturating_add(call.get_dispatch_info().call_weight);
+				let result = with_storage_layer(|| {
+					Self::do_execute_proposal(who.clone(), multisig_id, proposal_index, call)
+				});
+				match result {
+					Ok(executed) => weight.saturating_accrue(executed),
+					Err(error) => {
+						weight.saturating_accrue(max_weight);
+						Self::deposit_event(Event::BatchExecutionFailed {
+							multisig_id,
+							proposal_index,
+							error,
+						});
+					},
+				}
+			}
+			Ok(Some(weight).into())
+		}
+
+		/// Creates a new multisig wallet whose owners' votes carry different weights.
+		///
+		/// This behaves like `create_multisig`, but the threshold is the total weight of the
+		/// approvals a proposal needs. An owner with a weight of two counts as two approvals,
+		/// so a founder's key can count double without a second account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the user creating the multisig.
+		/// - `owners`: Each owner of the new wallet with their vote weight, which must be at least
+		///   one.
+		/// - `threshold`: The total vote weight of the approvals required to execute a proposal.
+		///
+		/// ### Emits:
+		/// - `MultisigCreated` on successful creation.
+		#[pallet::call_index(61)]
+		#[pallet::weight(T::WeightInfo::create_multisig(owners.len() as u32))]
+		pub fn create_weighted_multisig(
+			origin: OriginFor<T>,
+			owners: Vec<(T::AccountId, u32)>,
+			threshold: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (owners, weights): (Vec<_>, Vec<_>) = owners.into_iter().unzip();
+			let bounded_owners: BoundedVec<_, _> =
+				owners.try_into().map_err(|_| Error::<T>::TooManyOwners)?;
+			for (i, owner) in bounded_owners.iter().enumerate() {
+				ensure!(!bounded_owners[..i].contains(owner), Error::<T>::DuplicateAccount);
+			}
+			ensure!(weights.iter().all(|weight| *weight > 0), Error::<T>::InvalidVoteWeight);
+			let total = weights.iter().fold(0, |total: u32, weight| total.saturating_add(*weight));
+			ensure!(threshold > 0 && threshold <= total, Error::<T>::InvalidThreshold);
+
+			let multisig_id = Self::do_create_multisig(
+				who,
+				Multisig {
+					owners: bounded_owners.clone(),
+					threshold,
+					required_approvers: BoundedVec::new(),
+					approval_order: BoundedVec::new(),
+				},
+				None,
+			)?;
+			for (owner, weight) in bounded_owners.into_iter().zip(weights) {
+				if weight != 1 {
+					<OwnerWeights<T>>::insert(multisig_id, owner, weight);
+				}
+			}
+			Ok(())
+		}
+
+		/// Sets the vote weight of an owner of a multisig.
+		///
+		/// The change is refused if the owners who are not suspended would no longer carry
+		/// enough weight to meet the threshold or a class threshold, or if all the owners
+		/// together would fall short of the velocity limit's override threshold. This must be
+		/// dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig.
+		/// - `owner`: The owner whose weight to set.
+		/// - `weight`: The owner's new vote weight, which must be at least one.
+		///
+		/// ### Emits:
+		/// - `OwnerWeightSet` on success.
+		#[pallet::call_index(62)]
+		#[pallet::weight(T::WeightInfo::set_owner_weight())]
+		pub fn set_owner_weight(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			owner: T::AccountId,
+			weight: u32,
+		) -> DispatchResult {
+			let multisig = Self::ensure_sovereign(origin, multisig_id)?;
+			ensure!(multisig.owners.contains(&owner), Error::<T>::NotAnOwner);
+			ensure!(weight > 0, Error::<T>::InvalidVoteWeight);
+
+			if weight == 1 {
+				<OwnerWeights<T>>::remove(multisig_id, &owner);
+			} else {
+				<OwnerWeights<T>>::insert(multisig_id, &owner, weight);
+			}
+			let active = Self::total_weight(multisig_id, &multisig.owners).saturating_sub(
+				Self::total_weight(multisig_id, &Self::suspended_owners(multisig_id)),
+			);
+			ensure!(active >= multisig.threshold, Error::<T>::InvalidThreshold);
+			Self::ensure_class_thresholds_reachable(multisig_id, active)?;
+			if let Some(limit) = Self::velocity_limit(multisig_id) {
+				ensure!(
+					limit.override_threshold <= Self::total_weight(multisig_id, &multisig.owners),
+					Error::<T>::InvalidVelocityLimit
+				);
+			}
+
+			Self::deposit_event(Event::OwnerWeightSet { multisig_id, owner, weight });
+			Ok(())
+		}
+
+		/// Sets the threshold a multisig applies to proposals of one class of calls.
+		///
+		/// Routine transfers can then need fewer approvals than owner changes. The threshold
+		/// is a total vote weight, like the multisig's own, and is checked when a proposal is
+		/// executed. This must be dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig.
+		/// - `class`: The class of calls.
+		/// - `threshold`: The class's threshold, or `None` to use the multisig's threshold.
+		///
+		/// ### Emits:
+		/// - `ClassThresholdSet` on success.
+		#[pallet::call_index(63)]
+		#[pallet::weight(T::WeightInfo::set_class_threshold())]
+		pub fn set_class_threshold(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			class: CallClass,
+			threshold: Option<u32>,
+		) -> DispatchResult {
+			let multisig = Self::ensure_sovereign(origin, multisig_id)?;
+			if let Some(threshold) = threshold {
+				let total = Self::total_weight(multisig_id, &multisig.owners);
+				ensure!(threshold > 0 && threshold <= total, Error::<T>::InvalidThreshold);
+			}
+			<ClassThresholds<T>>::set(multisig_id, class, threshold);
+
+			Self::deposit_event(Event::ClassThresholdSet { multisig_id, class, threshold });
+			Ok(())
+		}
+
+		/// Sets or clears the filter restricting the calls a multisig may propose.
+		///
+		/// With an allow-list, only calls matching one of its patterns may be proposed; with a
+		/// deny-list, calls matching one of its patterns may not. Proposals already pending are
+		/// unaffected. An allow-list that omits this call locks the filter in for good, which
+		/// suits a wallet meant to only ever make transfers. This must be dispatched by the
+		/// multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig.
+		/// - `filter`: Whether to allow or deny the listed calls, and the list, or `None` to clear
+		///   the filter.
+		///
+		/// ### Emits:
+		/// - `CallFilterSet` on success.
+		#[pallet::call_index(64)]
+		#[pallet::weight(T::WeightInfo::set_call_filter())]
+		pub fn set_call_filter(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			filter: Option<(FilterMode, Vec<CallPattern>)>,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			let filter = filter
+				.map(|(mode, patterns)| -> Result<_, DispatchError> {
+					let patterns =
+						patterns.try_into().map_err(|_| Error::<T>::TooManyFilterEntries)?;
+					Ok(CallFilter { mode, patterns })
+				})
+				.transpose()?;
+			let mode = filter.as_ref().map(|filter| filter.mode);
+			<CallFilters<T>>::set(multisig_id, filter);
+
+			Self::deposit_event(Event::CallFilterSet { multisig_id, mode });
+			Ok(())
+		}
+
+		/// Sets or removes the spending limit of a multisig.
+		///
+		/// Once set, any single owner may transfer up to `amount` in total from the sovereign
+		/// account in each `period` blocks with `spend_allowance`, without a proposal. Setting
+		/// a limit starts a new period. This must be dispatched by the multisig's own sovereign
+		/// account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `limit`: The new spending limit, or `None` to remove it.
+		///
+		/// ### Emits:
+		/// - `SpendingLimitSet` on success.
+		#[pallet::call_index(65)]
+		#[pallet::weight(T::WeightInfo::set_spending_limit())]
+		pub fn set_spending_limit(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			limit: Option<SpendingLimitOf<T>>,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			if let Some(limit) = &limit {
+				ensure!(!limit.period.is_zero(), Error::<T>::InvalidSpendingLimit);
+			}
+			<SpendingLimits<T>>::set(multisig_id, limit.clone());
+			<SpendingWindows<T>>::remove(multisig_id);
+
+			Self::deposit_event(Event::SpendingLimitSet { multisig_id, limit });
+			Ok(())
+		}
+
+		/// Transfers funds from a multisig's sovereign account within its spending limit.
+		///
+		/// Any owner who is not suspended may call this on their own. The transfer counts
+		/// towards the allowance of the current period and the multisig's velocity limit, and
+		/// may not take the sovereign account below its protected reserve.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of an owner of the multisig.
+		/// - `multisig_id`: The ID of the multisig to spend from.
+		/// - `dest`: The recipient of the transfer.
+		/// - `amount`: The amount to transfer.
+		///
+		/// ### Emits:
+		/// - `AllowanceSpent` on success.
+		#[pallet::call_index(66)]
+		#[pallet::weight(T::WeightInfo::spend_allowance())]
+		pub fn spend_allowance(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			dest: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			ensure!(multisig.owners.contains(&who), Error::<T>::NotAnOwner);
+			ensure!(
+				!Self::suspended_owners(multisig_id).contains(&who),
+				Error::<T>::SuspendedOwner
+			);
+			let limit = Self::spending_limit(multisig_id).ok_or(Error::<T>::NoSpendingLimit)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut window = Self::spending_window(multisig_id);
+			if now >= window.started_at.saturating_add(limit.period) || window.spent.is_zero() {
+				window = SpendingWindow { started_at: now, spent: Zero::zero() };
+			}
+			window.spent = window.spent.saturating_add(amount);
+			ensure!(window.spent <= limit.amount, Error::<T>::SpendingLimitExceeded);
+
+			let multisig_account = Self::multi_account_id(multisig_id);
+			T::Currency::transfer(
+				&multisig_account,
+				&dest,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			if let Some(reserve) = Self::protected_reserve(multisig_id) {
+				ensure!(
+					T::Currency::total_balance(&multisig_account) >= reserve,
+					Error::<T>::ReserveBreached
+				);
+			}
+			Self::record_spend(multisig_id, amount)?;
+			<SpendingWindows<T>>::insert(multisig_id, window);
+			Self::note_owner_activity(multisig_id);
+
+			Self::deposit_event(Event::AllowanceSpent { multisig_id, who, dest, amount });
+			Ok(())
+		}
+
+		/// Sets the number of blocks a multisig's proposals must wait after meeting their
+		/// threshold before they can be executed.
+		///
+		/// During the delay owners can spot a compromised quorum and revoke their approvals or
+		/// vote against the proposal. Proposals approved by every owner are not delayed. This
+		/// must be dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `delay`: The new delay, or `None` to use `DefaultExecutionDelay`.
+		///
+		/// ### Emits:
+		/// - `ExecutionDelaySet` on success.
+		#[pallet::call_index(67)]
+		#[pallet::weight(T::WeightInfo::set_execution_delay())]
+		pub fn set_execution_delay(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			delay: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			<ExecutionDelays<T>>::set(multisig_id, delay);
+
+			Self::deposit_event(Event::ExecutionDelaySet { multisig_id, delay });
+			Ok(())
+		}
+
+		/// Sets the veto accounts of a multisig.
+		///
+		/// Veto accounts cannot submit or approve proposals, but may cancel any pending one on
+		/// their own, giving a security team a break-glass reviewer. Passing an empty list
+		/// removes all veto accounts. This must be dispatched by the multisig's own sovereign
+		/// account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `vetoers`: The veto accounts.
+		///
+		/// ### Emits:
+		/// - `VetoersSet` on success.
+		#[pallet::call_index(68)]
+		#[pallet::weight(T::WeightInfo::set_vetoers())]
+		pub fn set_vetoers(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			vetoers: Vec<T::AccountId>,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+
+			let bounded: BoundedVec<_, _> =
+				vetoers.clone().try_into().map_err(|_| Error::<T>::TooManyOwners)?;
+			for (i, vetoer) in bounded.iter().enumerate() {
+				ensure!(!bounded[..i].contains(vetoer), Error::<T>::DuplicateAccount);
+			}
+			<Vetoers<T>>::set(multisig_id, bounded);
+
+			Self::deposit_event(Event::VetoersSet { multisig_id, vetoers });
+			Ok(())
+		}
+
+		/// Cancels a pending proposal as a veto account of its multisig.
+		///
+		/// The proposal is removed, its deposits refunded and any funds earmarked for it
+		/// released, however many owners have approved it.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of a veto account of the multisig.
+		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
+		/// - `proposal_index`: The index of the proposal to veto.
+		///
+		/// ### Emits:
+		/// - `ProposalVetoed` on success.
+		/// - `EarmarkReleased` if funds were earmarked for the proposal.
+		#[pallet::call_index(69)]
+		#[pallet::weight(T::WeightInfo::cancel_proposal(T::MaxOwners::get()))]
+		pub fn veto_proposal(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::vetoers(multisig_id).contains(&who), Error::<T>::NotAVetoer);
+			let proposal =
+				Self::proposals(multisig_id, proposal_index).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(proposal.status.is_open(), Error::<T>::AlreadyExecuted);
+
+			Self::remove_pending_proposal(multisig_id, proposal_index, ProposalStatus::Cancelled);
+
+			Self::deposit_event(Event::ProposalVetoed { multisig_id, proposal_index, who });
+			Ok(())
+		}
+
+		/// Configures the guardians who may jointly replace a multisig's owners, replacing any
+		/// previous configuration.
+		///
+		/// If the owners lose their keys, a guardian may start a recovery naming a new owner set.
+		/// Once `threshold` guardians approve it and `delay` blocks have passed since it started,
+		/// it may be claimed. Until then, any owner may cancel it. This must be dispatched by the
+		/// multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `guardians`: The guardian accounts.
+		/// - `threshold`: The number of guardians who must approve a recovery.
+		/// - `delay`: The number of blocks before an initiated recovery may be claimed.
+		///
+		/// ### Emits:
+		/// - `RecoveryConfigSet` on success.
+		#[pallet::call_index(70)]
+		#[pallet::weight(T::WeightInfo::set_recovery_config())]
+		pub fn set_recovery_config(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			guardians: Vec<T::AccountId>,
+			threshold: u32,
+			delay: BlockNumberFor<T>,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			let guardians = Self::bounded_owner_set(guardians, threshold)?;
+
+			<RecoveryConfigs<T>>::insert(
+				multisig_id,
+				RecoveryConfig { guardians, threshold, delay },
+			);
+
+			Self::deposit_event(Event::RecoveryConfigSet { multisig_id, delay });
+			Ok(())
+		}
+
+		/// Removes a multisig's guardian configuration, cancelling any recovery in progress.
+		///
+		/// This must be dispatched by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		///
+		/// ### Emits:
+		/// - `RecoveryConfigRemoved` on success.
+		#[pallet::call_index(71)]
+		#[pallet::weight(T::WeightInfo::remove_recovery_config())]
+		pub fn remove_recovery_config(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+		) -> DispatchResult {
+			Self::ensure_sovereign(origin, multisig_id)?;
+			<RecoveryConfigs<T>>::take(multisig_id).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			<ActiveRecoveries<T>>::remove(multisig_id);
+
+			Self::deposit_event(Event::RecoveryConfigRemoved { multisig_id });
+			Ok(())
+		}
+
+		/// Starts a recovery of a multisig, proposing a new owner set.
+		///
+		/// The initiating guardian's approval is recorded automatically.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of a guardian of the multisig.
+		/// - `multisig_id`: The ID of the multisig to recover.
+		/// - `new_owners`: The accounts to become the owners.
+		/// - `new_threshold`: The approval threshold for the new owners.
+		///
+		/// ### Emits:
+		/// - `RecoveryInitiated` on success.
+		#[pallet::call_index(72)]
+		#[pallet::weight(T::WeightInfo::initiate_recovery())]
+		pub fn initiate_recovery(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			new_owners: Vec<T::AccountId>,
+			new_threshold: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(<Multisigs<T>>::contains_key(multisig_id), Error::<T>::MultisigNotFound);
+			let config =
+				Self::recovery_config(multisig_id).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			ensure!(config.guardians.contains(&who), Error::<T>::NotAGuardian);
+			ensure!(
+				!<ActiveRecoveries<T>>::contains_key(multisig_id),
+				Error::<T>::RecoveryAlreadyActive
+			);
+			let new_owners = Self::bounded_owner_set(new_owners, new_threshold)?;
+
+			let mut approvals = BoundedVec::new();
+			approvals.try_push(who.clone()).map_err(|_| Error::<T>::TooManyOwners)?;
+			<ActiveRecoveries<T>>::insert(
+				multisig_id,
+				ActiveRecovery {
+					new_owners,
+					new_threshold,
+					initiated_at: frame_system::Pallet::<T>::block_number(),
+					approvals,
+				},
+			);
+
+			Self::deposit_event(Event::RecoveryInitiated { multisig_id, who });
+			Ok(())
+		}
+
+		/// Approves the recovery in progress for a multisig.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of a guardian of the multisig.
+		/// - `multisig_id`: The ID of the multisig being recovered.
+		///
+		/// ### Emits:
+		/// - `RecoveryApproved` on success.
+		#[pallet::call_index(73)]
+		#[pallet::weight(T::WeightInfo::approve_recovery())]
+		pub fn approve_recovery(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let config =
+				Self::recovery_config(multisig_id).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			ensure!(config.guardians.contains(&who), Error::<T>::NotAGuardian);
+			let mut recovery =
+				Self::active_recovery(multisig_id).ok_or(Error::<T>::NoActiveRecovery)?;
+			ensure!(!recovery.approvals.contains(&who), Error::<T>::RecoveryAlreadyApproved);
+
+			recovery
+				.approvals
+				.try_push(who.clone())
+				.map_err(|_| Error::<T>::TooManyOwners)?;
+			<ActiveRecoveries<T>>::insert(multisig_id, recovery);
+
+			Self::deposit_event(Event::RecoveryApproved { multisig_id, who });
+			Ok(())
+		}
+
+		/// Completes a recovery, making its proposed owner set the multisig's owners.
+		///
+		/// Anyone may call this once enough guardians have approved the recovery and its delay
+		/// has passed. As with `activate_backup_owners`, every policy that names the previous
+		/// owners is cleared.
+		///
+		/// ### Parameters:
+		/// - `origin`: Any signed account.
+		/// - `multisig_id`: The ID of the multisig being recovered.
+		///
+		/// ### Emits:
+		/// - `RecoveryClaimed` on success.
+		#[pallet::call_index(74)]
+		#[pallet::weight(T::WeightInfo::claim_recovery())]
+		pub fn claim_recovery(origin: OriginFor<T>, multisig_id: T::MultisigId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			let config =
+				Self::recovery_config(multisig_id).ok_or(Error::<T>::RecoveryNotConfigured)?;
+			let recovery =
+				Self::active_recovery(multisig_id).ok_or(Error::<T>::NoActiveRecovery)?;
+			let approvals = recovery
+				.approvals
+				.iter()
+				.filter(|guardian| config.guardians.contains(guardian))
+				.count() as u32;
+			ensure!(approvals >= config.threshold, Error::<T>::RecoveryNotApproved);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now >= recovery.initiated_at.saturating_add(config.delay),
+				Error::<T>::RecoveryDelayActive
+			);
+
+			Self::replace_owners(
+				multisig_id,
+				multisig,
+				recovery.new_owners,
+				recovery.new_threshold,
+			)?;
+			<ActiveRecoveries<T>>::remove(multisig_id);
+
+			Self::deposit_event(Event::RecoveryClaimed { multisig_id });
+			Ok(())
+		}
+
+		/// Cancels the recovery in progress for a multisig.
+		///
+		/// Any owner may call this, proving that the owners still hold their keys.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of an owner of the multisig.
+		/// - `multisig_id`: The ID of the multisig being recovered.
+		///
+		/// ### Emits:
+		/// - `RecoveryCancelled` on success.
+		#[pallet::call_index(75)]
+		#[pallet::weight(T::WeightInfo::cancel_recovery())]
+		pub fn cancel_recovery(origin: OriginFor<T>, multisig_id: T::MultisigId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			ensure!(multisig.owners.contains(&who), Error::<T>::NotAnOwner);
+			<ActiveRecoveries<T>>::take(multisig_id).ok_or(Error::<T>::NoActiveRecovery)?;
+
+			Self::deposit_event(Event::RecoveryCancelled { multisig_id, who });
+			Ok(())
+		}
+
+		/// Sets or removes the threshold decay policy of a multisig.
+		///
+		/// Once set, if the multisig executes no proposal for `inactivity_period` blocks, its
+		/// threshold, and any lower class threshold, drops to the policy's threshold until the
+		/// next execution. This lets heirs or the remaining signers recover funds after keys
+		/// are lost. Setting a policy restarts the inactivity period. This must be dispatched
+		/// by the multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig to configure.
+		/// - `decay`: The new policy, or `None` to remove it.
+		///
+		/// ### Emits:
+		/// - `ThresholdDecaySet` on success.
+		#[pallet::call_index(76)]
+		#[pallet::weight(T::WeightInfo::set_threshold_decay())]
+		pub fn set_threshold_decay(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			decay: Option<ThresholdDecayOf<T>>,
+		) -> DispatchResult {
+			let multisig = Self::ensure_sovereign(origin, multisig_id)?;
+			if let Some(decay) = &decay {
+				ensure!(
+					decay.threshold > 0 && decay.threshold <= multisig.threshold,
+					Error::<T>::InvalidThreshold
+				);
+				<LastExecutedAt<T>>::insert(multisig_id, frame_system::Pallet::<T>::block_number());
+			}
+			<ThresholdDecays<T>>::set(multisig_id, decay.clone());
+
+			Self::deposit_event(Event::ThresholdDecaySet { multisig_id, decay });
+			Ok(())
+		}
+
+		/// Delegates the caller's right to confirm proposals of a multisig to another account
+		/// for `duration` blocks, replacing any previous delegation.
+		///
+		/// Confirmations the delegate makes count as the owner's, and the owner's deposit is
+		/// reserved for them. The delegation lapses on its own once `duration` blocks have
+		/// passed, and the owner may revoke it sooner. The delegate cannot submit, reject or
+		/// execute proposals on the owner's behalf.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of an owner of the multisig.
+		/// - `multisig_id`: The ID of the multisig.
+		/// - `delegate`: The account to confirm on the owner's behalf.
+		/// - `duration`: The number of blocks the delegation lasts.
+		///
+		/// ### Emits:
+		/// - `ConfirmationsDelegated` on success.
+		#[pallet::call_index(77)]
+		#[pallet::weight(T::WeightInfo::delegate_confirmations(T::MaxOwners::get()))]
+		pub fn delegate_confirmations(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			delegate: T::AccountId,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			ensure!(multisig.owners.contains(&owner), Error::<T>::NotAnOwner);
+			ensure!(
+				!duration.is_zero() &&
+					!multisig.owners.contains(&delegate) &&
+					Self::delegator_of(multisig_id, delegate.clone()) == delegate,
+				Error::<T>::InvalidDelegation
+			);
+
+			let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			<Delegations<T>>::insert(
+				multisig_id,
+				&owner,
+				Delegation { delegate: delegate.clone(), expires_at },
+			);
+
+			Self::deposit_event(Event::ConfirmationsDelegated {
+				multisig_id,
+				owner,
+				delegate,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Revokes the caller's delegation of their confirmation right.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the delegating owner.
+		/// - `multisig_id`: The ID of the multisig.
+		///
+		/// ### Emits:
+		/// - `DelegationRevoked` on success.
+		#[pallet::call_index(78)]
+		#[pallet::weight(T::WeightInfo::revoke_delegation())]
+		pub fn revoke_delegation(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			<Delegations<T>>::take(multisig_id, &owner).ok_or(Error::<T>::NoDelegation)?;
+
+			Self::deposit_event(Event::DelegationRevoked { multisig_id, owner });
+			Ok(())
+		}
+
+		/// Submits a proposal on behalf of an owner, as one of the owner's proxies.
+		///
+		/// The proposal is submitted exactly as if the owner had called `submit_proposal`: the
+		/// owner is its proposer and first approver, and pays its deposit.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of a proxy of `owner`.
+		/// - `multisig_id`: The ID of the multisig for which the proposal is being made.
+		/// - `owner`: The owner to act for.
+		/// - `call`: The `RuntimeCall` that the multisig owners will vote on to execute.
+		///
+		/// ### Emits:
+		/// - `ProposalSubmitted` and `ProxyVoted` on success.
+		#[pallet::call_index(79)]
+		#[pallet::weight(T::WeightInfo::submit_proposal())]
+		pub fn submit_proposal_as_proxy(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			owner: T::AccountId,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let proxy = ensure_signed(origin)?;
+			ensure!(T::OwnerProxies::is_proxy(&owner, &proxy), Error::<T>::NotAProxy);
+			let proposal_index =
+				Self::do_submit_proposal(owner.clone(), multisig_id, &call, None, None, None)?;
+			Self::meter_usage(
+				multisig_id,
+				MeteredOperation::Submission,
+				T::WeightInfo::submit_proposal(),
+			);
+
+			Self::deposit_event(Event::ProxyVoted { multisig_id, proposal_index, owner, proxy });
+			Ok(())
+		}
+
+		/// Confirms a pending proposal on behalf of an owner, as one of the owner's proxies.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of a proxy of `owner`.
+		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
+		/// - `owner`: The owner to act for.
+		/// - `proposal_index`: The index of the proposal being confirmed.
+		///
+		/// ### Emits:
+		/// - `Confirmation` and `ProxyVoted` on success.
+		#[pallet::call_index(80)]
+		#[pallet::weight(T::WeightInfo::confirm_proposal(
+			T::MaxOwners::get(),
+			T::MaxOwners::get()
+		))]
+		pub fn confirm_proposal_as_proxy(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			owner: T::AccountId,
+			proposal_index: T::ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			let proxy = ensure_signed(origin)?;
+			ensure!(T::OwnerProxies::is_proxy(&owner, &proxy), Error::<T>::NotAProxy);
+			let weight = Self::do_confirm_proposal(owner.clone(), multisig_id, proposal_index)?;
+
+			Self::deposit_event(Event::ProxyVoted { multisig_id, proposal_index, owner, proxy });
+			Ok(Some(weight).into())
+		}
+
+		/// Dispatches a call from one of a multisig's sub-accounts.
+		///
+		/// Each multisig controls a family of sub-accounts, derived from its ID and a
+		/// `sub_index`, so one wallet can keep isolated balances, such as a hot wallet, payroll
+		/// and grants, without creating new multisigs. Policies such as the protected reserve
+		/// and spending limit guard the sovereign account only. This must be dispatched by the
+		/// multisig's own sovereign account.
+		///
+		/// ### Parameters:
+		/// - `origin`: The sovereign `AccountId` of the multisig.
+		/// - `multisig_id`: The ID of the multisig.
+		/// - `sub_index`: The index of the sub-account to dispatch from.
+		/// - `call`: The call to dispatch.
+		///
+		/// ### Emits:
+		/// - `DispatchedAsSub` with the result of the dispatched call.
+		#[pallet::call_index(81)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			T::WeightInfo::dispatch_as_sub().saturating_add(dispatch_info.call_weight)
+		})]
+		pub fn dispatch_as_sub(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			sub_index: u16,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_sovereign(origin, multisig_id)?;
+
+			let sub_account = Self::sub_account_id(multisig_id, sub_index);
+			let info = call.get_dispatch_info();
+			let result = call.dispatch(RawOrigin::Signed(sub_account).into());
+			let weight = T::WeightInfo::dispatch_as_sub()
+				.saturating_add(extract_actual_weight(&result, &info));
+
+			Self::deposit_event(Event::DispatchedAsSub {
+				multisig_id,
+				sub_index,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+			Ok(Some(weight).into())
+		}
+
+		/// Creates a new multisig wallet and submits its first proposal in one transaction.
+		///
+		/// The creator must be one of `owners`; they become the proposal's proposer and first
+		/// approver, exactly as with `create_multisig` followed by `submit_proposal`. If the
+		/// proposal cannot be submitted, the wallet is not created either.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the owner creating the multisig.
+		/// - `owners`: The owners of the new wallet.
+		/// - `threshold`: The number of owner approvals required to execute a proposal.
+		/// - `call`: The `RuntimeCall` of the first proposal.
+		///
+		/// ### Emits:
+		/// - `MultisigCreated` and `ProposalSubmitted` on success, carrying the new multisig's ID
+		///   and the proposal's index.
+		#[pallet::call_index(82)]
+		#[pallet::weight(
+			T::WeightInfo::create_multisig(owners.len() as u32)
+				.saturating_add(T::WeightInfo::submit_proposal())
+		)]
+		pub fn create_and_propose(
+			origin: OriginFor<T>,
+			owners: Vec<T::AccountId>,
+			threshold: u32,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owners = Self::bounded_owner_set(owners, threshold)?;
+			ensure!(owners.contains(&who), Error::<T>::NotAnOwner);
+
+			let new_multisig = Multisig {
+				owners,
+				threshold,
+				required_approvers: BoundedVec::new(),
+				approval_order: BoundedVec::new(),
+			};
+			let multisig_id = Self::do_create_multisig(who.clone(), new_multisig, None)?;
+			Self::do_submit_proposal(who, multisig_id, &call, None, None, None)?;
+			Self::meter_usage(
+				multisig_id,
+				MeteredOperation::Submission,
+				T::WeightInfo::submit_proposal(),
+			);
+			Ok(())
+		}
+
+		/// Creates a counterfactual multisig wallet, whose sovereign account is derived from
+		/// its owner set, threshold and `salt` instead of its sequential ID.
+		///
+		/// Anyone can compute the address in advance with `counterfactual_account_id`, so funds
+		/// can be sent to it before the wallet exists on chain. The owners may be listed in any
+		/// order. Only one wallet may use an address at a time; a different `salt` gives the
+		/// same owners another address.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the user creating the multisig.
+		/// - `owners`: The owners of the new wallet.
+		/// - `threshold`: The number of owner approvals required to execute a proposal.
+		/// - `salt`: Arbitrary bytes distinguishing wallets with the same owners and threshold.
+		///
+		/// ### Emits:
+		/// - `MultisigCreated` on success, with the counterfactual account.
+		#[pallet::call_index(83)]
+		#[pallet::weight(T::WeightInfo::create_multisig(owners.len() as u32))]
+		pub fn create_counterfactual_multisig(
+			origin: OriginFor<T>,
+			owners: Vec<T::AccountId>,
+			threshold: u32,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let owners = Self::bounded_owner_set(owners, threshold)?;
+			let account = Self::counterfactual_account_id(&owners, threshold, &salt);
+			ensure!(!<MultisigAccounts<T>>::contains_key(&account), Error::<T>::AccountInUse);
+
+			let new_multisig = Multisig {
+				owners,
+				threshold,
+				required_approvers: BoundedVec::new(),
+				approval_order: BoundedVec::new(),
+			};
+			Self::do_create_multisig(who, new_multisig, Some(account))?;
+			Ok(())
+		}
+
+		/// Creates a new multisig wallet whose sovereign account is derived from its ID
+		/// together with `salt`.
+		///
+		/// This behaves like `create_multisig`, but lets the creator choose among many addresses
+		/// for the wallet, for example to avoid one already used elsewhere.
 		///
 		/// ### Parameters:
 		/// - `origin`: The signed account of the user creating the multisig.
-		/// - `owners`: A vector of `AccountId`s who will be the owners of the new wallet.
+		/// - `owners`: The owners of the new wallet.
 		/// - `threshold`: The number of owner approvals required to execute a proposal.
+		/// - `salt`: The salt mixed into the sovereign account's derivation.
 		///
 		/// ### Emits:
-		/// - `MultisigCreated` on successful creation.
-		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::create_multisig())]
-		pub fn create_multisig(
+		/// - `MultisigCreated` on success, with the salted account.
+		#[pallet::call_index(84)]
+		#[pallet::weight(T::WeightInfo::create_multisig(owners.len() as u32))]
+		pub fn create_salted_multisig(
 			origin: OriginFor<T>,
 			owners: Vec<T::AccountId>,
 			threshold: u32,
+			salt: [u8; 32],
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			let owners = Self::bounded_owner_set(owners, threshold)?;
+			let account = address::multisig_account_id(
+				&T::PalletId::get().0,
+				Self::next_multisig_id(),
+				Some(&salt),
+			);
+			ensure!(!<MultisigAccounts<T>>::contains_key(&account), Error::<T>::AccountInUse);
 
-			//   Convert the user-provided `Vec` into a `BoundedVec` to prevent a
-			// potential DoS attack where a user provides an extremely large number of owners,
-			// bloating storage and computation. This fails if `owners.len() > MaxOwners`.
-			let bounded_owners: BoundedVec<_, _> =
-				owners.try_into().map_err(|_| Error::<T>::TooManyOwners)?;
+			let new_multisig = Multisig {
+				owners,
+				threshold,
+				required_approvers: BoundedVec::new(),
+				approval_order: BoundedVec::new(),
+			};
+			Self::do_create_multisig(who, new_multisig, Some(account))?;
+			Ok(())
+		}
+
+		/// Submits a new proposal that is dispatched with `Origin::Multisig`, carrying the
+		/// multisig's ID and approvals, instead of signed by its sovereign account.
+		///
+		/// This extrinsic can only be called by an owner of the specified multisig. The
+		/// proposal is otherwise voted on and executed like any other.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account of the multisig owner submitting the proposal.
+		/// - `multisig_id`: The ID of the multisig for which the proposal is being made.
+		/// - `call`: The `RuntimeCall` that the multisig owners will vote on to execute.
+		///
+		/// ### Emits:
+		/// - `ProposalSubmitted` on successful submission.
+		#[pallet::call_index(85)]
+		#[pallet::weight(T::WeightInfo::submit_proposal())]
+		pub fn submit_proposal_with_multisig_origin(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let proposal_index =
+				Self::do_submit_proposal(who, multisig_id, &call, None, None, None)?;
+			<MultisigOriginProposals<T>>::insert(multisig_id, proposal_index, ());
+			Self::meter_usage(
+				multisig_id,
+				MeteredOperation::Submission,
+				T::WeightInfo::submit_proposal(),
+			);
+			Ok(())
+		}
+
+		/// Confirms a pending proposal with a confirmation its owner signed off-chain.
+		///
+		/// This lets an air-gapped owner approve a proposal without holding a funded hot
+		/// account: they sign the proposal's `confirmation_payload`, and any account relays the
+		/// signature and pays the transaction fee. The payload commits to the multisig's signing
+		/// nonce, so a signature stays valid until the nonce is bumped with
+		/// `bump_signing_nonce`. An owner who revokes a confirmation they signed off-chain should
+		/// have the nonce bumped, as the signature could otherwise be relayed again.
+		///
+		/// ### Parameters:
+		/// - `origin`: The signed account relaying the confirmation.
+		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
+		/// - `proposal_index`: The index of the proposal being confirmed.
+		/// - `owner`: The owner who signed the confirmation.
+		/// - `signature`: The owner's signature over the proposal's `confirmation_payload`.
+		///
+		/// ### Emits:
+		/// - `Confirmation` and `SignedConfirmationRelayed` on success.
+		///
+		/// ### Errors:
+		/// - `ProposalNotFound` if there is no such proposal.
+		/// - `InvalidSignature` if the signature was not made by `owner` over the current
+		///   confirmation payload.
+		#[pallet::call_index(86)]
+		#[pallet::weight(
+			T::WeightInfo::confirm_proposal(T::MaxOwners::get(), T::MaxOwners::get())
+				.saturating_add(T::WeightInfo::verify_signature())
+		)]
+		pub fn confirm_proposal_with_signature(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+			owner: T::AccountId,
+			signature: T::OffchainSignature,
+		) -> DispatchResultWithPostInfo {
+			let relayer = ensure_signed(origin)?;
+			let payload = Self::confirmation_payload(multisig_id, proposal_index)
+				.ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(signature.verify(&payload[..], &owner), Error::<T>::InvalidSignature);
+			let weight = Self::do_confirm_proposal(owner.clone(), multisig_id, proposal_index)?;
+
+			Self::deposit_event(Event::SignedConfirmationRelayed {
+				multisig_id,
+				proposal_index,
+				owner,
+				relayer,
+			});
+			Ok(Some(weight.saturating_add(T::WeightInfo::verify_signature())).into())
+		}
+	}
+
+	#[pallet::view_functions_experimental]
+	impl<T: Config> Pallet<T> {
+		/// Returns whether `who` is an owner of `multisig_id`.
+		pub fn is_owner(multisig_id: T::MultisigId, who: T::AccountId) -> bool {
+			Self::multisigs(multisig_id).is_some_and(|multisig| multisig.owners.contains(&who))
+		}
+
+		/// Returns the threshold of `multisig_id`, after any inactivity decay, or `None` if it
+		/// does not exist.
+		pub fn threshold_of(multisig_id: T::MultisigId) -> Option<u32> {
+			Self::multisigs(multisig_id)
+				.map(|multisig| Self::decayed_threshold(multisig_id, multisig.threshold))
+		}
+
+		/// Returns the number of unexecuted proposals of `multisig_id`.
+		pub fn pending_proposal_count(multisig_id: T::MultisigId) -> u32 {
+			<PendingProposalCount<T>>::get(multisig_id)
+		}
+
+		/// Returns the approvals gathered by the pending proposal `proposal_index` of
+		/// `multisig_id`, or `None` if there is no such pending proposal.
+		pub fn approval_status(
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) -> Option<ApprovalStatus> {
+			let multisig = Self::multisigs(multisig_id)?;
+			let proposal = Self::proposals(multisig_id, proposal_index)
+				.filter(|proposal| proposal.status.is_open())?;
+			let approvals = Self::counted_approvals(multisig_id, &multisig, &proposal.approvals);
+			let rejections = Self::counted_approvals(multisig_id, &multisig, &proposal.rejections);
+			Some(ApprovalStatus {
+				approvals: approvals.len() as u32,
+				rejections: rejections.len() as u32,
+				threshold: Self::decayed_threshold(multisig_id, multisig.threshold),
+				executable: Self::is_executable(multisig_id, &multisig, &proposal),
+			})
+		}
+	}
+
+	//HELPER FUNCTIONS
+	impl<T: Config> Pallet<T> {
+		/// Derives a unique, deterministic account ID for a multisig wallet.
+		// This function is the cornerstone of the stateful design. It uses the multisig's
+		// unique `seed` (its `MultisigId`) and the runtime's `PalletId` to generate a 32-byte
+		// hash, which is then decoded into a valid `AccountId`. This allows the pallet
+		// to programmatically control an on-chain account. The derivation itself lives in
+		// the runtime-independent `address` module so it can be reproduced off-chain.
+		//
+		// A counterfactual or salted multisig instead keeps the account derived when it was
+		// created, which is stored alongside it.
+		pub fn multi_account_id(seed: T::MultisigId) -> T::AccountId {
+			<SovereignAccounts<T>>::get(seed)
+				.unwrap_or_else(|| address::multisig_account_id(&T::PalletId::get().0, seed, None))
+		}
+
+		/// Derives the sovereign account a counterfactual multisig with `owners`, `threshold`
+		/// and `salt` will have once created.
+		pub fn counterfactual_account_id(
+			owners: &[T::AccountId],
+			threshold: u32,
+			salt: &[u8; 32],
+		) -> T::AccountId {
+			address::counterfactual_account_id(&T::PalletId::get().0, owners, threshold, salt)
+		}
+
+		/// Derives the account ID of the sub-account `sub_index` of a multisig wallet.
+		pub fn sub_account_id(seed: T::MultisigId, sub_index: u16) -> T::AccountId {
+			address::sub_account_id(&T::PalletId::get().0, seed, sub_index)
+		}
+
+		/// Ensures `origin` is the sovereign account of `multisig_id` and returns its
+		/// configuration.
+		///
+		/// This is the gate for every self-governed configuration change: such calls can only
+		/// be made by proposing, confirming and executing them through the multisig itself.
+		pub(crate) fn ensure_sovereign(
+			origin: OriginFor<T>,
+			multisig_id: T::MultisigId,
+		) -> Result<MultisigOf<T>, DispatchError> {
+			let who = ensure_signed(origin)?;
+			ensure!(who == Self::multi_account_id(multisig_id), Error::<T>::MustBeMultisig);
+			Ok(Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?)
+		}
+
+		/// Stores `multisig` under a freshly allocated ID and announces it.
+		///
+		/// The configuration must already have been validated by the caller.
+		///
+		/// `sovereign` is the account of a counterfactual multisig; other multisigs pass `None`
+		/// and use the account derived from their ID.
+		pub(crate) fn do_create_multisig(
+			creator: T::AccountId,
+			multisig: MultisigOf<T>,
+			sovereign: Option<T::AccountId>,
+		) -> Result<T::MultisigId, DispatchError> {
+			let active = Self::active_multisig_count();
+			if let Some(max) = Self::max_active_multisigs() {
+				ensure!(active < max, Error::<T>::TooManyMultisigs);
+			}
+
+			// Generate a new, unique ID for the multisig.
+			let multisig_id = Self::next_multisig_id();
+			NextMultisigId::<T>::put(
+				multisig_id.checked_add(&One::one()).ok_or(Error::<T>::StorageOverflow)?,
+			);
+
+			// Derive the sovereign account ID for the new multisig.
+			let deposit = T::DepositCalculator::creation_deposit(multisig.owners.len() as u32);
+			if !deposit.is_zero() && Self::is_exempt_account(&creator).is_none() {
+				T::Currency::reserve(&creator, deposit)
+					.map_err(|_| Error::<T>::InsufficientDeposit)?;
+				<CreationDeposits<T>>::insert(multisig_id, (creator.clone(), deposit));
+			}
+
+			if let Some(account) = sovereign {
+				<SovereignAccounts<T>>::insert(multisig_id, account);
+			}
+			let multisig_account = Self::multi_account_id(multisig_id);
+			<MultisigAccounts<T>>::insert(&multisig_account, multisig_id);
+			// A new multisig has no group quorum, call filter, weights or class thresholds yet.
+			let config_hash = Self::config_commitment(&multisig, None, None, &[], &[]);
+			Self::reindex_owners(multisig_id, &[], &multisig.owners);
+			<Multisigs<T>>::insert(multisig_id, multisig);
+			ActiveMultisigCount::<T>::put(active.saturating_add(1));
+
+			// Emit an event to notify the outside world of the new multisig.
+			Self::deposit_event(Event::MultisigCreated {
+				creator,
+				multisig_id,
+				multisig_account: multisig_account.clone(),
+				config_hash,
+			});
+			T::OnMultisigCreated::on_multisig_created(multisig_id, &multisig_account);
+			Ok(multisig_id)
+		}
+
+		/// Returns the hash under which `call` is proposed and pre-approved.
+		///
+		/// The hash is computed with the runtime's configured `Hashing`, so off-chain tooling can
+		/// reproduce it from the runtime metadata alone.
+		pub fn call_hash(call: &<T as Config>::RuntimeCall) -> CallHashOf<T> {
+			T::Hashing::hash_of(call)
+		}
+
+		/// Returns the owners who have approved a proposal, or an empty list if it does not exist.
+		pub fn approvals(
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) -> BoundedVec<T::AccountId, T::MaxOwners> {
+			Self::proposals(multisig_id, proposal_index)
+				.map(|proposal| proposal.approvals)
+				.unwrap_or_default()
+		}
+
+		/// Records a new proposal for `call` on behalf of the owner `who`.
+		///
+		/// This holds the logic shared by every submission path. The submitter, any owner who
+		/// pre-approved the call hash and, for typed proposals, any owner holding a matching
+		/// standing approval are counted as approvals straight away.
+		pub(crate) fn do_submit_proposal(
+			who: T::AccountId,
+			multisig_id: T::MultisigId,
+			call: &<T as Config>::RuntimeCall,
+			typed: Option<&TypedProposalOf<T>>,
+			content_ref: Option<ContentRefOf<T>>,
+			lifetime: Option<BlockNumberFor<T>>,
+		) -> Result<T::ProposalIndex, DispatchError> {
+			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+			//  This is the core authorization check, ensuring only owners can create proposals.
+			ensure!(multisig.owners.contains(&who), Error::<T>::NotAnOwner);
+			let suspended = Self::suspended_owners(multisig_id);
+			ensure!(!suspended.contains(&who), Error::<T>::SuspendedOwner);
+
+			// In an ordered wallet, submitting counts as approving, so it must respect the chain.
+			ensure!(
+				multisig.approval_in_order(&[], &suspended, &who),
+				Error::<T>::OutOfOrderApproval
+			);
+
+			if let Some(filter) = Self::call_filter(multisig_id) {
+				ensure!(filter.permits(&call.encode()), Error::<T>::CallFiltered);
+			}
+
+			// Bound the number of pending proposals so every operation on them stays bounded.
+			let pending = Self::pending_proposal_count(multisig_id);
+			ensure!(pending < T::MaxPendingProposals::get(), Error::<T>::TooManyPendingProposals);
+			PendingProposalCount::<T>::insert(multisig_id, pending + 1);
+
+			// Generate a new, unique index for this proposal within the scope of the multisig.
+			let proposal_index = Self::next_proposal_index(multisig_id);
+			NextProposalIndex::<T>::insert(
+				multisig_id,
+				proposal_index.checked_add(&One::one()).ok_or(Error::<T>::StorageOverflow)?,
+			);
+
+			let deposit =
+				T::DepositCalculator::proposal_deposit(call.encoded_size() as u32, pending);
+			Self::reserve_proposal_deposit(multisig_id, proposal_index, &who, deposit)?;
+
+			// Calculate the hash of the call for storage optimization.
+			let call_hash = Self::call_hash(call);
+
+			//    The submitter automatically confirms their own proposal. This improves
+			// UX by saving them from sending a second, separate `confirm_proposal` transaction.
+			let mut approvals: BoundedVec<T::AccountId, T::MaxOwners> = BoundedVec::new();
+			approvals.try_push(who.clone()).map_err(|_| Error::<T>::TooManyOwners)?;
+
+			// Any owner who pre-approved this exact call hash is counted straight away. A counted
+			// pre-approval is consumed so it cannot be reused for a later proposal.
+			let pre_approvals = <PreApprovals<T>>::take(multisig_id, call_hash);
+			let mut candidates: Vec<T::AccountId> = pre_approvals
+				.iter()
+				.filter(|approver| *approver != &who && multisig.owners.contains(approver))
+				.filter(|approver| !suspended.contains(approver))
+				.cloned()
+				.collect();
+
+			// Standing approvals are only consulted for typed proposals, whose intent the
+			// pallet can check against the approved pattern. Unlike pre-approvals, they are
+			// not consumed.
+			if let Some(typed) = typed {
+				let now = frame_system::Pallet::<T>::block_number();
+				for owner in multisig.owners.iter() {
+					if owner == &who || candidates.contains(owner) || suspended.contains(owner) {
+						continue;
+					}
+					let standing = Self::standing_approvals(multisig_id, owner);
+					if standing.iter().any(|approval| approval.matches(typed, &now)) {
+						candidates.push(owner.clone());
+					}
+				}
+			}
+
+			let counted = Self::count_in_order(&multisig, &suspended, &mut approvals, candidates)?;
+			Self::retain_pre_approvals(
+				multisig_id,
+				&multisig,
+				call_hash,
+				pre_approvals,
+				&approvals,
+			);
+
+			let expires_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(lifetime.unwrap_or_else(T::ProposalLifetime::get));
+			let mut new_proposal = Proposal {
+				call_hash,
+				status: ProposalStatus::Pending,
+				proposer: who,
+				approvals,
+				rejections: Default::default(),
+				content_ref: content_ref.clone(),
+				expires_at,
+				threshold_met_at: None,
+			};
+			Self::track_threshold(multisig_id, &mut new_proposal);
+			Self::index_proposal(multisig_id, proposal_index, &new_proposal);
+			<ProposalsByCallHash<T>>::insert(
+				new_proposal.call_hash,
+				(multisig_id, proposal_index),
+				(),
+			);
+			<Proposals<T>>::insert(multisig_id, proposal_index, new_proposal);
+			Self::note_owner_activity(multisig_id);
+
+			// Emit an event to notify users of the new proposal.
+			Self::deposit_event(Event::ProposalSubmitted {
+				multisig_id,
+				proposal_index,
+				call_hash,
+				content_ref: content_ref.map(Into::into),
+			});
+			for who in counted {
+				Self::deposit_event(Event::Confirmation { who, multisig_id, proposal_index });
+			}
+			Ok(proposal_index)
+		}
+
+		/// Ensures a typed proposal respects the policy limits `multisig_id` has configured for
+		/// its kind.
+		pub(crate) fn ensure_within_limits(
+			multisig_id: T::MultisigId,
+			proposal: &TypedProposalOf<T>,
+		) -> DispatchResult {
+			match proposal {
+				TypedProposal::Transfer { .. } |
+				TypedProposal::TransferItem { .. } |
+				TypedProposal::TreasurySpend { .. } |
+				TypedProposal::TreasuryPayout { .. } |
+				TypedProposal::AcceptCurator { .. } |
+				TypedProposal::AwardBounty { .. } |
+				TypedProposal::ClaimBounty { .. } |
+				TypedProposal::ClaimPoolPayout |
+				TypedProposal::PurgeSessionKeys => Ok(()),
+				TypedProposal::SetSessionKeys { keys, .. } => {
+					ensure!(
+						keys.len() as u32 == T::SessionKeysLength::get(),
+						Error::<T>::InvalidSessionKeys
+					);
+					Ok(())
+				},
+				TypedProposal::JoinPool { amount, .. } |
+				TypedProposal::BondExtraToPool { amount } => {
+					ensure!(!amount.is_zero(), Error::<T>::InvalidPoolBond);
+					Ok(())
+				},
+				// A grant must actually vest: it may not start in the past or unlock in full
+				// in a single block.
+				TypedProposal::VestedTransfer { locked, per_block, starting_block, .. } => {
+					ensure!(
+						!per_block.is_zero() &&
+							per_block < locked && *starting_block >=
+							frame_system::Pallet::<T>::block_number(),
+						Error::<T>::InvalidVestingSchedule
+					);
+					Ok(())
+				},
+				TypedProposal::InstantiateContract { value, gas_limit, .. } |
+				TypedProposal::CallContract { value, gas_limit, .. } => {
+					if let Some(limits) = Self::contract_limits(multisig_id) {
+						ensure!(
+							limits.allows(value, gas_limit),
+							Error::<T>::ContractLimitsExceeded
+						);
+					}
+					Ok(())
+				},
+				TypedProposal::EvmCall { value, gas_limit, .. } => {
+					if let Some(limits) = Self::evm_limits(multisig_id) {
+						ensure!(
+							value <= &limits.max_value && gas_limit <= &limits.max_gas,
+							Error::<T>::EvmLimitsExceeded
+						);
+					}
+					Ok(())
+				},
+			}
+		}
+
+		/// Returns the EVM address mapped to the sovereign account of `multisig_id`.
+		///
+		/// This is the leading 20 bytes of the encoded account ID, matching Frontier's truncated
+		/// address mapping, and is the address EVM proposals are sent from.
+		pub fn evm_address(multisig_id: T::MultisigId) -> H160 {
+			address::evm_address(&Self::multi_account_id(multisig_id))
+		}
+
+		/// Returns the off-chain index key under which the history of a proposal is recorded.
+		pub fn offchain_proposal_key(
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) -> Vec<u8> {
+			(b"pba/multisig/proposal", multisig_id, proposal_index).encode()
+		}
+
+		/// Records the latest state of a proposal in the off-chain index.
+		///
+		/// Nodes running with off-chain indexing enabled keep these records even after the
+		/// on-chain state is pruned or the wallet is destroyed, so front-ends can serve wallet
+		/// history without a separate indexer.
+		pub(crate) fn index_proposal(
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+			proposal: &ProposalOf<T>,
+		) {
+			let key = Self::offchain_proposal_key(multisig_id, proposal_index);
+			sp_io::offchain_index::set(&key, &proposal.encode());
+		}
+
+		/// Returns a hash commitment to the approval policy of `multisig` with the cross-group
+		/// quorum `group_quorum`, the call filter `call_filter`, the vote weights
+		/// `owner_weights` and the per-class thresholds `class_thresholds`.
+		///
+		/// The commitment covers the owners and required approvers, each sorted so that listing
+		/// order does not matter, the threshold, the sign-off chain in its given order, the
+		/// group quorum and call filter as stored, and the weights and class thresholds sorted
+		/// by owner and class. It is the `Hashing` of their SCALE encoding, so clients can
+		/// recompute it from a policy document and compare it with the one announced in
+		/// `MultisigCreated`.
+		pub fn config_commitment(
+			multisig: &MultisigOf<T>,
+			group_quorum: Option<&GroupQuorumOf<T>>,
+			call_filter: Option<&CallFilterOf<T>>,
+			owner_weights: &[(T::AccountId, u32)],
+			class_thresholds: &[(CallClass, u32)],
+		) -> T::Hash {
+			let mut owners = multisig.owners.to_vec();
+			owners.sort();
+			let mut required_approvers = multisig.required_approvers.to_vec();
+			required_approvers.sort();
+			let mut owner_weights = owner_weights.to_vec();
+			owner_weights.sort();
+			let mut class_thresholds = class_thresholds.to_vec();
+			class_thresholds.sort();
+			T::Hashing::hash_of(&(
+				owners,
+				multisig.threshold,
+				required_approvers,
+				&multisig.approval_order,
+				group_quorum,
+				call_filter,
+				owner_weights,
+				class_thresholds,
+			))
+		}
+
+		/// Returns the commitment to the current policy of `multisig_id`, see
+		/// `config_commitment`, or `None` if it does not exist.
+		pub fn current_config_commitment(multisig_id: T::MultisigId) -> Option<T::Hash> {
+			let multisig = Self::multisigs(multisig_id)?;
+			let owner_weights: Vec<_> = <OwnerWeights<T>>::iter_prefix(multisig_id).collect();
+			let class_thresholds: Vec<_> = <ClassThresholds<T>>::iter_prefix(multisig_id).collect();
+			Some(Self::config_commitment(
+				&multisig,
+				Self::group_quorums(multisig_id).as_ref(),
+				Self::call_filter(multisig_id).as_ref(),
+				&owner_weights,
+				&class_thresholds,
+			))
+		}
+
+		/// Returns whether the current configuration of `multisig_id` matches `commitment`.
+		///
+		/// This is false if the multisig does not exist or its policy has changed since the
+		/// commitment was made.
+		pub fn verify_config(multisig_id: T::MultisigId, commitment: T::Hash) -> bool {
+			Self::current_config_commitment(multisig_id) == Some(commitment)
+		}
+
+		/// Adds `multisig_id` to the payrolls falling due at block `at`.
+		pub(crate) fn schedule_payroll(
+			multisig_id: T::MultisigId,
+			at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			<PayrollAgenda<T>>::try_mutate(at, |agenda| {
+				agenda.try_push(multisig_id).map_err(|_| Error::<T>::PayrollAgendaFull.into())
+			})
+		}
+
+		/// Pays out the payroll of `multisig_id` if it falls due at block `now`, and schedules
+		/// its next payout.
+		///
+		/// Each payment keeps the sovereign account alive and, like a proposal approved by
+		/// less than every owner, may not dip into the protected reserve. Payments that cannot
+		/// be made are skipped and reported.
+		pub(crate) fn pay_payroll(multisig_id: T::MultisigId, now: BlockNumberFor<T>) {
+			let Some(mut payroll) = Self::payrolls(multisig_id) else { return };
+			// A stale agenda entry for a payroll that has since been replaced.
+			if payroll.next_payout != now {
+				return
+			}
+
+			if !payroll.paused {
+				let multisig_account = Self::multi_account_id(multisig_id);
+				let reserve = Self::protected_reserve(multisig_id).unwrap_or_default();
+				let mut total = BalanceOf::<T>::zero();
+				for (payee, amount) in payroll.payees.iter() {
+					let balance = T::Currency::total_balance(&multisig_account);
+					let paid = balance.saturating_sub(*amount) >= reserve &&
+						T::Currency::transfer(
+							&multisig_account,
+							payee,
+							*amount,
+							ExistenceRequirement::KeepAlive,
+						)
+						.is_ok();
+					if paid {
+						total = total.saturating_add(*amount);
+					} else {
+						Self::deposit_event(Event::PayrollPaymentFailed {
+							multisig_id,
+							payee: payee.clone(),
+							amount: *amount,
+						});
+					}
+				}
+				Self::deposit_event(Event::PayrollPaid { multisig_id, total });
+			}
+
+			// Find the next block with room on the agenda, skipping whole periods if needed. The
+			// payroll only ends here once the block number itself is exhausted.
+			let mut next_payout = now;
+			loop {
+				let candidate = next_payout.saturating_add(payroll.period);
+				if candidate == next_payout {
+					<Payrolls<T>>::remove(multisig_id);
+					return
+				}
+				next_payout = candidate;
+				if Self::schedule_payroll(multisig_id, next_payout).is_ok() {
+					break
+				}
+			}
+			payroll.next_payout = next_payout;
+			<Payrolls<T>>::insert(multisig_id, payroll);
+		}
+
+		/// Dispatches `call` from the sovereign account of `multisig_id`, or with `origin` if
+		/// one is given.
+		///
+		/// Unless the call was approved by every owner, it is rolled back if it leaves the
+		/// sovereign account's balance below the protected reserve.
+		///
+		/// The actual weight of the call is returned whether or not it succeeded.
+		pub(crate) fn dispatch_as_sovereign(
+			multisig_id: T::MultisigId,
+			call: <T as Config>::RuntimeCall,
+			unanimous: bool,
+			origin: Option<Origin<T>>,
+		) -> DispatchResultWithPostInfo {
+			let multisig_account = Self::multi_account_id(multisig_id);
+			let reserve = Self::protected_reserve(multisig_id).filter(|_| !unanimous);
+			let balance_before = T::Currency::total_balance(&multisig_account);
+			let origin = match origin {
+				Some(origin) => origin.into(),
+				None => RawOrigin::Signed(multisig_account.clone()).into(),
+			};
+			let info = call.get_dispatch_info();
+			with_storage_layer(|| {
+				let result = call.dispatch(origin);
+				let weight = extract_actual_weight(&result, &info);
+				result.map_err(|e| e.error.with_weight(weight))?;
+				if let Some(reserve) = reserve {
+					let balance_after = T::Currency::total_balance(&multisig_account);
+					ensure!(
+						balance_after >= reserve || balance_after >= balance_before,
+						Error::<T>::ReserveBreached.with_weight(weight)
+					);
+				}
+				Ok(Some(weight).into())
+			})
+		}
+
+		/// Reserves `deposit` from `who` for a proposal, recording it so it can be refunded.
+		///
+		/// Nothing is reserved if `who` or the multisig is exempt from deposits.
+		pub(crate) fn reserve_proposal_deposit(
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+			who: &T::AccountId,
+			deposit: BalanceOf<T>,
+		) -> DispatchResult {
+			let exempt = Self::is_exempt_account(who).is_some() ||
+				Self::is_exempt_multisig(multisig_id).is_some();
+			if deposit.is_zero() || exempt {
+				return Ok(())
+			}
+			T::Currency::reserve(who, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+			<ProposalDeposits<T>>::try_mutate(multisig_id, proposal_index, |deposits| {
+				deposits
+					.try_push((who.clone(), deposit))
+					.map_err(|_| Error::<T>::TooManyOwners.into())
+			})
+		}
+
+		/// Refunds every deposit held for a proposal.
+		pub(crate) fn release_proposal_deposits(
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) {
+			for (who, deposit) in <ProposalDeposits<T>>::take(multisig_id, proposal_index) {
+				T::Currency::unreserve(&who, deposit);
+			}
+		}
+
+		/// Refunds every deposit held for a multisig and releases its earmarked funds.
+		///
+		/// Returns the number of entries released.
+		pub(crate) fn release_multisig_reserves(multisig_id: T::MultisigId) -> u32 {
+			let multisig_account = Self::multi_account_id(multisig_id);
+			let mut released = 0;
+			for (_, amount) in <Earmarks<T>>::drain_prefix(multisig_id) {
+				T::Currency::unreserve(&multisig_account, amount);
+				released += 1;
+			}
+			if let Some((creator, deposit)) = <CreationDeposits<T>>::take(multisig_id) {
+				T::Currency::unreserve(&creator, deposit);
+				released += 1;
+			}
+			for (_, deposits) in <ProposalDeposits<T>>::drain_prefix(multisig_id) {
+				for (who, deposit) in deposits {
+					T::Currency::unreserve(&who, deposit);
+				}
+				released += 1;
+			}
+			let uploads = <CallUploads<T>>::drain_prefix(multisig_id)
+				.map(|(_, upload)| upload)
+				.chain(<UploadedCalls<T>>::drain_prefix(multisig_id).map(|(_, upload)| upload));
+			for upload in uploads {
+				T::Currency::unreserve(&upload.depositor, upload.deposit);
+				released += 1;
+			}
+			released
+		}
+
+		/// Removes all storage kept for a multisig other than its configuration.
+		///
+		/// `clear_prefix` is used for convenience to clean up all proposals, along with their
+		/// approvals, in a single action. Returns the number of storage items that held entries
+		/// for the multisig.
+		pub(crate) fn clear_multisig_storage(multisig_id: T::MultisigId) -> u32 {
+			let held = [
+				<Proposals<T>>::iter_key_prefix(multisig_id).next().is_some(),
+				<PreApprovals<T>>::iter_key_prefix(multisig_id).next().is_some(),
+				<PreApprovalCount<T>>::contains_key(multisig_id),
+				<StandingApprovals<T>>::iter_key_prefix(multisig_id).next().is_some(),
+				<OperatorSessions<T>>::iter_key_prefix(multisig_id).next().is_some(),
+				<InboundSpends<T>>::iter_key_prefix(multisig_id).next().is_some(),
+				<ProposalFlags<T>>::iter_key_prefix(multisig_id).next().is_some(),
+				<MultisigOriginProposals<T>>::iter_key_prefix(multisig_id).next().is_some(),
+				<OwnerLabels<T>>::iter_key_prefix(multisig_id).next().is_some(),
+				<Delegations<T>>::iter_key_prefix(multisig_id).next().is_some(),
+				<OwnerWeights<T>>::iter_key_prefix(multisig_id).next().is_some(),
+				<ClassThresholds<T>>::iter_key_prefix(multisig_id).next().is_some(),
+				<CallFilters<T>>::contains_key(multisig_id),
+				<SovereignAccounts<T>>::contains_key(multisig_id),
+				<NextProposalIndex<T>>::contains_key(multisig_id),
+				<PendingProposalCount<T>>::contains_key(multisig_id),
+				<GroupQuorums<T>>::contains_key(multisig_id),
+				<MultisigContractLimits<T>>::contains_key(multisig_id),
+				<MultisigEvmLimits<T>>::contains_key(multisig_id),
+				<ProtectedReserves<T>>::contains_key(multisig_id),
+				<Payrolls<T>>::contains_key(multisig_id),
+				<Auditors<T>>::contains_key(multisig_id),
+				<Vetoers<T>>::contains_key(multisig_id),
+				<RecoveryConfigs<T>>::contains_key(multisig_id),
+				<ActiveRecoveries<T>>::contains_key(multisig_id),
+				<SuspendedOwners<T>>::contains_key(multisig_id),
+				<BackupOwnerSets<T>>::contains_key(multisig_id),
+				<DepositExemptMultisigs<T>>::contains_key(multisig_id),
+				<VelocityLimits<T>>::contains_key(multisig_id),
+				<ExecutionWindows<T>>::contains_key(multisig_id),
+				<SpendingLimits<T>>::contains_key(multisig_id),
+				<ThresholdDecays<T>>::contains_key(multisig_id),
+				<LastExecutedAt<T>>::contains_key(multisig_id),
+				<ExecutionDelays<T>>::contains_key(multisig_id),
+				<SpendingWindows<T>>::contains_key(multisig_id),
+				<SigningNonces<T>>::contains_key(multisig_id),
+				<UsageMeters<T>>::contains_key(multisig_id),
+			];
+
+			for (proposal_index, proposal) in <Proposals<T>>::iter_prefix(multisig_id) {
+				<ProposalsByCallHash<T>>::remove(proposal.call_hash, (multisig_id, proposal_index));
+			}
+			let _ = <Proposals<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			let _ = <PreApprovals<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			<PreApprovalCount<T>>::remove(multisig_id);
+			let _ = <StandingApprovals<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			let _ = <OperatorSessions<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			let _ = <InboundSpends<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			let _ = <ProposalFlags<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			let _ = <MultisigOriginProposals<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			let _ = <OwnerLabels<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			let _ = <Delegations<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			let _ = <OwnerWeights<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			let _ = <ClassThresholds<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			<MultisigAccounts<T>>::remove(Self::multi_account_id(multisig_id));
+			<SovereignAccounts<T>>::remove(multisig_id);
+			<CallFilters<T>>::remove(multisig_id);
+			<NextProposalIndex<T>>::remove(multisig_id);
+			<PendingProposalCount<T>>::remove(multisig_id);
+			<GroupQuorums<T>>::remove(multisig_id);
+			<MultisigContractLimits<T>>::remove(multisig_id);
+			<MultisigEvmLimits<T>>::remove(multisig_id);
+			<ProtectedReserves<T>>::remove(multisig_id);
+			<Payrolls<T>>::remove(multisig_id);
+			<Auditors<T>>::remove(multisig_id);
+			<Vetoers<T>>::remove(multisig_id);
+			<RecoveryConfigs<T>>::remove(multisig_id);
+			<ActiveRecoveries<T>>::remove(multisig_id);
+			<SuspendedOwners<T>>::remove(multisig_id);
+			<BackupOwnerSets<T>>::remove(multisig_id);
+			<DepositExemptMultisigs<T>>::remove(multisig_id);
+			<VelocityLimits<T>>::remove(multisig_id);
+			<ExecutionWindows<T>>::remove(multisig_id);
+			<SpendingLimits<T>>::remove(multisig_id);
+			<ThresholdDecays<T>>::remove(multisig_id);
+			<LastExecutedAt<T>>::remove(multisig_id);
+			<ExecutionDelays<T>>::remove(multisig_id);
+			<SpendingWindows<T>>::remove(multisig_id);
+			<SigningNonces<T>>::remove(multisig_id);
+			<UsageMeters<T>>::remove(multisig_id);
+			held.iter().filter(|held| **held).count() as u32
+		}
+
+		/// Repairs the bookkeeping of an existing multisig against its proposals.
+		///
+		/// Recounts its pending proposals and pre-approved call hashes, refunds deposits and
+		/// releases earmarks held for proposals that no longer exist or have already executed,
+		/// and drops standing approvals of accounts that are no longer owners. Returns the
+		/// number of repairs made.
+		pub(crate) fn reconcile_multisig(
+			multisig_id: T::MultisigId,
+			multisig: &MultisigOf<T>,
+		) -> u32 {
+			let mut repaired = 0;
+			let is_pending = |index: T::ProposalIndex| {
+				Self::proposals(multisig_id, index)
+					.is_some_and(|proposal| proposal.status.is_open())
+			};
+
+			let pending = <Proposals<T>>::iter_prefix_values(multisig_id)
+				.filter(|proposal| proposal.status.is_open())
+				.count() as u32;
+			if Self::pending_proposal_count(multisig_id) != pending {
+				PendingProposalCount::<T>::insert(multisig_id, pending);
+				repaired += 1;
+			}
+
+			let pre_approved = <PreApprovals<T>>::iter_key_prefix(multisig_id).count() as u32;
+			if Self::pre_approval_count(multisig_id) != pre_approved {
+				PreApprovalCount::<T>::insert(multisig_id, pre_approved);
+				repaired += 1;
+			}
+
+			let stale_deposits: Vec<_> = <ProposalDeposits<T>>::iter_key_prefix(multisig_id)
+				.filter(|index| !is_pending(*index))
+				.collect();
+			for index in stale_deposits {
+				Self::release_proposal_deposits(multisig_id, index);
+				repaired += 1;
+			}
+
+			let multisig_account = Self::multi_account_id(multisig_id);
+			let stale_earmarks: Vec<_> = <Earmarks<T>>::iter_prefix(multisig_id)
+				.filter(|(index, _)| !is_pending(*index))
+				.collect();
+			for (index, amount) in stale_earmarks {
+				<Earmarks<T>>::remove(multisig_id, index);
+				T::Currency::unreserve(&multisig_account, amount);
+				repaired += 1;
+			}
+
+			let stale_grantors: Vec<_> = <StandingApprovals<T>>::iter_key_prefix(multisig_id)
+				.filter(|grantor| !multisig.owners.contains(grantor))
+				.collect();
+			for grantor in stale_grantors {
+				<StandingApprovals<T>>::remove(multisig_id, grantor);
+				repaired += 1;
+			}
+			repaired
+		}
+
+		/// Ensures `approvals` and `rejections` satisfy every approval rule of the multisig: its
+		/// vote tally against `threshold`, lowered by any threshold decay, mandatory approvers
+		/// and group quorum.
+		pub(crate) fn ensure_approved(
+			multisig_id: T::MultisigId,
+			multisig: &MultisigOf<T>,
+			threshold: u32,
+			approvals: &[T::AccountId],
+			rejections: &[T::AccountId],
+		) -> DispatchResult {
+			let threshold = Self::decayed_threshold(multisig_id, threshold);
+			let weights: Vec<u32> = multisig
+				.owners
+				.iter()
+				.map(|owner| Self::vote_weight(multisig_id, owner))
+				.collect();
+			ensure!(
+				T::VoteTally::has_passed(
+					&multisig.owners,
+					&weights,
+					threshold,
+					approvals,
+					rejections,
+				),
+				Error::<T>::NotEnoughApprovals
+			);
+			// Mandatory approvers must always have signed, however many other owners have,
+			// unless they are suspended.
+			let suspended = Self::suspended_owners(multisig_id);
+			ensure!(
+				multisig
+					.required_approvers
+					.iter()
+					.all(|approver| approvals.contains(approver) || suspended.contains(approver)),
+				Error::<T>::MissingRequiredApproval
+			);
+			if let Some(quorum) = Self::group_quorums(multisig_id) {
+				let mut groups = quorum.groups.iter();
+				let met = match quorum.rule {
+					GroupRule::All => groups.all(|group| group.is_met(approvals)),
+					GroupRule::Any => groups.any(|group| group.is_met(approvals)),
+				};
+				ensure!(met, Error::<T>::GroupQuorumNotMet);
+			}
+			Ok(())
+		}
+
+		/// Returns the index and call hash of every proposal of `multisig_id` that is neither
+		/// executed nor expired.
+		pub fn pending_proposals(
+			multisig_id: T::MultisigId,
+		) -> Vec<(T::ProposalIndex, CallHashOf<T>)> {
+			<Proposals<T>>::iter_prefix(multisig_id)
+				.filter(|(_, proposal)| proposal.status.is_open() && !Self::is_expired(proposal))
+				.map(|(index, proposal)| (index, proposal.call_hash))
+				.collect()
+		}
+
+		/// Returns the index and call hash of every pending proposal of `multisig_id` that has
+		/// gathered the approvals it needs to be executed.
+		///
+		/// The runtime's `PolicyCheck` and the multisig's class thresholds are not applied, as
+		/// they depend on the call itself and only the call's hash is stored; execution may
+		/// still be refused by them.
+		pub fn executable_proposals(
+			multisig_id: T::MultisigId,
+		) -> Vec<(T::ProposalIndex, CallHashOf<T>)> {
+			let Some(multisig) = Self::multisigs(multisig_id) else { return Vec::new() };
+			<Proposals<T>>::iter_prefix(multisig_id)
+				.filter(|(_, proposal)| Self::is_executable(multisig_id, &multisig, proposal))
+				.map(|(index, proposal)| (index, proposal.call_hash))
+				.collect()
+		}
+
+		/// Returns whether `proposal` is pending and has gathered the approvals it needs to be
+		/// executed, leaving out the call-dependent checks.
+		pub(crate) fn is_executable(
+			multisig_id: T::MultisigId,
+			multisig: &MultisigOf<T>,
+			proposal: &ProposalOf<T>,
+		) -> bool {
+			let approvals = Self::counted_approvals(multisig_id, multisig, &proposal.approvals);
+			let rejections = Self::counted_approvals(multisig_id, multisig, &proposal.rejections);
+			let timelocked = !multisig.is_unanimous(&approvals) &&
+				!Self::timelock_elapsed(multisig_id, proposal);
+			proposal.status.is_open() &&
+				!Self::is_expired(proposal) &&
+				!timelocked && Self::ensure_approved(
+				multisig_id,
+				multisig,
+				multisig.threshold,
+				&approvals,
+				&rejections,
+			)
+			.is_ok()
+		}
+
+		/// Returns the multisig and index of every pending proposal of `call_hash`.
+		pub fn proposals_by_call_hash(
+			call_hash: CallHashOf<T>,
+		) -> Vec<(T::MultisigId, T::ProposalIndex)> {
+			<ProposalsByCallHash<T>>::iter_key_prefix(call_hash).collect()
+		}
+
+		/// Returns the labels of the owners of `multisig_id` that have one.
+		pub fn owner_labels(multisig_id: T::MultisigId) -> Vec<(T::AccountId, NameOf<T>)> {
+			<OwnerLabels<T>>::iter_prefix(multisig_id).collect()
+		}
+
+		/// Returns the approvals that count towards execution, leaving out those of suspended
+		/// owners and of accounts that are no longer owners.
+		pub(crate) fn counted_approvals(
+			multisig_id: T::MultisigId,
+			multisig: &MultisigOf<T>,
+			approvals: &[T::AccountId],
+		) -> Vec<T::AccountId> {
+			let suspended = Self::suspended_owners(multisig_id);
+			approvals
+				.iter()
+				.filter(|approver| multisig.owners.contains(approver))
+				.filter(|approver| !suspended.contains(approver))
+				.cloned()
+				.collect()
+		}
+
+		/// Records that `multisig_id` no longer holds pre-approvals for one call hash.
+		pub(crate) fn note_pre_approval_removed(multisig_id: T::MultisigId) {
+			<PreApprovalCount<T>>::mutate_exists(multisig_id, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			});
+		}
+
+		/// Records activity by the owners of `multisig_id`, postponing the takeover of any
+		/// backup owner set.
+		pub(crate) fn note_owner_activity(multisig_id: T::MultisigId) {
+			<BackupOwnerSets<T>>::mutate(multisig_id, |backup| {
+				if let Some(backup) = backup {
+					backup.last_activity = frame_system::Pallet::<T>::block_number();
+				}
+			});
+		}
+
+		/// Takes a snapshot of the complete state of `multisig_id`, or `None` if it does not
+		/// exist.
+		pub fn export_state(multisig_id: T::MultisigId) -> Option<WalletSnapshot<T>> {
+			let config = Self::multisigs(multisig_id)?;
+			let account = Self::multi_account_id(multisig_id);
+			let open_proposals = <Proposals<T>>::iter_prefix(multisig_id)
+				.filter(|(_, proposal)| proposal.status.is_open())
+				.map(|(index, proposal)| {
+					let earmark = Self::earmarks(multisig_id, index);
+					(index, proposal, earmark, Self::proposal_deposits(multisig_id, index))
+				})
+				.collect();
+			Some(WalletSnapshot {
+				version: SNAPSHOT_VERSION,
+				block_number: frame_system::Pallet::<T>::block_number(),
+				balance: (
+					T::Currency::free_balance(&account),
+					T::Currency::reserved_balance(&account),
+				),
+				account,
+				config,
+				group_quorum: Self::group_quorums(multisig_id),
+				contract_limits: Self::contract_limits(multisig_id),
+				evm_limits: Self::evm_limits(multisig_id),
+				protected_reserve: Self::protected_reserve(multisig_id),
+				auditors: Self::auditors(multisig_id).into_inner(),
+				vetoers: Self::vetoers(multisig_id).into_inner(),
+				suspended_owners: Self::suspended_owners(multisig_id).into_inner(),
+				owner_labels: Self::owner_labels(multisig_id),
+				delegations: <Delegations<T>>::iter_prefix(multisig_id).collect(),
+				owner_weights: <OwnerWeights<T>>::iter_prefix(multisig_id).collect(),
+				class_thresholds: <ClassThresholds<T>>::iter_prefix(multisig_id).collect(),
+				call_filter: Self::call_filter(multisig_id),
+				backup_owners: Self::backup_owners(multisig_id),
+				recovery: Self::recovery_config(multisig_id),
+				payroll: Self::payrolls(multisig_id),
+				velocity: Self::velocity_limit(multisig_id)
+					.map(|limit| (limit, Self::execution_window(multisig_id))),
+				spending: Self::spending_limit(multisig_id)
+					.map(|limit| (limit, Self::spending_window(multisig_id))),
+				threshold_decay: Self::threshold_decay(multisig_id),
+				last_executed_at: Self::last_executed_at(multisig_id),
+				execution_delay: Self::execution_delay(multisig_id),
+				signing_nonce: Self::signing_nonce(multisig_id),
+				usage: Self::usage_meter(multisig_id),
+				call_uploads: <CallUploads<T>>::iter_prefix_values(multisig_id).collect(),
+				uploaded_calls: <UploadedCalls<T>>::iter_prefix(multisig_id).collect(),
+				pending_proposal_count: Self::pending_proposal_count(multisig_id),
+				next_proposal_index: Self::next_proposal_index(multisig_id),
+				open_proposals,
+			})
+		}
+
+		/// Counts an execution that spent `spent` against the velocity limit of `multisig_id`,
+		/// failing if it exceeds the limit without the vote weight `approvals` reaching the
+		/// override threshold.
+		pub(crate) fn record_execution(
+			multisig_id: T::MultisigId,
+			approvals: u32,
+			spent: BalanceOf<T>,
+		) -> DispatchResult {
+			Self::record_velocity(multisig_id, 1, approvals, spent)
+		}
+
+		/// Counts `spent` that left the sovereign account of `multisig_id` without a proposal,
+		/// such as an operator's call or an allowance, against its velocity limit.
+		///
+		/// Such a spend has no approvals, so it can only exceed the limit if the override
+		/// threshold is zero.
+		pub(crate) fn record_spend(
+			multisig_id: T::MultisigId,
+			spent: BalanceOf<T>,
+		) -> DispatchResult {
+			Self::record_velocity(multisig_id, 0, 0, spent)
+		}
 
-			// Ensure the threshold is a sensible value.
-			ensure!(
-				threshold > 0 && threshold <= bounded_owners.len() as u32,
-				Error::<T>::InvalidThreshold
-			);
+		/// Counts `executions` that spent `spent` against the velocity limit of `multisig_id`,
+		/// failing if it exceeds the limit without the vote weight `approvals` reaching the
+		/// override threshold.
+		fn record_velocity(
+			multisig_id: T::MultisigId,
+			executions: u32,
+			approvals: u32,
+			spent: BalanceOf<T>,
+		) -> DispatchResult {
+			let Some(limit) = Self::velocity_limit(multisig_id) else { return Ok(()) };
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut window = Self::execution_window(multisig_id);
+			let unused = window.executions == 0 && window.spent.is_zero();
+			if now >= window.started_at.saturating_add(limit.period) || unused {
+				window = ExecutionWindow { started_at: now, executions: 0, spent: Zero::zero() };
+			}
+			window.executions = window.executions.saturating_add(executions);
+			window.spent = window.spent.saturating_add(spent);
 
-			// Generate a new, unique ID for the multisig.
-			let multisig_id = Self::next_multisig_id();
-			NextMultisigId::<T>::put(
-				multisig_id.checked_add(1).ok_or(Error::<T>::StorageOverflow)?,
+			let within_limit = limit.max_executions.is_none_or(|max| window.executions <= max) &&
+				limit.max_value.is_none_or(|max| window.spent <= max);
+			ensure!(
+				within_limit || approvals >= limit.override_threshold,
+				Error::<T>::VelocityLimitExceeded
 			);
+			<ExecutionWindows<T>>::insert(multisig_id, window);
+			Ok(())
+		}
 
-			// Derive the sovereign account ID for the new multisig.
-			let multisig_account = Self::multi_account_id(multisig_id);
+		/// Marks a proposal whose call was dispatched successfully as executed, and removes
+		/// what it no longer needs.
+		///
+		/// This is the only place a proposal is marked executed. It must run in the same
+		/// storage layer as the dispatch, after every check that can still fail, so a proposal
+		/// is either executed with all of its bookkeeping or left open without any of it.
+		pub(crate) fn mark_executed(
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+			proposal: &mut ProposalOf<T>,
+		) {
+			// The existence check is a critical safety check to handle the edge case where the
+			// executed call was `destroy_multisig`. In that case, the multisig no longer exists,
+			// and we must not attempt to write to its storage again. The off-chain history is
+			// still updated, as it outlives the wallet, and keeps the approvals as the receipt
+			// of who signed; on-chain they are pruned, as an executed proposal no longer needs
+			// them and only its `Executed` status guards against replay.
+			proposal.status = ProposalStatus::Executed;
+			<ProposalsByCallHash<T>>::remove(proposal.call_hash, (multisig_id, proposal_index));
+			<MultisigOriginProposals<T>>::remove(multisig_id, proposal_index);
+			Self::index_proposal(multisig_id, proposal_index, proposal);
+			proposal.approvals = BoundedVec::new();
+			Self::release_proposal_deposits(multisig_id, proposal_index);
+			Self::release_uploaded_call(multisig_id, proposal_index);
+			if <Multisigs<T>>::contains_key(multisig_id) {
+				<LastExecutedAt<T>>::insert(multisig_id, frame_system::Pallet::<T>::block_number());
+				<Proposals<T>>::insert(multisig_id, proposal_index, &*proposal);
+				PendingProposalCount::<T>::mutate(multisig_id, |count| {
+					*count = count.saturating_sub(1)
+				});
+			}
+		}
 
-			// Create and store the new multisig's configuration.
-			let new_multisig = Multisig { owners: bounded_owners, threshold };
-			<Multisigs<T>>::insert(multisig_id, new_multisig);
+		/// Returns the canonical bytes an owner signs off-chain to approve `target` on
+		/// `multisig_id`, or `None` if the multisig does not exist.
+		///
+		/// The payload is the SCALE encoding of `SIGNING_DOMAIN`, the chain's genesis hash, the
+		/// multisig's ID, its current signing nonce and `target`, so a signature is bound to
+		/// one chain, one multisig and one nonce. `confirm_proposal_with_signature` verifies
+		/// signatures against it.
+		pub fn signing_payload(
+			multisig_id: T::MultisigId,
+			target: ApprovalTargetOf<T>,
+		) -> Option<Vec<u8>> {
+			<Multisigs<T>>::contains_key(multisig_id).then(|| {
+				let genesis_hash =
+					frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+				(
+					SIGNING_DOMAIN,
+					genesis_hash,
+					multisig_id,
+					Self::signing_nonce(multisig_id),
+					target,
+				)
+					.encode()
+			})
+		}
 
-			// Emit an event to notify the outside world of the new multisig.
-			Self::deposit_event(Event::MultisigCreated {
-				creator: who,
+		/// Returns the payload an owner signs off-chain to confirm the pending proposal
+		/// `proposal_index` of `multisig_id`, or `None` if there is no such proposal.
+		pub fn confirmation_payload(
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) -> Option<Vec<u8>> {
+			let proposal = Self::proposals(multisig_id, proposal_index)?;
+			Self::signing_payload(
 				multisig_id,
-				multisig_account,
-			});
+				ApprovalTarget::Confirmation { proposal_index, call_hash: proposal.call_hash },
+			)
+		}
 
-			Ok(())
+		/// Adds an operation consuming `weight` to the usage meter of `multisig_id`.
+		pub(crate) fn meter_usage(
+			multisig_id: T::MultisigId,
+			operation: MeteredOperation,
+			weight: Weight,
+		) {
+			<UsageMeters<T>>::mutate(multisig_id, |meter| {
+				let count = match operation {
+					MeteredOperation::Submission => &mut meter.submissions,
+					MeteredOperation::Confirmation => &mut meter.confirmations,
+					MeteredOperation::Execution => &mut meter.executions,
+				};
+				*count = count.saturating_add(1);
+				meter.weight = meter.weight.saturating_add(weight);
+				meter.fees = meter.fees.saturating_add(T::FeeEstimator::convert(weight));
+			});
 		}
 
-		/// Submits a new proposal for a multisig wallet to execute.
-		///
-		/// This extrinsic can only be called by an owner of the specified multisig.
-		/// It creates a new proposal record, storing the hash of the `call` to be
-		/// executed. The submitter's account is automatically added as the first
-		/// confirmation for the proposal.
-		///
-		/// ### Parameters:
-		/// - `origin`: The signed account of the multisig owner submitting the proposal.
-		/// - `multisig_id`: The ID of the multisig for which the proposal is being made.
-		/// - `call`: The `RuntimeCall` that the multisig owners will vote on to execute.
+		/// Executes the proposal `proposal_index` of `multisig_id` on behalf of `who`, given its
+		/// `call`.
 		///
-		/// ### Emits:
-		/// - `ProposalSubmitted` on successful submission.
-		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::submit_proposal())]
-		pub fn submit_proposal(
-			origin: OriginFor<T>,
-			multisig_id: MultisigId,
+		/// This is the body of `execute_proposal`, shared with the other ways of executing a
+		/// proposal. Returns the actual weight of the execution, including the call's own.
+		pub(crate) fn do_execute_proposal(
+			who: T::AccountId,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
 			call: Box<<T as Config>::RuntimeCall>,
-		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+		) -> Result<Weight, DispatchError> {
 			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
-			//  This is the core authorization check, ensuring only owners can create proposals.
-			ensure!(multisig.owners.contains(&who), Error::<T>::NotAnOwner);
+			let mut proposal =
+				Self::proposals(multisig_id, proposal_index).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(proposal.status.is_open(), Error::<T>::AlreadyExecuted);
+			ensure!(!Self::is_expired(&proposal), Error::<T>::Expired);
 
-			// Generate a new, unique index for this proposal within the scope of the multisig.
-			let proposal_index = Self::next_proposal_index(multisig_id);
-			NextProposalIndex::<T>::insert(
-				multisig_id,
-				proposal_index.checked_add(1).ok_or(Error::<T>::StorageOverflow)?,
+			//  Verify that the provided call matches the one that was approved.
+			// This prevents a user from tricking owners into approving one action and then
+			// executing another, different action.
+			let call_hash = Self::call_hash(&call);
+			ensure!(proposal.call_hash == call_hash, Error::<T>::CallHashMismatch);
+
+			// The core authorization check: has the threshold been met? Approvals of suspended
+			// owners do not count.
+			let approvals = &Self::counted_approvals(multisig_id, &multisig, &proposal.approvals);
+			let rejections = Self::counted_approvals(multisig_id, &multisig, &proposal.rejections);
+			let threshold = Self::class_threshold(multisig_id, T::CallClassifier::classify(&call))
+				.unwrap_or(multisig.threshold);
+			Self::ensure_approved(multisig_id, &multisig, threshold, approvals, &rejections)?;
+			ensure!(
+				T::PolicyCheck::allows(multisig_id, &call, approvals),
+				Error::<T>::PolicyCheckFailed
 			);
+			let unanimous = multisig.is_unanimous(approvals);
+			ensure!(unanimous || !T::CriticalCalls::contains(&call), Error::<T>::UnanimityRequired);
+			let timelock_waived = !Self::timelock_elapsed(multisig_id, &proposal);
+			ensure!(unanimous || !timelock_waived, Error::<T>::TimelockActive);
+			if multisig.owners.contains(&who) {
+				Self::note_owner_activity(multisig_id);
+			}
 
-			// Calculate the hash of the call for storage optimization.
-			let call_hash = blake2_256(&call.encode());
-			let new_proposal = Proposal { call_hash, executed: false };
-			<Proposals<T>>::insert(multisig_id, proposal_index, new_proposal);
+			// The dispatch and all of the pallet's bookkeeping around it share one storage
+			// layer, so if the call fails, or partially mutates state and then fails, every
+			// change is rolled back together: the proposal stays pending with its earmark,
+			// deposits and approvals exactly as they were, and can be executed again. Only once
+			// the call succeeds is the proposal marked executed, in the same layer.
+			let multisig_account = Self::multi_account_id(multisig_id);
+			let earmark = Self::earmarks(multisig_id, proposal_index);
+			let balance_before = T::Currency::total_balance(&multisig_account);
+			let execution_weight = T::WeightInfo::execute_proposal(
+				multisig.owners.len() as u32,
+				proposal.approvals.len() as u32,
+			);
+			// The call is charged its declared weight unless it reports what it actually used.
+			let mut call_weight = call.get_dispatch_info().call_weight;
+			let origin =
+				Self::uses_multisig_origin(multisig_id, proposal_index).map(|_| Origin::Multisig {
+					multisig_id,
+					approvals: approvals.len() as u32,
+					threshold: Self::decayed_threshold(multisig_id, threshold),
+				});
+			let result = with_storage_layer(|| -> DispatchResult {
+				// Release any earmark first, so the transfer it guards can spend the funds.
+				if let Some(amount) = <Earmarks<T>>::take(multisig_id, proposal_index) {
+					T::Currency::unreserve(&multisig_account, amount);
+				}
 
-			//    The submitter automatically confirms their own proposal. This improves
-			// UX by saving them from sending a second, separate `confirm_proposal` transaction.
-			let mut approvals = BoundedVec::new();
-			approvals.try_push(who.clone()).map_err(|_| Error::<T>::TooManyOwners)?;
-			<Approvals<T>>::insert(multisig_id, proposal_index, approvals);
+				// Dispatch the call from the multisig's sovereign account.
+				let dispatched = Self::dispatch_as_sovereign(multisig_id, *call, unanimous, origin);
+				let post_info = match &dispatched {
+					Ok(post_info) => post_info,
+					Err(error) => &error.post_info,
+				};
+				call_weight = post_info.actual_weight.unwrap_or(call_weight);
+				dispatched.map_err(|error| error.error)?;
+				let spent =
+					balance_before.saturating_sub(T::Currency::total_balance(&multisig_account));
+				let weight = Self::total_weight(multisig_id, approvals);
+				Self::record_execution(multisig_id, weight, spent)?;
+				Self::mark_executed(multisig_id, proposal_index, &mut proposal);
+				Ok(())
+			});
+			let status = match result {
+				Ok(()) => ProposalStatus::Executed,
+				Err(error) => {
+					// The proposal was rolled back as it was and stays open, but records why its
+					// execution failed.
+					proposal.status = ProposalStatus::Failed { error };
+					Self::index_proposal(multisig_id, proposal_index, &proposal);
+					<Proposals<T>>::insert(multisig_id, proposal_index, &proposal);
+					proposal.status
+				},
+			};
 
-			// Emit an event to notify users of the new proposal.
-			Self::deposit_event(Event::ProposalSubmitted {
+			if let (Some(amount), true) = (earmark, result.is_ok()) {
+				Self::deposit_event(Event::EarmarkReleased { multisig_id, proposal_index, amount });
+			}
+			if timelock_waived && result.is_ok() {
+				Self::deposit_event(Event::TimelockWaived { multisig_id, proposal_index });
+			}
+			let weight = execution_weight.saturating_add(call_weight);
+			// A destroyed multisig has nothing left to meter.
+			if <Multisigs<T>>::contains_key(multisig_id) {
+				Self::meter_usage(multisig_id, MeteredOperation::Execution, weight);
+			}
+			T::OnProposalExecuted::on_proposal_executed(
 				multisig_id,
 				proposal_index,
 				call_hash,
+				&result,
+			);
+			Self::deposit_event(Event::ProposalStatusChanged {
+				multisig_id,
+				proposal_index,
+				status,
 			});
-			Ok(())
+			Self::deposit_event(Event::ProposalExecuted { multisig_id, proposal_index, result });
+			Ok(weight)
 		}
 
-		/// Confirms a pending proposal.
+		/// Decodes the call held in `upload`.
+		pub(crate) fn decode_uploaded_call(
+			upload: &CallUploadOf<T>,
+		) -> Result<<T as Config>::RuntimeCall, DispatchError> {
+			<T as Config>::RuntimeCall::decode_all_with_depth_limit(
+				MAX_CALL_DECODE_DEPTH,
+				&mut &upload.data[..],
+			)
+			.map_err(|_| Error::<T>::UndecodableCall.into())
+		}
+
+		/// Returns the higher of two dispatch classes, where `Normal` ranks below `Operational`
+		/// and `Operational` below `Mandatory`.
+		pub(crate) fn higher_class(a: DispatchClass, b: DispatchClass) -> DispatchClass {
+			match (a, b) {
+				(DispatchClass::Mandatory, _) | (_, DispatchClass::Mandatory) =>
+					DispatchClass::Mandatory,
+				(DispatchClass::Operational, _) | (_, DispatchClass::Operational) =>
+					DispatchClass::Operational,
+				_ => DispatchClass::Normal,
+			}
+		}
+
+		/// Reserves the deposit for `len` bytes of call data from `who`, returning the amount
+		/// reserved.
 		///
-		/// This extrinsic can only be called by an owner of the specified multisig who has not
-		/// yet confirmed the proposal.
+		/// Nothing is reserved if `who` or the multisig is exempt from deposits.
+		pub(crate) fn reserve_call_data_deposit(
+			multisig_id: T::MultisigId,
+			who: &T::AccountId,
+			len: u32,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let deposit = T::DepositCalculator::call_data_deposit(len);
+			if deposit.is_zero() ||
+				Self::is_exempt_account(who).is_some() ||
+				Self::is_exempt_multisig(multisig_id).is_some()
+			{
+				return Ok(Zero::zero())
+			}
+			T::Currency::reserve(who, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+			Ok(deposit)
+		}
+
+		/// Removes the call uploaded for a proposal, if any, refunding its deposit.
+		pub(crate) fn release_uploaded_call(
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) {
+			if let Some(upload) = <UploadedCalls<T>>::take(multisig_id, proposal_index) {
+				T::Currency::unreserve(&upload.depositor, upload.deposit);
+			}
+		}
+
+		/// Removes `removed` from, and adds `added` to, the owners of `multisig_id`, returning
+		/// the resulting threshold.
 		///
-		/// ### Parameters:
-		/// - `origin`: The signed account of the owner confirming the proposal.
-		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
-		/// - `proposal_index`: The index of the proposal being confirmed.
+		/// When both are given, `added` takes `removed`'s place in the mandatory approvers, the
+		/// sign-off chain and the owner groups; otherwise a removed owner is dropped from them.
+		/// The result is checked against the threshold, class thresholds, suspended owners, group
+		/// quorum and velocity limit before anything is stored.
+		pub(crate) fn change_owners(
+			multisig_id: T::MultisigId,
+			mut multisig: MultisigOf<T>,
+			removed: Option<&T::AccountId>,
+			added: Option<T::AccountId>,
+			new_threshold: Option<u32>,
+		) -> Result<u32, DispatchError> {
+			if let Some(removed) = removed {
+				ensure!(multisig.owners.contains(removed), Error::<T>::NotAnOwner);
+			}
+			if let Some(added) = &added {
+				ensure!(!multisig.owners.contains(added), Error::<T>::DuplicateAccount);
+			}
+			let previous_owners = multisig.owners.clone();
+
+			// Replaces `removed` in `accounts` with `added` if both are given, or drops it.
+			let substitute = |accounts: &mut BoundedVec<T::AccountId, T::MaxOwners>| {
+				let Some(removed) = removed else { return };
+				match (&added, accounts.iter().position(|account| account == removed)) {
+					(Some(added), Some(position)) => accounts[position] = added.clone(),
+					(None, Some(_)) => accounts.retain(|account| account != removed),
+					(_, None) => {},
+				}
+			};
+			substitute(&mut multisig.owners);
+			substitute(&mut multisig.required_approvers);
+			substitute(&mut multisig.approval_order);
+			if let (Some(added), None) = (&added, removed) {
+				multisig.owners.try_push(added.clone()).map_err(|_| Error::<T>::TooManyOwners)?;
+			}
+
+			// An added owner takes over the vote weight of the owner they replace.
+			let added_weight = removed.map_or(1, |removed| Self::vote_weight(multisig_id, removed));
+			let weight_of = |owner: &T::AccountId| {
+				if Some(owner) == added.as_ref() {
+					added_weight
+				} else {
+					Self::vote_weight(multisig_id, owner)
+				}
+			};
+			let total = multisig.owners.iter().map(weight_of).fold(0, u32::saturating_add);
+			multisig.threshold = new_threshold.unwrap_or(multisig.threshold);
+			ensure!(
+				multisig.threshold > 0 && multisig.threshold <= total,
+				Error::<T>::InvalidThreshold
+			);
+			let mut suspended = Self::suspended_owners(multisig_id);
+			if let Some(removed) = removed {
+				suspended.retain(|owner| owner != removed);
+			}
+			let suspended_weight = suspended.iter().map(weight_of).fold(0, u32::saturating_add);
+			ensure!(
+				total.saturating_sub(suspended_weight) >= multisig.threshold,
+				Error::<T>::InvalidThreshold
+			);
+			Self::ensure_class_thresholds_reachable(
+				multisig_id,
+				total.saturating_sub(suspended_weight),
+			)?;
+
+			let quorum = Self::group_quorums(multisig_id)
+				.map(|mut quorum| -> Result<_, DispatchError> {
+					for group in quorum.groups.iter_mut() {
+						substitute(&mut group.members);
+						ensure!(
+							group.threshold <= group.members.len() as u32,
+							Error::<T>::InvalidGroupThreshold
+						);
+					}
+					Ok(quorum)
+				})
+				.transpose()?;
+			if let Some(limit) = Self::velocity_limit(multisig_id) {
+				ensure!(
+					multisig.threshold <= limit.override_threshold &&
+						limit.override_threshold <= total,
+					Error::<T>::InvalidVelocityLimit
+				);
+			}
+
+			if let Some(removed) = removed {
+				<OwnerLabels<T>>::remove(multisig_id, removed);
+				<Delegations<T>>::remove(multisig_id, removed);
+				<StandingApprovals<T>>::remove(multisig_id, removed);
+				let weight = <OwnerWeights<T>>::take(multisig_id, removed);
+				if let (Some(added), Some(weight)) = (&added, weight) {
+					<OwnerWeights<T>>::insert(multisig_id, added, weight);
+				}
+			}
+			let threshold = multisig.threshold;
+			Self::reindex_owners(multisig_id, &previous_owners, &multisig.owners);
+			<Multisigs<T>>::insert(multisig_id, multisig);
+			<SuspendedOwners<T>>::insert(multisig_id, suspended);
+			<GroupQuorums<T>>::set(multisig_id, quorum);
+			Ok(threshold)
+		}
+
+		/// Removes a pending proposal with everything held for it, refunding its deposits and
+		/// releasing its earmarked funds.
 		///
-		/// ### Emits:
-		/// - `Confirmation` on successful confirmation.
-		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::confirm_proposal())]
-		pub fn confirm_proposal(
-			origin: OriginFor<T>,
-			multisig_id: MultisigId,
-			proposal_index: ProposalIndex,
-		) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+		/// The proposal's off-chain history is closed with `status`, which is also announced in
+		/// `ProposalStatusChanged`.
+		pub(crate) fn remove_pending_proposal(
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+			status: ProposalStatus,
+		) {
+			if let Some(mut proposal) = <Proposals<T>>::take(multisig_id, proposal_index) {
+				<ProposalsByCallHash<T>>::remove(proposal.call_hash, (multisig_id, proposal_index));
+				proposal.status = status;
+				Self::index_proposal(multisig_id, proposal_index, &proposal);
+				Self::deposit_event(Event::ProposalStatusChanged {
+					multisig_id,
+					proposal_index,
+					status,
+				});
+			}
+			<ProposalFlags<T>>::remove(multisig_id, proposal_index);
+			<MultisigOriginProposals<T>>::remove(multisig_id, proposal_index);
+			PendingProposalCount::<T>::mutate(multisig_id, |count| {
+				*count = count.saturating_sub(1)
+			});
+			Self::release_proposal_deposits(multisig_id, proposal_index);
+			Self::release_uploaded_call(multisig_id, proposal_index);
+			if let Some(amount) = <Earmarks<T>>::take(multisig_id, proposal_index) {
+				T::Currency::unreserve(&Self::multi_account_id(multisig_id), amount);
+				Self::deposit_event(Event::EarmarkReleased { multisig_id, proposal_index, amount });
+			}
+		}
+
+		/// Returns whether `proposal` has expired.
+		pub(crate) fn is_expired(proposal: &ProposalOf<T>) -> bool {
+			frame_system::Pallet::<T>::block_number() > proposal.expires_at
+		}
+
+		/// Refunds the approval deposit `who` paid for a proposal.
+		pub(crate) fn refund_approval_deposit(
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+			who: &T::AccountId,
+		) {
+			<ProposalDeposits<T>>::mutate(multisig_id, proposal_index, |deposits| {
+				deposits.retain(|(depositor, deposit)| {
+					if depositor == who {
+						T::Currency::unreserve(depositor, *deposit);
+					}
+					depositor != who
+				})
+			});
+		}
+
+		/// Pays `beneficiary` the `ExpiredDepositSlash` share of the submission deposit
+		/// `proposer` holds for an expired proposal, leaving the rest reserved to be refunded.
+		pub(crate) fn slash_expired_deposit(
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+			proposer: &T::AccountId,
+			beneficiary: &T::AccountId,
+		) {
+			let mut deposits = Self::proposal_deposits(multisig_id, proposal_index);
+			let Some((_, deposit)) =
+				deposits.iter_mut().find(|(depositor, _)| depositor == proposer)
+			else {
+				return
+			};
+			let slash = T::ExpiredDepositSlash::get() * *deposit;
+			if slash.is_zero() {
+				return
+			}
+			let unpaid =
+				T::Currency::repatriate_reserved(proposer, beneficiary, slash, BalanceStatus::Free)
+					.unwrap_or(slash);
+			let amount = slash.saturating_sub(unpaid);
+			*deposit = deposit.saturating_sub(amount);
+			<ProposalDeposits<T>>::insert(multisig_id, proposal_index, deposits);
+			if !amount.is_zero() {
+				Self::deposit_event(Event::DepositSlashed {
+					multisig_id,
+					proposal_index,
+					who: proposer.clone(),
+					beneficiary: beneficiary.clone(),
+					amount,
+				});
+			}
+		}
 
+		/// Records `who`'s approval of a proposal, reserving their approval deposit.
+		///
+		/// Returns the actual weight of the confirmation.
+		pub(crate) fn do_confirm_proposal(
+			who: T::AccountId,
+			multisig_id: T::MultisigId,
+			proposal_index: T::ProposalIndex,
+		) -> Result<Weight, DispatchError> {
 			// By checking conditions in this order (cheapest to most expensive), we can
 			// fail early and save computational resources if a condition is not met.
 			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
 			ensure!(multisig.owners.contains(&who), Error::<T>::NotAnOwner);
-			let proposal =
+			let suspended = Self::suspended_owners(multisig_id);
+			ensure!(!suspended.contains(&who), Error::<T>::SuspendedOwner);
+			// Perform a read-modify-write operation on the proposal and its approvals.
+			let mut proposal =
 				Self::proposals(multisig_id, proposal_index).ok_or(Error::<T>::ProposalNotFound)?;
-			ensure!(!proposal.executed, Error::<T>::AlreadyExecuted);
+			ensure!(proposal.status.is_open(), Error::<T>::AlreadyExecuted);
+			ensure!(!Self::is_expired(&proposal), Error::<T>::Expired);
 
-			// Perform a read-modify-write operation on the approvals.
-			let mut approvals = Self::approvals(multisig_id, proposal_index);
 			//  This check prevents a single owner from confirming the same proposal
 			// multiple times to artificially meet the threshold.
-			ensure!(!approvals.contains(&who), Error::<T>::AlreadyConfirmed);
+			ensure!(!proposal.approvals.contains(&who), Error::<T>::AlreadyConfirmed);
+			ensure!(
+				multisig.approval_in_order(&proposal.approvals, &suspended, &who),
+				Error::<T>::OutOfOrderApproval
+			);
 
-			approvals.try_push(who.clone()).map_err(|_| Error::<T>::TooManyOwners)?;
-			<Approvals<T>>::insert(multisig_id, proposal_index, approvals);
+			// An owner who voted against the proposal changes their vote.
+			proposal.rejections.retain(|rejecter| rejecter != &who);
+			proposal
+				.approvals
+				.try_push(who.clone())
+				.map_err(|_| Error::<T>::TooManyOwners)?;
+			let deposit = T::DepositCalculator::approval_deposit(multisig.owners.len() as u32);
+			Self::reserve_proposal_deposit(multisig_id, proposal_index, &who, deposit)?;
+
+			// Pre-approvals of this call that were waiting for their owner's turn in the
+			// sign-off chain, or for their owner to be reinstated, are counted as soon as
+			// they can be.
+			let pre_approvals = <PreApprovals<T>>::take(multisig_id, proposal.call_hash);
+			let candidates = pre_approvals
+				.iter()
+				.filter(|approver| multisig.owners.contains(approver))
+				.filter(|approver| !proposal.approvals.contains(approver))
+				.filter(|approver| !suspended.contains(approver))
+				.cloned()
+				.collect();
+			let counted =
+				Self::count_in_order(&multisig, &suspended, &mut proposal.approvals, candidates)?;
+			Self::retain_pre_approvals(
+				multisig_id,
+				&multisig,
+				proposal.call_hash,
+				pre_approvals,
+				&proposal.approvals,
+			);
+			Self::track_threshold(multisig_id, &mut proposal);
+			Self::index_proposal(multisig_id, proposal_index, &proposal);
+			let weight = T::WeightInfo::confirm_proposal(
+				multisig.owners.len() as u32,
+				proposal.approvals.len() as u32,
+			);
+			<Proposals<T>>::insert(multisig_id, proposal_index, proposal);
+
+			Self::note_owner_activity(multisig_id);
+			Self::meter_usage(multisig_id, MeteredOperation::Confirmation, weight);
 
 			Self::deposit_event(Event::Confirmation { who, multisig_id, proposal_index });
-			Ok(())
+			for who in counted {
+				Self::deposit_event(Event::Confirmation { who, multisig_id, proposal_index });
+			}
+			Ok(weight)
 		}
 
-		/// Executes a proposal that has met its confirmation threshold.
-		///
-		/// This extrinsic can be called by any signed account, as the authorization is
-		/// based on the on-chain confirmation state, not the caller's identity.
+		/// Counts each of `candidates` towards `approvals`.
 		///
-		/// ### Parameters:
-		/// - `origin`: Any signed account.
-		/// - `multisig_id`: The ID of the multisig the proposal belongs to.
-		/// - `proposal_index`: The index of the proposal to be executed.
-		/// - `call`: The full `RuntimeCall` corresponding to the proposal's stored hash.
+		/// In a wallet with an ordered sign-off chain a candidate only counts once everyone
+		/// ahead of it has approved, so whoever is next in line keeps being added. Returns the
+		/// candidates counted, in order.
+		pub(crate) fn count_in_order(
+			multisig: &MultisigOf<T>,
+			suspended: &[T::AccountId],
+			approvals: &mut BoundedVec<T::AccountId, T::MaxOwners>,
+			mut candidates: Vec<T::AccountId>,
+		) -> Result<Vec<T::AccountId>, DispatchError> {
+			let mut counted = Vec::new();
+			while let Some(position) = candidates
+				.iter()
+				.position(|c| multisig.approval_in_order(approvals, suspended, c))
+			{
+				let approver = candidates.remove(position);
+				approvals.try_push(approver.clone()).map_err(|_| Error::<T>::TooManyOwners)?;
+				counted.push(approver);
+			}
+			Ok(counted)
+		}
+
+		/// Writes back the pre-approvals taken for `call_hash` that `approvals` has not
+		/// counted, so that they are counted once their owner can approve.
 		///
-		/// ### Emits:
-		/// - `ProposalExecuted` with the result of the dispatched call.
-		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::execute_proposal())]
-		pub fn execute_proposal(
-			origin: OriginFor<T>,
-			multisig_id: MultisigId,
-			proposal_index: ProposalIndex,
-			call: Box<<T as Config>::RuntimeCall>,
-		) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
-			let multisig = Self::multisigs(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
-			let mut proposal =
-				Self::proposals(multisig_id, proposal_index).ok_or(Error::<T>::ProposalNotFound)?;
-			ensure!(!proposal.executed, Error::<T>::AlreadyExecuted);
+		/// Pre-approvals of accounts that are no longer owners are dropped.
+		pub(crate) fn retain_pre_approvals(
+			multisig_id: T::MultisigId,
+			multisig: &MultisigOf<T>,
+			call_hash: CallHashOf<T>,
+			mut pre_approvals: BoundedVec<T::AccountId, T::MaxOwners>,
+			approvals: &[T::AccountId],
+		) {
+			if pre_approvals.is_empty() {
+				return;
+			}
+			pre_approvals.retain(|approver| {
+				multisig.owners.contains(approver) && !approvals.contains(approver)
+			});
+			if pre_approvals.is_empty() {
+				Self::note_pre_approval_removed(multisig_id);
+			} else {
+				<PreApprovals<T>>::insert(multisig_id, call_hash, pre_approvals);
+			}
+		}
 
-			//  Verify that the provided call matches the one that was approved.
-			// This prevents a user from tricking owners into approving one action and then
-			// executing another, different action.
-			let call_hash = blake2_256(&call.encode());
-			ensure!(proposal.call_hash == call_hash, Error::<T>::CallHashMismatch);
+		/// Returns the vote weight of `owner` in `multisig_id`.
+		pub fn vote_weight(multisig_id: T::MultisigId, owner: &T::AccountId) -> u32 {
+			Self::owner_weight(multisig_id, owner).unwrap_or(1)
+		}
 
-			// The core authorization check: has the threshold been met?
-			let approvals = Self::approvals(multisig_id, proposal_index);
-			ensure!(approvals.len() as u32 >= multisig.threshold, Error::<T>::NotEnoughApprovals);
+		/// Returns the total vote weight of `accounts` in `multisig_id`.
+		pub(crate) fn total_weight(multisig_id: T::MultisigId, accounts: &[T::AccountId]) -> u32 {
+			accounts
+				.iter()
+				.map(|account| Self::vote_weight(multisig_id, account))
+				.fold(0, u32::saturating_add)
+		}
 
-			// Dispatch the call from the multisig's sovereign account.
-			let multisig_account = Self::multi_account_id(multisig_id);
-			let result = call.dispatch(RawOrigin::Signed(multisig_account).into());
+		/// Returns the execution delay of `multisig_id`.
+		pub fn execution_delay(multisig_id: T::MultisigId) -> BlockNumberFor<T> {
+			<ExecutionDelays<T>>::get(multisig_id).unwrap_or_else(T::DefaultExecutionDelay::get)
+		}
 
-			//   Only update the proposal's state if the dispatch was successful.
-			// The second condition is a critical safety check to handle the edge case where the
-			// executed call was `destroy_multisig`. In that case, the multisig no longer
-			// exists, and we must not attempt to write to its storage again.
-			if result.is_ok() && <Multisigs<T>>::contains_key(multisig_id) {
-				proposal.executed = true;
-				<Proposals<T>>::insert(multisig_id, proposal_index, proposal);
-			}
+		/// Records when `proposal`'s approvals first meet the threshold, or clears the record
+		/// if they fall short of it again.
+		///
+		/// The lowest threshold the multisig applies to any class of call is used, as only the
+		/// proposal's hash is known; execution still checks the threshold of its actual class.
+		pub(crate) fn track_threshold(multisig_id: T::MultisigId, proposal: &mut ProposalOf<T>) {
+			let Some(multisig) = Self::multisigs(multisig_id) else { return };
+			let threshold = <ClassThresholds<T>>::iter_prefix_values(multisig_id)
+				.fold(multisig.threshold, |lowest, threshold| lowest.min(threshold));
+			let approvals = Self::counted_approvals(multisig_id, &multisig, &proposal.approvals);
+			let rejections = Self::counted_approvals(multisig_id, &multisig, &proposal.rejections);
+			let met =
+				Self::ensure_approved(multisig_id, &multisig, threshold, &approvals, &rejections)
+					.is_ok();
+			proposal.threshold_met_at = match (met, proposal.threshold_met_at) {
+				(true, None) => Some(frame_system::Pallet::<T>::block_number()),
+				(true, met_at) => met_at,
+				(false, _) => None,
+			};
+		}
 
-			Self::deposit_event(Event::ProposalExecuted {
-				multisig_id,
-				proposal_index,
-				result: result.map(|_| ()).map_err(|e| e.error),
-			});
+		/// Returns whether `proposal` has met its threshold at least the multisig's execution
+		/// delay ago.
+		pub(crate) fn timelock_elapsed(
+			multisig_id: T::MultisigId,
+			proposal: &ProposalOf<T>,
+		) -> bool {
+			let delay = Self::execution_delay(multisig_id);
+			delay.is_zero() ||
+				proposal.threshold_met_at.is_some_and(|met_at| {
+					frame_system::Pallet::<T>::block_number() >= met_at.saturating_add(delay)
+				})
+		}
+
+		/// Hands a multisig over to a new owner set, clearing every policy that names the
+		/// previous owners, and the class thresholds, which were set against their vote weights.
+		pub(crate) fn replace_owners(
+			multisig_id: T::MultisigId,
+			mut multisig: MultisigOf<T>,
+			owners: BoundedVec<T::AccountId, T::MaxOwners>,
+			threshold: u32,
+		) -> DispatchResult {
+			Self::reindex_owners(multisig_id, &multisig.owners, &owners);
+			multisig.owners = owners;
+			multisig.threshold = threshold;
+			multisig.required_approvers = BoundedVec::new();
+			multisig.approval_order = BoundedVec::new();
+			<Multisigs<T>>::insert(multisig_id, multisig);
+			<GroupQuorums<T>>::remove(multisig_id);
+			<SuspendedOwners<T>>::remove(multisig_id);
+			let _ = <OwnerLabels<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			let _ = <Delegations<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			let _ = <OwnerWeights<T>>::clear_prefix(multisig_id, u32::MAX, None);
+			let _ = <ClassThresholds<T>>::clear_prefix(multisig_id, u32::MAX, None);
 			Ok(())
 		}
 
-		/// Destroys a multisig wallet and cleans up all associated storage.
-		///
-		///  This is a sovereign action. It can only be called successfully
-		/// if the `origin` is the multisig's own derived `AccountId`. This means that
-		/// to destroy a wallet, the owners must first propose, confirm, and execute a
-		/// call to this very extrinsic.
-		///
-		/// ### Parameters:
-		/// - `origin`: The sovereign `AccountId` of the multisig being destroyed.
-		/// - `multisig_id`: The ID of the multisig to destroy.
+		/// Checks that every class threshold of `multisig_id` can be met by owners carrying
+		/// `active` vote weight.
 		///
-		/// ### Emits:
-		/// - `MultisigDestroyed` on successful destruction.
-		#[pallet::call_index(4)]
-		#[pallet::weight(T::WeightInfo::destroy_multisig())]
-		pub fn destroy_multisig(origin: OriginFor<T>, multisig_id: MultisigId) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-			let multisig_account = Self::multi_account_id(multisig_id);
-
-			// The core security check for this extrinsic.
-			ensure!(who == multisig_account, Error::<T>::MustBeMultisig);
-
-			// A check to ensure we are not trying to destroy a non-existent multisig.
-			// Prefixed with `_` to silence the "unused variable" warning, as its only
-			// purpose is to exist for this check.
-			let _multisig = <Multisigs<T>>::get(multisig_id).ok_or(Error::<T>::MultisigNotFound)?;
+		/// The calls that lower a class threshold may themselves be of a class, so one left
+		/// out of reach could never be changed again.
+		pub(crate) fn ensure_class_thresholds_reachable(
+			multisig_id: T::MultisigId,
+			active: u32,
+		) -> DispatchResult {
+			ensure!(
+				<ClassThresholds<T>>::iter_prefix_values(multisig_id)
+					.all(|threshold| threshold <= active),
+				Error::<T>::InvalidThreshold
+			);
+			Ok(())
+		}
 
-			//   This is a critical safety net to prevent the accidental, irreversible
-			// loss of funds. It forces the owners to first empty the wallet's balance
-			// via a separate proposal before they can destroy it.
-			let balance = T::Currency::free_balance(&multisig_account);
-			ensure!(balance.is_zero(), Error::<T>::NonZeroBalance);
+		/// Checks an owner set and its threshold, returning the bounded set.
+		pub(crate) fn bounded_owner_set(
+			owners: Vec<T::AccountId>,
+			threshold: u32,
+		) -> Result<BoundedVec<T::AccountId, T::MaxOwners>, DispatchError> {
+			let owners: BoundedVec<_, T::MaxOwners> =
+				owners.try_into().map_err(|_| Error::<T>::TooManyOwners)?;
+			ensure!(
+				threshold > 0 && threshold <= owners.len() as u32,
+				Error::<T>::InvalidThreshold
+			);
+			for (i, owner) in owners.iter().enumerate() {
+				ensure!(!owners[..i].contains(owner), Error::<T>::DuplicateAccount);
+			}
+			Ok(owners)
+		}
 
-			// Clean up all storage associated with the multisig.
-			//    `clear_prefix` is used for convenience to clean up all proposals
-			// and approvals in a single action. While this has a variable weight, the sovereign
-			// security model ensures this potentially expensive operation is a deliberate,
-			// multi-approved decision.
-			<Multisigs<T>>::remove(multisig_id);
-			<NextProposalIndex<T>>::remove(multisig_id);
-			let _ = <Proposals<T>>::clear_prefix(multisig_id, u32::MAX, None);
-			let _ = <Approvals<T>>::clear_prefix(multisig_id, u32::MAX, None);
+		/// Returns `threshold`, lowered to the decayed threshold of `multisig_id` if it has
+		/// executed no proposal for the decay's inactivity period.
+		pub fn decayed_threshold(multisig_id: T::MultisigId, threshold: u32) -> u32 {
+			let Some(decay) = Self::threshold_decay(multisig_id) else { return threshold };
+			let last_executed_at = Self::last_executed_at(multisig_id).unwrap_or_default();
+			let now = frame_system::Pallet::<T>::block_number();
+			if now >= last_executed_at.saturating_add(decay.inactivity_period) {
+				threshold.min(decay.threshold)
+			} else {
+				threshold
+			}
+		}
 
-			Self::deposit_event(Event::MultisigDestroyed { multisig_id });
-			Ok(())
+		/// Returns the owner of `multisig_id` on whose behalf `who` may currently confirm
+		/// proposals, or `who` itself if it holds no unexpired delegation.
+		pub(crate) fn delegator_of(multisig_id: T::MultisigId, who: T::AccountId) -> T::AccountId {
+			let now = frame_system::Pallet::<T>::block_number();
+			<Delegations<T>>::iter_prefix(multisig_id)
+				.find(|(_, delegation)| delegation.delegate == who && now < delegation.expires_at)
+				.map_or(who, |(owner, _)| owner)
 		}
-	}
 
-	//HELPER FUNCTIONS
-	impl<T: Config> Pallet<T> {
-		/// Derives a unique, deterministic account ID for a multisig wallet.
-		// This function is the cornerstone of the stateful design. It uses the multisig's
-		// unique `seed` (its `MultisigId`) and a constant namespace to generate a 32-byte
-		// hash, which is then decoded into a valid `AccountId`. This allows the pallet
-		// to programmatically control an on-chain account.
-		pub fn multi_account_id(seed: u32) -> T::AccountId {
-			let entropy = (b"pba/multisig", seed).using_encoded(blake2_256);
-			Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
-				.expect("infinite length input; no invalid inputs for type; qed")
+		/// Updates the `OwnerToMultisigs` index of `multisig_id` for its owners changing from
+		/// `previous` to `current`.
+		///
+		/// The index is best-effort: anyone can create a multisig naming any account as an
+		/// owner, so an owner whose index is full is left out of it, with `OwnerIndexFull`
+		/// emitted, rather than failing the creation or owner change.
+		pub(crate) fn reindex_owners(
+			multisig_id: T::MultisigId,
+			previous: &[T::AccountId],
+			current: &[T::AccountId],
+		) {
+			for owner in previous.iter().filter(|owner| !current.contains(owner)) {
+				<OwnerToMultisigs<T>>::mutate_exists(owner, |ids| {
+					if let Some(list) = ids {
+						list.retain(|id| *id != multisig_id);
+						if list.is_empty() {
+							*ids = None;
+						}
+					}
+				});
+			}
+			for owner in current.iter().filter(|owner| !previous.contains(owner)) {
+				let indexed = <OwnerToMultisigs<T>>::mutate(owner, |ids| ids.try_push(multisig_id));
+				if indexed.is_err() {
+					Self::deposit_event(Event::OwnerIndexFull {
+						owner: owner.clone(),
+						multisig_id,
+					});
+				}
+			}
 		}
 	}
 }
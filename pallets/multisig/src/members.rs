@@ -0,0 +1,38 @@
+//! Member-set adapters backed by a multisig's owners.
+//!
+//! Other pallets that take a member set, such as membership-gated features or
+//! `EnsureSignedBy`, can be configured with these to treat the owners of a multisig as their
+//! members. The set follows the multisig as its owners change, and is empty once it is
+//! destroyed.
+
+use crate::{Config, Pallet};
+use core::marker::PhantomData;
+use frame_support::traits::{Contains, Get, SortedMembers};
+use sp_std::prelude::*;
+
+/// The owners of the multisig with ID `Id`, suspended owners included.
+pub struct OwnersOf<T, Id>(PhantomData<(T, Id)>);
+
+impl<T: Config, Id: Get<T::MultisigId>> SortedMembers<T::AccountId> for OwnersOf<T, Id> {
+	fn sorted_members() -> Vec<T::AccountId> {
+		let mut owners = Pallet::<T>::multisigs(Id::get())
+			.map(|multisig| multisig.owners.to_vec())
+			.unwrap_or_default();
+		owners.sort();
+		owners
+	}
+
+	fn contains(who: &T::AccountId) -> bool {
+		<Self as Contains<T::AccountId>>::contains(who)
+	}
+
+	fn count() -> usize {
+		Pallet::<T>::multisigs(Id::get()).map_or(0, |multisig| multisig.owners.len())
+	}
+}
+
+impl<T: Config, Id: Get<T::MultisigId>> Contains<T::AccountId> for OwnersOf<T, Id> {
+	fn contains(who: &T::AccountId) -> bool {
+		Pallet::<T>::multisigs(Id::get()).is_some_and(|multisig| multisig.owners.contains(who))
+	}
+}
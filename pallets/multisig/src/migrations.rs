@@ -0,0 +1,241 @@
+//! Storage migrations of the multisig pallet.
+//!
+//! Each migration moves the pallet's storage from one `StorageVersion` to the next and is
+//! wrapped in a `VersionedMigration`, so it only runs against the version it was written for.
+//! The layouts it migrates from are kept in a module named after their version, so the
+//! encodings already on chain can still be decoded after the pallet's types change.
+
+use crate::*;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, storage_alias,
+	traits::UncheckedOnRuntimeUpgrade, weights::Weight,
+};
+use sp_runtime::traits::{Saturating, TrailingZeroInput};
+use sp_std::prelude::*;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// The storage layout the pallet launched with, before its storage was versioned.
+pub mod v0 {
+	use super::*;
+	use sp_core::hashing::blake2_256;
+
+	/// The multisig ID type of the launch layout.
+	pub type MultisigId = u32;
+
+	/// The proposal index type of the launch layout.
+	pub type ProposalIndex = u32;
+
+	/// A multisig's configuration in the launch layout.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	#[scale_info(skip_type_params(MaxOwners))]
+	pub struct Multisig<AccountId, MaxOwners: Get<u32>> {
+		/// The owners of the multisig.
+		pub owners: BoundedVec<AccountId, MaxOwners>,
+		/// The number of owner approvals required to execute a proposal.
+		pub threshold: u32,
+	}
+
+	/// A proposal in the launch layout, whose approvals were kept in `Approvals`.
+	#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct Proposal {
+		/// The blake2 hash of the proposed call.
+		pub call_hash: [u8; 32],
+		/// Whether the proposal has been executed.
+		pub executed: bool,
+	}
+
+	#[storage_alias]
+	pub type NextMultisigId<T: Config> = StorageValue<Pallet<T>, MultisigId, ValueQuery>;
+
+	#[storage_alias]
+	pub type Multisigs<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		MultisigId,
+		Multisig<<T as frame_system::Config>::AccountId, <T as Config>::MaxOwners>,
+	>;
+
+	#[storage_alias]
+	pub type Proposals<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		MultisigId,
+		Blake2_128Concat,
+		ProposalIndex,
+		Proposal,
+	>;
+
+	#[storage_alias]
+	pub type NextProposalIndex<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, MultisigId, ProposalIndex, ValueQuery>;
+
+	#[storage_alias]
+	pub type Approvals<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		MultisigId,
+		Blake2_128Concat,
+		ProposalIndex,
+		BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxOwners>,
+		ValueQuery,
+	>;
+
+	/// Derives the sovereign account of `multisig_id` as the launch layout did, before
+	/// accounts were namespaced by `Config::PalletId`.
+	pub fn account_id<AccountId: Decode>(multisig_id: MultisigId) -> AccountId {
+		let entropy = (b"pba/multisig", multisig_id).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+}
+
+/// Migrates the launch layout to version 1.
+pub mod v1 {
+	use super::*;
+
+	/// Migrates the launch layout to version 1, without checking the storage version.
+	///
+	/// - Multisigs and proposals are re-keyed by the configured `MultisigId` and `ProposalIndex`,
+	///   and rewritten in their current encoding. Each pending proposal takes its approvals from
+	///   the removed `Approvals` map, its first approver as its proposer and a fresh
+	///   `ProposalLifetime`.
+	/// - Every multisig keeps the sovereign account it launched with, through `SovereignAccounts`,
+	///   so funds held under the old derivation stay reachable.
+	/// - The indexes added since launch are backfilled: `ActiveMultisigCount`,
+	///   `PendingProposalCount`, `MultisigAccounts`, `OwnerToMultisigs` and `ProposalsByCallHash`.
+	///
+	/// Proposals whose multisig no longer exists, or whose call hash does not decode as the
+	/// runtime's `Hash`, are dropped.
+	pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads = 1u64;
+			let mut writes = 1u64;
+
+			NextMultisigId::<T>::put(T::MultisigId::from(v0::NextMultisigId::<T>::take()));
+
+			let multisigs: Vec<_> = v0::Multisigs::<T>::drain().collect();
+			ActiveMultisigCount::<T>::put(multisigs.len() as u32);
+			for (id, old) in multisigs {
+				let multisig_id = T::MultisigId::from(id);
+				let account: T::AccountId = v0::account_id(id);
+				Pallet::<T>::reindex_owners(multisig_id, &[], &old.owners);
+				<SovereignAccounts<T>>::insert(multisig_id, &account);
+				<MultisigAccounts<T>>::insert(account, multisig_id);
+				<Multisigs<T>>::insert(
+					multisig_id,
+					Multisig {
+						owners: old.owners,
+						threshold: old.threshold,
+						required_approvers: BoundedVec::new(),
+						approval_order: BoundedVec::new(),
+					},
+				);
+				reads.saturating_inc();
+				writes.saturating_accrue(5);
+			}
+
+			let next_indexes: Vec<_> = v0::NextProposalIndex::<T>::drain().collect();
+			for (id, index) in next_indexes {
+				<NextProposalIndex<T>>::insert(
+					T::MultisigId::from(id),
+					T::ProposalIndex::from(index),
+				);
+				reads.saturating_inc();
+				writes.saturating_inc();
+			}
+
+			let expires_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::ProposalLifetime::get());
+			let proposals: Vec<_> = v0::Proposals::<T>::drain().collect();
+			for (id, index, old) in proposals {
+				let approvals = v0::Approvals::<T>::take(id, index);
+				reads.saturating_accrue(2);
+				writes.saturating_inc();
+				let (multisig_id, proposal_index) =
+					(T::MultisigId::from(id), T::ProposalIndex::from(index));
+				let Some(multisig) = Pallet::<T>::multisigs(multisig_id) else { continue };
+				let Ok(call_hash) = CallHashOf::<T>::decode(&mut &old.call_hash[..]) else {
+					continue
+				};
+				let Some(proposer) = approvals.first().or_else(|| multisig.owners.first()).cloned()
+				else {
+					continue
+				};
+
+				let mut proposal = Proposal {
+					call_hash,
+					status: if old.executed {
+						ProposalStatus::Executed
+					} else {
+						ProposalStatus::Pending
+					},
+					proposer,
+					approvals: if old.executed { BoundedVec::new() } else { approvals },
+					rejections: BoundedVec::new(),
+					content_ref: None,
+					expires_at,
+					threshold_met_at: None,
+				};
+				if proposal.status.is_open() {
+					Pallet::<T>::track_threshold(multisig_id, &mut proposal);
+					<ProposalsByCallHash<T>>::insert(
+						proposal.call_hash,
+						(multisig_id, proposal_index),
+						(),
+					);
+					PendingProposalCount::<T>::mutate(multisig_id, |count| {
+						*count = count.saturating_add(1)
+					});
+					writes.saturating_accrue(2);
+				}
+				<Proposals<T>>::insert(multisig_id, proposal_index, proposal);
+			}
+			// Approvals of proposals that no longer existed.
+			let _ = v0::Approvals::<T>::clear(u32::MAX, None);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let multisigs = v0::Multisigs::<T>::iter_keys().count() as u32;
+			Ok(multisigs.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let multisigs = u32::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("undecodable pre-upgrade state"))?;
+			ensure!(
+				<Multisigs<T>>::iter_keys().count() as u32 == multisigs,
+				TryRuntimeError::Other("multisigs were lost")
+			);
+			ensure!(
+				ActiveMultisigCount::<T>::get() == multisigs,
+				TryRuntimeError::Other("active multisig count was not backfilled")
+			);
+			for multisig_id in <Multisigs<T>>::iter_keys() {
+				let account = Pallet::<T>::multi_account_id(multisig_id);
+				ensure!(
+					Pallet::<T>::multisig_of_account(account) == Some(multisig_id),
+					TryRuntimeError::Other("a sovereign account was not indexed")
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrates the launch layout to version 1, if the pallet is at version 0.
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -3,13 +3,14 @@ use frame::runtime::prelude::*;
 
 use frame_support::{
 	derive_impl,
-	traits::{ConstU128, ConstU16, ConstU32, ConstU64},
+	traits::{ConstU128, ConstU16, ConstU32, ConstU64, InstanceFilter},
 };
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Perbill,
 };
+use std::cell::RefCell;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 type Balance = u128;
@@ -26,7 +27,8 @@ mod runtime {
 		RuntimeHoldReason,
 		RuntimeSlashReason,
 		RuntimeLockId,
-		RuntimeTask
+		RuntimeTask,
+		RuntimeViewFunction
 	)]
 	pub struct Test;
 
@@ -83,14 +85,339 @@ impl pallet_balances::Config for Test {
 	type MaxFreezes = ConstU32<10>;
 }
 
+frame_support::parameter_types! {
+	pub const MultisigPalletId: frame_support::PalletId = frame_support::PalletId(*b"pba/msig");
+}
+
 impl pallet_multisig::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type RuntimeCall = RuntimeCall;
 	type MaxOwners = ConstU32<10>;
+	type MaxPendingProposals = ConstU32<16>;
+	type MaxStandingApprovals = ConstU32<4>;
+	type MaxGroups = ConstU32<4>;
+	type MaxNameLength = ConstU32<32>;
+	type MaxContentRefLength = ConstU32<64>;
+	type PalletId = MultisigPalletId;
+	type TemplateOrigin = frame_system::EnsureRoot<u64>;
+	type MaxContractDataLength = ConstU32<256>;
+	type ItemCustody = MockItemCustody;
+	type MaxFlagReasonLength = ConstU32<64>;
+	type PolicyCheck = MockPolicyCheck;
+	type CallClassifier = MockCallClassifier;
+	type OwnerProxies = MockOwnerProxies;
+	type RecipientGate = MockRecipientGate;
+	type CriticalCalls = MockCriticalCalls;
+	type SessionKeysLength = ConstU32<64>;
+	type MaxPayees = ConstU32<8>;
+	type MaxPayrollsPerBlock = ConstU32<4>;
+	type SessionScope = MockSessionScope;
+	type VoteTally = MockVoteTally;
+	type DepositCalculator = MockDeposits;
+	type DepositExemptionOrigin = frame_system::EnsureRoot<u64>;
+	type WalletCapOrigin = frame_support::traits::EitherOfDiverse<
+		frame_system::EnsureRoot<u64>,
+		pallet_multisig::EnsureMultisigApprovals<Test, ConstU32<0>, ConstU32<2>>,
+	>;
+	type FeeEstimator = MockFeeEstimator;
+	type MaxCallSize = ConstU32<1_024>;
+	type ProposalLifetime = ConstU64<1_000>;
+	type ExpiredDepositSlash = MockExpiredDepositSlash;
+	type MaxCallFilterEntries = ConstU32<8>;
+	type DefaultExecutionDelay = ConstU64<0>;
+	type MaxMultisigsPerOwner = ConstU32<32>;
+	type OnProposalExecuted = MockLifecycleHooks;
+	type OnMultisigCreated = MockLifecycleHooks;
+	type OnMultisigDestroyed = MockLifecycleHooks;
+	type OffchainSignature = sp_runtime::testing::TestSignature;
+	type SigningPublicKey = sp_runtime::testing::UintAuthorityId;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockBenchmarkHelper;
+	type CallBuilder = MockCallBuilder;
+	type MultisigId = u32;
+	type ProposalIndex = u64;
 	type WeightInfo = ();
 }
 
+/// Builds typed proposals out of the pallets available in the mock runtime.
+pub struct MockCallBuilder;
+impl pallet_multisig::TypedCallBuilder<Test> for MockCallBuilder {
+	fn build(
+		_sovereign: &u64,
+		proposal: &pallet_multisig::TypedProposalOf<Test>,
+	) -> Option<RuntimeCall> {
+		match proposal {
+			pallet_multisig::TypedProposal::Transfer { dest, amount } => Some(
+				pallet_balances::Call::transfer_keep_alive { dest: *dest, value: *amount }.into(),
+			),
+			// The mock runtime has no vesting pallet, so a plain transfer of the grant stands in.
+			pallet_multisig::TypedProposal::VestedTransfer { dest, locked, .. } => Some(
+				pallet_balances::Call::transfer_keep_alive { dest: *dest, value: *locked }.into(),
+			),
+			// The mock runtime has no contracts, EVM or session pallet, so their calls stand in as
+			// remarks.
+			pallet_multisig::TypedProposal::CallContract { data, .. } |
+			pallet_multisig::TypedProposal::EvmCall { input: data, .. } |
+			pallet_multisig::TypedProposal::SetSessionKeys { keys: data, .. } =>
+				Some(frame_system::Call::remark { remark: data.to_vec() }.into()),
+			// Nor has it a bounties pallet, so bounty calls stand in as remarks of the proposal,
+			// which record on execution who made them.
+			pallet_multisig::TypedProposal::AcceptCurator { .. } |
+			pallet_multisig::TypedProposal::AwardBounty { .. } |
+			pallet_multisig::TypedProposal::ClaimBounty { .. } =>
+				Some(frame_system::Call::remark_with_event { remark: proposal.encode() }.into()),
+			pallet_multisig::TypedProposal::InstantiateContract { .. } |
+			pallet_multisig::TypedProposal::TransferItem { .. } |
+			pallet_multisig::TypedProposal::TreasurySpend { .. } |
+			pallet_multisig::TypedProposal::TreasuryPayout { .. } |
+			pallet_multisig::TypedProposal::JoinPool { .. } |
+			pallet_multisig::TypedProposal::BondExtraToPool { .. } |
+			pallet_multisig::TypedProposal::ClaimPoolPayout |
+			pallet_multisig::TypedProposal::PurgeSessionKeys => None,
+		}
+	}
+}
+
+/// The operator session scopes of the mock runtime.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	MaxEncodedLen,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+)]
+pub enum MockSessionScope {
+	/// Any call.
+	Any,
+	/// Only `frame_system::Call::remark`.
+	Remarks,
+}
+
+impl InstanceFilter<RuntimeCall> for MockSessionScope {
+	fn filter(&self, call: &RuntimeCall) -> bool {
+		match self {
+			MockSessionScope::Any => true,
+			MockSessionScope::Remarks =>
+				matches!(call, RuntimeCall::System(frame_system::Call::remark { .. })),
+		}
+	}
+}
+
+thread_local! {
+	/// The unit the mock's deposits are multiples of; deposits are disabled while it is zero.
+	pub static DEPOSIT_UNIT: RefCell<u128> = const { RefCell::new(0) };
+}
+
+/// Deposits of one unit per owner on creation, one unit per pending proposal on submission,
+/// one unit per approval and one unit per byte of uploaded call data.
+pub struct MockDeposits;
+impl pallet_multisig::DepositCalculator<u128> for MockDeposits {
+	fn creation_deposit(owner_count: u32) -> u128 {
+		DEPOSIT_UNIT.with(|unit| *unit.borrow()) * owner_count as u128
+	}
+
+	fn proposal_deposit(_call_len: u32, open_proposals: u32) -> u128 {
+		DEPOSIT_UNIT.with(|unit| *unit.borrow()) * (1 + open_proposals as u128)
+	}
+
+	fn approval_deposit(_owner_count: u32) -> u128 {
+		DEPOSIT_UNIT.with(|unit| *unit.borrow())
+	}
+
+	fn call_data_deposit(len: u32) -> u128 {
+		DEPOSIT_UNIT.with(|unit| *unit.borrow()) * len as u128
+	}
+}
+
+thread_local! {
+	/// The share of an expired proposal's submission deposit that is forfeited.
+	pub static EXPIRED_DEPOSIT_SLASH: RefCell<Perbill> = const { RefCell::new(Perbill::zero()) };
+}
+
+/// Forfeits the `EXPIRED_DEPOSIT_SLASH` share of expired proposals' deposits.
+pub struct MockExpiredDepositSlash;
+impl Get<Perbill> for MockExpiredDepositSlash {
+	fn get() -> Perbill {
+		EXPIRED_DEPOSIT_SLASH.with(|slash| *slash.borrow())
+	}
+}
+
+/// A fee of one unit per picosecond of execution time.
+pub struct MockFeeEstimator;
+impl sp_runtime::traits::Convert<Weight, u128> for MockFeeEstimator {
+	fn convert(weight: Weight) -> u128 {
+		weight.ref_time().into()
+	}
+}
+
+thread_local! {
+	/// Whether the mock's vote tally requires every owner instead of the threshold.
+	pub static REQUIRE_UNANIMITY: RefCell<bool> = const { RefCell::new(false) };
+}
+
+/// A vote tally counting approvals, or requiring unanimity when `REQUIRE_UNANIMITY` is set.
+pub struct MockVoteTally;
+impl pallet_multisig::VoteTally<u64> for MockVoteTally {
+	fn has_passed(
+		owners: &[u64],
+		weights: &[u32],
+		threshold: u32,
+		approvals: &[u64],
+		rejections: &[u64],
+	) -> bool {
+		if REQUIRE_UNANIMITY.with(|required| *required.borrow()) {
+			owners.iter().all(|owner| approvals.contains(owner))
+		} else {
+			<pallet_multisig::CountApprovals as pallet_multisig::VoteTally<u64>>::has_passed(
+				owners, weights, threshold, approvals, rejections,
+			)
+		}
+	}
+}
+
+thread_local! {
+	/// The accounts the mock considers to be holding non-fungible items.
+	pub static ITEM_HOLDERS: RefCell<Vec<u64>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Item custody backed by `ITEM_HOLDERS`, as the mock runtime has no NFT pallet.
+pub struct MockItemCustody;
+impl pallet_multisig::ItemCustody<u64> for MockItemCustody {
+	fn holds_items(who: &u64) -> bool {
+		ITEM_HOLDERS.with(|holders| holders.borrow().contains(who))
+	}
+}
+
+thread_local! {
+	/// The attestor whose approval the mock policy requires, if any.
+	pub static POLICY_ATTESTOR: RefCell<Option<u64>> = const { RefCell::new(None) };
+}
+
+/// A policy requiring `POLICY_ATTESTOR`, when set, to have approved every proposal.
+pub struct MockPolicyCheck;
+impl pallet_multisig::PolicyCheck<Test> for MockPolicyCheck {
+	fn allows(_multisig_id: u32, _call: &RuntimeCall, approvals: &[u64]) -> bool {
+		POLICY_ATTESTOR.with(|attestor| match *attestor.borrow() {
+			Some(attestor) => approvals.contains(&attestor),
+			None => true,
+		})
+	}
+}
+
+/// The account the mock's compliance gate refuses to pay.
+pub const BLOCKED_RECIPIENT: u64 = 666;
+
+/// A recipient gate allowing every account except `BLOCKED_RECIPIENT`.
+pub struct MockRecipientGate;
+impl frame_support::traits::Contains<u64> for MockRecipientGate {
+	fn contains(who: &u64) -> bool {
+		*who != BLOCKED_RECIPIENT
+	}
+}
+
+/// Treats runtime upgrades as critical calls.
+pub struct MockCriticalCalls;
+impl frame_support::traits::Contains<RuntimeCall> for MockCriticalCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::System(frame_system::Call::set_code { .. }))
+	}
+}
+
+/// Classes balance transfers as `Transfer` and the multisig's owner changes as `Governance`.
+pub struct MockCallClassifier;
+impl pallet_multisig::CallClassifier<Test> for MockCallClassifier {
+	fn classify(call: &RuntimeCall) -> pallet_multisig::CallClass {
+		match call {
+			RuntimeCall::Balances(_) => pallet_multisig::CallClass::Transfer,
+			RuntimeCall::Multisig(
+				pallet_multisig::Call::add_owner { .. } |
+				pallet_multisig::Call::remove_owner { .. } |
+				pallet_multisig::Call::swap_owner { .. },
+			) => pallet_multisig::CallClass::Governance,
+			_ => pallet_multisig::CallClass::Other,
+		}
+	}
+}
+
+/// Signs benchmark payloads with the test key of account 1.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl
+	pallet_multisig::BenchmarkHelper<
+		sp_runtime::testing::UintAuthorityId,
+		sp_runtime::testing::TestSignature,
+	> for MockBenchmarkHelper
+{
+	fn sign_message(
+		message: &[u8],
+	) -> (sp_runtime::testing::UintAuthorityId, sp_runtime::testing::TestSignature) {
+		(1.into(), sp_runtime::testing::TestSignature(1, message.to_vec()))
+	}
+}
+
+/// The offset from an account to the account that is its proxy in the mock.
+pub const PROXY_OFFSET: u64 = 100;
+
+/// Makes `owner + PROXY_OFFSET` the proxy of every `owner`.
+pub struct MockOwnerProxies;
+impl pallet_multisig::OwnerProxies<u64> for MockOwnerProxies {
+	fn is_proxy(owner: &u64, proxy: &u64) -> bool {
+		*proxy == owner.saturating_add(PROXY_OFFSET)
+	}
+}
+
+thread_local! {
+	/// The lifecycle hook calls made so far, in order.
+	pub static LIFECYCLE_CALLS: RefCell<Vec<LifecycleCall>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A call made to one of the pallet's lifecycle hooks.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum LifecycleCall {
+	Created(u32, u64),
+	Executed(u32, u64, H256, DispatchResult),
+	Destroyed(u32),
+}
+
+/// Lifecycle hooks recording every call in `LIFECYCLE_CALLS`.
+pub struct MockLifecycleHooks;
+impl pallet_multisig::OnProposalExecuted<Test> for MockLifecycleHooks {
+	fn on_proposal_executed(
+		multisig_id: u32,
+		proposal_index: u64,
+		call_hash: H256,
+		result: &DispatchResult,
+	) {
+		LIFECYCLE_CALLS.with(|calls| {
+			calls.borrow_mut().push(LifecycleCall::Executed(
+				multisig_id,
+				proposal_index,
+				call_hash,
+				*result,
+			))
+		});
+	}
+}
+impl pallet_multisig::OnMultisigCreated<Test> for MockLifecycleHooks {
+	fn on_multisig_created(multisig_id: u32, account: &u64) {
+		LIFECYCLE_CALLS
+			.with(|calls| calls.borrow_mut().push(LifecycleCall::Created(multisig_id, *account)));
+	}
+}
+impl pallet_multisig::OnMultisigDestroyed<Test> for MockLifecycleHooks {
+	fn on_multisig_destroyed(multisig_id: u32) {
+		LIFECYCLE_CALLS
+			.with(|calls| calls.borrow_mut().push(LifecycleCall::Destroyed(multisig_id)));
+	}
+}
+
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
 }
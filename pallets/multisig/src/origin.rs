@@ -0,0 +1,94 @@
+//! Origin checks for calls that must come from a multisig.
+//!
+//! Other pallets in the runtime use these as their `EnsureOrigin` types to accept only calls
+//! dispatched by a multisig, without deriving and comparing account IDs themselves.
+
+use crate::{Config, Multisigs, Origin, Pallet};
+use core::marker::PhantomData;
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::{pallet_prelude::OriginFor, RawOrigin};
+
+/// Ensures that the origin is the sovereign account of the multisig with ID `Id`.
+///
+/// Succeeds with the sovereign account.
+pub struct EnsureMultisig<T, Id>(PhantomData<(T, Id)>);
+
+impl<T: Config, Id: Get<T::MultisigId>> EnsureOrigin<OriginFor<T>> for EnsureMultisig<T, Id> {
+	type Success = T::AccountId;
+
+	fn try_origin(o: OriginFor<T>) -> Result<Self::Success, OriginFor<T>> {
+		let multisig_id = Id::get();
+		o.into().and_then(|o| match o {
+			RawOrigin::Signed(who)
+				if <Multisigs<T>>::contains_key(multisig_id) &&
+					who == Pallet::<T>::multi_account_id(multisig_id) =>
+				Ok(who),
+			o => Err(o.into()),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<OriginFor<T>, ()> {
+		Ok(RawOrigin::Signed(Pallet::<T>::multi_account_id(Id::get())).into())
+	}
+}
+
+/// Ensures that the origin is the sovereign account of any existing multisig.
+///
+/// Succeeds with the ID of the multisig and its sovereign account.
+pub struct EnsureMultisigAccount<T>(PhantomData<T>);
+
+impl<T: Config> EnsureOrigin<OriginFor<T>> for EnsureMultisigAccount<T> {
+	type Success = (T::MultisigId, T::AccountId);
+
+	fn try_origin(o: OriginFor<T>) -> Result<Self::Success, OriginFor<T>> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Signed(who) => match Pallet::<T>::multisig_of_account(&who) {
+				Some(multisig_id) => Ok((multisig_id, who)),
+				None => Err(RawOrigin::Signed(who).into()),
+			},
+			o => Err(o.into()),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<OriginFor<T>, ()> {
+		// An origin only succeeds once a multisig exists, which benchmarks must set up.
+		Err(())
+	}
+}
+
+/// Ensures that the origin is `Origin::Multisig` of the multisig with ID `Id`, approved by at
+/// least `N` owners.
+///
+/// Only proposals submitted with `submit_proposal_with_multisig_origin` are dispatched with
+/// this origin. Succeeds with the number of approvals.
+pub struct EnsureMultisigApprovals<T, Id, N>(PhantomData<(T, Id, N)>);
+
+impl<O, T, Id, N> EnsureOrigin<O> for EnsureMultisigApprovals<T, Id, N>
+where
+	O: Into<Result<Origin<T>, O>> + From<Origin<T>>,
+	T: Config,
+	Id: Get<T::MultisigId>,
+	N: Get<u32>,
+{
+	type Success = u32;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			Origin::Multisig { multisig_id, approvals, .. }
+				if multisig_id == Id::get() && approvals >= N::get() =>
+				Ok(approvals),
+			o => Err(O::from(o)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(O::from(Origin::Multisig {
+			multisig_id: Id::get(),
+			approvals: N::get(),
+			threshold: N::get(),
+		}))
+	}
+}
@@ -0,0 +1,59 @@
+//! Runtime API for querying multisigs from outside the runtime.
+//!
+//! Off-chain clients such as executor bots call these through the node's `state_call` RPC
+//! rather than reassembling the answers from individual storage reads.
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+frame::runtime::apis::decl_runtime_apis! {
+	/// Queries over the state of the multisig pallet.
+	pub trait MultisigApi<AccountId, Balance, MultisigId, ProposalIndex, Hash>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		MultisigId: Codec,
+		ProposalIndex: Codec,
+		Hash: Codec,
+	{
+		/// Returns the sovereign account of `multisig_id`, or `None` if it does not exist.
+		fn account_of(multisig_id: MultisigId) -> Option<AccountId>;
+
+		/// Returns the index and call hash of every proposal of `multisig_id` that is neither
+		/// executed nor expired.
+		fn pending_proposals(multisig_id: MultisigId) -> Vec<(ProposalIndex, Hash)>;
+
+		/// Returns the index and call hash of every pending proposal of `multisig_id` whose
+		/// approvals already satisfy the multisig, ready to be executed.
+		fn executable_proposals(multisig_id: MultisigId) -> Vec<(ProposalIndex, Hash)>;
+
+		/// Returns the human-readable label of every owner of `multisig_id` that has one.
+		fn owner_labels(multisig_id: MultisigId) -> Vec<(AccountId, Vec<u8>)>;
+
+		/// Returns the SCALE-encoded `WalletSnapshot` of `multisig_id`, or `None` if it does
+		/// not exist.
+		///
+		/// The snapshot starts with its layout version, so auditors can keep decoding exports
+		/// taken under older runtimes.
+		fn export_state(multisig_id: MultisigId) -> Option<Vec<u8>>;
+
+		/// Returns the exact bytes an air-gapped signer signs to confirm the pending proposal
+		/// `proposal_index` of `multisig_id`, or `None` if there is no such proposal.
+		///
+		/// The payload opens with `SIGNING_DOMAIN` and commits to the chain's genesis hash and
+		/// the multisig's signing nonce. Any account can then relay the signature with
+		/// `confirm_proposal_with_signature`, until the nonce is bumped.
+		fn confirmation_payload(
+			multisig_id: MultisigId,
+			proposal_index: ProposalIndex,
+		) -> Option<Vec<u8>>;
+
+		/// Returns the operations the owners of `multisig_id` have made and the weight and fees
+		/// they consumed, or `None` if it does not exist.
+		fn usage_meter(multisig_id: MultisigId) -> Option<crate::UsageMeter<Balance>>;
+
+		/// Returns the multisig and index of every pending proposal of `call_hash`, so a relayer
+		/// that only knows the hash can find the proposal to execute.
+		fn proposals_by_call_hash(call_hash: Hash) -> Vec<(MultisigId, ProposalIndex)>;
+	}
+}
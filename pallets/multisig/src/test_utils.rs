@@ -0,0 +1,99 @@
+//! Builders for setting up multisigs in tests.
+//!
+//! These are enabled by the `test-utils` feature so that downstream runtimes can set up
+//! wallets and proposals in their own integration tests instead of re-implementing this
+//! crate's private test helpers. Every builder dispatches the pallet's real extrinsics, must be
+//! run inside externalities and panics if any step fails.
+
+use crate::{Config, Pallet};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+/// A multisig created by [`MultisigBuilder`].
+pub struct BuiltMultisig<T: Config> {
+	/// The ID of the multisig.
+	pub id: T::MultisigId,
+	/// The sovereign account of the multisig.
+	pub account: T::AccountId,
+	/// The index and call of the proposal submitted with
+	/// [`MultisigBuilder::with_ready_proposal`], if any.
+	pub ready_proposal: Option<(T::ProposalIndex, <T as Config>::RuntimeCall)>,
+}
+
+/// Builds a multisig, optionally with a proposal that has met its threshold.
+///
+/// ```ignore
+/// let built = MultisigBuilder::<Runtime>::new()
+///     .owners(vec![alice, bob, charlie])
+///     .threshold(2)
+///     .with_ready_proposal(call)
+///     .build();
+/// ```
+pub struct MultisigBuilder<T: Config> {
+	owners: Vec<T::AccountId>,
+	threshold: Option<u32>,
+	ready_proposal: Option<<T as Config>::RuntimeCall>,
+}
+
+impl<T: Config> Default for MultisigBuilder<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config> MultisigBuilder<T> {
+	/// Starts a builder with no owners.
+	pub fn new() -> Self {
+		Self { owners: Vec::new(), threshold: None, ready_proposal: None }
+	}
+
+	/// Sets the owners. The first owner creates the multisig and submits any proposal.
+	pub fn owners(mut self, owners: Vec<T::AccountId>) -> Self {
+		self.owners = owners;
+		self
+	}
+
+	/// Sets the approval threshold, which defaults to the number of owners.
+	pub fn threshold(mut self, threshold: u32) -> Self {
+		self.threshold = Some(threshold);
+		self
+	}
+
+	/// Submits `call` as a proposal and confirms it by owners, in order, until the threshold
+	/// is met, so it is ready to be executed.
+	pub fn with_ready_proposal(mut self, call: <T as Config>::RuntimeCall) -> Self {
+		self.ready_proposal = Some(call);
+		self
+	}
+
+	/// Creates the multisig and any proposal.
+	pub fn build(self) -> BuiltMultisig<T> {
+		let creator = self.owners.first().expect("a multisig needs owners").clone();
+		let threshold = self.threshold.unwrap_or(self.owners.len() as u32);
+		let id = Pallet::<T>::next_multisig_id();
+		Pallet::<T>::create_multisig(
+			RawOrigin::Signed(creator.clone()).into(),
+			self.owners.clone(),
+			threshold,
+			None,
+		)
+		.expect("creating the multisig failed");
+
+		let ready_proposal = self.ready_proposal.map(|call| {
+			let index = Pallet::<T>::next_proposal_index(id);
+			Pallet::<T>::submit_proposal(
+				RawOrigin::Signed(creator).into(),
+				id,
+				Box::new(call.clone()),
+			)
+			.expect("submitting the proposal failed");
+			for owner in self.owners.iter().skip(1).take(threshold.saturating_sub(1) as usize) {
+				Pallet::<T>::confirm_proposal(RawOrigin::Signed(owner.clone()).into(), id, index)
+					.expect("confirming the proposal failed");
+			}
+			(index, call)
+		});
+
+		BuiltMultisig { id, account: Pallet::<T>::multi_account_id(id), ready_proposal }
+	}
+}
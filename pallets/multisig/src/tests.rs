@@ -5,12 +5,13 @@
 // 2. Act: Dispatch the extrinsic being tested.
 // 3. Assert: Verify that the state has changed as expected and the correct events were emitted.
 
-use crate::{mock::*, Error, Event, Proposals};
-use codec::Encode;
+use crate::{mock::*, Error, Event, ProposalStatus, Proposals};
 use frame_support::{
-	assert_noop, assert_ok, dispatch::DispatchResult, traits::Currency, BoundedVec,
+	assert_noop, assert_ok, dispatch::DispatchResult, traits::Currency, weights::Weight, BoundedVec,
 };
+use sp_core::H256;
 use sp_io::hashing::blake2_256;
+use sp_runtime::traits::{BlakeTwo256, Hash};
 
 // --- TESTS FOR create_multisig ---
 /// Tests for the `create_multisig` extrinsic.
@@ -31,7 +32,8 @@ mod create_multisig {
 			assert_ok!(Multisig::create_multisig(
 				RuntimeOrigin::signed(creator),
 				owners.clone(),
-				threshold
+				threshold,
+				None
 			));
 
 			// Assert: Verify the final state is correct.
@@ -44,8 +46,10 @@ mod create_multisig {
 			assert_eq!(Multisig::next_multisig_id(), 1);
 			// Check that the correct event was emitted.
 			let multisig_account = Multisig::multi_account_id(multisig_id);
+			let config_hash = Multisig::config_commitment(&multisig, None, None, &[], &[]);
 			System::assert_last_event(
-				Event::MultisigCreated { creator, multisig_id, multisig_account }.into(),
+				Event::MultisigCreated { creator, multisig_id, multisig_account, config_hash }
+					.into(),
 			);
 		});
 	}
@@ -56,7 +60,7 @@ mod create_multisig {
 		new_test_ext().execute_with(|| {
 			// Act & Assert: Ensure the extrinsic fails with the correct error.
 			assert_noop!(
-				Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 0),
+				Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 0, None),
 				Error::<Test>::InvalidThreshold
 			);
 		});
@@ -68,7 +72,7 @@ mod create_multisig {
 		new_test_ext().execute_with(|| {
 			// Act & Assert: Ensure the extrinsic fails with the correct error.
 			assert_noop!(
-				Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 4),
+				Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 4, None),
 				Error::<Test>::InvalidThreshold
 			);
 		});
@@ -83,7 +87,7 @@ mod create_multisig {
 
 			// Act & Assert: Ensure the extrinsic fails with the correct error.
 			assert_noop!(
-				Multisig::create_multisig(RuntimeOrigin::signed(1), owners, 2),
+				Multisig::create_multisig(RuntimeOrigin::signed(1), owners, 2, None),
 				Error::<Test>::TooManyOwners
 			);
 		});
@@ -98,7 +102,7 @@ mod submit_proposal {
 	fn create_test_multisig() -> u32 {
 		let owners = vec![1, 2, 3];
 		let threshold = 2;
-		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), owners, threshold));
+		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), owners, threshold, None));
 		0 // Returns the ID of the created multisig.
 	}
 
@@ -111,7 +115,7 @@ mod submit_proposal {
 			let multisig_id = create_test_multisig();
 			let proposer = 1; // An owner of the multisig.
 			let call: RuntimeCall = frame_system::Call::remark { remark: vec![0; 10] }.into();
-			let call_hash = blake2_256(&call.encode());
+			let call_hash = BlakeTwo256::hash_of(&call);
 
 			// Act: Dispatch the extrinsic.
 			assert_ok!(Multisig::submit_proposal(
@@ -125,7 +129,8 @@ mod submit_proposal {
 			// Check that the proposal was stored with the correct hash and is not executed.
 			let proposal = Multisig::proposals(multisig_id, proposal_index).unwrap();
 			assert_eq!(proposal.call_hash, call_hash);
-			assert!(!proposal.executed);
+			assert_eq!(proposal.status, ProposalStatus::Pending);
+			assert_eq!(proposal.proposer, proposer);
 			// Check that the proposer's approval was automatically recorded.
 			let expected_approvals: BoundedVec<u64, <Test as crate::Config>::MaxOwners> =
 				vec![proposer].try_into().unwrap();
@@ -134,7 +139,13 @@ mod submit_proposal {
 			assert_eq!(Multisig::next_proposal_index(multisig_id), 1);
 			// Check that the correct event was emitted.
 			System::assert_last_event(
-				Event::ProposalSubmitted { multisig_id, proposal_index, call_hash }.into(),
+				Event::ProposalSubmitted {
+					multisig_id,
+					proposal_index,
+					call_hash,
+					content_ref: None,
+				}
+				.into(),
 			);
 		});
 	}
@@ -182,14 +193,19 @@ mod confirm_proposal {
 	use super::*;
 
 	/// A helper function to create a multisig with a pending proposal.
-	fn setup_multisig_with_proposal() -> (u32, u32) {
+	fn setup_multisig_with_proposal() -> (u32, u64) {
 		let owners = vec![1, 2, 3];
 		let threshold = 2;
 		let proposer = 1;
 		let call: RuntimeCall = frame_system::Call::remark { remark: vec![0; 10] }.into();
 
 		// Create the multisig.
-		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(proposer), owners, threshold));
+		assert_ok!(Multisig::create_multisig(
+			RuntimeOrigin::signed(proposer),
+			owners,
+			threshold,
+			None
+		));
 		let multisig_id = 0;
 
 		// Submit the proposal.
@@ -277,7 +293,7 @@ mod confirm_proposal {
 			// Arrange: Set up a proposal and then manually mark it as executed.
 			let (multisig_id, proposal_index) = setup_multisig_with_proposal();
 			let mut proposal = Multisig::proposals(multisig_id, proposal_index).unwrap();
-			proposal.executed = true;
+			proposal.status = ProposalStatus::Executed;
 			Proposals::<Test>::insert(multisig_id, proposal_index, proposal);
 
 			// Act & Assert: Another owner tries to confirm the now-executed proposal.
@@ -292,10 +308,11 @@ mod confirm_proposal {
 /// Tests for the `execute_proposal` extrinsic.
 mod execute_proposal {
 	use super::*;
+	use frame_support::dispatch::GetDispatchInfo;
 
 	/// A helper function to set up a proposal that has met its threshold and is ready to be
 	/// executed.
-	fn setup_ready_to_execute_proposal() -> (u32, u32, RuntimeCall) {
+	fn setup_ready_to_execute_proposal() -> (u32, u64, RuntimeCall) {
 		let owners = vec![1, 2, 3];
 		let threshold = 2;
 		let proposer = 1;
@@ -303,7 +320,12 @@ mod execute_proposal {
 		let call: RuntimeCall = frame_system::Call::remark_with_event { remark: vec![42] }.into();
 
 		// Create the multisig.
-		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(proposer), owners, threshold));
+		assert_ok!(Multisig::create_multisig(
+			RuntimeOrigin::signed(proposer),
+			owners,
+			threshold,
+			None
+		));
 		let multisig_id = 0;
 
 		// Submit the proposal.
@@ -338,22 +360,25 @@ mod execute_proposal {
 				RuntimeOrigin::signed(executor),
 				multisig_id,
 				proposal_index,
-				Box::new(call.clone())
+				Box::new(call.clone()),
+				Weight::MAX
 			));
 
 			// Assert
 			// Verify the proposal is now marked as executed in storage.
 			let proposal = Multisig::proposals(multisig_id, proposal_index).unwrap();
-			assert!(proposal.executed);
+			assert_eq!(proposal.status, ProposalStatus::Executed);
+			// Verify the approvals were pruned, as they are no longer needed on-chain.
+			assert!(proposal.approvals.is_empty());
 			// Verify the `ProposalExecuted` event was emitted with a successful result.
-			let result: DispatchResult = Ok(().into());
+			let result: DispatchResult = Ok(());
 			System::assert_last_event(
 				Event::ProposalExecuted { multisig_id, proposal_index, result }.into(),
 			);
 			// Verify that the inner call (`remark_with_event`) was actually dispatched by checking
 			// for its specific event.
 			let multisig_account = Multisig::multi_account_id(multisig_id);
-			let remark_hash = blake2_256(&vec![42]);
+			let remark_hash = blake2_256(&[42]);
 			System::assert_has_event(
 				frame_system::Event::Remarked {
 					sender: multisig_account,
@@ -372,7 +397,12 @@ mod execute_proposal {
 			// needs 2.
 			let owners = vec![1, 2, 3];
 			let threshold = 2;
-			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), owners, threshold));
+			assert_ok!(Multisig::create_multisig(
+				RuntimeOrigin::signed(1),
+				owners,
+				threshold,
+				None
+			));
 			let multisig_id = 0;
 			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
 			assert_ok!(Multisig::submit_proposal(
@@ -388,7 +418,8 @@ mod execute_proposal {
 					RuntimeOrigin::signed(1),
 					multisig_id,
 					proposal_index,
-					Box::new(call)
+					Box::new(call),
+					Weight::MAX
 				),
 				Error::<Test>::NotEnoughApprovals
 			);
@@ -412,12 +443,44 @@ mod execute_proposal {
 					RuntimeOrigin::signed(1),
 					multisig_id,
 					proposal_index,
-					Box::new(different_call)
+					Box::new(different_call),
+					Weight::MAX
 				),
 				Error::<Test>::CallHashMismatch
 			);
 		});
 	}
+
+	/// Tests that the extrinsic fails if `max_weight` is below the weight of the call, and
+	/// succeeds once it covers it exactly.
+	#[test]
+	fn fails_if_max_weight_is_too_low() {
+		new_test_ext().execute_with(|| {
+			// Arrange: Set up a ready-to-execute proposal and weigh its call.
+			let (multisig_id, proposal_index, call) = setup_ready_to_execute_proposal();
+			let call_weight = call.get_dispatch_info().call_weight;
+			assert!(call_weight.ref_time() > 0);
+
+			// Act & Assert: One unit of weight short of the call is rejected.
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					multisig_id,
+					proposal_index,
+					Box::new(call.clone()),
+					call_weight.saturating_sub(Weight::from_parts(1, 0))
+				),
+				Error::<Test>::MaxWeightTooLow
+			);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				multisig_id,
+				proposal_index,
+				Box::new(call),
+				call_weight
+			));
+		});
+	}
 }
 
 /// Tests for the `destroy_multisig` extrinsic.
@@ -433,7 +496,12 @@ mod destroy_multisig {
 			// Create a multisig.
 			let owners = vec![1, 2, 3];
 			let threshold = 2;
-			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), owners, threshold));
+			assert_ok!(Multisig::create_multisig(
+				RuntimeOrigin::signed(1),
+				owners,
+				threshold,
+				None
+			));
 			let multisig_id = 0;
 
 			// Act: The owners must propose, confirm, and execute the destruction of their own
@@ -458,7 +526,8 @@ mod destroy_multisig {
 				RuntimeOrigin::signed(3), // Can be anyone
 				multisig_id,
 				proposal_index,
-				Box::new(destroy_call)
+				Box::new(destroy_call),
+				Weight::MAX
 			));
 
 			// Assert: Verify that all storage related to the multisig has been cleaned up.
@@ -479,7 +548,12 @@ mod destroy_multisig {
 			// Arrange: Create a multisig.
 			let owners = vec![1, 2, 3];
 			let threshold = 2;
-			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), owners, threshold));
+			assert_ok!(Multisig::create_multisig(
+				RuntimeOrigin::signed(1),
+				owners,
+				threshold,
+				None
+			));
 			let multisig_id = 0;
 
 			// Act & Assert: A regular user (even an owner) tries to call destroy_multisig directly.
@@ -498,7 +572,12 @@ mod destroy_multisig {
 			System::set_block_number(1);
 			let owners = vec![1, 2, 3];
 			let threshold = 2;
-			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), owners, threshold));
+			assert_ok!(Multisig::create_multisig(
+				RuntimeOrigin::signed(1),
+				owners,
+				threshold,
+				None
+			));
 			let multisig_id = 0;
 			let multisig_account = Multisig::multi_account_id(multisig_id);
 			// Fund the multisig account so it has a non-zero balance.
@@ -521,7 +600,8 @@ mod destroy_multisig {
 				RuntimeOrigin::signed(3),
 				multisig_id,
 				proposal_index,
-				Box::new(destroy_call)
+				Box::new(destroy_call),
+				Weight::MAX
 			));
 
 			// The outer `execute_proposal` succeeds, but the inner `destroy_multisig` fails.
@@ -534,4 +614,6804 @@ mod destroy_multisig {
 			assert!(Multisig::multisigs(multisig_id).is_some());
 		});
 	}
+
+	/// Tests that destruction is blocked if the multisig's sovereign account still holds
+	/// non-fungible items.
+	#[test]
+	fn fails_if_items_are_held() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let multisig_account = Multisig::multi_account_id(0);
+			ITEM_HOLDERS.with(|holders| holders.borrow_mut().push(multisig_account));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::destroy_multisig(RuntimeOrigin::signed(multisig_account), 0),
+				Error::<Test>::ItemsHeld
+			);
+		});
+	}
+}
+
+/// Tests for the `pre_approve` and `cancel_pre_approval` extrinsics.
+mod pre_approve {
+	use super::*;
+
+	/// Tests that a pre-approval is counted automatically when a matching proposal is submitted.
+	#[test]
+	fn pre_approval_is_counted_on_submission() {
+		new_test_ext().execute_with(|| {
+			// Arrange: Owner 2 pre-approves a call before it has been proposed.
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let multisig_id = 0;
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![7] }.into();
+			let call_hash = BlakeTwo256::hash_of(&call);
+			assert_ok!(Multisig::pre_approve(RuntimeOrigin::signed(2), multisig_id, call_hash));
+			System::assert_last_event(Event::PreApproved { who: 2, multisig_id, call_hash }.into());
+
+			// Act: Owner 1 submits the matching proposal.
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				multisig_id,
+				Box::new(call.clone())
+			));
+
+			// Assert: Both the proposer and the pre-approver are counted, and the pre-approval
+			// has been consumed, so the proposal can be executed immediately.
+			let expected_approvals: BoundedVec<u64, <Test as crate::Config>::MaxOwners> =
+				vec![1, 2].try_into().unwrap();
+			assert_eq!(Multisig::approvals(multisig_id, 0), expected_approvals);
+			assert!(Multisig::pre_approvals(multisig_id, call_hash).is_empty());
+			System::assert_last_event(
+				Event::Confirmation { who: 2, multisig_id, proposal_index: 0 }.into(),
+			);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(4),
+				multisig_id,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_eq!(
+				Multisig::proposals(multisig_id, 0).unwrap().status,
+				ProposalStatus::Executed
+			);
+		});
+	}
+
+	/// Tests that only owners can pre-approve and that an owner cannot pre-approve twice.
+	#[test]
+	fn fails_for_non_owner_or_duplicate() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call_hash = H256::repeat_byte(1);
+
+			assert_noop!(
+				Multisig::pre_approve(RuntimeOrigin::signed(4), 0, call_hash),
+				Error::<Test>::NotAnOwner
+			);
+			assert_ok!(Multisig::pre_approve(RuntimeOrigin::signed(2), 0, call_hash));
+			assert_noop!(
+				Multisig::pre_approve(RuntimeOrigin::signed(2), 0, call_hash),
+				Error::<Test>::AlreadyPreApproved
+			);
+		});
+	}
+
+	/// Tests that a cancelled pre-approval is no longer counted.
+	#[test]
+	fn cancelled_pre_approval_is_not_counted() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![7] }.into();
+			let call_hash = BlakeTwo256::hash_of(&call);
+			assert_ok!(Multisig::pre_approve(RuntimeOrigin::signed(2), 0, call_hash));
+
+			assert_ok!(Multisig::cancel_pre_approval(RuntimeOrigin::signed(2), 0, call_hash));
+			assert_noop!(
+				Multisig::cancel_pre_approval(RuntimeOrigin::signed(2), 0, call_hash),
+				Error::<Test>::PreApprovalNotFound
+			);
+
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			let expected_approvals: BoundedVec<u64, <Test as crate::Config>::MaxOwners> =
+				vec![1].try_into().unwrap();
+			assert_eq!(Multisig::approvals(0, 0), expected_approvals);
+		});
+	}
+
+	/// Tests that a multisig holds pre-approvals for at most `MaxPendingProposals` call hashes,
+	/// and that cancelling or consuming one frees its slot.
+	#[test]
+	fn pre_approvals_are_capped_per_multisig() {
+		use frame_support::traits::Get;
+
+		new_test_ext().execute_with(|| {
+			// Arrange: Owner 2 pre-approves as many call hashes as the multisig may hold.
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let max: u32 = <Test as crate::Config>::MaxPendingProposals::get();
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![7] }.into();
+			let call_hash = BlakeTwo256::hash_of(&call);
+			assert_ok!(Multisig::pre_approve(RuntimeOrigin::signed(2), 0, call_hash));
+			for byte in 1..max {
+				assert_ok!(Multisig::pre_approve(
+					RuntimeOrigin::signed(2),
+					0,
+					H256::repeat_byte(byte as u8)
+				));
+			}
+			assert_eq!(Multisig::pre_approval_count(0), max);
+
+			// Act & Assert: A new call hash is refused, while another owner can still join an
+			// existing one.
+			let extra = H256::repeat_byte(0xff);
+			assert_noop!(
+				Multisig::pre_approve(RuntimeOrigin::signed(3), 0, extra),
+				Error::<Test>::TooManyPreApprovals
+			);
+			assert_ok!(Multisig::pre_approve(RuntimeOrigin::signed(3), 0, H256::repeat_byte(1)));
+			assert_eq!(Multisig::pre_approval_count(0), max);
+
+			// Cancelling the last pre-approval of a call hash frees its slot.
+			assert_ok!(Multisig::cancel_pre_approval(
+				RuntimeOrigin::signed(2),
+				0,
+				H256::repeat_byte(2)
+			));
+			assert_ok!(Multisig::pre_approve(RuntimeOrigin::signed(3), 0, extra));
+			assert_noop!(
+				Multisig::pre_approve(RuntimeOrigin::signed(3), 0, H256::repeat_byte(0xfe)),
+				Error::<Test>::TooManyPreApprovals
+			);
+
+			// Consuming a pre-approval on submission frees its slot too.
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			assert_eq!(Multisig::pre_approval_count(0), max - 1);
+			assert_ok!(Multisig::pre_approve(RuntimeOrigin::signed(3), 0, H256::repeat_byte(0xfe)));
+		});
+	}
+}
+
+/// Tests for standing approvals and the `submit_typed_proposal` extrinsic.
+mod standing_approvals {
+	use super::*;
+	use crate::{StandingApproval, TypedProposal};
+
+	/// Tests that a matching standing approval is counted for a typed transfer proposal, so the
+	/// transfer can be executed straight away.
+	#[test]
+	fn matching_standing_approval_is_counted() {
+		new_test_ext().execute_with(|| {
+			// Arrange: Owner 2 approves transfers of up to 50 to account 7 until block 10.
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let multisig_id = 0;
+			let multisig_account = Multisig::multi_account_id(multisig_id);
+			let _ = Balances::deposit_creating(&multisig_account, 100);
+			assert_ok!(Multisig::grant_standing_approval(
+				RuntimeOrigin::signed(2),
+				multisig_id,
+				7,
+				50,
+				10
+			));
+			System::assert_last_event(
+				Event::StandingApprovalGranted {
+					who: 2,
+					multisig_id,
+					approval: StandingApproval { dest: 7, max_amount: 50, expires_at: 10 },
+				}
+				.into(),
+			);
+
+			// Act: Owner 1 proposes a typed transfer covered by the standing approval.
+			let proposal = TypedProposal::Transfer { dest: 7, amount: 40 };
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				multisig_id,
+				proposal
+			));
+
+			// Assert: Owner 2's approval was counted and the transfer can be executed.
+			let expected_approvals: BoundedVec<u64, <Test as crate::Config>::MaxOwners> =
+				vec![1, 2].try_into().unwrap();
+			assert_eq!(Multisig::approvals(multisig_id, 0), expected_approvals);
+			let call: RuntimeCall =
+				pallet_balances::Call::transfer_keep_alive { dest: 7, value: 40 }.into();
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(4),
+				multisig_id,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_eq!(Balances::free_balance(7), 40);
+		});
+	}
+
+	/// Tests that standing approvals are ignored for proposals outside their pattern.
+	#[test]
+	fn non_matching_standing_approval_is_ignored() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let _ = Balances::deposit_creating(&Multisig::multi_account_id(0), 100);
+			assert_ok!(Multisig::grant_standing_approval(RuntimeOrigin::signed(2), 0, 7, 50, 10));
+
+			// Too large an amount, the wrong recipient and an expired approval are not counted.
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				TypedProposal::Transfer { dest: 7, amount: 51 }
+			));
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				TypedProposal::Transfer { dest: 8, amount: 10 }
+			));
+			System::set_block_number(11);
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				TypedProposal::Transfer { dest: 7, amount: 10 }
+			));
+
+			let expected_approvals: BoundedVec<u64, <Test as crate::Config>::MaxOwners> =
+				vec![1].try_into().unwrap();
+			for proposal_index in 0..3 {
+				assert_eq!(Multisig::approvals(0, proposal_index), expected_approvals);
+			}
+		});
+	}
+
+	/// Tests that a revoked standing approval is no longer counted.
+	#[test]
+	fn revoked_standing_approval_is_not_counted() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let _ = Balances::deposit_creating(&Multisig::multi_account_id(0), 100);
+			assert_ok!(Multisig::grant_standing_approval(RuntimeOrigin::signed(2), 0, 7, 50, 10));
+
+			assert_ok!(Multisig::revoke_standing_approval(RuntimeOrigin::signed(2), 0, 0));
+			assert!(Multisig::standing_approvals(0, 2).is_empty());
+			assert_noop!(
+				Multisig::revoke_standing_approval(RuntimeOrigin::signed(2), 0, 0),
+				Error::<Test>::StandingApprovalNotFound
+			);
+
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				TypedProposal::Transfer { dest: 7, amount: 10 }
+			));
+			assert_eq!(Multisig::approvals(0, 0).len(), 1);
+		});
+	}
+
+	/// Tests that only owners can grant standing approvals, and never already-expired ones.
+	#[test]
+	fn grant_fails_for_non_owner_or_expired() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(5);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+
+			assert_noop!(
+				Multisig::grant_standing_approval(RuntimeOrigin::signed(4), 0, 7, 50, 10),
+				Error::<Test>::NotAnOwner
+			);
+			assert_noop!(
+				Multisig::grant_standing_approval(RuntimeOrigin::signed(2), 0, 7, 50, 4),
+				Error::<Test>::StandingApprovalExpired
+			);
+		});
+	}
+}
+
+/// Tests for the `set_required_approvers` extrinsic.
+mod required_approvers {
+	use super::*;
+
+	/// Tests that a proposal meeting the threshold cannot execute without a mandatory approver.
+	#[test]
+	fn execution_requires_mandatory_approver() {
+		new_test_ext().execute_with(|| {
+			// Arrange: Owner 3 is made a mandatory approver of a 2-of-3 multisig.
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let multisig_account = Multisig::multi_account_id(0);
+			assert_ok!(Multisig::set_required_approvers(
+				RuntimeOrigin::signed(multisig_account),
+				0,
+				vec![3]
+			));
+			System::assert_last_event(
+				Event::RequiredApproversSet { multisig_id: 0, approvers: vec![3] }.into(),
+			);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act & Assert: The threshold is met, but owner 3 has not signed yet.
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call.clone()),
+					Weight::MAX
+				),
+				Error::<Test>::MissingRequiredApproval
+			);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+		});
+	}
+
+	/// Tests that only the sovereign account can designate mandatory approvers, and only owners.
+	#[test]
+	fn fails_for_wrong_origin_or_non_owner() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let multisig_account = Multisig::multi_account_id(0);
+
+			assert_noop!(
+				Multisig::set_required_approvers(RuntimeOrigin::signed(1), 0, vec![3]),
+				Error::<Test>::MustBeMultisig
+			);
+			assert_noop!(
+				Multisig::set_required_approvers(
+					RuntimeOrigin::signed(multisig_account),
+					0,
+					vec![4]
+				),
+				Error::<Test>::NotAnOwner
+			);
+			assert_noop!(
+				Multisig::set_required_approvers(
+					RuntimeOrigin::signed(multisig_account),
+					0,
+					vec![3, 3]
+				),
+				Error::<Test>::DuplicateAccount
+			);
+		});
+	}
+}
+
+/// Tests for the `set_approval_order` extrinsic.
+mod approval_order {
+	use super::*;
+
+	/// Tests that confirmations must follow the configured sign-off chain.
+	#[test]
+	fn confirmations_must_follow_the_chain() {
+		new_test_ext().execute_with(|| {
+			// Arrange: A 3-of-3 multisig whose owners must sign off as 1, then 2, then 3.
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 3, None));
+			let multisig_account = Multisig::multi_account_id(0);
+			assert_ok!(Multisig::set_approval_order(
+				RuntimeOrigin::signed(multisig_account),
+				0,
+				vec![1, 2, 3]
+			));
+			System::assert_last_event(
+				Event::ApprovalOrderSet { multisig_id: 0, order: vec![1, 2, 3] }.into(),
+			);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Act & Assert: Owner 3 cannot sign before owner 2, but can afterwards.
+			assert_noop!(
+				Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0),
+				Error::<Test>::OutOfOrderApproval
+			);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+		});
+	}
+
+	/// Tests that an owner later in the chain cannot submit, since submitting counts as
+	/// approving.
+	#[test]
+	fn submission_must_follow_the_chain() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let multisig_account = Multisig::multi_account_id(0);
+			assert_ok!(Multisig::set_approval_order(
+				RuntimeOrigin::signed(multisig_account),
+				0,
+				vec![1, 2]
+			));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			assert_noop!(
+				Multisig::submit_proposal(RuntimeOrigin::signed(2), 0, Box::new(call.clone())),
+				Error::<Test>::OutOfOrderApproval
+			);
+			// Owner 3 is outside the chain and may propose freely.
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(3), 0, Box::new(call)));
+		});
+	}
+
+	/// Tests that pre-approvals are counted in chain order at submission.
+	#[test]
+	fn pre_approvals_are_counted_in_chain_order() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(
+				RuntimeOrigin::signed(1),
+				vec![1, 2, 3, 4],
+				4,
+				None
+			));
+			let multisig_account = Multisig::multi_account_id(0);
+			assert_ok!(Multisig::set_approval_order(
+				RuntimeOrigin::signed(multisig_account),
+				0,
+				vec![1, 2, 3]
+			));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			let call_hash = BlakeTwo256::hash_of(&call);
+			assert_ok!(Multisig::pre_approve(RuntimeOrigin::signed(3), 0, call_hash));
+			assert_ok!(Multisig::pre_approve(RuntimeOrigin::signed(2), 0, call_hash));
+
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			let expected_approvals: BoundedVec<u64, <Test as crate::Config>::MaxOwners> =
+				vec![1, 2, 3].try_into().unwrap();
+			assert_eq!(Multisig::approvals(0, 0), expected_approvals);
+		});
+	}
+
+	/// Tests that a pre-approval that is out of turn at submission is kept, and counted once
+	/// the owner ahead of it confirms.
+	#[test]
+	fn out_of_order_pre_approval_is_counted_when_its_turn_comes() {
+		new_test_ext().execute_with(|| {
+			// Arrange: Owner 3 pre-approves a call, but owner 2 must sign off before them.
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(
+				RuntimeOrigin::signed(1),
+				vec![1, 2, 3, 4],
+				4,
+				None
+			));
+			let multisig_account = Multisig::multi_account_id(0);
+			assert_ok!(Multisig::set_approval_order(
+				RuntimeOrigin::signed(multisig_account),
+				0,
+				vec![1, 2, 3]
+			));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			let call_hash = BlakeTwo256::hash_of(&call);
+			assert_ok!(Multisig::pre_approve(RuntimeOrigin::signed(3), 0, call_hash));
+
+			// Act: Owner 1 submits the proposal while owner 3 is still out of turn.
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Assert: The pre-approval is not counted yet, but it is kept.
+			assert_eq!(Multisig::approvals(0, 0).to_vec(), vec![1]);
+			assert_eq!(Multisig::pre_approvals(0, call_hash).to_vec(), vec![3]);
+			assert_eq!(Multisig::pre_approval_count(0), 1);
+
+			// Act: Owner 2 confirms, which brings owner 3 into turn.
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Assert: Owner 3's pre-approval is counted and consumed.
+			assert_eq!(Multisig::approvals(0, 0).to_vec(), vec![1, 2, 3]);
+			System::assert_has_event(
+				Event::Confirmation { who: 2, multisig_id: 0, proposal_index: 0 }.into(),
+			);
+			System::assert_last_event(
+				Event::Confirmation { who: 3, multisig_id: 0, proposal_index: 0 }.into(),
+			);
+			assert!(Multisig::pre_approvals(0, call_hash).is_empty());
+			assert_eq!(Multisig::pre_approval_count(0), 0);
+		});
+	}
+}
+
+/// Tests for the `set_group_quorum` extrinsic.
+mod group_quorum {
+	use super::*;
+	use crate::{GroupRule, OwnerGroup};
+
+	fn group(name: &[u8], members: Vec<u64>, threshold: u32) -> crate::OwnerGroupOf<Test> {
+		OwnerGroup {
+			name: name.to_vec().try_into().unwrap(),
+			members: members.try_into().unwrap(),
+			threshold,
+		}
+	}
+
+	/// Tests that all groups must be satisfied under the `All` rule.
+	#[test]
+	fn all_groups_must_be_met() {
+		new_test_ext().execute_with(|| {
+			// Arrange: 2-of-Engineering {1, 2, 3} AND 1-of-Legal {4, 5}, overall 2-of-5.
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(
+				RuntimeOrigin::signed(1),
+				vec![1, 2, 3, 4, 5],
+				2,
+				None
+			));
+			let multisig_account = Multisig::multi_account_id(0);
+			assert_ok!(Multisig::set_group_quorum(
+				RuntimeOrigin::signed(multisig_account),
+				0,
+				GroupRule::All,
+				vec![group(b"Engineering", vec![1, 2, 3], 2), group(b"Legal", vec![4, 5], 1)]
+			));
+			System::assert_last_event(
+				Event::GroupQuorumSet { multisig_id: 0, rule: GroupRule::All, groups: 2 }.into(),
+			);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act & Assert: Engineering is satisfied, but Legal has not signed yet.
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call.clone()),
+					Weight::MAX
+				),
+				Error::<Test>::GroupQuorumNotMet
+			);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(5), 0, 0));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+		});
+	}
+
+	/// Tests that a single satisfied group is enough under the `Any` rule.
+	#[test]
+	fn any_group_suffices() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(
+				RuntimeOrigin::signed(1),
+				vec![1, 2, 3, 4],
+				2,
+				None
+			));
+			let multisig_account = Multisig::multi_account_id(0);
+			assert_ok!(Multisig::set_group_quorum(
+				RuntimeOrigin::signed(multisig_account),
+				0,
+				GroupRule::Any,
+				vec![group(b"Ops", vec![1, 2], 2), group(b"Board", vec![3, 4], 2)]
+			));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+
+			// One member of each group does not satisfy either group.
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call.clone()),
+					Weight::MAX
+				),
+				Error::<Test>::GroupQuorumNotMet
+			);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(4), 0, 0));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+		});
+	}
+
+	/// Tests that groups are validated against the owner set and their own size.
+	#[test]
+	fn fails_for_invalid_groups() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let origin = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+
+			assert_noop!(
+				Multisig::set_group_quorum(
+					origin.clone(),
+					0,
+					GroupRule::All,
+					vec![group(b"Ops", vec![1, 2], 3)]
+				),
+				Error::<Test>::InvalidGroupThreshold
+			);
+			assert_noop!(
+				Multisig::set_group_quorum(
+					origin,
+					0,
+					GroupRule::All,
+					vec![group(b"Ops", vec![1, 9], 1)]
+				),
+				Error::<Test>::NotAnOwner
+			);
+		});
+	}
+}
+
+/// Tests for the `clone_multisig` extrinsic.
+mod clone_multisig {
+	use super::*;
+	use crate::{GroupRule, OwnerGroup};
+
+	/// Tests that a clone copies the source's configuration under a fresh ID and account.
+	#[test]
+	fn it_clones_a_multisig_successfully() {
+		new_test_ext().execute_with(|| {
+			// Arrange: A configured source wallet.
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let source_account = Multisig::multi_account_id(0);
+			assert_ok!(Multisig::set_required_approvers(
+				RuntimeOrigin::signed(source_account),
+				0,
+				vec![3]
+			));
+			let group = OwnerGroup {
+				name: b"Ops".to_vec().try_into().unwrap(),
+				members: vec![1, 2].try_into().unwrap(),
+				threshold: 1,
+			};
+			assert_ok!(Multisig::set_group_quorum(
+				RuntimeOrigin::signed(source_account),
+				0,
+				GroupRule::All,
+				vec![group]
+			));
+
+			// Act
+			assert_ok!(Multisig::clone_multisig(RuntimeOrigin::signed(4), 0));
+
+			// Assert
+			let multisig_id = 1;
+			let (clone, source) =
+				(Multisig::multisigs(multisig_id).unwrap(), Multisig::multisigs(0).unwrap());
+			assert_eq!(clone.owners, source.owners);
+			assert_eq!(clone.threshold, source.threshold);
+			assert_eq!(clone.required_approvers, source.required_approvers);
+			assert_eq!(
+				Multisig::group_quorums(multisig_id).unwrap().groups,
+				Multisig::group_quorums(0).unwrap().groups
+			);
+			assert_ne!(Multisig::multi_account_id(multisig_id), source_account);
+			System::assert_has_event(
+				Event::MultisigCreated {
+					creator: 4,
+					multisig_id,
+					multisig_account: Multisig::multi_account_id(multisig_id),
+					config_hash: Multisig::config_commitment(&clone, None, None, &[], &[]),
+				}
+				.into(),
+			);
+			System::assert_last_event(
+				Event::MultisigCloned {
+					source_id: 0,
+					multisig_id,
+					config_hash: Multisig::current_config_commitment(0).unwrap(),
+				}
+				.into(),
+			);
+		});
+	}
+
+	/// Tests that cloning a non-existent multisig fails.
+	#[test]
+	fn fails_if_source_not_found() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Multisig::clone_multisig(RuntimeOrigin::signed(1), 99),
+				Error::<Test>::MultisigNotFound
+			);
+		});
+	}
+}
+
+/// Tests for the wallet template registry.
+mod templates {
+	use super::*;
+	use frame_support::sp_runtime::DispatchError;
+
+	/// Registers a "CFO, Treasurer, Clerk" template where the CFO must always sign and the
+	/// Clerk must sign before the CFO.
+	fn register_finance_template() {
+		assert_ok!(Multisig::register_template(
+			RuntimeOrigin::root(),
+			b"finance".to_vec(),
+			vec![b"CFO".to_vec(), b"Treasurer".to_vec(), b"Clerk".to_vec()],
+			2,
+			vec![0],
+			vec![2, 0]
+		));
+	}
+
+	/// Tests that a wallet created from a template gets the template's policies mapped onto
+	/// the given owners.
+	#[test]
+	fn it_creates_a_multisig_from_a_template() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			register_finance_template();
+			System::assert_last_event(
+				Event::TemplateRegistered { template_id: 0, name: b"finance".to_vec() }.into(),
+			);
+
+			// Act: Accounts 7, 8 and 9 take the CFO, Treasurer and Clerk roles.
+			assert_ok!(Multisig::create_from_template(RuntimeOrigin::signed(1), 0, vec![7, 8, 9]));
+
+			// Assert
+			let multisig = Multisig::multisigs(0).unwrap();
+			assert_eq!(multisig.owners.to_vec(), vec![7, 8, 9]);
+			assert_eq!(multisig.threshold, 2);
+			assert_eq!(multisig.required_approvers.to_vec(), vec![7]);
+			assert_eq!(multisig.approval_order.to_vec(), vec![9, 7]);
+			System::assert_last_event(
+				Event::MultisigCreatedFromTemplate { template_id: 0, multisig_id: 0 }.into(),
+			);
+		});
+	}
+
+	/// Tests that only the template origin can manage templates and that invalid templates are
+	/// rejected.
+	#[test]
+	fn registration_is_gated_and_validated() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Multisig::register_template(
+					RuntimeOrigin::signed(1),
+					b"finance".to_vec(),
+					vec![b"CFO".to_vec()],
+					1,
+					vec![],
+					vec![]
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Multisig::register_template(
+					RuntimeOrigin::root(),
+					b"finance".to_vec(),
+					vec![b"CFO".to_vec()],
+					1,
+					vec![1],
+					vec![]
+				),
+				Error::<Test>::InvalidTemplate
+			);
+
+			register_finance_template();
+			assert_ok!(Multisig::remove_template(RuntimeOrigin::root(), 0));
+			assert_noop!(
+				Multisig::create_from_template(RuntimeOrigin::signed(1), 0, vec![7, 8, 9]),
+				Error::<Test>::TemplateNotFound
+			);
+		});
+	}
+
+	/// Tests that the owners must match the template's roles one-to-one.
+	#[test]
+	fn fails_if_owners_do_not_match_roles() {
+		new_test_ext().execute_with(|| {
+			register_finance_template();
+
+			assert_noop!(
+				Multisig::create_from_template(RuntimeOrigin::signed(1), 0, vec![7, 8]),
+				Error::<Test>::OwnersDoNotMatchTemplate
+			);
+			assert_noop!(
+				Multisig::create_from_template(RuntimeOrigin::signed(1), 0, vec![7, 8, 7]),
+				Error::<Test>::DuplicateAccount
+			);
+		});
+	}
+}
+
+/// Tests for attaching off-chain content references to proposals.
+mod content_ref {
+	use super::*;
+
+	/// Tests that the content reference is stored with the proposal and announced in the event.
+	#[test]
+	fn it_stores_and_emits_the_content_reference() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+			let cid = b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_vec();
+
+			// Act
+			assert_ok!(Multisig::submit_proposal_with_content(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone()),
+				cid.clone()
+			));
+
+			// Assert
+			let proposal = Multisig::proposals(0, 0).unwrap();
+			assert_eq!(proposal.content_ref.unwrap().to_vec(), cid);
+			System::assert_last_event(
+				Event::ProposalSubmitted {
+					multisig_id: 0,
+					proposal_index: 0,
+					call_hash: BlakeTwo256::hash_of(&call),
+					content_ref: Some(cid),
+				}
+				.into(),
+			);
+		});
+	}
+
+	/// Tests that an oversized content reference is rejected.
+	#[test]
+	fn fails_if_content_ref_is_too_long() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+
+			assert_noop!(
+				Multisig::submit_proposal_with_content(
+					RuntimeOrigin::signed(1),
+					0,
+					Box::new(call),
+					vec![0; 65]
+				),
+				Error::<Test>::ContentRefTooLong
+			);
+		});
+	}
+}
+
+/// Tests for the configurable identifier types.
+mod wide_ids {
+	use super::*;
+	use crate::NextProposalIndex;
+
+	/// Tests that a wide `ProposalIndex` keeps counting past the range of a `u32`.
+	#[test]
+	fn proposal_indices_are_not_capped_at_u32() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			NextProposalIndex::<Test>::insert(0, u32::MAX as u64);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+
+			// Act
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Assert
+			assert!(Multisig::proposals(0, u32::MAX as u64).is_some());
+			assert_eq!(Multisig::next_proposal_index(0), u32::MAX as u64 + 1);
+		});
+	}
+}
+
+/// Tests for contract proposals and their per-wallet limits.
+mod contract_limits {
+	use super::*;
+	use crate::{ContractLimits, TypedProposal};
+	use frame_support::weights::Weight;
+
+	/// Builds a contract call proposal transferring `value` with a gas limit of `gas`.
+	fn call_contract(value: u128, gas: u64) -> crate::TypedProposalOf<Test> {
+		TypedProposal::CallContract {
+			dest: 42,
+			data: vec![0xde, 0xad].try_into().unwrap(),
+			value,
+			gas_limit: Weight::from_parts(gas, 0),
+		}
+	}
+
+	/// Tests that contract proposals within the wallet's limits are accepted and those beyond
+	/// them are rejected.
+	#[test]
+	fn it_enforces_contract_limits() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let limits = ContractLimits { max_value: 100, max_gas: Weight::from_parts(1_000, 0) };
+
+			// Act
+			assert_ok!(Multisig::set_contract_limits(
+				RuntimeOrigin::signed(Multisig::multi_account_id(0)),
+				0,
+				Some(limits.clone())
+			));
+
+			// Assert
+			System::assert_last_event(
+				Event::ContractLimitsSet { multisig_id: 0, limits: Some(limits) }.into(),
+			);
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				call_contract(100, 1_000)
+			));
+			assert_noop!(
+				Multisig::submit_typed_proposal(RuntimeOrigin::signed(1), 0, call_contract(101, 1)),
+				Error::<Test>::ContractLimitsExceeded
+			);
+			assert_noop!(
+				Multisig::submit_typed_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					call_contract(1, 1_001)
+				),
+				Error::<Test>::ContractLimitsExceeded
+			);
+		});
+	}
+
+	/// Tests that contract limits can only be set by the multisig itself.
+	#[test]
+	fn fails_if_not_called_by_the_multisig() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			assert_noop!(
+				Multisig::set_contract_limits(RuntimeOrigin::signed(1), 0, None),
+				Error::<Test>::MustBeMultisig
+			);
+		});
+	}
+}
+
+/// Tests for EVM proposals and their per-wallet limits.
+mod evm_limits {
+	use super::*;
+	use crate::{EvmLimits, TypedProposal};
+	use sp_core::{H160, U256};
+
+	/// Builds an EVM proposal transferring `value` wei with a gas limit of `gas`.
+	fn evm_call(value: u64, gas: u64) -> crate::TypedProposalOf<Test> {
+		TypedProposal::EvmCall {
+			target: H160::repeat_byte(0xaa),
+			input: vec![0xbe, 0xef].try_into().unwrap(),
+			value: U256::from(value),
+			gas_limit: gas,
+		}
+	}
+
+	/// Tests that EVM proposals within the wallet's limits are accepted and those beyond them
+	/// are rejected.
+	#[test]
+	fn it_enforces_evm_limits() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let limits = EvmLimits { max_value: U256::from(1_000), max_gas: 21_000 };
+
+			// Act
+			assert_ok!(Multisig::set_evm_limits(
+				RuntimeOrigin::signed(Multisig::multi_account_id(0)),
+				0,
+				Some(limits.clone())
+			));
+
+			// Assert
+			System::assert_last_event(
+				Event::EvmLimitsSet { multisig_id: 0, limits: Some(limits) }.into(),
+			);
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				evm_call(1_000, 21_000)
+			));
+			assert_noop!(
+				Multisig::submit_typed_proposal(RuntimeOrigin::signed(1), 0, evm_call(1_001, 1)),
+				Error::<Test>::EvmLimitsExceeded
+			);
+			assert_noop!(
+				Multisig::submit_typed_proposal(RuntimeOrigin::signed(1), 0, evm_call(1, 21_001)),
+				Error::<Test>::EvmLimitsExceeded
+			);
+		});
+	}
+
+	/// Tests that each multisig maps to its own EVM address.
+	#[test]
+	fn evm_addresses_are_distinct() {
+		new_test_ext().execute_with(|| {
+			assert_ne!(Multisig::evm_address(0), Multisig::evm_address(1));
+		});
+	}
+}
+
+/// Tests for tracking treasury spends a multisig expects to receive.
+mod inbound_spends {
+	use super::*;
+
+	/// Tests that an owner can track and later untrack an expected spend.
+	#[test]
+	fn it_tracks_and_untracks_inbound_spends() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			// Act
+			assert_ok!(Multisig::track_inbound_spend(RuntimeOrigin::signed(2), 0, 7, 500));
+
+			// Assert
+			assert_eq!(Multisig::inbound_spends(0, 7), Some(500));
+			System::assert_last_event(
+				Event::InboundSpendTracked { multisig_id: 0, spend_index: 7, amount: 500 }.into(),
+			);
+			assert_noop!(
+				Multisig::track_inbound_spend(RuntimeOrigin::signed(1), 0, 7, 500),
+				Error::<Test>::SpendAlreadyTracked
+			);
+
+			assert_ok!(Multisig::untrack_inbound_spend(RuntimeOrigin::signed(1), 0, 7));
+			assert_eq!(Multisig::inbound_spends(0, 7), None);
+			assert_noop!(
+				Multisig::untrack_inbound_spend(RuntimeOrigin::signed(1), 0, 7),
+				Error::<Test>::SpendNotTracked
+			);
+		});
+	}
+
+	/// Tests that only owners can track spends.
+	#[test]
+	fn fails_if_not_an_owner() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			assert_noop!(
+				Multisig::track_inbound_spend(RuntimeOrigin::signed(3), 0, 7, 500),
+				Error::<Test>::NotAnOwner
+			);
+		});
+	}
+}
+
+/// Tests for auditors and proposal flags.
+mod auditors {
+	use super::*;
+
+	/// Sets up a 2-of-2 multisig with auditor 9 and a pending proposal.
+	fn setup() {
+		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+		assert_ok!(Multisig::set_auditors(
+			RuntimeOrigin::signed(Multisig::multi_account_id(0)),
+			0,
+			vec![9]
+		));
+		let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+		assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+	}
+
+	/// Tests that a flag is recorded and announced without blocking execution.
+	#[test]
+	fn it_flags_a_proposal_without_blocking_it() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			setup();
+
+			// Act
+			assert_ok!(Multisig::flag_proposal(
+				RuntimeOrigin::signed(9),
+				0,
+				0,
+				b"no invoice".to_vec()
+			));
+
+			// Assert
+			let flags = Multisig::proposal_flags(0, 0);
+			assert_eq!(flags.len(), 1);
+			assert_eq!(flags[0].0, 9);
+			assert_eq!(flags[0].1.to_vec(), b"no invoice".to_vec());
+			System::assert_last_event(
+				Event::ProposalFlagged {
+					auditor: 9,
+					multisig_id: 0,
+					proposal_index: 0,
+					reason: b"no invoice".to_vec(),
+				}
+				.into(),
+			);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+		});
+	}
+
+	/// Tests that only auditors can flag, and that auditors cannot approve.
+	#[test]
+	fn only_auditors_can_flag() {
+		new_test_ext().execute_with(|| {
+			setup();
+
+			assert_noop!(
+				Multisig::flag_proposal(RuntimeOrigin::signed(1), 0, 0, vec![]),
+				Error::<Test>::NotAnAuditor
+			);
+			assert_noop!(
+				Multisig::confirm_proposal(RuntimeOrigin::signed(9), 0, 0),
+				Error::<Test>::NotAnOwner
+			);
+		});
+	}
+}
+
+/// Tests for the runtime's external policy check.
+mod policy_check {
+	use super::*;
+
+	/// Tests that execution requires the policy attestor's approval once the policy demands it.
+	#[test]
+	fn execution_requires_the_attestation() {
+		new_test_ext().execute_with(|| {
+			// Arrange: Account 3 is the 2FA service account owner the policy relies on.
+			POLICY_ATTESTOR.with(|attestor| *attestor.borrow_mut() = Some(3));
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call.clone()),
+					Weight::MAX
+				),
+				Error::<Test>::PolicyCheckFailed
+			);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+		});
+	}
+}
+
+/// Tests for the compliance gate on typed transfer recipients.
+mod recipient_gate {
+	use super::*;
+	use crate::TypedProposal;
+
+	/// Tests that typed transfers to accounts outside the gate are rejected.
+	#[test]
+	fn fails_if_recipient_is_not_allowed() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let _ = Balances::deposit_creating(&Multisig::multi_account_id(0), 100);
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::submit_typed_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					TypedProposal::Transfer { dest: BLOCKED_RECIPIENT, amount: 10 }
+				),
+				Error::<Test>::RecipientNotAllowed
+			);
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				TypedProposal::Transfer { dest: 5, amount: 10 }
+			));
+		});
+	}
+}
+
+/// Tests for off-chain indexing of proposal history.
+mod offchain_index {
+	use super::*;
+	use crate::ProposalOf;
+	use codec::Decode;
+
+	/// Tests that a proposal's latest state is written to the off-chain index.
+	#[test]
+	fn it_indexes_the_proposal_lifecycle() {
+		// Arrange
+		let mut ext = new_test_ext();
+		let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+		ext.execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			// Act
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+		});
+		ext.persist_offchain_overlay();
+
+		// Assert
+		let key = Multisig::offchain_proposal_key(0, 0);
+		let record = ext.offchain_db().get(&key).unwrap();
+		let proposal = ProposalOf::<Test>::decode(&mut &record[..]).unwrap();
+		assert_eq!(proposal.status, ProposalStatus::Executed);
+		assert_eq!(proposal.approvals.to_vec(), vec![1, 2]);
+	}
+}
+
+/// Tests for the governance rescue path.
+mod dispatch_as_multisig {
+	use super::*;
+	use frame_support::sp_runtime::DispatchError;
+
+	/// Tests that root can dispatch a call as the multisig's sovereign account.
+	#[test]
+	fn root_dispatches_as_the_sovereign_account() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let multisig_account = Multisig::multi_account_id(0);
+			let _ = Balances::deposit_creating(&multisig_account, 100);
+			let call: RuntimeCall =
+				pallet_balances::Call::transfer_allow_death { dest: 5, value: 100 }.into();
+
+			// Act
+			assert_ok!(Multisig::dispatch_as_multisig(RuntimeOrigin::root(), 0, Box::new(call)));
+
+			// Assert
+			assert_eq!(Balances::free_balance(5), 100);
+			System::assert_last_event(
+				Event::DispatchedAsMultisig { multisig_id: 0, result: Ok(()) }.into(),
+			);
+		});
+	}
+
+	/// Tests that only root can use the rescue path, and only for existing multisigs.
+	#[test]
+	fn fails_if_not_root_or_multisig_missing() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			assert_noop!(
+				Multisig::dispatch_as_multisig(RuntimeOrigin::signed(1), 0, Box::new(call.clone())),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Multisig::dispatch_as_multisig(RuntimeOrigin::root(), 1, Box::new(call)),
+				Error::<Test>::MultisigNotFound
+			);
+		});
+	}
+}
+
+/// Tests for the bound on pending proposals.
+mod pending_proposals {
+	use super::*;
+	use frame_support::traits::Get;
+
+	/// Tests that submissions fail once the pending cap is reached and succeed after execution.
+	#[test]
+	fn are_capped_until_one_executes() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let max: u32 = <Test as crate::Config>::MaxPendingProposals::get();
+			for i in 0..max {
+				let call: RuntimeCall =
+					frame_system::Call::remark { remark: i.to_le_bytes().to_vec() }.into();
+				assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			}
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call.clone())),
+				Error::<Test>::TooManyPendingProposals
+			);
+			let first: RuntimeCall =
+				frame_system::Call::remark { remark: 0u32.to_le_bytes().to_vec() }.into();
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(first),
+				Weight::MAX
+			));
+			assert_eq!(Multisig::pending_proposal_count(0), max - 1);
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+		});
+	}
+}
+
+/// Tests for the runtime-independent address derivation.
+mod address {
+	use super::*;
+	use crate::address;
+
+	/// Tests that the standalone helpers agree with the pallet's own derivation.
+	#[test]
+	fn matches_the_pallet_derivation() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let account = Multisig::multi_account_id(7);
+
+			// Act
+			let derived: u64 = address::multisig_account_id(b"pba/msig", 7u32, None);
+
+			// Assert
+			assert_eq!(derived, account);
+			assert!(address::verify_multisig_account(b"pba/msig", 7u32, None, &account));
+			assert!(!address::verify_multisig_account(b"pba/msig", 8u32, None, &account));
+			assert!(!address::verify_multisig_account(b"py/other", 7u32, None, &account));
+			assert_eq!(address::evm_address(&account), Multisig::evm_address(7));
+		});
+	}
+}
+
+/// Tests for the configuration commitment.
+mod config_commitment {
+	use super::*;
+	use frame_system::RawOrigin;
+
+	/// Tests that the commitment ignores owner order but tracks policy changes.
+	#[test]
+	fn verifies_until_the_policy_changes() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![3, 1, 2], 2, None));
+			let commitment = Multisig::current_config_commitment(0).unwrap();
+
+			// Act & Assert
+			assert!(Multisig::verify_config(0, commitment));
+			assert!(Multisig::verify_config(1, commitment));
+			assert!(!Multisig::verify_config(2, commitment));
+			let sovereign = Multisig::multi_account_id(0);
+			assert_ok!(Multisig::set_required_approvers(
+				RawOrigin::Signed(sovereign).into(),
+				0,
+				vec![3]
+			));
+			assert!(!Multisig::verify_config(0, commitment));
+		});
+	}
+
+	/// Tests that the commitment tracks the group quorum and the call filter.
+	#[test]
+	fn covers_group_quorum_and_call_filter() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let sovereign = Multisig::multi_account_id(0);
+			let commitment = Multisig::current_config_commitment(0).unwrap();
+
+			// Act & Assert: Setting a group quorum changes the commitment.
+			let group = crate::OwnerGroup {
+				name: b"Ops".to_vec().try_into().unwrap(),
+				members: vec![1, 2].try_into().unwrap(),
+				threshold: 1,
+			};
+			assert_ok!(Multisig::set_group_quorum(
+				RawOrigin::Signed(sovereign).into(),
+				0,
+				crate::GroupRule::All,
+				vec![group]
+			));
+			assert!(!Multisig::verify_config(0, commitment));
+			let commitment = Multisig::current_config_commitment(0).unwrap();
+			assert!(Multisig::verify_config(0, commitment));
+
+			// Setting a call filter changes it too, and lifting the filter restores it.
+			let pattern = crate::CallPattern { pallet_index: 0, call_index: None };
+			assert_ok!(Multisig::set_call_filter(
+				RawOrigin::Signed(sovereign).into(),
+				0,
+				Some((crate::FilterMode::Allow, vec![pattern]))
+			));
+			assert!(!Multisig::verify_config(0, commitment));
+			assert_ok!(Multisig::set_call_filter(RawOrigin::Signed(sovereign).into(), 0, None));
+			assert!(Multisig::verify_config(0, commitment));
+		});
+	}
+
+	/// Tests that the commitment tracks vote weights and class thresholds.
+	#[test]
+	fn covers_weights_and_class_thresholds() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let sovereign = Multisig::multi_account_id(0);
+			let commitment = Multisig::current_config_commitment(0).unwrap();
+
+			// Act & Assert: Weighting an owner changes the commitment.
+			assert_ok!(Multisig::set_owner_weight(RawOrigin::Signed(sovereign).into(), 0, 1, 2));
+			assert!(!Multisig::verify_config(0, commitment));
+			let commitment = Multisig::current_config_commitment(0).unwrap();
+			assert!(Multisig::verify_config(0, commitment));
+
+			// Setting a class threshold changes it too, and clearing it restores it.
+			assert_ok!(Multisig::set_class_threshold(
+				RawOrigin::Signed(sovereign).into(),
+				0,
+				crate::CallClass::Admin,
+				Some(3)
+			));
+			assert!(!Multisig::verify_config(0, commitment));
+			assert_ok!(Multisig::set_class_threshold(
+				RawOrigin::Signed(sovereign).into(),
+				0,
+				crate::CallClass::Admin,
+				None
+			));
+			assert!(Multisig::verify_config(0, commitment));
+		});
+	}
+}
+
+/// Tests for earmarking the funds of pending transfer proposals.
+mod earmarks {
+	use super::*;
+	use crate::TypedProposal;
+
+	/// Tests that concurrent transfers cannot promise more than the wallet holds.
+	#[test]
+	fn pending_transfers_cannot_overspend() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let multisig_account = Multisig::multi_account_id(0);
+			let _ = Balances::deposit_creating(&multisig_account, 100);
+
+			// Act
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				TypedProposal::Transfer { dest: 7, amount: 60 }
+			));
+
+			// Assert
+			System::assert_last_event(
+				Event::FundsEarmarked { multisig_id: 0, proposal_index: 0, amount: 60 }.into(),
+			);
+			assert_eq!(Balances::reserved_balance(multisig_account), 60);
+			assert_noop!(
+				Multisig::submit_typed_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					TypedProposal::Transfer { dest: 8, amount: 60 }
+				),
+				Error::<Test>::InsufficientBalance
+			);
+		});
+	}
+
+	/// Tests that executing a transfer releases its earmark and spends the funds.
+	#[test]
+	fn execution_releases_the_earmark() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let multisig_account = Multisig::multi_account_id(0);
+			let _ = Balances::deposit_creating(&multisig_account, 100);
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				TypedProposal::Transfer { dest: 7, amount: 60 }
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			let call: RuntimeCall =
+				pallet_balances::Call::transfer_keep_alive { dest: 7, value: 60 }.into();
+
+			// Act
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(Balances::free_balance(7), 60);
+			assert_eq!(Balances::reserved_balance(multisig_account), 0);
+			assert_eq!(Multisig::earmarks(0, 0), None);
+			System::assert_has_event(
+				Event::EarmarkReleased { multisig_id: 0, proposal_index: 0, amount: 60 }.into(),
+			);
+		});
+	}
+}
+
+/// Tests for the protected reserve.
+mod protected_reserve {
+	use super::*;
+	use frame_system::RawOrigin;
+
+	/// Creates a funded 2-of-3 multisig with a protected reserve of 50 and returns a proposal
+	/// spending into it, approved by the proposer only.
+	fn setup_reserve_and_proposal() -> (u64, RuntimeCall) {
+		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+		let multisig_account = Multisig::multi_account_id(0);
+		let _ = Balances::deposit_creating(&multisig_account, 100);
+		assert_ok!(Multisig::set_protected_reserve(
+			RawOrigin::Signed(multisig_account).into(),
+			0,
+			Some(50)
+		));
+		let call: RuntimeCall =
+			pallet_balances::Call::transfer_keep_alive { dest: 7, value: 60 }.into();
+		assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call.clone())));
+		(multisig_account, call)
+	}
+
+	/// Tests that a threshold-only proposal cannot spend into the reserve.
+	#[test]
+	fn threshold_spend_into_reserve_fails() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			let (multisig_account, call) = setup_reserve_and_proposal();
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(Balances::free_balance(multisig_account), 100);
+			assert_eq!(
+				Multisig::proposals(0, 0).unwrap().status,
+				ProposalStatus::Failed { error: Error::<Test>::ReserveBreached.into() }
+			);
+			System::assert_last_event(
+				Event::ProposalExecuted {
+					multisig_id: 0,
+					proposal_index: 0,
+					result: Err(Error::<Test>::ReserveBreached.into()),
+				}
+				.into(),
+			);
+		});
+	}
+
+	/// Tests that a unanimous proposal may spend into the reserve.
+	#[test]
+	fn unanimous_spend_into_reserve_succeeds() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let (multisig_account, call) = setup_reserve_and_proposal();
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(Balances::free_balance(multisig_account), 40);
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+		});
+	}
+}
+
+/// Tests for the payroll subsystem.
+mod payroll {
+	use super::*;
+	use frame_support::traits::Hooks;
+	use frame_system::RawOrigin;
+
+	/// Advances to block `n`, running the pallet's `on_initialize` for each block.
+	fn run_to_block(n: u64) {
+		while System::block_number() < n {
+			System::set_block_number(System::block_number() + 1);
+			Multisig::on_initialize(System::block_number());
+		}
+	}
+
+	/// Creates a funded multisig whose payroll pays 10 to account 7 and 20 to account 8 every
+	/// 5 blocks from block 5, returning its sovereign account.
+	fn setup_payroll() -> u64 {
+		System::set_block_number(1);
+		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+		let multisig_account = Multisig::multi_account_id(0);
+		let _ = Balances::deposit_creating(&multisig_account, 100);
+		assert_ok!(Multisig::set_payroll(
+			RawOrigin::Signed(multisig_account).into(),
+			0,
+			vec![(7, 10), (8, 20)],
+			5,
+			5
+		));
+		multisig_account
+	}
+
+	/// Tests that the payroll is paid out every period.
+	#[test]
+	fn it_pays_out_each_period() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			setup_payroll();
+
+			// Act
+			run_to_block(10);
+
+			// Assert
+			assert_eq!(Balances::free_balance(7), 20);
+			assert_eq!(Balances::free_balance(8), 40);
+			assert_eq!(Multisig::payrolls(0).unwrap().next_payout, 15);
+			System::assert_last_event(Event::PayrollPaid { multisig_id: 0, total: 30 }.into());
+		});
+	}
+
+	/// Tests that a paused payroll skips payouts and a cancelled one stops them.
+	#[test]
+	fn pause_and_cancel_stop_payouts() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let multisig_account = setup_payroll();
+			let sovereign = || RuntimeOrigin::from(RawOrigin::Signed(multisig_account));
+
+			// Act & Assert
+			assert_ok!(Multisig::set_payroll_paused(sovereign(), 0, true));
+			run_to_block(5);
+			assert_eq!(Balances::free_balance(7), 0);
+			assert_ok!(Multisig::set_payroll_paused(sovereign(), 0, false));
+			run_to_block(10);
+			assert_eq!(Balances::free_balance(7), 10);
+			assert_ok!(Multisig::cancel_payroll(sovereign(), 0));
+			run_to_block(15);
+			assert_eq!(Balances::free_balance(7), 10);
+			assert_noop!(Multisig::cancel_payroll(sovereign(), 0), Error::<Test>::PayrollNotFound);
+		});
+	}
+
+	/// Tests that payments which would dip into the protected reserve are skipped.
+	#[test]
+	fn payments_respect_the_protected_reserve() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let multisig_account = setup_payroll();
+			assert_ok!(Multisig::set_protected_reserve(
+				RawOrigin::Signed(multisig_account).into(),
+				0,
+				Some(80)
+			));
+
+			// Act
+			run_to_block(5);
+
+			// Assert
+			assert_eq!(Balances::free_balance(7), 10);
+			assert_eq!(Balances::free_balance(8), 0);
+			System::assert_has_event(
+				Event::PayrollPaymentFailed { multisig_id: 0, payee: 8, amount: 20 }.into(),
+			);
+		});
+	}
+
+	/// Tests that invalid payrolls are rejected.
+	#[test]
+	fn fails_for_invalid_payroll() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let sovereign = RuntimeOrigin::from(RawOrigin::Signed(Multisig::multi_account_id(0)));
+
+			assert_noop!(
+				Multisig::set_payroll(sovereign.clone(), 0, vec![], 5, 5),
+				Error::<Test>::InvalidPayroll
+			);
+			assert_noop!(
+				Multisig::set_payroll(sovereign.clone(), 0, vec![(7, 10)], 0, 5),
+				Error::<Test>::InvalidPayroll
+			);
+			assert_noop!(
+				Multisig::set_payroll(sovereign, 0, vec![(7, 10)], 5, 1),
+				Error::<Test>::InvalidPayroll
+			);
+		});
+	}
+}
+
+/// Tests for vested transfer proposals.
+mod vested_transfer {
+	use super::*;
+	use crate::TypedProposal;
+
+	/// Tests that a vested transfer with a real schedule is accepted and earmarked.
+	#[test]
+	fn it_submits_a_vested_transfer() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let _ = Balances::deposit_creating(&Multisig::multi_account_id(0), 100);
+
+			// Act
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				TypedProposal::VestedTransfer {
+					dest: 7,
+					locked: 60,
+					per_block: 1,
+					starting_block: 5
+				}
+			));
+
+			// Assert
+			assert!(Multisig::proposals(0, 0).is_some());
+			assert_eq!(Multisig::earmarks(0, 0), Some(60));
+		});
+	}
+
+	/// Tests that schedules which do not actually vest are rejected.
+	#[test]
+	fn fails_for_invalid_schedules() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(10);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let _ = Balances::deposit_creating(&Multisig::multi_account_id(0), 100);
+
+			for (per_block, starting_block) in [(0, 10), (60, 10), (1, 9)] {
+				assert_noop!(
+					Multisig::submit_typed_proposal(
+						RuntimeOrigin::signed(1),
+						0,
+						TypedProposal::VestedTransfer {
+							dest: 7,
+							locked: 60,
+							per_block,
+							starting_block,
+						}
+					),
+					Error::<Test>::InvalidVestingSchedule
+				);
+			}
+		});
+	}
+}
+
+/// Tests for time-boxed operator sessions.
+mod operator_sessions {
+	use super::*;
+	use frame_support::dispatch::{DispatchClass, GetDispatchInfo};
+	use frame_system::RawOrigin;
+
+	/// Creates a multisig and grants account 9 a remarks-only session for 10 blocks.
+	fn setup_session() {
+		System::set_block_number(1);
+		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+		assert_ok!(Multisig::grant_session(
+			RawOrigin::Signed(Multisig::multi_account_id(0)).into(),
+			0,
+			9,
+			MockSessionScope::Remarks,
+			10
+		));
+	}
+
+	/// Tests that an operator can execute calls within their scope without approvals.
+	#[test]
+	fn operator_executes_within_scope() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			setup_session();
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+
+			// Act
+			assert_ok!(Multisig::operator_execute(RuntimeOrigin::signed(9), 0, Box::new(call)));
+
+			// Assert
+			System::assert_last_event(
+				Event::OperatorExecuted { multisig_id: 0, operator: 9, result: Ok(()) }.into(),
+			);
+		});
+	}
+
+	/// Tests that calls outside the scope, or after expiry, are rejected.
+	#[test]
+	fn fails_outside_scope_or_after_expiry() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			setup_session();
+			let transfer: RuntimeCall =
+				pallet_balances::Call::transfer_allow_death { dest: 9, value: 1 }.into();
+			let remark: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::operator_execute(RuntimeOrigin::signed(9), 0, Box::new(transfer)),
+				Error::<Test>::CallOutsideSession
+			);
+			assert_noop!(
+				Multisig::operator_execute(RuntimeOrigin::signed(8), 0, Box::new(remark.clone())),
+				Error::<Test>::SessionNotFound
+			);
+			System::set_block_number(12);
+			assert_noop!(
+				Multisig::operator_execute(RuntimeOrigin::signed(9), 0, Box::new(remark)),
+				Error::<Test>::SessionExpired
+			);
+		});
+	}
+
+	/// Tests that an operator's call is weighed up front in its own dispatch class.
+	#[test]
+	fn operator_execution_takes_the_class_of_its_call() {
+		// Arrange
+		let call: RuntimeCall = frame_system::Call::set_heap_pages { pages: 64 }.into();
+		let call_info = call.get_dispatch_info();
+
+		// Act
+		let info = RuntimeCall::Multisig(crate::Call::operator_execute {
+			multisig_id: 0,
+			call: Box::new(call),
+		})
+		.get_dispatch_info();
+
+		// Assert
+		assert_eq!(info.class, DispatchClass::Operational);
+		assert!(info.call_weight.all_gte(call_info.call_weight));
+	}
+
+	/// Tests that any single owner can revoke a session early.
+	#[test]
+	fn any_owner_can_revoke() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			setup_session();
+
+			// Act
+			assert_noop!(
+				Multisig::revoke_session(RuntimeOrigin::signed(3), 0, 9),
+				Error::<Test>::NotAnOwner
+			);
+			assert_ok!(Multisig::revoke_session(RuntimeOrigin::signed(2), 0, 9));
+
+			// Assert
+			assert!(Multisig::operator_sessions(0, 9).is_none());
+			System::assert_last_event(Event::SessionRevoked { multisig_id: 0, operator: 9 }.into());
+		});
+	}
+}
+
+/// Tests for the pluggable vote tally.
+mod vote_tally {
+	use super::*;
+
+	/// Tests that execution follows the configured tally rather than the raw threshold.
+	#[test]
+	fn execution_follows_the_tally() {
+		new_test_ext().execute_with(|| {
+			// Arrange: The runtime's tally demands unanimity, above the 2-of-3 threshold.
+			REQUIRE_UNANIMITY.with(|required| *required.borrow_mut() = true);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call.clone()),
+					Weight::MAX
+				),
+				Error::<Test>::NotEnoughApprovals
+			);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+		});
+	}
+}
+
+/// Tests for the builders exposed to downstream runtimes.
+mod test_utils {
+	use super::*;
+	use crate::test_utils::MultisigBuilder;
+
+	/// Tests that the builder creates a multisig with a proposal ready to execute.
+	#[test]
+	fn builder_creates_a_ready_proposal() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+
+			// Act
+			let built = MultisigBuilder::<Test>::new()
+				.owners(vec![1, 2, 3])
+				.threshold(2)
+				.with_ready_proposal(call)
+				.build();
+
+			// Assert
+			let (index, call) = built.ready_proposal.unwrap();
+			assert_eq!(built.account, Multisig::multi_account_id(built.id));
+			assert_eq!(Multisig::approvals(built.id, index).to_vec(), vec![1, 2]);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(4),
+				built.id,
+				index,
+				Box::new(call),
+				Weight::MAX
+			));
+		});
+	}
+}
+
+/// Tests for attaching funds to a proposal.
+mod funded_proposals {
+	use super::*;
+
+	/// Tests that the funds and the proposal arrive together.
+	#[test]
+	fn it_funds_and_submits_atomically() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let _ = Balances::deposit_creating(&1, 100);
+			let multisig_account = Multisig::multi_account_id(0);
+			let call: RuntimeCall =
+				pallet_balances::Call::transfer_keep_alive { dest: 7, value: 40 }.into();
+
+			// Act
+			assert_ok!(Multisig::submit_proposal_with_funds(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call),
+				50
+			));
+
+			// Assert
+			assert_eq!(Balances::free_balance(multisig_account), 50);
+			assert!(Multisig::proposals(0, 0).is_some());
+			System::assert_last_event(
+				Event::ProposalFunded { multisig_id: 0, proposal_index: 0, who: 1, amount: 50 }
+					.into(),
+			);
+		});
+	}
+
+	/// Tests that nothing is submitted if the funds cannot be sent.
+	#[test]
+	fn fails_without_submitting_if_the_transfer_fails() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			assert_noop!(
+				Multisig::submit_proposal_with_funds(
+					RuntimeOrigin::signed(1),
+					0,
+					Box::new(call),
+					50
+				),
+				sp_runtime::ArithmeticError::Underflow
+			);
+		});
+	}
+}
+
+/// Tests for the deposits computed by the runtime's calculator.
+mod deposits {
+	use super::*;
+
+	fn enable_deposits() {
+		DEPOSIT_UNIT.with(|unit| *unit.borrow_mut() = 2);
+		for who in [1, 2, 3] {
+			let _ = Balances::deposit_creating(&who, 100);
+		}
+	}
+
+	/// Tests that each step reserves the calculated deposit and execution refunds the
+	/// proposal's deposits.
+	#[test]
+	fn deposits_are_reserved_and_refunded_on_execution() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			enable_deposits();
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+
+			// Act
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Assert: 3 owners at 2 each, then one proposal and one approval at 2 each.
+			assert_eq!(Balances::reserved_balance(1), 8);
+			assert_eq!(Balances::reserved_balance(2), 2);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(3),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_eq!(Balances::reserved_balance(1), 6);
+			assert_eq!(Balances::reserved_balance(2), 0);
+			assert!(Multisig::proposal_deposits(0, 0).is_empty());
+		});
+	}
+
+	/// Tests that each open proposal makes the next one dearer.
+	#[test]
+	fn proposal_deposit_grows_with_open_proposals() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			enable_deposits();
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(2), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			// Act
+			for _ in 0..3 {
+				assert_ok!(Multisig::submit_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					Box::new(call.clone())
+				));
+			}
+
+			// Assert: 2 + 4 + 6
+			assert_eq!(Balances::reserved_balance(1), 12);
+		});
+	}
+
+	/// Tests that an account unable to cover a deposit is rejected.
+	#[test]
+	fn fails_without_funds_for_the_deposit() {
+		new_test_ext().execute_with(|| {
+			DEPOSIT_UNIT.with(|unit| *unit.borrow_mut() = 2);
+
+			assert_noop!(
+				Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None),
+				Error::<Test>::InsufficientDeposit
+			);
+		});
+	}
+
+	/// Tests that destroying a multisig refunds every outstanding deposit.
+	#[test]
+	fn destroy_refunds_all_deposits() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			enable_deposits();
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let remark: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(2), 0, Box::new(remark)));
+			let destroy: RuntimeCall = crate::Call::destroy_multisig { multisig_id: 0 }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(destroy.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 1));
+
+			// Act
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				1,
+				Box::new(destroy),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Balances::reserved_balance(2), 0);
+			assert!(Multisig::creation_deposit(0).is_none());
+		});
+	}
+}
+
+/// Tests that execution's bookkeeping is all-or-nothing.
+mod transactional_execution {
+	use super::*;
+
+	/// Tests that a failing call leaves the proposal and its deposits untouched.
+	#[test]
+	fn failed_dispatch_leaves_bookkeeping_untouched() {
+		new_test_ext().execute_with(|| {
+			// Arrange: A root-only call, which the sovereign account cannot dispatch.
+			System::set_block_number(1);
+			DEPOSIT_UNIT.with(|unit| *unit.borrow_mut() = 2);
+			for who in [1, 2] {
+				let _ = Balances::deposit_creating(&who, 100);
+			}
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall =
+				pallet_balances::Call::force_set_balance { who: 7, new_free: 10 }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			System::assert_last_event(
+				Event::ProposalExecuted {
+					multisig_id: 0,
+					proposal_index: 0,
+					result: Err(sp_runtime::DispatchError::BadOrigin),
+				}
+				.into(),
+			);
+			assert_eq!(
+				Multisig::proposals(0, 0).unwrap().status,
+				ProposalStatus::Failed { error: sp_runtime::DispatchError::BadOrigin }
+			);
+			assert_eq!(Multisig::approvals(0, 0).to_vec(), vec![1, 2]);
+			assert_eq!(Multisig::proposal_deposits(0, 0).len(), 2);
+			assert_eq!(Balances::reserved_balance(2), 2);
+			assert_eq!(Multisig::pending_proposal_count(0), 1);
+		});
+	}
+
+	/// Tests that a call which transfers funds and then fails is undone entirely, and that
+	/// the proposal can be executed once the call succeeds, exactly once.
+	#[test]
+	fn partially_applied_call_rolls_back_and_can_be_retried() {
+		new_test_ext().execute_with(|| {
+			// Arrange: A transfer into the protected reserve, approved by the threshold only,
+			// which the balances pallet applies and the reserve check then rejects.
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let multisig_account = Multisig::multi_account_id(0);
+			let _ = Balances::deposit_creating(&multisig_account, 100);
+			let sovereign = || RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::set_protected_reserve(sovereign(), 0, Some(50)));
+			let call: RuntimeCall =
+				pallet_balances::Call::transfer_keep_alive { dest: 7, value: 60 }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act & Assert: The failed execution leaves no trace of the transfer.
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call.clone()),
+				Weight::MAX
+			));
+			assert_eq!(Balances::free_balance(multisig_account), 100);
+			assert_eq!(Balances::free_balance(7), 0);
+			assert_eq!(
+				Multisig::proposals(0, 0).unwrap().status,
+				ProposalStatus::Failed { error: Error::<Test>::ReserveBreached.into() }
+			);
+			assert_eq!(Multisig::pending_proposal_count(0), 1);
+
+			// Act & Assert: Without the reserve, the same proposal executes.
+			assert_ok!(Multisig::set_protected_reserve(sovereign(), 0, None));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call.clone()),
+				Weight::MAX
+			));
+			assert_eq!(Balances::free_balance(7), 60);
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+			assert_eq!(Multisig::pending_proposal_count(0), 0);
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call),
+					Weight::MAX
+				),
+				Error::<Test>::AlreadyExecuted
+			);
+		});
+	}
+}
+
+/// Tests for repairing inconsistent storage.
+mod reconcile {
+	use super::*;
+	use crate::{PendingProposalCount, ProposalDeposits};
+	use frame_support::traits::ReservableCurrency;
+
+	/// Tests that leftovers of a multisig that no longer exists are removed and refunded.
+	#[test]
+	fn it_cleans_up_after_a_missing_multisig() {
+		new_test_ext().execute_with(|| {
+			// Arrange: A proposal and its deposit outlive their multisig.
+			System::set_block_number(1);
+			let _ = Balances::deposit_creating(&1, 100);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			assert_ok!(Balances::reserve(&1, 5));
+			ProposalDeposits::<Test>::insert(0, 0, BoundedVec::truncate_from(vec![(1, 5)]));
+			crate::Multisigs::<Test>::remove(0);
+
+			// Act
+			assert_ok!(Multisig::reconcile(RuntimeOrigin::signed(9), 0));
+
+			// Assert: The proposal, its index, count, usage meter and deposit.
+			assert!(Multisig::proposals(0, 0).is_none());
+			assert_eq!(Balances::reserved_balance(1), 0);
+			System::assert_last_event(Event::Reconciled { multisig_id: 0, repaired: 5 }.into());
+		});
+	}
+
+	/// Tests that the pending count of an existing multisig is recounted.
+	#[test]
+	fn it_repairs_an_existing_multisig() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			PendingProposalCount::<Test>::insert(0, 5);
+
+			// Act
+			assert_ok!(Multisig::reconcile(RuntimeOrigin::signed(9), 0));
+
+			// Assert
+			assert_eq!(Multisig::pending_proposal_count(0), 1);
+		});
+	}
+
+	/// Tests that a consistent multisig is left alone.
+	#[test]
+	fn fails_if_nothing_is_inconsistent() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			assert_noop!(
+				Multisig::reconcile(RuntimeOrigin::signed(9), 0),
+				Error::<Test>::NothingToReconcile
+			);
+			assert_noop!(
+				Multisig::reconcile(RuntimeOrigin::signed(9), 1),
+				Error::<Test>::NothingToReconcile
+			);
+		});
+	}
+}
+
+/// Tests for confirming and executing in one step.
+mod confirm_and_execute {
+	use super::*;
+
+	/// Tests that the final confirmation also executes the proposal.
+	#[test]
+	fn it_confirms_and_executes() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+
+			// Act
+			assert_ok!(Multisig::confirm_and_execute(
+				RuntimeOrigin::signed(2),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+			System::assert_has_event(
+				Event::Confirmation { who: 2, multisig_id: 0, proposal_index: 0 }.into(),
+			);
+			System::assert_last_event(
+				Event::ProposalExecuted { multisig_id: 0, proposal_index: 0, result: Ok(()) }
+					.into(),
+			);
+		});
+	}
+
+	/// Tests that the confirmation is not kept if the proposal cannot yet be executed.
+	#[test]
+	fn fails_without_confirming_below_the_threshold() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 3, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::confirm_and_execute(
+					RuntimeOrigin::signed(2),
+					0,
+					0,
+					Box::new(call),
+					Weight::MAX
+				),
+				Error::<Test>::NotEnoughApprovals
+			);
+			assert_eq!(Multisig::approvals(0, 0).to_vec(), vec![1]);
+		});
+	}
+}
+
+/// Tests for listing the proposals ready to execute.
+mod executable_proposals {
+	use super::*;
+
+	/// Tests that only pending proposals meeting the threshold are listed.
+	#[test]
+	fn it_lists_ready_pending_proposals() {
+		new_test_ext().execute_with(|| {
+			// Arrange: Proposal 0 is executed, 1 is ready and 2 lacks approvals.
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let calls: Vec<RuntimeCall> =
+				(0..3).map(|i| frame_system::Call::remark { remark: vec![i] }.into()).collect();
+			for call in &calls {
+				assert_ok!(Multisig::submit_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					Box::new(call.clone())
+				));
+			}
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 1));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(calls[0].clone()),
+				Weight::MAX
+			));
+
+			// Act
+			let ready = Multisig::executable_proposals(0);
+
+			// Assert
+			assert_eq!(ready, vec![(1, Multisig::call_hash(&calls[1]))]);
+			assert!(Multisig::executable_proposals(1).is_empty());
+			assert_eq!(
+				Multisig::pending_proposals(0),
+				vec![(1, Multisig::call_hash(&calls[1])), (2, Multisig::call_hash(&calls[2]))]
+			);
+		});
+	}
+}
+
+/// Tests for the view functions.
+mod view_functions {
+	use super::*;
+	use crate::{ApprovalStatus, IsOwnerViewFunction};
+	use codec::{Decode, Encode};
+	use frame_support::view_functions::ViewFunction;
+
+	/// Tests that the view functions report a multisig's owners, threshold and proposals.
+	#[test]
+	fn they_report_wallet_state() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Act
+			let before = Multisig::approval_status(0, 0);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			let after = Multisig::approval_status(0, 0);
+
+			// Assert
+			assert!(Multisig::is_owner(0, 2));
+			assert!(!Multisig::is_owner(0, 4));
+			assert_eq!(Multisig::threshold_of(0), Some(2));
+			assert_eq!(Multisig::threshold_of(1), None);
+			assert_eq!(Multisig::pending_proposal_count(0), 1);
+			assert_eq!(
+				before,
+				Some(ApprovalStatus {
+					approvals: 1,
+					rejections: 0,
+					threshold: 2,
+					executable: false
+				})
+			);
+			assert_eq!(
+				after,
+				Some(ApprovalStatus {
+					approvals: 2,
+					rejections: 0,
+					threshold: 2,
+					executable: true
+				})
+			);
+			assert_eq!(Multisig::approval_status(0, 1), None);
+		});
+	}
+
+	/// Tests that the view functions can be dispatched through the runtime by their id.
+	#[test]
+	fn they_dispatch_through_the_runtime() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let query = IsOwnerViewFunction::<Test>::new(0, 2);
+
+			// Act
+			let output =
+				Test::execute_view_function(IsOwnerViewFunction::<Test>::id(), query.encode())
+					.expect("the view function is registered");
+
+			// Assert
+			assert_eq!(bool::decode(&mut &output[..]), Ok(true));
+		});
+	}
+}
+
+/// Tests for labelling owners.
+mod owner_labels {
+	use super::*;
+
+	fn label(text: &str) -> crate::NameOf<Test> {
+		BoundedVec::truncate_from(text.as_bytes().to_vec())
+	}
+
+	/// Tests that the sovereign account can label, relabel and unlabel an owner.
+	#[test]
+	fn sovereign_sets_and_removes_labels() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+
+			// Act
+			assert_ok!(Multisig::set_owner_label(sovereign.clone(), 0, 2, Some(label("CFO"))));
+
+			// Assert
+			assert_eq!(Multisig::owner_labels(0), vec![(2, label("CFO"))]);
+			System::assert_last_event(
+				Event::OwnerLabelSet { multisig_id: 0, owner: 2, label: Some(label("CFO")) }.into(),
+			);
+			assert_ok!(Multisig::set_owner_label(sovereign, 0, 2, None));
+			assert!(Multisig::owner_label(0, 2).is_none());
+		});
+	}
+
+	/// Tests that only owners can be labelled, and only by the sovereign account.
+	#[test]
+	fn fails_for_non_owners_and_other_origins() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+
+			assert_noop!(
+				Multisig::set_owner_label(sovereign, 0, 3, Some(label("Intern"))),
+				Error::<Test>::NotAnOwner
+			);
+			assert_noop!(
+				Multisig::set_owner_label(RuntimeOrigin::signed(1), 0, 1, Some(label("CEO"))),
+				Error::<Test>::MustBeMultisig
+			);
+		});
+	}
+}
+
+/// Tests for suspending and reinstating owners.
+mod suspension {
+	use super::*;
+
+	fn sovereign() -> RuntimeOrigin {
+		RuntimeOrigin::signed(Multisig::multi_account_id(0))
+	}
+
+	/// Tests that a suspended owner can neither propose nor confirm.
+	#[test]
+	fn suspended_owner_cannot_propose_or_confirm() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+
+			// Act
+			assert_ok!(Multisig::suspend_owner(sovereign(), 0, 2));
+
+			// Assert
+			System::assert_last_event(Event::OwnerSuspended { multisig_id: 0, owner: 2 }.into());
+			assert_noop!(
+				Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0),
+				Error::<Test>::SuspendedOwner
+			);
+			assert_noop!(
+				Multisig::submit_proposal(RuntimeOrigin::signed(2), 0, Box::new(call)),
+				Error::<Test>::SuspendedOwner
+			);
+		});
+	}
+
+	/// Tests that a suspended owner's approval stops counting until they are reinstated.
+	#[test]
+	fn approvals_count_again_after_reinstatement() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::suspend_owner(sovereign(), 0, 2));
+
+			// Assert
+			assert!(Multisig::executable_proposals(0).is_empty());
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call.clone()),
+					Weight::MAX
+				),
+				Error::<Test>::NotEnoughApprovals
+			);
+			assert_ok!(Multisig::reinstate_owner(sovereign(), 0, 2));
+			assert!(Multisig::suspended_owners(0).is_empty());
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+		});
+	}
+
+	/// Tests that the threshold must stay reachable by the active owners.
+	#[test]
+	fn fails_if_the_threshold_becomes_unreachable() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			assert_ok!(Multisig::suspend_owner(sovereign(), 0, 3));
+
+			assert_noop!(
+				Multisig::suspend_owner(sovereign(), 0, 3),
+				Error::<Test>::AlreadySuspended
+			);
+			assert_noop!(
+				Multisig::suspend_owner(sovereign(), 0, 2),
+				Error::<Test>::InvalidThreshold
+			);
+			assert_noop!(Multisig::reinstate_owner(sovereign(), 0, 1), Error::<Test>::NotSuspended);
+		});
+	}
+
+	/// Tests that a suspended mandatory approver is not required, so the other owners can
+	/// still reinstate them.
+	#[test]
+	fn suspended_required_approver_can_be_reinstated() {
+		new_test_ext().execute_with(|| {
+			// Arrange: Owner 1 must approve everything, and is then suspended.
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			assert_ok!(Multisig::set_required_approvers(sovereign(), 0, vec![1]));
+			assert_ok!(Multisig::suspend_owner(sovereign(), 0, 1));
+			let call: RuntimeCall =
+				crate::Call::reinstate_owner { multisig_id: 0, owner: 1 }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(2),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(2),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			assert!(Multisig::suspended_owners(0).is_empty());
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+		});
+	}
+
+	/// Tests that a suspended member of the sign-off chain is passed over.
+	#[test]
+	fn suspended_signer_is_passed_over() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			assert_ok!(Multisig::set_approval_order(sovereign(), 0, vec![1, 2, 3]));
+			assert_ok!(Multisig::suspend_owner(sovereign(), 0, 1));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			// Act
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(2), 0, Box::new(call)));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+
+			// Assert
+			assert_eq!(Multisig::approvals(0, 0).to_vec(), vec![2, 3]);
+			assert_eq!(Multisig::executable_proposals(0).len(), 1);
+		});
+	}
+
+	/// Tests that an owner cannot be suspended if the group quorum would become unreachable.
+	#[test]
+	fn fails_if_the_group_quorum_becomes_unreachable() {
+		new_test_ext().execute_with(|| {
+			// Arrange: Legal {3} must always sign.
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let legal = crate::OwnerGroup {
+				name: b"Legal".to_vec().try_into().unwrap(),
+				members: vec![3].try_into().unwrap(),
+				threshold: 1,
+			};
+			assert_ok!(Multisig::set_group_quorum(
+				sovereign(),
+				0,
+				crate::GroupRule::All,
+				vec![legal]
+			));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::suspend_owner(sovereign(), 0, 3),
+				Error::<Test>::InvalidGroupThreshold
+			);
+			assert_ok!(Multisig::suspend_owner(sovereign(), 0, 2));
+		});
+	}
+}
+
+/// Tests for the backup owner set.
+mod backup_owners {
+	use super::*;
+
+	fn sovereign() -> RuntimeOrigin {
+		RuntimeOrigin::signed(Multisig::multi_account_id(0))
+	}
+
+	/// Tests that the backup set takes over once the owners have been inactive long enough.
+	#[test]
+	fn backup_set_takes_over_after_inactivity() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			assert_ok!(Multisig::set_backup_owners(sovereign(), 0, vec![7, 8, 9], 2, 100));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			System::set_block_number(101);
+			assert_ok!(Multisig::activate_backup_owners(RuntimeOrigin::signed(9), 0));
+
+			// Assert: The previous owners' approvals no longer count.
+			let multisig = Multisig::multisigs(0).unwrap();
+			assert_eq!(multisig.owners.to_vec(), vec![7, 8, 9]);
+			assert_eq!(multisig.threshold, 2);
+			assert!(Multisig::backup_owners(0).is_none());
+			System::assert_last_event(Event::BackupOwnersActivated { multisig_id: 0 }.into());
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(7),
+					0,
+					0,
+					Box::new(call),
+					Weight::MAX
+				),
+				Error::<Test>::NotEnoughApprovals
+			);
+		});
+	}
+
+	/// Tests that owner activity postpones the takeover.
+	#[test]
+	fn activity_postpones_the_takeover() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			assert_ok!(Multisig::set_backup_owners(sovereign(), 0, vec![7, 8], 1, 100));
+
+			// Act
+			System::set_block_number(50);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Assert
+			System::set_block_number(101);
+			assert_noop!(
+				Multisig::activate_backup_owners(RuntimeOrigin::signed(9), 0),
+				Error::<Test>::OwnersStillActive
+			);
+			System::set_block_number(150);
+			assert_ok!(Multisig::activate_backup_owners(RuntimeOrigin::signed(9), 0));
+		});
+	}
+
+	/// Tests that the backup set is validated and can be cleared.
+	#[test]
+	fn backup_set_is_validated_and_can_be_cleared() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			assert_noop!(
+				Multisig::set_backup_owners(sovereign(), 0, vec![7, 8], 3, 100),
+				Error::<Test>::InvalidThreshold
+			);
+			assert_noop!(
+				Multisig::set_backup_owners(sovereign(), 0, vec![7, 7], 1, 100),
+				Error::<Test>::DuplicateAccount
+			);
+			assert_noop!(
+				Multisig::clear_backup_owners(sovereign(), 0),
+				Error::<Test>::BackupOwnersNotFound
+			);
+			assert_ok!(Multisig::set_backup_owners(sovereign(), 0, vec![7, 8], 1, 100));
+			assert_ok!(Multisig::clear_backup_owners(sovereign(), 0));
+			assert_noop!(
+				Multisig::activate_backup_owners(RuntimeOrigin::signed(9), 0),
+				Error::<Test>::BackupOwnersNotFound
+			);
+		});
+	}
+}
+
+/// Tests for exporting a wallet's state.
+mod state_export {
+	use super::*;
+	use crate::{WalletSnapshot, SNAPSHOT_VERSION};
+	use codec::{Decode, Encode};
+
+	/// Tests that the snapshot captures the configuration and open proposals and survives a
+	/// round trip through SCALE.
+	#[test]
+	fn snapshot_round_trips() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(3);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let _ = Balances::deposit_creating(&Multisig::multi_account_id(0), 50);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Act
+			let encoded = Multisig::export_state(0).unwrap().encode();
+
+			// Assert
+			let snapshot = WalletSnapshot::<Test>::decode(&mut &encoded[..]).unwrap();
+			assert_eq!(snapshot.version, SNAPSHOT_VERSION);
+			assert_eq!(snapshot.block_number, 3);
+			assert_eq!(snapshot.balance, (50, 0));
+			assert_eq!(snapshot.config.owners.to_vec(), vec![1, 2]);
+			assert_eq!(snapshot.open_proposals.len(), 1);
+			assert_eq!(snapshot.open_proposals[0].1.approvals.to_vec(), vec![1]);
+			assert!(Multisig::export_state(1).is_none());
+		});
+	}
+}
+
+/// Tests for exempting accounts and multisigs from deposits.
+mod deposit_exemptions {
+	use super::*;
+
+	fn enable_deposits() {
+		DEPOSIT_UNIT.with(|unit| *unit.borrow_mut() = 2);
+		for who in [1, 2] {
+			let _ = Balances::deposit_creating(&who, 100);
+		}
+	}
+
+	/// Tests that an exempt account pays no creation or proposal deposit.
+	#[test]
+	fn exempt_account_pays_no_deposits() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			enable_deposits();
+
+			// Act
+			assert_ok!(Multisig::set_account_deposit_exemption(RuntimeOrigin::root(), 1, true));
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Assert: Only the confirmation by the non-exempt owner was charged.
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Balances::reserved_balance(2), 2);
+			System::assert_has_event(
+				Event::AccountDepositExemptionSet { who: 1, exempt: true }.into(),
+			);
+		});
+	}
+
+	/// Tests that nobody pays proposal deposits on an exempt multisig.
+	#[test]
+	fn exempt_multisig_charges_no_proposal_deposits() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			enable_deposits();
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			// Act
+			assert_ok!(Multisig::set_multisig_deposit_exemption(RuntimeOrigin::root(), 0, true));
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(2), 0, Box::new(call)));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(1), 0, 0));
+
+			// Assert: Only the creation deposit, paid before the exemption.
+			assert_eq!(Balances::reserved_balance(1), 4);
+			assert_eq!(Balances::reserved_balance(2), 0);
+		});
+	}
+
+	/// Tests that only the exemption origin can grant exemptions.
+	#[test]
+	fn fails_for_other_origins() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			assert_noop!(
+				Multisig::set_account_deposit_exemption(RuntimeOrigin::signed(1), 1, true),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Multisig::set_multisig_deposit_exemption(RuntimeOrigin::root(), 1, true),
+				Error::<Test>::MultisigNotFound
+			);
+		});
+	}
+}
+
+/// Tests for capping how much a multisig executes per period.
+mod velocity_limits {
+	use super::*;
+	use crate::VelocityLimit;
+
+	fn sovereign() -> RuntimeOrigin {
+		RuntimeOrigin::signed(Multisig::multi_account_id(0))
+	}
+
+	fn propose_and_confirm(call: &RuntimeCall, confirmers: &[u64]) -> u64 {
+		let index = Multisig::next_proposal_index(0);
+		assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call.clone())));
+		for who in confirmers {
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(*who), 0, index));
+		}
+		index
+	}
+
+	fn last_result() -> DispatchResult {
+		match System::events().last().map(|record| record.event.clone()) {
+			Some(RuntimeEvent::Multisig(Event::ProposalExecuted { result, .. })) => result,
+			other => panic!("unexpected event {other:?}"),
+		}
+	}
+
+	/// Tests that executions beyond the cap fail until the period rolls over.
+	#[test]
+	fn caps_executions_per_period() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let limit = VelocityLimit {
+				period: 10,
+				max_executions: Some(1),
+				max_value: None,
+				override_threshold: 3,
+			};
+			assert_ok!(Multisig::set_velocity_limit(sovereign(), 0, Some(limit)));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			// Act & Assert
+			let first = propose_and_confirm(&call, &[2]);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				first,
+				Box::new(call.clone()),
+				Weight::MAX
+			));
+			assert_eq!(last_result(), Ok(()));
+			let second = propose_and_confirm(&call, &[2]);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				second,
+				Box::new(call.clone()),
+				Weight::MAX
+			));
+			assert_eq!(last_result(), Err(Error::<Test>::VelocityLimitExceeded.into()));
+			assert_eq!(
+				Multisig::proposals(0, second).unwrap().status,
+				ProposalStatus::Failed { error: Error::<Test>::VelocityLimitExceeded.into() }
+			);
+			System::set_block_number(11);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				second,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_eq!(last_result(), Ok(()));
+		});
+	}
+
+	/// Tests that spending beyond the cap needs the override threshold.
+	#[test]
+	fn caps_value_unless_overridden() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let _ = Balances::deposit_creating(&Multisig::multi_account_id(0), 1_000);
+			let limit = VelocityLimit {
+				period: 10,
+				max_executions: None,
+				max_value: Some(100),
+				override_threshold: 3,
+			};
+			assert_ok!(Multisig::set_velocity_limit(sovereign(), 0, Some(limit)));
+			let call: RuntimeCall =
+				pallet_balances::Call::transfer_keep_alive { dest: 7, value: 150 }.into();
+
+			// Act & Assert
+			let index = propose_and_confirm(&call, &[2]);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				index,
+				Box::new(call.clone()),
+				Weight::MAX
+			));
+			assert_eq!(last_result(), Err(Error::<Test>::VelocityLimitExceeded.into()));
+			assert_eq!(Balances::free_balance(7), 0);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, index));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				index,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_eq!(last_result(), Ok(()));
+			assert_eq!(Balances::free_balance(7), 150);
+		});
+	}
+
+	/// Tests that the override threshold must lie between the threshold and the owners' total
+	/// weight.
+	#[test]
+	fn fails_with_an_invalid_limit() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let limit = |period, override_threshold| VelocityLimit {
+				period,
+				max_executions: Some(1),
+				max_value: None,
+				override_threshold,
+			};
+
+			for (period, override_threshold) in [(0, 3), (10, 1), (10, 4)] {
+				assert_noop!(
+					Multisig::set_velocity_limit(
+						sovereign(),
+						0,
+						Some(limit(period, override_threshold))
+					),
+					Error::<Test>::InvalidVelocityLimit
+				);
+			}
+		});
+	}
+
+	/// Tests that the override threshold is a vote weight, which may exceed the owner count
+	/// but not the owners' total weight.
+	#[test]
+	fn override_counts_vote_weight() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			assert_ok!(Multisig::set_owner_weight(sovereign(), 0, 1, 3));
+			let limit = VelocityLimit {
+				period: 10,
+				max_executions: Some(0),
+				max_value: None,
+				override_threshold: 4,
+			};
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			// Act & Assert: An override above the owner count is valid, and owner changes
+			// that keep the total weight above it go through.
+			assert_ok!(Multisig::set_velocity_limit(sovereign(), 0, Some(limit)));
+			assert_ok!(Multisig::swap_owner(sovereign(), 0, 3, 4));
+			assert_noop!(
+				Multisig::set_owner_weight(sovereign(), 0, 1, 1),
+				Error::<Test>::InvalidVelocityLimit
+			);
+
+			// Act & Assert: The heavy owner alone meets the threshold but not the override.
+			let index = propose_and_confirm(&call, &[]);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				index,
+				Box::new(call.clone()),
+				Weight::MAX
+			));
+			assert_eq!(last_result(), Err(Error::<Test>::VelocityLimitExceeded.into()));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, index));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				index,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_eq!(last_result(), Ok(()));
+		});
+	}
+
+	/// Tests that funds spent by an operator or from the allowance count towards the value
+	/// cap, and that a spend beyond it is undone.
+	#[test]
+	fn caps_spends_without_a_proposal() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let _ = Balances::deposit_creating(&Multisig::multi_account_id(0), 1_000);
+			let limit = VelocityLimit {
+				period: 10,
+				max_executions: None,
+				max_value: Some(100),
+				override_threshold: 3,
+			};
+			assert_ok!(Multisig::set_velocity_limit(sovereign(), 0, Some(limit)));
+			assert_ok!(Multisig::set_spending_limit(
+				sovereign(),
+				0,
+				Some(crate::SpendingLimit { period: 10, amount: 1_000 })
+			));
+			assert_ok!(Multisig::grant_session(sovereign(), 0, 9, MockSessionScope::Any, 10));
+			let transfer = |value| -> Box<RuntimeCall> {
+				Box::new(pallet_balances::Call::transfer_keep_alive { dest: 7, value }.into())
+			};
+
+			// Act & Assert: Spends within the cap go through.
+			assert_ok!(Multisig::operator_execute(RuntimeOrigin::signed(9), 0, transfer(60)));
+			assert_ok!(Multisig::spend_allowance(RuntimeOrigin::signed(2), 0, 7, 30));
+			assert_eq!(Balances::free_balance(7), 90);
+			assert_eq!(Multisig::execution_window(0).spent, 90);
+
+			// Act & Assert: Spends beyond it fail and are undone.
+			assert_noop!(
+				Multisig::spend_allowance(RuntimeOrigin::signed(2), 0, 7, 20),
+				Error::<Test>::VelocityLimitExceeded
+			);
+			assert_ok!(Multisig::operator_execute(RuntimeOrigin::signed(9), 0, transfer(20)));
+			System::assert_last_event(
+				Event::OperatorExecuted {
+					multisig_id: 0,
+					operator: 9,
+					result: Err(Error::<Test>::VelocityLimitExceeded.into()),
+				}
+				.into(),
+			);
+			assert_eq!(Balances::free_balance(7), 90);
+		});
+	}
+}
+
+/// Tests for nomination-pool proposals.
+mod pool_proposals {
+	use super::*;
+	use crate::TypedProposal;
+
+	/// Tests that bonding nothing into a pool is rejected.
+	#[test]
+	fn fails_to_bond_nothing() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			for proposal in [
+				TypedProposal::JoinPool { pool_id: 1, amount: 0 },
+				TypedProposal::BondExtraToPool { amount: 0 },
+			] {
+				assert_noop!(
+					Multisig::submit_typed_proposal(RuntimeOrigin::signed(1), 0, proposal),
+					Error::<Test>::InvalidPoolBond
+				);
+			}
+		});
+	}
+
+	/// Tests that pool proposals are refused by a runtime without nomination pools.
+	#[test]
+	fn fails_without_a_pools_pallet() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let _ = Balances::deposit_creating(&Multisig::multi_account_id(0), 100);
+
+			assert_noop!(
+				Multisig::submit_typed_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					TypedProposal::JoinPool { pool_id: 1, amount: 50 }
+				),
+				Error::<Test>::UnsupportedProposal
+			);
+			assert_noop!(
+				Multisig::submit_typed_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					TypedProposal::ClaimPoolPayout
+				),
+				Error::<Test>::UnsupportedProposal
+			);
+		});
+	}
+}
+
+/// Tests for bounty proposals.
+mod bounty_proposals {
+	use super::*;
+	use crate::TypedProposal;
+	use codec::Encode;
+
+	/// Tests that a bounty award is submitted, confirmed and executed by the sovereign account.
+	#[test]
+	fn it_awards_a_bounty() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let proposal = TypedProposal::AwardBounty { bounty_id: 3, beneficiary: 4 };
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				proposal.clone()
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			let remark = proposal.encode();
+			let call: RuntimeCall =
+				frame_system::Call::remark_with_event { remark: remark.clone() }.into();
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+			System::assert_has_event(
+				frame_system::Event::Remarked {
+					sender: Multisig::multi_account_id(0),
+					hash: BlakeTwo256::hash(&remark),
+				}
+				.into(),
+			);
+		});
+	}
+}
+
+/// Tests for session key proposals.
+mod session_key_proposals {
+	use super::*;
+	use crate::TypedProposal;
+
+	/// Tests that keys of the runtime's session key length are accepted.
+	#[test]
+	fn it_submits_keys_of_the_right_length() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			assert_ok!(Multisig::submit_typed_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				TypedProposal::SetSessionKeys {
+					keys: BoundedVec::truncate_from(vec![7; 64]),
+					proof: BoundedVec::new(),
+				}
+			));
+
+			assert!(Multisig::proposals(0, 0).is_some());
+		});
+	}
+
+	/// Tests that key material of the wrong length is rejected.
+	#[test]
+	fn fails_for_keys_of_the_wrong_length() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			for len in [0, 32, 65] {
+				assert_noop!(
+					Multisig::submit_typed_proposal(
+						RuntimeOrigin::signed(1),
+						0,
+						TypedProposal::SetSessionKeys {
+							keys: BoundedVec::truncate_from(vec![7; len]),
+							proof: BoundedVec::new(),
+						}
+					),
+					Error::<Test>::InvalidSessionKeys
+				);
+			}
+		});
+	}
+}
+
+/// Tests for the signing nonce.
+mod signing_nonce {
+	use super::*;
+
+	/// Tests that bumping the nonce drops the outstanding pre-approvals.
+	#[test]
+	fn bump_invalidates_pre_approvals() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			let call_hash = Multisig::call_hash(&call);
+			assert_ok!(Multisig::pre_approve(RuntimeOrigin::signed(2), 0, call_hash));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+
+			// Act
+			assert_ok!(Multisig::bump_signing_nonce(sovereign, 0));
+
+			// Assert
+			assert_eq!(Multisig::signing_nonce(0), 1);
+			System::assert_last_event(
+				Event::SigningNonceBumped { multisig_id: 0, nonce: 1 }.into(),
+			);
+			assert!(Multisig::pre_approvals(0, call_hash).is_empty());
+			assert_eq!(Multisig::pre_approval_count(0), 0);
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			assert_eq!(Multisig::approvals(0, 0).to_vec(), vec![1]);
+		});
+	}
+
+	/// Tests that only the sovereign account can bump the nonce.
+	#[test]
+	fn fails_for_other_origins() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			assert_noop!(
+				Multisig::bump_signing_nonce(RuntimeOrigin::signed(1), 0),
+				Error::<Test>::MustBeMultisig
+			);
+		});
+	}
+}
+
+/// Tests for the `confirm_proposal_with_signature` extrinsic.
+mod signed_confirmations {
+	use super::*;
+	use sp_runtime::testing::TestSignature;
+
+	/// Tests that a confirmation an owner signed off-chain is counted when anyone relays it.
+	#[test]
+	fn relayed_confirmation_is_counted() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			let payload = Multisig::confirmation_payload(0, 0).unwrap();
+
+			// Act: Account 9, which is not an owner, relays owner 2's signature.
+			assert_ok!(Multisig::confirm_proposal_with_signature(
+				RuntimeOrigin::signed(9),
+				0,
+				0,
+				2,
+				TestSignature(2, payload)
+			));
+
+			// Assert
+			assert_eq!(Multisig::approvals(0, 0).to_vec(), vec![1, 2]);
+			System::assert_has_event(
+				Event::Confirmation { who: 2, multisig_id: 0, proposal_index: 0 }.into(),
+			);
+			System::assert_last_event(
+				Event::SignedConfirmationRelayed {
+					multisig_id: 0,
+					proposal_index: 0,
+					owner: 2,
+					relayer: 9,
+				}
+				.into(),
+			);
+		});
+	}
+
+	/// Tests that a signature by another account, or over another payload, is refused.
+	#[test]
+	fn fails_for_an_invalid_signature() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			let payload = Multisig::confirmation_payload(0, 0).unwrap();
+
+			assert_noop!(
+				Multisig::confirm_proposal_with_signature(
+					RuntimeOrigin::signed(9),
+					0,
+					0,
+					2,
+					TestSignature(3, payload)
+				),
+				Error::<Test>::InvalidSignature
+			);
+			assert_noop!(
+				Multisig::confirm_proposal_with_signature(
+					RuntimeOrigin::signed(9),
+					0,
+					0,
+					2,
+					TestSignature(2, b"another payload".to_vec())
+				),
+				Error::<Test>::InvalidSignature
+			);
+			assert_noop!(
+				Multisig::confirm_proposal_with_signature(
+					RuntimeOrigin::signed(9),
+					0,
+					1,
+					2,
+					TestSignature(2, vec![])
+				),
+				Error::<Test>::ProposalNotFound
+			);
+		});
+	}
+}
+
+/// Tests for the cap on active multisigs.
+mod wallet_cap {
+	use super::*;
+	use frame_support::sp_runtime::DispatchError;
+
+	/// Tests that creation fails once the cap is reached and succeeds again after a wallet is
+	/// destroyed.
+	#[test]
+	fn creation_is_capped() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::set_max_active_multisigs(RuntimeOrigin::root(), Some(1)));
+			System::assert_last_event(Event::MaxActiveMultisigsSet { max: Some(1) }.into());
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 1, None));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 1, None),
+				Error::<Test>::TooManyMultisigs
+			);
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::destroy_multisig(sovereign, 0));
+			assert_eq!(Multisig::active_multisig_count(), 0);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 1, None));
+			assert_eq!(Multisig::active_multisig_count(), 1);
+		});
+	}
+
+	/// Tests that only the configured origin can set the cap.
+	#[test]
+	fn fails_for_other_origins() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Multisig::set_max_active_multisigs(RuntimeOrigin::signed(1), Some(1)),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+}
+
+/// Tests for the canonical off-chain signing payloads.
+mod signing_payload {
+	use super::*;
+	use codec::Encode;
+
+	/// Tests that the confirmation payload commits to the domain, chain, multisig, nonce and
+	/// proposal, and changes when the nonce is bumped.
+	#[test]
+	fn commits_to_domain_and_nonce() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			let call_hash = Multisig::call_hash(&call);
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			let genesis_hash = System::block_hash(0);
+			let target =
+				crate::ApprovalTargetOf::<Test>::Confirmation { proposal_index: 0, call_hash };
+
+			// Act
+			let payload = Multisig::confirmation_payload(0, 0);
+
+			// Assert
+			assert_eq!(
+				payload,
+				Some((crate::SIGNING_DOMAIN, genesis_hash, 0u32, 0u64, target.clone()).encode())
+			);
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::bump_signing_nonce(sovereign, 0));
+			assert_eq!(
+				Multisig::confirmation_payload(0, 0),
+				Some((crate::SIGNING_DOMAIN, genesis_hash, 0u32, 1u64, target).encode())
+			);
+		});
+	}
+
+	/// Tests that a signature over the confirmation payload is accepted, and that one made
+	/// before a nonce bump is refused.
+	#[test]
+	fn signature_over_payload_is_refused_after_a_bump() {
+		new_test_ext().execute_with(|| {
+			// Arrange: Owners 2 and 3 sign the confirmation payload of a 3-of-3 proposal.
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 3, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			let payload = Multisig::confirmation_payload(0, 0).unwrap();
+			let signature = |owner| sp_runtime::testing::TestSignature(owner, payload.clone());
+
+			// Act & Assert: Owner 2's signature is accepted.
+			assert_ok!(Multisig::confirm_proposal_with_signature(
+				RuntimeOrigin::signed(9),
+				0,
+				0,
+				2,
+				signature(2)
+			));
+			assert_eq!(Multisig::approvals(0, 0).to_vec(), vec![1, 2]);
+
+			// Owner 3's signature no longer verifies once the nonce has been bumped.
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::bump_signing_nonce(sovereign, 0));
+			assert_noop!(
+				Multisig::confirm_proposal_with_signature(
+					RuntimeOrigin::signed(9),
+					0,
+					0,
+					3,
+					signature(3)
+				),
+				Error::<Test>::InvalidSignature
+			);
+			let payload = Multisig::confirmation_payload(0, 0).unwrap();
+			assert_ok!(Multisig::confirm_proposal_with_signature(
+				RuntimeOrigin::signed(9),
+				0,
+				0,
+				3,
+				sp_runtime::testing::TestSignature(3, payload)
+			));
+			assert_eq!(Multisig::approvals(0, 0).to_vec(), vec![1, 2, 3]);
+		});
+	}
+
+	/// Tests that no payload is given for a missing multisig or proposal.
+	#[test]
+	fn none_when_missing() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			assert_eq!(Multisig::confirmation_payload(0, 0), None);
+			assert_eq!(
+				Multisig::signing_payload(
+					1,
+					crate::ApprovalTarget::Confirmation {
+						proposal_index: 0,
+						call_hash: Default::default()
+					}
+				),
+				None
+			);
+		});
+	}
+}
+
+/// Tests for the per-multisig usage meters.
+mod usage_meter {
+	use super::*;
+	use crate::weight::WeightInfo;
+	use frame_support::dispatch::GetDispatchInfo;
+
+	/// Tests that submissions, confirmations and executions are metered with their weight, for
+	/// the multisig's actual owners and approvals, and the fees it is estimated at.
+	#[test]
+	fn meters_each_operation() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			let expected_weight = <() as WeightInfo>::submit_proposal()
+				.saturating_add(<() as WeightInfo>::confirm_proposal(2, 2))
+				.saturating_add(<() as WeightInfo>::execute_proposal(2, 2))
+				.saturating_add(call.get_dispatch_info().call_weight);
+
+			// Act
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			let meter = Multisig::usage_meter(0);
+			assert_eq!((meter.submissions, meter.confirmations, meter.executions), (1, 1, 1));
+			assert_eq!(meter.weight, expected_weight);
+			assert_eq!(meter.fees, expected_weight.ref_time() as u128);
+		});
+	}
+
+	/// Tests that failed operations are not metered.
+	#[test]
+	fn failed_operations_are_not_metered() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			assert_noop!(
+				Multisig::submit_proposal(RuntimeOrigin::signed(3), 0, Box::new(call)),
+				Error::<Test>::NotAnOwner
+			);
+
+			assert_eq!(Multisig::usage_meter(0), Default::default());
+		});
+	}
+}
+
+/// Tests for uploading large calls in chunks.
+mod chunked_upload {
+	use super::*;
+	use codec::Encode;
+	use frame_support::dispatch::{DispatchClass, GetDispatchInfo};
+
+	fn chunk(bytes: &[u8]) -> crate::CallDataOf<Test> {
+		BoundedVec::truncate_from(bytes.to_vec())
+	}
+
+	/// Tests that a call uploaded in chunks can be proposed and executed from its stored
+	/// bytes, refunding the upload deposit.
+	#[test]
+	fn upload_propose_and_execute() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			let _ = Balances::deposit_creating(&1, 1_000);
+			let _ = Balances::deposit_creating(&2, 1_000);
+			DEPOSIT_UNIT.with(|unit| *unit.borrow_mut() = 1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![7; 40] }.into();
+			let encoded = call.encode();
+			let (first, second) = encoded.split_at(20);
+
+			// Act
+			assert_ok!(Multisig::note_call_chunk(RuntimeOrigin::signed(1), 0, chunk(first)));
+			assert_ok!(Multisig::note_call_chunk(RuntimeOrigin::signed(1), 0, chunk(second)));
+			System::assert_last_event(
+				Event::CallChunkNoted { multisig_id: 0, who: 1, len: encoded.len() as u32 }.into(),
+			);
+			assert_ok!(Multisig::finalize_call(RuntimeOrigin::signed(1), 0));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::execute_uploaded_proposal(
+				RuntimeOrigin::signed(9),
+				0,
+				0,
+				Weight::MAX,
+				DispatchClass::Normal
+			));
+
+			// Assert
+			assert_eq!(Multisig::proposals(0, 0).unwrap().call_hash, Multisig::call_hash(&call));
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+			assert!(Multisig::uploaded_calls(0, 0).is_none());
+			// Only the creation deposit remains reserved.
+			assert_eq!(Balances::reserved_balance(1), 2);
+		});
+	}
+
+	/// Tests that an uploaded call is only executed with a `max_weight` covering its weight and
+	/// its own dispatch class, in which it is weighed up front.
+	#[test]
+	fn fails_if_max_weight_is_too_low() {
+		new_test_ext().execute_with(|| {
+			// Arrange: An operational call, proposed with its stored bytes.
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::set_heap_pages { pages: 64 }.into();
+			let call_weight = call.get_dispatch_info().call_weight;
+			assert_ok!(Multisig::submit_proposal_with_preimage(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call)
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			let info = RuntimeCall::Multisig(crate::Call::execute_uploaded_proposal {
+				multisig_id: 0,
+				proposal_index: 0,
+				max_weight: call_weight,
+				class: DispatchClass::Operational,
+			})
+			.get_dispatch_info();
+			assert_eq!(info.class, DispatchClass::Operational);
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::execute_uploaded_proposal(
+					RuntimeOrigin::signed(9),
+					0,
+					0,
+					call_weight.saturating_sub(Weight::from_parts(1, 0)),
+					DispatchClass::Operational
+				),
+				Error::<Test>::MaxWeightTooLow
+			);
+			assert_noop!(
+				Multisig::execute_uploaded_proposal(
+					RuntimeOrigin::signed(9),
+					0,
+					0,
+					call_weight,
+					DispatchClass::Normal
+				),
+				Error::<Test>::WrongDispatchClass
+			);
+			assert_ok!(Multisig::execute_uploaded_proposal(
+				RuntimeOrigin::signed(9),
+				0,
+				0,
+				call_weight,
+				DispatchClass::Operational
+			));
+		});
+	}
+
+	/// Tests that uploads are bounded, must decode, and can be discarded for a refund.
+	#[test]
+	fn upload_is_validated_and_can_be_discarded() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let _ = Balances::deposit_creating(&1, 2_000);
+			DEPOSIT_UNIT.with(|unit| *unit.borrow_mut() = 1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::note_call_chunk(RuntimeOrigin::signed(3), 0, chunk(&[1])),
+				Error::<Test>::NotAnOwner
+			);
+			assert_ok!(Multisig::note_call_chunk(
+				RuntimeOrigin::signed(1),
+				0,
+				chunk(&[255; 1_000])
+			));
+			assert_noop!(
+				Multisig::note_call_chunk(RuntimeOrigin::signed(1), 0, chunk(&[255; 25])),
+				Error::<Test>::CallTooLarge
+			);
+			assert_noop!(
+				Multisig::finalize_call(RuntimeOrigin::signed(1), 0),
+				Error::<Test>::UndecodableCall
+			);
+			assert_eq!(Balances::reserved_balance(1), 1_002);
+			assert_ok!(Multisig::discard_call_upload(RuntimeOrigin::signed(1), 0));
+			assert_eq!(Balances::reserved_balance(1), 2);
+			assert_noop!(
+				Multisig::discard_call_upload(RuntimeOrigin::signed(1), 0),
+				Error::<Test>::CallUploadNotFound
+			);
+		});
+	}
+}
+
+/// Tests for the unanimity required by critical calls.
+mod critical_calls {
+	use super::*;
+
+	fn set_code() -> RuntimeCall {
+		frame_system::Call::set_code { code: vec![] }.into()
+	}
+
+	/// Tests that a critical call is refused at execution unless every owner approved it.
+	#[test]
+	fn critical_call_requires_every_owner() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(set_code())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act & Assert: The threshold is met, but not every owner has approved.
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(set_code()),
+					Weight::MAX
+				),
+				Error::<Test>::UnanimityRequired
+			);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(set_code()),
+				Weight::MAX
+			));
+			// The call is dispatched, and then refused by the system pallet as it needs root.
+			System::assert_last_event(
+				Event::ProposalExecuted {
+					multisig_id: 0,
+					proposal_index: 0,
+					result: Err(sp_runtime::DispatchError::BadOrigin),
+				}
+				.into(),
+			);
+		});
+	}
+
+	/// Tests that ordinary calls still only need the threshold.
+	#[test]
+	fn ordinary_call_needs_only_the_threshold() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+		});
+	}
+}
+
+/// Tests for managing the owners of a multisig.
+mod owner_management {
+	use super::*;
+
+	fn sovereign() -> RuntimeOrigin {
+		RuntimeOrigin::signed(Multisig::multi_account_id(0))
+	}
+
+	/// Tests that owners can be added, removed and swapped by the sovereign account.
+	#[test]
+	fn add_remove_and_swap() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			// Act & Assert
+			assert_ok!(Multisig::add_owner(sovereign(), 0, 3, None));
+			System::assert_last_event(
+				Event::OwnerAdded { multisig_id: 0, owner: 3, threshold: 2 }.into(),
+			);
+			assert_ok!(Multisig::swap_owner(sovereign(), 0, 2, 4));
+			System::assert_last_event(
+				Event::OwnerSwapped { multisig_id: 0, old: 2, new: 4 }.into(),
+			);
+			assert_ok!(Multisig::remove_owner(sovereign(), 0, 1, Some(1)));
+			System::assert_last_event(
+				Event::OwnerRemoved { multisig_id: 0, owner: 1, threshold: 1 }.into(),
+			);
+			let multisig = Multisig::multisigs(0).unwrap();
+			assert_eq!(multisig.owners.to_vec(), vec![4, 3]);
+			assert_eq!(multisig.threshold, 1);
+		});
+	}
+
+	/// Tests that changes leaving the threshold unreachable, or duplicating owners, are
+	/// refused.
+	#[test]
+	fn invalid_changes_fail() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+
+			assert_noop!(
+				Multisig::remove_owner(sovereign(), 0, 2, None),
+				Error::<Test>::InvalidThreshold
+			);
+			assert_noop!(
+				Multisig::add_owner(sovereign(), 0, 3, Some(4)),
+				Error::<Test>::InvalidThreshold
+			);
+			assert_noop!(
+				Multisig::add_owner(sovereign(), 0, 2, None),
+				Error::<Test>::DuplicateAccount
+			);
+			assert_noop!(
+				Multisig::swap_owner(sovereign(), 0, 1, 2),
+				Error::<Test>::DuplicateAccount
+			);
+			assert_noop!(
+				Multisig::remove_owner(sovereign(), 0, 3, None),
+				Error::<Test>::NotAnOwner
+			);
+			assert_noop!(
+				Multisig::add_owner(RuntimeOrigin::signed(1), 0, 3, None),
+				Error::<Test>::MustBeMultisig
+			);
+		});
+	}
+
+	/// Tests that a removed owner's approvals stop counting and their label and suspension
+	/// are dropped.
+	#[test]
+	fn removed_owner_is_forgotten() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(3),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::set_owner_label(
+				sovereign(),
+				0,
+				3,
+				Some(BoundedVec::truncate_from(b"laptop".to_vec()))
+			));
+			assert_ok!(Multisig::suspend_owner(sovereign(), 0, 3));
+
+			// Act
+			assert_ok!(Multisig::remove_owner(sovereign(), 0, 3, None));
+
+			// Assert
+			assert!(Multisig::owner_label(0, 3).is_none());
+			assert!(Multisig::suspended_owners(0).is_empty());
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call),
+					Weight::MAX
+				),
+				Error::<Test>::NotEnoughApprovals
+			);
+		});
+	}
+}
+
+/// Tests for withdrawing approvals.
+mod revoke_approval {
+	use super::*;
+
+	/// Tests that a revoked approval no longer counts and its deposit is refunded.
+	#[test]
+	fn revoked_approval_stops_counting() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			let _ = Balances::deposit_creating(&1, 100);
+			let _ = Balances::deposit_creating(&2, 100);
+			DEPOSIT_UNIT.with(|unit| *unit.borrow_mut() = 1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_eq!(Balances::reserved_balance(2), 1);
+
+			// Act
+			assert_ok!(Multisig::revoke_approval(RuntimeOrigin::signed(2), 0, 0));
+
+			// Assert
+			System::assert_last_event(
+				Event::ApprovalRevoked { who: 2, multisig_id: 0, proposal_index: 0 }.into(),
+			);
+			assert_eq!(Multisig::approvals(0, 0).to_vec(), vec![1]);
+			assert_eq!(Balances::reserved_balance(2), 0);
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call),
+					Weight::MAX
+				),
+				Error::<Test>::NotEnoughApprovals
+			);
+		});
+	}
+
+	/// Tests that only a pending approval can be revoked.
+	#[test]
+	fn fails_without_pending_approval() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 1, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+
+			assert_noop!(
+				Multisig::revoke_approval(RuntimeOrigin::signed(2), 0, 0),
+				Error::<Test>::NotApproved
+			);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_noop!(
+				Multisig::revoke_approval(RuntimeOrigin::signed(1), 0, 0),
+				Error::<Test>::AlreadyExecuted
+			);
+		});
+	}
+}
+
+/// Tests for cancelling proposals.
+mod cancel_proposal {
+	use super::*;
+
+	/// Tests that the proposer can cancel a pending proposal, which is removed with its
+	/// deposits refunded.
+	#[test]
+	fn proposer_cancels() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			let _ = Balances::deposit_creating(&1, 100);
+			let _ = Balances::deposit_creating(&2, 100);
+			DEPOSIT_UNIT.with(|unit| *unit.borrow_mut() = 1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 3, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::cancel_proposal(RuntimeOrigin::signed(1), 0, 0));
+
+			// Assert
+			System::assert_last_event(
+				Event::ProposalCancelled { multisig_id: 0, proposal_index: 0, who: 1 }.into(),
+			);
+			assert!(Multisig::proposals(0, 0).is_none());
+			assert_eq!(Multisig::pending_proposal_count(0), 0);
+			// Only the creation deposit remains reserved.
+			assert_eq!(Balances::reserved_balance(1), 3);
+			assert_eq!(Balances::reserved_balance(2), 0);
+		});
+	}
+
+	/// Tests that only the proposer may cancel, and only before execution.
+	#[test]
+	fn only_proposer_before_execution() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 1, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+
+			assert_noop!(
+				Multisig::cancel_proposal(RuntimeOrigin::signed(2), 0, 0),
+				Error::<Test>::NotProposer
+			);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_noop!(
+				Multisig::cancel_proposal(RuntimeOrigin::signed(1), 0, 0),
+				Error::<Test>::AlreadyExecuted
+			);
+		});
+	}
+}
+
+/// Tests for proposal expiry.
+mod expiry {
+	use super::*;
+
+	/// Tests that an expired proposal can no longer be confirmed or executed, and can then be
+	/// removed by anyone.
+	#[test]
+	fn expired_proposal_is_closed_and_removable() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_eq!(Multisig::proposals(0, 0).unwrap().expires_at, 1_001);
+			assert_noop!(
+				Multisig::remove_expired_proposal(RuntimeOrigin::signed(9), 0, 0),
+				Error::<Test>::NotExpired
+			);
+
+			// Act
+			System::set_block_number(1_002);
+
+			// Assert
+			assert_noop!(
+				Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0),
+				Error::<Test>::Expired
+			);
+			assert_ok!(Multisig::remove_expired_proposal(RuntimeOrigin::signed(9), 0, 0));
+			System::assert_last_event(
+				Event::ProposalExpired { multisig_id: 0, proposal_index: 0 }.into(),
+			);
+			assert!(Multisig::proposals(0, 0).is_none());
+			assert_eq!(Multisig::pending_proposal_count(0), 0);
+		});
+	}
+
+	/// Tests that a proposer can choose a proposal's lifetime, after which it cannot be
+	/// executed.
+	#[test]
+	fn custom_lifetime() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 1, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_noop!(
+				Multisig::submit_proposal_with_lifetime(
+					RuntimeOrigin::signed(1),
+					0,
+					Box::new(call.clone()),
+					0
+				),
+				Error::<Test>::InvalidLifetime
+			);
+
+			// Act
+			assert_ok!(Multisig::submit_proposal_with_lifetime(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone()),
+				5
+			));
+			System::set_block_number(7);
+
+			// Assert
+			assert_eq!(Multisig::proposals(0, 0).unwrap().expires_at, 6);
+			assert!(Multisig::executable_proposals(0).is_empty());
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call),
+					Weight::MAX
+				),
+				Error::<Test>::Expired
+			);
+		});
+	}
+
+	/// Tests that whoever removes an expired proposal is paid the configured share of the
+	/// proposer's deposit, and every other deposit is refunded.
+	#[test]
+	fn removal_slashes_proposer_deposit() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			for account in [1, 2, 9] {
+				let _ = Balances::deposit_creating(&account, 100);
+			}
+			DEPOSIT_UNIT.with(|unit| *unit.borrow_mut() = 10);
+			EXPIRED_DEPOSIT_SLASH
+				.with(|slash| *slash.borrow_mut() = sp_runtime::Perbill::from_percent(50));
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 3, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			System::set_block_number(1_002);
+
+			// Act
+			assert_ok!(Multisig::remove_expired_proposal(RuntimeOrigin::signed(9), 0, 0));
+
+			// Assert
+			System::assert_has_event(
+				Event::DepositSlashed {
+					multisig_id: 0,
+					proposal_index: 0,
+					who: 1,
+					beneficiary: 9,
+					amount: 5,
+				}
+				.into(),
+			);
+			assert_eq!(Balances::free_balance(9), 105);
+			// Only the creation deposit remains reserved.
+			assert_eq!(Balances::reserved_balance(1), 30);
+			assert_eq!(Balances::free_balance(1), 65);
+			assert_eq!(Balances::reserved_balance(2), 0);
+		});
+	}
+}
+
+/// Tests for voting against proposals.
+mod rejection {
+	use super::*;
+
+	/// Tests that a proposal is removed once enough owners vote against it that it can no
+	/// longer pass.
+	#[test]
+	fn rejected_once_threshold_is_out_of_reach() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Act
+			assert_ok!(Multisig::reject_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Assert
+			System::assert_last_event(
+				Event::Rejection { who: 2, multisig_id: 0, proposal_index: 0 }.into(),
+			);
+			assert_eq!(Multisig::proposals(0, 0).unwrap().rejections.to_vec(), vec![2]);
+
+			// Act
+			assert_ok!(Multisig::reject_proposal(RuntimeOrigin::signed(3), 0, 0));
+
+			// Assert
+			System::assert_last_event(
+				Event::ProposalRejected { multisig_id: 0, proposal_index: 0 }.into(),
+			);
+			assert!(Multisig::proposals(0, 0).is_none());
+			assert_eq!(Multisig::pending_proposal_count(0), 0);
+		});
+	}
+
+	/// Tests that an owner who approved a proposal can vote against it instead, getting their
+	/// approval deposit back.
+	#[test]
+	fn approver_switches_to_nay() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let _ = Balances::deposit_creating(&1, 100);
+			let _ = Balances::deposit_creating(&2, 100);
+			DEPOSIT_UNIT.with(|unit| *unit.borrow_mut() = 1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 3, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::reject_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Assert
+			// Unanimity is now impossible, so a single nay rejects the proposal.
+			assert!(Multisig::proposals(0, 0).is_none());
+			assert_eq!(Balances::reserved_balance(2), 0);
+			// Only the creation deposit remains reserved.
+			assert_eq!(Balances::reserved_balance(1), 3);
+		});
+	}
+
+	/// Tests that an owner cannot vote against a proposal twice, but may confirm it instead.
+	#[test]
+	fn nay_can_be_changed_to_aye() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(
+				RuntimeOrigin::signed(1),
+				vec![1, 2, 3, 4],
+				2,
+				None
+			));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			assert_ok!(Multisig::reject_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::reject_proposal(RuntimeOrigin::signed(2), 0, 0),
+				Error::<Test>::AlreadyRejected
+			);
+			assert_noop!(
+				Multisig::reject_proposal(RuntimeOrigin::signed(5), 0, 0),
+				Error::<Test>::NotAnOwner
+			);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			let proposal = Multisig::proposals(0, 0).unwrap();
+			assert!(proposal.rejections.is_empty());
+			assert_eq!(proposal.approvals.to_vec(), vec![1, 2]);
+		});
+	}
+}
+
+/// Tests for storing proposals' calls on chain.
+mod preimage {
+	use super::*;
+	use codec::Encode;
+	use frame_support::dispatch::DispatchClass;
+
+	/// Tests that a proposal submitted with its call can be executed by anyone without
+	/// resending it, refunding the byte deposit.
+	#[test]
+	fn submit_and_execute_from_preimage() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			let _ = Balances::deposit_creating(&1, 1_000);
+			let _ = Balances::deposit_creating(&2, 1_000);
+			DEPOSIT_UNIT.with(|unit| *unit.borrow_mut() = 1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![7; 40] }.into();
+			let len = call.encode().len() as u128;
+
+			// Act
+			assert_ok!(Multisig::submit_proposal_with_preimage(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+
+			// Assert
+			assert_eq!(Multisig::uploaded_calls(0, 0).unwrap().data.to_vec(), call.encode());
+			// The creation, submission and byte deposits are reserved.
+			assert_eq!(Balances::reserved_balance(1), 2 + 1 + len);
+
+			// Act
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::execute_uploaded_proposal(
+				RuntimeOrigin::signed(9),
+				0,
+				0,
+				Weight::MAX,
+				DispatchClass::Normal
+			));
+
+			// Assert
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+			assert!(Multisig::uploaded_calls(0, 0).is_none());
+			assert_eq!(Balances::reserved_balance(1), 2);
+		});
+	}
+
+	/// Tests that calls larger than `MaxCallSize` cannot be stored.
+	#[test]
+	fn call_too_large() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![7; 2_000] }.into();
+
+			assert_noop!(
+				Multisig::submit_proposal_with_preimage(
+					RuntimeOrigin::signed(1),
+					0,
+					Box::new(call)
+				),
+				Error::<Test>::CallTooLarge
+			);
+		});
+	}
+}
+
+/// Tests for confirming several proposals at once.
+mod batch_confirm {
+	use super::*;
+
+	/// Tests that a batch confirms every proposal it can, reporting the ones it cannot.
+	#[test]
+	fn confirms_each_and_reports_failures() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 3, None));
+			for remark in [vec![0], vec![1], vec![2]] {
+				let call: RuntimeCall = frame_system::Call::remark { remark }.into();
+				assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			}
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 1));
+
+			// Act
+			assert_ok!(Multisig::batch_confirm(
+				RuntimeOrigin::signed(2),
+				0,
+				BoundedVec::truncate_from(vec![0, 1, 2, 7])
+			));
+
+			// Assert
+			assert_eq!(Multisig::proposals(0, 0).unwrap().approvals.to_vec(), vec![1, 2]);
+			assert_eq!(Multisig::proposals(0, 2).unwrap().approvals.to_vec(), vec![1, 2]);
+			System::assert_has_event(
+				Event::Confirmation { who: 2, multisig_id: 0, proposal_index: 2 }.into(),
+			);
+			System::assert_has_event(
+				Event::BatchConfirmationFailed {
+					who: 2,
+					multisig_id: 0,
+					proposal_index: 1,
+					error: Error::<Test>::AlreadyConfirmed.into(),
+				}
+				.into(),
+			);
+			System::assert_last_event(
+				Event::BatchConfirmationFailed {
+					who: 2,
+					multisig_id: 0,
+					proposal_index: 7,
+					error: Error::<Test>::ProposalNotFound.into(),
+				}
+				.into(),
+			);
+		});
+	}
+}
+
+/// Tests for executing several proposals at once.
+mod batch_execute {
+	use super::*;
+	use frame_support::dispatch::{DispatchClass, GetDispatchInfo};
+
+	/// Tests that a batch executes every approved proposal, reporting the ones it cannot.
+	#[test]
+	fn executes_each_and_reports_failures() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let calls: Vec<RuntimeCall> = (0..3u8)
+				.map(|i| frame_system::Call::remark { remark: vec![i] }.into())
+				.collect();
+			for call in &calls {
+				assert_ok!(Multisig::submit_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					Box::new(call.clone())
+				));
+			}
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 2));
+
+			// Act
+			assert_ok!(Multisig::batch_execute(
+				RuntimeOrigin::signed(9),
+				0,
+				BoundedVec::truncate_from(vec![
+					(0, Box::new(calls[0].clone())),
+					(1, Box::new(calls[1].clone())),
+					(2, Box::new(calls[0].clone())),
+				])
+			));
+
+			// Assert
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+			assert_eq!(Multisig::proposals(0, 1).unwrap().status, ProposalStatus::Pending);
+			assert_eq!(Multisig::proposals(0, 2).unwrap().status, ProposalStatus::Pending);
+			System::assert_has_event(
+				Event::ProposalExecuted { multisig_id: 0, proposal_index: 0, result: Ok(()) }
+					.into(),
+			);
+			System::assert_has_event(
+				Event::BatchExecutionFailed {
+					multisig_id: 0,
+					proposal_index: 1,
+					error: Error::<Test>::NotEnoughApprovals.into(),
+				}
+				.into(),
+			);
+			System::assert_last_event(
+				Event::BatchExecutionFailed {
+					multisig_id: 0,
+					proposal_index: 2,
+					error: Error::<Test>::CallHashMismatch.into(),
+				}
+				.into(),
+			);
+		});
+	}
+
+	/// Tests that a batch is weighed in the highest dispatch class among its calls.
+	#[test]
+	fn batch_takes_the_highest_class_of_its_calls() {
+		// Arrange
+		let remark: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		let heap_pages: RuntimeCall = frame_system::Call::set_heap_pages { pages: 64 }.into();
+		let weigh = |calls: Vec<RuntimeCall>| {
+			RuntimeCall::Multisig(crate::Call::batch_execute {
+				multisig_id: 0,
+				proposals: BoundedVec::truncate_from(
+					calls
+						.into_iter()
+						.enumerate()
+						.map(|(i, call)| (i as u64, Box::new(call)))
+						.collect(),
+				),
+			})
+			.get_dispatch_info()
+			.class
+		};
+
+		// Act & Assert
+		assert_eq!(weigh(vec![remark.clone(), remark.clone()]), DispatchClass::Normal);
+		assert_eq!(weigh(vec![remark, heap_pages]), DispatchClass::Operational);
+	}
+}
+
+/// Tests for weighted owner votes.
+mod weighted_votes {
+	use super::*;
+
+	/// Tests that an owner with a weight of two counts as two approvals.
+	#[test]
+	fn heavy_owner_counts_double() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_weighted_multisig(
+				RuntimeOrigin::signed(1),
+				vec![(1, 2), (2, 1), (3, 1)],
+				3
+			));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call.clone()),
+					Weight::MAX
+				),
+				Error::<Test>::NotEnoughApprovals
+			);
+
+			// Act
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+
+			// Assert
+			assert_eq!(Multisig::vote_weight(0, &1), 2);
+			assert_eq!(Multisig::vote_weight(0, &3), 1);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+		});
+	}
+
+	/// Tests that weights must be positive and able to meet the threshold.
+	#[test]
+	fn creation_is_validated() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Multisig::create_weighted_multisig(
+					RuntimeOrigin::signed(1),
+					vec![(1, 0), (2, 1)],
+					1
+				),
+				Error::<Test>::InvalidVoteWeight
+			);
+			assert_noop!(
+				Multisig::create_weighted_multisig(
+					RuntimeOrigin::signed(1),
+					vec![(1, 2), (2, 1)],
+					4
+				),
+				Error::<Test>::InvalidThreshold
+			);
+			assert_noop!(
+				Multisig::create_weighted_multisig(
+					RuntimeOrigin::signed(1),
+					vec![(1, 2), (1, 1)],
+					2
+				),
+				Error::<Test>::DuplicateAccount
+			);
+		});
+	}
+
+	/// Tests that the sovereign account can change an owner's weight, as long as the threshold
+	/// stays reachable.
+	#[test]
+	fn sovereign_sets_weight() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_weighted_multisig(
+				RuntimeOrigin::signed(1),
+				vec![(1, 2), (2, 2)],
+				4
+			));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::set_owner_weight(RuntimeOrigin::signed(1), 0, 1, 3),
+				Error::<Test>::MustBeMultisig
+			);
+			assert_noop!(
+				Multisig::set_owner_weight(sovereign.clone(), 0, 1, 1),
+				Error::<Test>::InvalidThreshold
+			);
+			assert_ok!(Multisig::set_owner_weight(sovereign, 0, 1, 4));
+			System::assert_last_event(
+				Event::OwnerWeightSet { multisig_id: 0, owner: 1, weight: 4 }.into(),
+			);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+		});
+	}
+
+	/// Tests that a proposal is rejected once the owners voting against it carry more weight
+	/// than the owners' total weight minus the threshold.
+	#[test]
+	fn rejection_is_weighted() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_weighted_multisig(
+				RuntimeOrigin::signed(1),
+				vec![(1, 1), (2, 1), (3, 2)],
+				3
+			));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Act
+			assert_ok!(Multisig::reject_proposal(RuntimeOrigin::signed(3), 0, 0));
+
+			// Assert
+			System::assert_last_event(
+				Event::ProposalRejected { multisig_id: 0, proposal_index: 0 }.into(),
+			);
+		});
+	}
+}
+
+/// Tests for thresholds per class of call.
+mod class_thresholds {
+	use super::*;
+	use crate::CallClass;
+
+	/// Tests that a class threshold applies to calls of that class only.
+	#[test]
+	fn transfers_need_fewer_approvals() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(
+				RuntimeOrigin::signed(1),
+				vec![1, 2, 3, 4, 5],
+				4,
+				None
+			));
+			let sovereign = Multisig::multi_account_id(0);
+			let _ = Balances::deposit_creating(&sovereign, 100);
+			let transfer: RuntimeCall =
+				pallet_balances::Call::transfer_keep_alive { dest: 9, value: 10 }.into();
+			let remark: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			// Act
+			assert_ok!(Multisig::set_class_threshold(
+				RuntimeOrigin::signed(sovereign),
+				0,
+				CallClass::Transfer,
+				Some(2)
+			));
+
+			// Assert
+			System::assert_last_event(
+				Event::ClassThresholdSet {
+					multisig_id: 0,
+					class: CallClass::Transfer,
+					threshold: Some(2),
+				}
+				.into(),
+			);
+			for call in [&transfer, &remark] {
+				assert_ok!(Multisig::submit_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					Box::new(call.clone())
+				));
+			}
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 1));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(transfer),
+				Weight::MAX
+			));
+			assert_eq!(Balances::free_balance(9), 10);
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					1,
+					Box::new(remark),
+					Weight::MAX
+				),
+				Error::<Test>::NotEnoughApprovals
+			);
+		});
+	}
+
+	/// Tests that owners cannot be removed or lose weight past a class threshold, since the
+	/// call lowering it may be of that class itself.
+	#[test]
+	fn owner_changes_keep_class_thresholds_reachable() {
+		new_test_ext().execute_with(|| {
+			// Arrange: Governance calls need every owner of [1 (weight 2), 2, 3].
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::set_owner_weight(sovereign.clone(), 0, 1, 2));
+			assert_ok!(Multisig::set_class_threshold(
+				sovereign.clone(),
+				0,
+				CallClass::Governance,
+				Some(4)
+			));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::remove_owner(sovereign.clone(), 0, 3, None),
+				Error::<Test>::InvalidThreshold
+			);
+			assert_noop!(
+				Multisig::set_owner_weight(sovereign.clone(), 0, 1, 1),
+				Error::<Test>::InvalidThreshold
+			);
+			assert_noop!(
+				Multisig::suspend_owner(sovereign.clone(), 0, 2),
+				Error::<Test>::InvalidThreshold
+			);
+			assert_ok!(Multisig::swap_owner(sovereign, 0, 3, 4));
+		});
+	}
+
+	/// Tests that a recovery hands over without the class thresholds set for the previous
+	/// owners.
+	#[test]
+	fn replacing_the_owners_clears_class_thresholds() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::set_class_threshold(
+				sovereign.clone(),
+				0,
+				CallClass::Governance,
+				Some(3)
+			));
+			assert_ok!(Multisig::set_backup_owners(sovereign, 0, vec![7, 8], 1, 100));
+
+			// Act
+			System::set_block_number(101);
+			assert_ok!(Multisig::activate_backup_owners(RuntimeOrigin::signed(9), 0));
+
+			// Assert
+			assert_eq!(Multisig::class_threshold(0, CallClass::Governance), None);
+		});
+	}
+
+	/// Tests that a class threshold must be reachable and can only be set by the sovereign
+	/// account.
+	#[test]
+	fn threshold_is_validated() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+
+			assert_noop!(
+				Multisig::set_class_threshold(sovereign.clone(), 0, CallClass::Governance, Some(4)),
+				Error::<Test>::InvalidThreshold
+			);
+			assert_noop!(
+				Multisig::set_class_threshold(
+					RuntimeOrigin::signed(1),
+					0,
+					CallClass::Governance,
+					Some(3)
+				),
+				Error::<Test>::MustBeMultisig
+			);
+			assert_ok!(Multisig::set_class_threshold(sovereign, 0, CallClass::Governance, Some(3)));
+			assert_eq!(Multisig::class_threshold(0, CallClass::Governance), Some(3));
+		});
+	}
+}
+
+/// Tests for per-wallet call filters.
+mod call_filter {
+	use super::*;
+	use crate::{CallPattern, FilterMode};
+	use codec::Encode;
+
+	fn pattern_of(call: &RuntimeCall, whole_pallet: bool) -> CallPattern {
+		let encoded = call.encode();
+		CallPattern {
+			pallet_index: encoded[0],
+			call_index: if whole_pallet { None } else { Some(encoded[1]) },
+		}
+	}
+
+	/// Tests that a wallet with an allow-list can only propose the calls it lists.
+	#[test]
+	fn allow_list_restricts_proposals() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let transfer: RuntimeCall =
+				pallet_balances::Call::transfer_keep_alive { dest: 9, value: 10 }.into();
+			let remark: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+
+			// Act
+			assert_ok!(Multisig::set_call_filter(
+				sovereign,
+				0,
+				Some((FilterMode::Allow, vec![pattern_of(&transfer, false)]))
+			));
+
+			// Assert
+			System::assert_last_event(
+				Event::CallFilterSet { multisig_id: 0, mode: Some(FilterMode::Allow) }.into(),
+			);
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(transfer)));
+			assert_noop!(
+				Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(remark)),
+				Error::<Test>::CallFiltered
+			);
+		});
+	}
+
+	/// Tests that a deny-list blocks whole pallets until it is cleared.
+	#[test]
+	fn deny_list_blocks_until_cleared() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let remark: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::set_call_filter(
+				sovereign.clone(),
+				0,
+				Some((FilterMode::Deny, vec![pattern_of(&remark, true)]))
+			));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(remark.clone())),
+				Error::<Test>::CallFiltered
+			);
+			assert_noop!(
+				Multisig::set_call_filter(
+					sovereign.clone(),
+					0,
+					Some((FilterMode::Deny, vec![pattern_of(&remark, true); 9]))
+				),
+				Error::<Test>::TooManyFilterEntries
+			);
+			assert_ok!(Multisig::set_call_filter(sovereign, 0, None));
+			assert!(Multisig::call_filter(0).is_none());
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(remark)));
+		});
+	}
+}
+
+/// Tests for the spending-limit allowance.
+mod spending_limit {
+	use super::*;
+	use crate::SpendingLimit;
+
+	/// Tests that a single owner can spend within the allowance, which resets every period.
+	#[test]
+	fn owner_spends_within_allowance() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let sovereign = Multisig::multi_account_id(0);
+			let _ = Balances::deposit_creating(&sovereign, 1_000);
+			assert_noop!(
+				Multisig::spend_allowance(RuntimeOrigin::signed(2), 0, 9, 10),
+				Error::<Test>::NoSpendingLimit
+			);
+			let limit = SpendingLimit { period: 10, amount: 50 };
+			assert_ok!(Multisig::set_spending_limit(
+				RuntimeOrigin::signed(sovereign),
+				0,
+				Some(limit.clone())
+			));
+			System::assert_last_event(
+				Event::SpendingLimitSet { multisig_id: 0, limit: Some(limit) }.into(),
+			);
+
+			// Act
+			assert_ok!(Multisig::spend_allowance(RuntimeOrigin::signed(2), 0, 9, 30));
+
+			// Assert
+			System::assert_last_event(
+				Event::AllowanceSpent { multisig_id: 0, who: 2, dest: 9, amount: 30 }.into(),
+			);
+			assert_eq!(Balances::free_balance(9), 30);
+			assert_noop!(
+				Multisig::spend_allowance(RuntimeOrigin::signed(3), 0, 9, 25),
+				Error::<Test>::SpendingLimitExceeded
+			);
+			assert_noop!(
+				Multisig::spend_allowance(RuntimeOrigin::signed(9), 0, 9, 5),
+				Error::<Test>::NotAnOwner
+			);
+
+			// Act
+			System::set_block_number(11);
+			assert_ok!(Multisig::spend_allowance(RuntimeOrigin::signed(3), 0, 9, 25));
+
+			// Assert
+			assert_eq!(Balances::free_balance(9), 55);
+			assert_eq!(Multisig::spending_window(0).spent, 25);
+		});
+	}
+
+	/// Tests that the allowance cannot dip into the protected reserve, and that only the
+	/// sovereign account can set a limit.
+	#[test]
+	fn respects_reserve_and_sovereignty() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let sovereign = Multisig::multi_account_id(0);
+			let _ = Balances::deposit_creating(&sovereign, 100);
+			let limit = SpendingLimit { period: 10, amount: 50 };
+			assert_noop!(
+				Multisig::set_spending_limit(RuntimeOrigin::signed(1), 0, Some(limit.clone())),
+				Error::<Test>::MustBeMultisig
+			);
+			assert_noop!(
+				Multisig::set_spending_limit(
+					RuntimeOrigin::signed(sovereign),
+					0,
+					Some(SpendingLimit { period: 0, amount: 50 })
+				),
+				Error::<Test>::InvalidSpendingLimit
+			);
+			assert_ok!(Multisig::set_spending_limit(
+				RuntimeOrigin::signed(sovereign),
+				0,
+				Some(limit)
+			));
+			assert_ok!(Multisig::set_protected_reserve(
+				RuntimeOrigin::signed(sovereign),
+				0,
+				Some(80)
+			));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::spend_allowance(RuntimeOrigin::signed(1), 0, 9, 30),
+				Error::<Test>::ReserveBreached
+			);
+			assert_ok!(Multisig::spend_allowance(RuntimeOrigin::signed(1), 0, 9, 20));
+		});
+	}
+}
+
+/// Tests for the execution delay after a proposal meets its threshold.
+mod timelock {
+	use super::*;
+
+	/// Tests that a proposal can only be executed once the delay has elapsed since it met its
+	/// threshold.
+	#[test]
+	fn execution_waits_for_delay() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::set_execution_delay(sovereign, 0, Some(10)));
+			System::assert_last_event(
+				Event::ExecutionDelaySet { multisig_id: 0, delay: Some(10) }.into(),
+			);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_eq!(Multisig::proposals(0, 0).unwrap().threshold_met_at, None);
+
+			// Act
+			System::set_block_number(2);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Assert
+			assert_eq!(Multisig::proposals(0, 0).unwrap().threshold_met_at, Some(2));
+			System::set_block_number(11);
+			assert!(Multisig::executable_proposals(0).is_empty());
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call.clone()),
+					Weight::MAX
+				),
+				Error::<Test>::TimelockActive
+			);
+			System::set_block_number(12);
+			assert_eq!(Multisig::executable_proposals(0).len(), 1);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+		});
+	}
+
+	/// Tests that revoking an approval restarts the delay, and that unanimous proposals are
+	/// not delayed.
+	#[test]
+	fn revocation_resets_and_unanimity_waives() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::set_execution_delay(sovereign, 0, Some(10)));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::revoke_approval(RuntimeOrigin::signed(2), 0, 0));
+
+			// Assert
+			assert_eq!(Multisig::proposals(0, 0).unwrap().threshold_met_at, None);
+
+			// Act
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+
+			// Assert
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+		});
+	}
+}
+
+/// Tests for the unanimous emergency path around the timelock.
+mod emergency_path {
+	use super::*;
+
+	/// Tests that a unanimous proposal is executed at once despite the timelock, and that the
+	/// waiver is announced.
+	#[test]
+	fn unanimous_proposal_waives_the_timelock() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::set_execution_delay(sovereign, 0, Some(10)));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+			System::assert_has_event(
+				Event::TimelockWaived { multisig_id: 0, proposal_index: 0 }.into(),
+			);
+		});
+	}
+
+	/// Tests that a unanimous proposal whose timelock has elapsed does not claim a waiver.
+	#[test]
+	fn elapsed_timelock_is_not_waived() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::set_execution_delay(sovereign, 0, Some(10)));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			System::set_block_number(11);
+
+			// Act
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			assert!(!System::events().iter().any(|record| matches!(
+				record.event,
+				RuntimeEvent::Multisig(Event::TimelockWaived { .. })
+			)));
+		});
+	}
+}
+
+/// Tests for veto accounts.
+mod veto {
+	use super::*;
+
+	/// Tests that a veto account can cancel a fully approved proposal but cannot approve one.
+	#[test]
+	fn vetoer_cancels_but_cannot_approve() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let sovereign = Multisig::multi_account_id(0);
+			assert_ok!(Multisig::set_vetoers(RuntimeOrigin::signed(sovereign), 0, vec![9]));
+			System::assert_last_event(
+				Event::VetoersSet { multisig_id: 0, vetoers: vec![9] }.into(),
+			);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_noop!(
+				Multisig::confirm_proposal(RuntimeOrigin::signed(9), 0, 0),
+				Error::<Test>::NotAnOwner
+			);
+
+			// Act
+			assert_ok!(Multisig::veto_proposal(RuntimeOrigin::signed(9), 0, 0));
+
+			// Assert
+			System::assert_last_event(
+				Event::ProposalVetoed { multisig_id: 0, proposal_index: 0, who: 9 }.into(),
+			);
+			assert!(Multisig::proposals(0, 0).is_none());
+			assert_eq!(Multisig::pending_proposal_count(0), 0);
+		});
+	}
+
+	/// Tests that only veto accounts may veto, and only the sovereign account may set them.
+	#[test]
+	fn veto_is_restricted() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let sovereign = Multisig::multi_account_id(0);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::set_vetoers(RuntimeOrigin::signed(1), 0, vec![9]),
+				Error::<Test>::MustBeMultisig
+			);
+			assert_noop!(
+				Multisig::set_vetoers(RuntimeOrigin::signed(sovereign), 0, vec![9, 9]),
+				Error::<Test>::DuplicateAccount
+			);
+			assert_noop!(
+				Multisig::veto_proposal(RuntimeOrigin::signed(2), 0, 0),
+				Error::<Test>::NotAVetoer
+			);
+			assert_ok!(Multisig::set_vetoers(RuntimeOrigin::signed(sovereign), 0, vec![9]));
+			assert_noop!(
+				Multisig::veto_proposal(RuntimeOrigin::signed(9), 0, 1),
+				Error::<Test>::ProposalNotFound
+			);
+		});
+	}
+}
+
+/// Tests for guardian recovery.
+mod recovery {
+	use super::*;
+
+	/// Tests that guardians can replace the owners once enough approve and the delay passes.
+	#[test]
+	fn guardians_replace_owners_after_delay() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let sovereign = Multisig::multi_account_id(0);
+			assert_ok!(Multisig::set_recovery_config(
+				RuntimeOrigin::signed(sovereign),
+				0,
+				vec![7, 8, 9],
+				2,
+				10
+			));
+			assert_ok!(Multisig::initiate_recovery(RuntimeOrigin::signed(7), 0, vec![5, 6], 1));
+			assert_noop!(
+				Multisig::claim_recovery(RuntimeOrigin::signed(5), 0),
+				Error::<Test>::RecoveryNotApproved
+			);
+
+			// Act
+			assert_ok!(Multisig::approve_recovery(RuntimeOrigin::signed(8), 0));
+
+			// Assert
+			System::assert_last_event(Event::RecoveryApproved { multisig_id: 0, who: 8 }.into());
+			assert_noop!(
+				Multisig::claim_recovery(RuntimeOrigin::signed(5), 0),
+				Error::<Test>::RecoveryDelayActive
+			);
+
+			// Act
+			System::set_block_number(11);
+			assert_ok!(Multisig::claim_recovery(RuntimeOrigin::signed(5), 0));
+
+			// Assert
+			System::assert_last_event(Event::RecoveryClaimed { multisig_id: 0 }.into());
+			let multisig = Multisig::multisigs(0).unwrap();
+			assert_eq!(multisig.owners.to_vec(), vec![5, 6]);
+			assert_eq!(multisig.threshold, 1);
+			assert!(Multisig::active_recovery(0).is_none());
+		});
+	}
+
+	/// Tests that an existing owner can cancel a recovery, and that only guardians may take
+	/// part in one.
+	#[test]
+	fn owner_cancels_recovery() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let sovereign = Multisig::multi_account_id(0);
+			assert_noop!(
+				Multisig::set_recovery_config(RuntimeOrigin::signed(1), 0, vec![7], 1, 10),
+				Error::<Test>::MustBeMultisig
+			);
+			assert_ok!(Multisig::set_recovery_config(
+				RuntimeOrigin::signed(sovereign),
+				0,
+				vec![7, 8],
+				2,
+				10
+			));
+			assert_noop!(
+				Multisig::initiate_recovery(RuntimeOrigin::signed(1), 0, vec![5], 1),
+				Error::<Test>::NotAGuardian
+			);
+			assert_ok!(Multisig::initiate_recovery(RuntimeOrigin::signed(7), 0, vec![5], 1));
+			assert_noop!(
+				Multisig::initiate_recovery(RuntimeOrigin::signed(8), 0, vec![6], 1),
+				Error::<Test>::RecoveryAlreadyActive
+			);
+			assert_noop!(
+				Multisig::approve_recovery(RuntimeOrigin::signed(7), 0),
+				Error::<Test>::RecoveryAlreadyApproved
+			);
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::cancel_recovery(RuntimeOrigin::signed(7), 0),
+				Error::<Test>::NotAnOwner
+			);
+			assert_ok!(Multisig::cancel_recovery(RuntimeOrigin::signed(2), 0));
+			System::assert_last_event(Event::RecoveryCancelled { multisig_id: 0, who: 2 }.into());
+			assert_noop!(
+				Multisig::approve_recovery(RuntimeOrigin::signed(8), 0),
+				Error::<Test>::NoActiveRecovery
+			);
+			assert_eq!(Multisig::multisigs(0).unwrap().owners.to_vec(), vec![1, 2]);
+		});
+	}
+}
+
+/// Tests for threshold decay after inactivity.
+mod threshold_decay {
+	use super::*;
+	use crate::ThresholdDecay;
+
+	/// Tests that the threshold drops once the multisig has been inactive, and recovers after
+	/// the next execution.
+	#[test]
+	fn threshold_decays_until_next_execution() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(
+				RuntimeOrigin::signed(1),
+				vec![1, 2, 3, 4],
+				3,
+				None
+			));
+			let sovereign = Multisig::multi_account_id(0);
+			let decay = ThresholdDecay { inactivity_period: 100, threshold: 2 };
+			assert_ok!(Multisig::set_threshold_decay(
+				RuntimeOrigin::signed(sovereign),
+				0,
+				Some(decay.clone())
+			));
+			System::assert_last_event(
+				Event::ThresholdDecaySet { multisig_id: 0, decay: Some(decay) }.into(),
+			);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					0,
+					Box::new(call.clone()),
+					Weight::MAX
+				),
+				Error::<Test>::NotEnoughApprovals
+			);
+
+			// Act
+			System::set_block_number(101);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call.clone()),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+			assert_eq!(Multisig::last_executed_at(0), Some(101));
+			assert_eq!(Multisig::decayed_threshold(0, 3), 3);
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 1));
+			assert_noop!(
+				Multisig::execute_proposal(
+					RuntimeOrigin::signed(1),
+					0,
+					1,
+					Box::new(call),
+					Weight::MAX
+				),
+				Error::<Test>::NotEnoughApprovals
+			);
+		});
+	}
+
+	/// Tests that a decay policy must lower the threshold and be set by the sovereign account.
+	#[test]
+	fn policy_is_validated() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let sovereign = Multisig::multi_account_id(0);
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::set_threshold_decay(
+					RuntimeOrigin::signed(1),
+					0,
+					Some(ThresholdDecay { inactivity_period: 10, threshold: 1 })
+				),
+				Error::<Test>::MustBeMultisig
+			);
+			for threshold in [0, 3] {
+				assert_noop!(
+					Multisig::set_threshold_decay(
+						RuntimeOrigin::signed(sovereign),
+						0,
+						Some(ThresholdDecay { inactivity_period: 10, threshold })
+					),
+					Error::<Test>::InvalidThreshold
+				);
+			}
+			assert_ok!(Multisig::set_threshold_decay(RuntimeOrigin::signed(sovereign), 0, None));
+			assert!(Multisig::threshold_decay(0).is_none());
+		});
+	}
+}
+
+/// Tests for delegating confirmation rights.
+mod delegation {
+	use super::*;
+
+	/// Tests that a delegate's confirmation counts as the owner's until the delegation expires.
+	#[test]
+	fn delegate_confirms_until_expiry() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 3, None));
+			assert_ok!(Multisig::delegate_confirmations(RuntimeOrigin::signed(2), 0, 9, 10));
+			System::assert_last_event(
+				Event::ConfirmationsDelegated {
+					multisig_id: 0,
+					owner: 2,
+					delegate: 9,
+					expires_at: 11,
+				}
+				.into(),
+			);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Act
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(9), 0, 0));
+
+			// Assert
+			System::assert_last_event(
+				Event::Confirmation { who: 2, multisig_id: 0, proposal_index: 0 }.into(),
+			);
+			assert_eq!(Multisig::proposals(0, 0).unwrap().approvals.to_vec(), vec![1, 2]);
+
+			// Act & Assert
+			System::set_block_number(11);
+			assert_noop!(
+				Multisig::confirm_proposal(RuntimeOrigin::signed(9), 0, 1),
+				Error::<Test>::NotAnOwner
+			);
+		});
+	}
+
+	/// Tests that a delegation can be revoked and is validated.
+	#[test]
+	fn delegation_is_revocable_and_validated() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::delegate_confirmations(RuntimeOrigin::signed(2), 0, 3, 10),
+				Error::<Test>::InvalidDelegation
+			);
+			assert_noop!(
+				Multisig::delegate_confirmations(RuntimeOrigin::signed(2), 0, 9, 0),
+				Error::<Test>::InvalidDelegation
+			);
+			assert_noop!(
+				Multisig::delegate_confirmations(RuntimeOrigin::signed(9), 0, 8, 10),
+				Error::<Test>::NotAnOwner
+			);
+			assert_ok!(Multisig::delegate_confirmations(RuntimeOrigin::signed(2), 0, 9, 10));
+			assert_noop!(
+				Multisig::delegate_confirmations(RuntimeOrigin::signed(3), 0, 9, 10),
+				Error::<Test>::InvalidDelegation
+			);
+			assert_ok!(Multisig::revoke_delegation(RuntimeOrigin::signed(2), 0));
+			assert_noop!(
+				Multisig::revoke_delegation(RuntimeOrigin::signed(2), 0),
+				Error::<Test>::NoDelegation
+			);
+			assert_noop!(
+				Multisig::confirm_proposal(RuntimeOrigin::signed(9), 0, 0),
+				Error::<Test>::NotAnOwner
+			);
+		});
+	}
+}
+
+/// Tests for proxies of owners.
+mod proxy {
+	use super::*;
+
+	/// Tests that a proxy can submit and confirm proposals, attributed to the owner.
+	#[test]
+	fn proxy_votes_for_owner() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			// Act
+			assert_ok!(Multisig::submit_proposal_as_proxy(
+				RuntimeOrigin::signed(1 + PROXY_OFFSET),
+				0,
+				1,
+				Box::new(call)
+			));
+			assert_ok!(Multisig::confirm_proposal_as_proxy(
+				RuntimeOrigin::signed(2 + PROXY_OFFSET),
+				0,
+				2,
+				0
+			));
+
+			// Assert
+			System::assert_has_event(
+				Event::ProxyVoted { multisig_id: 0, proposal_index: 0, owner: 1, proxy: 101 }
+					.into(),
+			);
+			System::assert_last_event(
+				Event::ProxyVoted { multisig_id: 0, proposal_index: 0, owner: 2, proxy: 102 }
+					.into(),
+			);
+			let proposal = Multisig::proposals(0, 0).unwrap();
+			assert_eq!(proposal.proposer, 1);
+			assert_eq!(proposal.approvals.to_vec(), vec![1, 2]);
+		});
+	}
+
+	/// Tests that only a proxy of the owner may act for them.
+	#[test]
+	fn non_proxy_is_rejected() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::confirm_proposal_as_proxy(RuntimeOrigin::signed(101), 0, 2, 0),
+				Error::<Test>::NotAProxy
+			);
+			assert_noop!(
+				Multisig::confirm_proposal_as_proxy(RuntimeOrigin::signed(104), 0, 4, 0),
+				Error::<Test>::NotAnOwner
+			);
+		});
+	}
+}
+
+/// Tests for sub-accounts.
+mod sub_accounts {
+	use super::*;
+
+	/// Tests that sub-accounts are distinct and controlled through their multisig.
+	#[test]
+	fn multisig_dispatches_from_sub_account() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let sovereign = Multisig::multi_account_id(0);
+			let payroll = Multisig::sub_account_id(0, 1);
+			assert_ne!(payroll, sovereign);
+			assert_ne!(payroll, Multisig::sub_account_id(0, 2));
+			assert_ne!(payroll, Multisig::sub_account_id(1, 1));
+			assert_eq!(payroll, crate::address::sub_account_id::<u64, _>(b"pba/msig", 0u32, 1));
+			let _ = Balances::deposit_creating(&payroll, 100);
+			let call: RuntimeCall =
+				pallet_balances::Call::transfer_allow_death { dest: 7, value: 40 }.into();
+
+			// Act
+			assert_ok!(Multisig::dispatch_as_sub(
+				RuntimeOrigin::signed(sovereign),
+				0,
+				1,
+				Box::new(call.clone())
+			));
+
+			// Assert
+			System::assert_last_event(
+				Event::DispatchedAsSub { multisig_id: 0, sub_index: 1, result: Ok(()) }.into(),
+			);
+			assert_eq!(Balances::free_balance(payroll), 60);
+			assert_eq!(Balances::free_balance(7), 40);
+			assert_noop!(
+				Multisig::dispatch_as_sub(RuntimeOrigin::signed(1), 0, 1, Box::new(call)),
+				Error::<Test>::MustBeMultisig
+			);
+		});
+	}
+}
+
+/// Tests for creating a multisig together with its first proposal.
+mod create_and_propose {
+	use super::*;
+
+	/// Tests that the wallet and its first proposal are created together.
+	#[test]
+	fn creates_wallet_with_proposal() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			// Act
+			assert_ok!(Multisig::create_and_propose(
+				RuntimeOrigin::signed(1),
+				vec![1, 2, 3],
+				2,
+				Box::new(call.clone())
+			));
+
+			// Assert
+			System::assert_last_event(
+				Event::ProposalSubmitted {
+					multisig_id: 0,
+					proposal_index: 0,
+					call_hash: Multisig::call_hash(&call),
+					content_ref: None,
+				}
+				.into(),
+			);
+			assert_eq!(Multisig::multisigs(0).unwrap().threshold, 2);
+			assert_eq!(Multisig::proposals(0, 0).unwrap().approvals.to_vec(), vec![1]);
+		});
+	}
+
+	/// Tests that nothing is created if the creator is not an owner.
+	#[test]
+	fn creator_must_be_owner() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::create_and_propose(
+					RuntimeOrigin::signed(9),
+					vec![1, 2, 3],
+					2,
+					Box::new(call.clone())
+				),
+				Error::<Test>::NotAnOwner
+			);
+			assert_noop!(
+				Multisig::create_and_propose(
+					RuntimeOrigin::signed(1),
+					vec![1, 2],
+					3,
+					Box::new(call)
+				),
+				Error::<Test>::InvalidThreshold
+			);
+			assert!(Multisig::multisigs(0).is_none());
+		});
+	}
+}
+
+/// Tests for funding a multisig when it is created.
+mod endowment {
+	use super::*;
+
+	/// Tests that the endowment is moved to the sovereign account on creation.
+	#[test]
+	fn creation_funds_sovereign_account() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let _ = Balances::deposit_creating(&1, 100);
+
+			// Act
+			assert_ok!(Multisig::create_multisig(
+				RuntimeOrigin::signed(1),
+				vec![1, 2],
+				2,
+				Some(40)
+			));
+
+			// Assert
+			assert_eq!(Balances::free_balance(Multisig::multi_account_id(0)), 40);
+			assert_eq!(Balances::free_balance(1), 60);
+		});
+	}
+
+	/// Tests that the wallet is not created if the endowment cannot be paid.
+	#[test]
+	fn failed_endowment_undoes_creation() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let _ = Balances::deposit_creating(&1, 10);
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, Some(40)),
+				sp_runtime::ArithmeticError::Underflow
+			);
+			assert!(Multisig::multisigs(0).is_none());
+		});
+	}
+}
+
+/// Tests for counterfactual wallet addresses.
+mod counterfactual {
+	use super::*;
+
+	/// Tests that funds sent to a counterfactual address belong to the wallet once created.
+	#[test]
+	fn wallet_controls_precomputed_address() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			let salt = [7u8; 32];
+			let account = Multisig::counterfactual_account_id(&[3, 1, 2], 2, &salt);
+			assert_eq!(account, Multisig::counterfactual_account_id(&[1, 2, 3], 2, &salt));
+			assert_ne!(account, Multisig::counterfactual_account_id(&[1, 2, 3], 2, &[8u8; 32]));
+			let _ = Balances::deposit_creating(&account, 100);
+
+			// Act
+			assert_ok!(Multisig::create_counterfactual_multisig(
+				RuntimeOrigin::signed(1),
+				vec![2, 1, 3],
+				2,
+				salt
+			));
+
+			// Assert
+			assert_eq!(Multisig::multi_account_id(0), account);
+			assert_eq!(Multisig::multisig_of_account(account), Some(0));
+			let call: RuntimeCall =
+				pallet_balances::Call::transfer_allow_death { dest: 7, value: 40 }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_eq!(Balances::free_balance(account), 60);
+			assert_eq!(Balances::free_balance(7), 40);
+		});
+	}
+
+	/// Tests that an address can only be used by one wallet at a time.
+	#[test]
+	fn address_is_unique() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let salt = [0u8; 32];
+			assert_ok!(Multisig::create_counterfactual_multisig(
+				RuntimeOrigin::signed(1),
+				vec![1, 2],
+				2,
+				salt
+			));
+
+			// Act & Assert
+			assert_noop!(
+				Multisig::create_counterfactual_multisig(
+					RuntimeOrigin::signed(2),
+					vec![2, 1],
+					2,
+					salt
+				),
+				Error::<Test>::AccountInUse
+			);
+			let account = Multisig::multi_account_id(0);
+			assert_ok!(Multisig::destroy_multisig(RuntimeOrigin::signed(account), 0));
+			assert!(Multisig::multisig_of_account(account).is_none());
+			assert_ok!(Multisig::create_counterfactual_multisig(
+				RuntimeOrigin::signed(2),
+				vec![2, 1],
+				2,
+				salt
+			));
+			assert_eq!(Multisig::multi_account_id(1), account);
+		});
+	}
+}
+
+/// Tests for wallets with a salted sovereign account.
+mod salted_account {
+	use super::*;
+
+	/// Tests that a salted wallet uses the account derived from its ID and salt.
+	#[test]
+	fn salt_changes_the_account() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let salt = [3u8; 32];
+			let expected: u64 = crate::address::multisig_account_id(b"pba/msig", 0u32, Some(&salt));
+
+			// Act
+			assert_ok!(Multisig::create_salted_multisig(
+				RuntimeOrigin::signed(1),
+				vec![1, 2],
+				2,
+				salt
+			));
+
+			// Assert
+			let account = Multisig::multi_account_id(0);
+			assert_eq!(account, expected);
+			assert_ne!(
+				account,
+				crate::address::multisig_account_id::<u64, _>(b"pba/msig", 0u32, None)
+			);
+			assert_ok!(Multisig::set_auditors(RuntimeOrigin::signed(account), 0, vec![5]));
+		});
+	}
+}
+
+/// Tests for the index of the multisigs each account owns.
+mod owner_index {
+	use super::*;
+
+	/// Tests that the index follows creation, owner changes and destruction.
+	#[test]
+	fn index_tracks_ownership() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 3], 2, None));
+			let sovereign = Multisig::multi_account_id(0);
+
+			// Act & Assert
+			assert_eq!(Multisig::owned_multisigs(1).to_vec(), vec![0, 1]);
+			assert_eq!(Multisig::owned_multisigs(2).to_vec(), vec![0]);
+			assert_ok!(Multisig::swap_owner(RuntimeOrigin::signed(sovereign), 0, 2, 4));
+			assert!(Multisig::owned_multisigs(2).is_empty());
+			assert_eq!(Multisig::owned_multisigs(4).to_vec(), vec![0]);
+			assert_ok!(Multisig::destroy_multisig(RuntimeOrigin::signed(sovereign), 0));
+			assert_eq!(Multisig::owned_multisigs(1).to_vec(), vec![1]);
+			assert!(Multisig::owned_multisigs(4).is_empty());
+		});
+	}
+
+	/// Tests that a full index does not block an owner's wallets: an account that lists the
+	/// victim in as many multisigs as the index holds cannot stop them from creating a wallet
+	/// or being added to one.
+	#[test]
+	fn full_index_is_skipped() {
+		new_test_ext().execute_with(|| {
+			// Arrange: Account 5 fills owner 2's index.
+			System::set_block_number(1);
+			for _ in 0..32 {
+				assert_ok!(Multisig::create_multisig(
+					RuntimeOrigin::signed(5),
+					vec![5, 2],
+					2,
+					None
+				));
+			}
+
+			// Act: Owner 2 creates a wallet of their own.
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(2), vec![2, 3], 2, None));
+
+			// Assert: The wallet exists and is indexed for owner 3, but not for owner 2.
+			assert!(Multisig::multisigs(32).is_some());
+			System::assert_has_event(Event::OwnerIndexFull { owner: 2, multisig_id: 32 }.into());
+			assert_eq!(Multisig::owned_multisigs(3).to_vec(), vec![32]);
+			assert_eq!(Multisig::owned_multisigs(2).len(), 32);
+			assert!(!Multisig::owned_multisigs(2).contains(&32));
+
+			// Act & Assert: Owner 2 can still be added to another wallet.
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(3), vec![3, 4], 2, None));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(33));
+			assert_ok!(Multisig::add_owner(sovereign, 33, 2, None));
+			System::assert_has_event(Event::OwnerIndexFull { owner: 2, multisig_id: 33 }.into());
+			assert!(Multisig::multisigs(33).unwrap().owners.contains(&2));
+		});
+	}
+}
+
+/// Tests for finding proposals by the hash of their call.
+mod call_hash_index {
+	use super::*;
+
+	/// Tests that pending proposals can be found by their call hash until they leave the
+	/// pending set.
+	#[test]
+	fn pending_proposals_are_found_by_hash() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![1] }.into();
+			let other: RuntimeCall = frame_system::Call::remark { remark: vec![2] }.into();
+			let hash = Multisig::call_hash(&call);
+
+			// Act
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(other)));
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(2),
+				1,
+				Box::new(call.clone())
+			));
+
+			// Assert
+			let mut found = Multisig::proposals_by_call_hash(hash);
+			found.sort();
+			assert_eq!(found, vec![(0, 0), (1, 0)]);
+
+			// Act
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+			assert_ok!(Multisig::cancel_proposal(RuntimeOrigin::signed(2), 1, 0));
+
+			// Assert
+			assert!(Multisig::proposals_by_call_hash(hash).is_empty());
+		});
+	}
+}
+
+/// Tests for the origin checks other pallets use to require a multisig.
+mod ensure_origin {
+	use super::*;
+	use crate::{EnsureMultisig, EnsureMultisigAccount};
+	use frame_support::traits::{ConstU32, EnsureOrigin};
+
+	/// Tests that `EnsureMultisig` accepts only the sovereign account of its multisig.
+	#[test]
+	fn ensure_multisig_accepts_only_its_sovereign_account() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 3], 2, None));
+			let sovereign = Multisig::multi_account_id(0);
+
+			// Act
+			let accepted =
+				EnsureMultisig::<Test, ConstU32<0>>::try_origin(RuntimeOrigin::signed(sovereign));
+
+			// Assert
+			assert_eq!(accepted.ok(), Some(sovereign));
+			let other = RuntimeOrigin::signed(Multisig::multi_account_id(1));
+			assert!(EnsureMultisig::<Test, ConstU32<0>>::try_origin(other).is_err());
+			assert!(
+				EnsureMultisig::<Test, ConstU32<0>>::try_origin(RuntimeOrigin::signed(1)).is_err()
+			);
+			assert!(EnsureMultisig::<Test, ConstU32<2>>::try_origin(RuntimeOrigin::signed(
+				Multisig::multi_account_id(2)
+			))
+			.is_err());
+		});
+	}
+
+	/// Tests that `EnsureMultisigAccount` resolves the multisig of any sovereign account,
+	/// salted ones included, until it is destroyed.
+	#[test]
+	fn ensure_multisig_account_resolves_the_multisig() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			assert_ok!(Multisig::create_salted_multisig(
+				RuntimeOrigin::signed(1),
+				vec![1, 2],
+				2,
+				[7; 32]
+			));
+			let salted = Multisig::multi_account_id(1);
+
+			// Act
+			let resolved = EnsureMultisigAccount::<Test>::try_origin(RuntimeOrigin::signed(salted));
+
+			// Assert
+			assert_eq!(resolved.ok(), Some((1, salted)));
+			assert!(EnsureMultisigAccount::<Test>::try_origin(RuntimeOrigin::signed(2)).is_err());
+			assert!(EnsureMultisigAccount::<Test>::try_origin(RuntimeOrigin::root()).is_err());
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::destroy_multisig(sovereign.clone(), 0));
+			assert!(EnsureMultisigAccount::<Test>::try_origin(sovereign).is_err());
+		});
+	}
+}
+
+/// Tests for dispatching proposals with the pallet's own origin.
+mod multisig_origin {
+	use super::*;
+	use crate::{EnsureMultisigApprovals, Origin};
+	use frame_support::traits::{ConstU32, EnsureOrigin};
+
+	fn set_cap(max: u32) -> RuntimeCall {
+		crate::Call::set_max_active_multisigs { max: Some(max) }.into()
+	}
+
+	/// Tests that an opted-in proposal is dispatched with its approvals, which a call gated
+	/// on them accepts.
+	#[test]
+	fn it_dispatches_with_the_approvals() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			assert_ok!(Multisig::submit_proposal_with_multisig_origin(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(set_cap(5))
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(set_cap(5)),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(Multisig::max_active_multisigs(), Some(5));
+			assert!(Multisig::uses_multisig_origin(0, 0).is_none());
+		});
+	}
+
+	/// Tests that other proposals are still signed by the sovereign account, which the gated
+	/// call refuses.
+	#[test]
+	fn other_proposals_are_signed() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(set_cap(5))
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(set_cap(5)),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(Multisig::max_active_multisigs(), None);
+			System::assert_last_event(
+				Event::ProposalExecuted {
+					multisig_id: 0,
+					proposal_index: 0,
+					result: Err(sp_runtime::DispatchError::BadOrigin),
+				}
+				.into(),
+			);
+		});
+	}
+
+	/// Tests that `EnsureMultisigApprovals` checks the multisig and the number of approvals.
+	#[test]
+	fn ensure_multisig_approvals_checks_the_approvals() {
+		new_test_ext().execute_with(|| {
+			let origin = |multisig_id, approvals| -> RuntimeOrigin {
+				Origin::<Test>::Multisig { multisig_id, approvals, threshold: 2 }.into()
+			};
+			type Ensure = EnsureMultisigApprovals<Test, ConstU32<7>, ConstU32<4>>;
+
+			assert_eq!(Ensure::try_origin(origin(7, 4)).ok(), Some(4));
+			assert!(Ensure::try_origin(origin(7, 3)).is_err());
+			assert!(Ensure::try_origin(origin(6, 5)).is_err());
+			assert!(Ensure::try_origin(RuntimeOrigin::signed(1)).is_err());
+		});
+	}
+}
+
+/// Tests for the member-set adapters over a multisig's owners.
+mod owner_members {
+	use super::*;
+	use crate::OwnersOf;
+	use frame_support::traits::{ConstU32, Contains, SortedMembers};
+
+	type Owners = OwnersOf<Test, ConstU32<0>>;
+
+	/// Tests that the owners are reported sorted and follow owner changes.
+	#[test]
+	fn it_follows_the_owner_set() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(3), vec![3, 1, 2], 2, None));
+
+			// Act
+			let members = Owners::sorted_members();
+
+			// Assert
+			assert_eq!(members, vec![1, 2, 3]);
+			assert_eq!(Owners::count(), 3);
+			assert!(<Owners as Contains<u64>>::contains(&2));
+			assert!(<Owners as SortedMembers<u64>>::contains(&2));
+			assert!(!<Owners as Contains<u64>>::contains(&4));
+			let sovereign = RuntimeOrigin::signed(Multisig::multi_account_id(0));
+			assert_ok!(Multisig::destroy_multisig(sovereign, 0));
+			assert!(Owners::sorted_members().is_empty());
+			assert!(!<Owners as Contains<u64>>::contains(&2));
+		});
+	}
+
+	/// Tests that the owners can gate another origin through `EnsureSignedBy`.
+	#[test]
+	fn it_backs_ensure_signed_by() {
+		new_test_ext().execute_with(|| {
+			use frame_support::traits::EnsureOrigin;
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			type Ensure = frame_system::EnsureSignedBy<Owners, u64>;
+
+			assert_eq!(Ensure::try_origin(RuntimeOrigin::signed(2)).ok(), Some(2));
+			assert!(Ensure::try_origin(RuntimeOrigin::signed(3)).is_err());
+		});
+	}
+}
+
+/// Tests for the lifecycle hooks other pallets use to react to wallet activity.
+mod lifecycle_hooks {
+	use super::*;
+
+	fn calls() -> Vec<LifecycleCall> {
+		LIFECYCLE_CALLS.with(|calls| calls.borrow().clone())
+	}
+
+	/// Tests that creating, executing through and destroying a multisig each call their hook.
+	#[test]
+	fn hooks_follow_the_wallet_lifecycle() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let account = Multisig::multi_account_id(0);
+			let call: RuntimeCall = crate::Call::destroy_multisig { multisig_id: 0 }.into();
+			let call_hash = Multisig::call_hash(&call);
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(
+				calls(),
+				vec![
+					LifecycleCall::Created(0, account),
+					LifecycleCall::Destroyed(0),
+					LifecycleCall::Executed(0, 0, call_hash, Ok(())),
+				]
+			);
+		});
+	}
+
+	/// Tests that the execution hook also reports calls that failed.
+	#[test]
+	fn execution_hook_reports_failures() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = crate::Call::set_max_active_multisigs { max: None }.into();
+			let call_hash = Multisig::call_hash(&call);
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(call.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(
+				calls().last(),
+				Some(&LifecycleCall::Executed(
+					0,
+					0,
+					call_hash,
+					Err(sp_runtime::DispatchError::BadOrigin)
+				))
+			);
+		});
+	}
+}
+
+/// Tests for creating and looking up multisigs from other pallets.
+mod multisig_provider {
+	use super::*;
+	use crate::MultisigProvider;
+
+	/// Tests that a multisig created through the provider works like any other.
+	#[test]
+	fn it_creates_and_looks_up_multisigs() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(1);
+
+			// Act
+			let multisig_id = <Multisig as MultisigProvider<u64>>::create(1, vec![1, 2, 3], 2);
+
+			// Assert
+			assert_eq!(multisig_id, Ok(0));
+			assert_eq!(Multisig::multisigs(0).map(|multisig| multisig.threshold), Some(2));
+			assert_eq!(
+				<Multisig as MultisigProvider<u64>>::account_of(0),
+				Some(Multisig::multi_account_id(0))
+			);
+			assert_eq!(<Multisig as MultisigProvider<u64>>::account_of(1), None);
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(2),
+				0,
+				Box::new(frame_system::Call::remark { remark: vec![] }.into())
+			));
+		});
+	}
+
+	/// Tests that the provider validates the owners and threshold.
+	#[test]
+	fn it_validates_the_configuration() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				<Multisig as MultisigProvider<u64>>::create(1, vec![1, 2], 3),
+				Error::<Test>::InvalidThreshold
+			);
+			assert_noop!(
+				<Multisig as MultisigProvider<u64>>::create(1, vec![1, 1], 1),
+				Error::<Test>::DuplicateAccount
+			);
+		});
+	}
+}
+
+/// Tests for the multisigs created at genesis.
+mod genesis {
+	use super::*;
+	use sp_runtime::BuildStorage;
+
+	/// Tests that the genesis multisigs are created in order and endowed.
+	#[test]
+	fn it_creates_and_endows_the_multisigs() {
+		// Arrange
+		let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+		crate::GenesisConfig::<Test> {
+			multisigs: vec![(vec![1, 2, 3], 2, Some(500)), (vec![4, 5], 1, None)],
+		}
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+		// Act
+		let mut ext: sp_io::TestExternalities = storage.into();
+
+		// Assert
+		ext.execute_with(|| {
+			assert_eq!(Multisig::multisigs(0).map(|multisig| multisig.threshold), Some(2));
+			assert_eq!(
+				Multisig::multisigs(1).map(|multisig| multisig.owners.to_vec()),
+				Some(vec![4, 5])
+			);
+			assert_eq!(Balances::free_balance(Multisig::multi_account_id(0)), 500);
+			assert_eq!(Balances::free_balance(Multisig::multi_account_id(1)), 0);
+			assert_eq!(Multisig::owned_multisigs(4).to_vec(), vec![1]);
+			assert_eq!(Multisig::next_multisig_id(), 2);
+		});
+	}
+}
+
+/// Tests for the storage migrations.
+mod migrations {
+	use super::*;
+	use crate::migrations::{v0, v1::MigrateV0ToV1};
+	use frame_support::traits::{
+		ConstU32, Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion,
+	};
+
+	fn v0_multisig(owners: Vec<u64>, threshold: u32) -> v0::Multisig<u64, ConstU32<10>> {
+		v0::Multisig { owners: BoundedVec::truncate_from(owners), threshold }
+	}
+
+	/// Tests that a launch-layout wallet keeps its account and proposals after the migration.
+	#[test]
+	fn v0_to_v1_migrates_wallets_and_proposals() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			System::set_block_number(10);
+			let pending = [1u8; 32];
+			let executed = [2u8; 32];
+			v0::NextMultisigId::<Test>::put(1);
+			v0::Multisigs::<Test>::insert(0, v0_multisig(vec![1, 2, 3], 2));
+			v0::NextProposalIndex::<Test>::insert(0, 2);
+			v0::Proposals::<Test>::insert(
+				0,
+				0,
+				v0::Proposal { call_hash: pending, executed: false },
+			);
+			v0::Proposals::<Test>::insert(
+				0,
+				1,
+				v0::Proposal { call_hash: executed, executed: true },
+			);
+			v0::Approvals::<Test>::insert(0, 0, BoundedVec::truncate_from(vec![2, 3]));
+			v0::Approvals::<Test>::insert(0, 1, BoundedVec::truncate_from(vec![1, 2]));
+			let legacy: u64 = v0::account_id(0);
+
+			// Act
+			MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+			// Assert
+			assert_eq!(Multisig::on_chain_storage_version(), StorageVersion::new(1));
+			assert_eq!(Multisig::next_multisig_id(), 1);
+			assert_eq!(Multisig::multisigs(0).map(|multisig| multisig.threshold), Some(2));
+			assert_eq!(Multisig::multi_account_id(0), legacy);
+			assert_eq!(Multisig::multisig_of_account(legacy), Some(0));
+			assert_eq!(Multisig::active_multisig_count(), 1);
+			assert_eq!(Multisig::owned_multisigs(3).to_vec(), vec![0]);
+			assert_eq!(Multisig::next_proposal_index(0), 2);
+			assert_eq!(Multisig::pending_proposal_count(0), 1);
+
+			let proposal = Multisig::proposals(0, 0).unwrap();
+			assert_eq!(proposal.call_hash, H256(pending));
+			assert_eq!(proposal.proposer, 2);
+			assert_eq!(proposal.approvals.to_vec(), vec![2, 3]);
+			assert_eq!(proposal.threshold_met_at, Some(10));
+			assert_eq!(
+				proposal.expires_at,
+				10 + <<Test as crate::Config>::ProposalLifetime as Get<u64>>::get()
+			);
+			assert_eq!(Multisig::proposals_by_call_hash(H256(pending)), vec![(0, 0)]);
+
+			let done = Multisig::proposals(0, 1).unwrap();
+			assert_eq!(done.status, ProposalStatus::Executed);
+			assert!(done.approvals.is_empty());
+			assert!(Multisig::proposals_by_call_hash(H256(executed)).is_empty());
+			assert!(v0::Approvals::<Test>::iter().next().is_none());
+		});
+	}
+
+	/// Tests that the migration does nothing once the pallet is at version 1.
+	#[test]
+	fn v0_to_v1_only_runs_at_v0() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			StorageVersion::new(1).put::<Multisig>();
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let account = Multisig::multi_account_id(0);
+
+			// Act
+			MigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+			// Assert
+			assert_eq!(Multisig::multi_account_id(0), account);
+			assert!(Multisig::multisigs(0).is_some());
+		});
+	}
+}
+
+mod integrity {
+	use super::*;
+	use frame_support::traits::Hooks;
+
+	/// Tests that the mock runtime's configuration passes the integrity test.
+	#[test]
+	fn mock_configuration_is_sane() {
+		new_test_ext().execute_with(|| {
+			<Multisig as Hooks<u64>>::integrity_test();
+		});
+	}
+
+	/// Tests that slashing expired deposits without charging any is rejected.
+	#[test]
+	#[should_panic(expected = "`ExpiredDepositSlash` requires the `DepositCalculator`")]
+	fn slash_without_proposal_deposit_fails() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			EXPIRED_DEPOSIT_SLASH
+				.with(|slash| *slash.borrow_mut() = sp_runtime::Perbill::from_percent(10));
+
+			// Act
+			<Multisig as Hooks<u64>>::integrity_test();
+		});
+	}
+
+	/// Tests that a slash is accepted once proposals carry a deposit.
+	#[test]
+	fn slash_with_proposal_deposit_passes() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			EXPIRED_DEPOSIT_SLASH
+				.with(|slash| *slash.borrow_mut() = sp_runtime::Perbill::from_percent(10));
+			DEPOSIT_UNIT.with(|unit| *unit.borrow_mut() = 2);
+
+			// Act
+			<Multisig as Hooks<u64>>::integrity_test();
+		});
+	}
+}
+
+mod proof_size {
+	use super::*;
+	use crate::weight::WeightInfo;
+	use frame_support::traits::StorageInfoTrait;
+
+	/// Tests that the calls reading a multisig and its proposals reserve proof size for at least
+	/// their largest encodings.
+	#[test]
+	fn weights_cover_the_storage_they_read() {
+		// Arrange
+		let max_size = |name: &[u8]| {
+			Multisig::storage_info()
+				.into_iter()
+				.find(|info| info.storage_name == name)
+				.and_then(|info| info.max_size)
+				.unwrap() as u64
+		};
+		let read = max_size(b"Multisigs") + max_size(b"Proposals");
+
+		// Act
+		let weights = [
+			<() as WeightInfo>::confirm_proposal(2, 2),
+			<() as WeightInfo>::execute_proposal(1, 1),
+			<() as WeightInfo>::revoke_approval(2),
+			<() as WeightInfo>::reject_proposal(2),
+		];
+
+		// Assert
+		for weight in weights {
+			assert!(weight.proof_size() >= read);
+		}
+		assert!(<() as WeightInfo>::submit_proposal().proof_size() >= max_size(b"Multisigs"));
+		assert!(
+			<() as WeightInfo>::destroy_multisig(2, 2).proof_size() >
+				<() as WeightInfo>::destroy_multisig(2, 1).proof_size()
+		);
+	}
+}
+
+mod weight_refunds {
+	use super::*;
+	use crate::weight::WeightInfo;
+	use frame_support::{
+		dispatch::{DispatchClass, GetDispatchInfo},
+		traits::Get,
+	};
+
+	/// Tests that an execution is weighed up front with its `max_weight`, in the dispatch class
+	/// of its call.
+	#[test]
+	fn execution_reserves_the_max_weight_in_the_class_of_the_call() {
+		new_test_ext().execute_with(|| {
+			// Arrange: An operational call, with room to spare in its `max_weight`.
+			let call: RuntimeCall = frame_system::Call::set_heap_pages { pages: 64 }.into();
+			let call_info = call.get_dispatch_info();
+			assert_eq!(call_info.class, DispatchClass::Operational);
+			let max_owners = <<Test as crate::Config>::MaxOwners as Get<u32>>::get();
+			let max_weight = call_info.call_weight.saturating_mul(2);
+
+			// Act
+			let info = RuntimeCall::Multisig(crate::Call::execute_proposal {
+				multisig_id: 0,
+				proposal_index: 0,
+				call: Box::new(call),
+				max_weight,
+			})
+			.get_dispatch_info();
+
+			// Assert
+			assert_eq!(
+				info.call_weight,
+				<() as WeightInfo>::execute_proposal(max_owners, max_owners)
+					.saturating_add(max_weight)
+			);
+			assert_eq!(info.class, DispatchClass::Operational);
+		});
+	}
+
+	/// Tests that a confirmation is charged for the multisig's actual owners and approvals.
+	#[test]
+	fn confirmation_refunds_unused_weight() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Act
+			let post_info = Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0).unwrap();
+
+			// Assert
+			assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::confirm_proposal(3, 2)));
+		});
+	}
+
+	/// Tests that an execution is charged for the approvals it read and the weight its call
+	/// actually used.
+	#[test]
+	fn execution_refunds_unused_weight_of_the_call() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+			let destroy: RuntimeCall = crate::Call::destroy_multisig { multisig_id: 0 }.into();
+			assert_ok!(Multisig::submit_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(destroy.clone())
+			));
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act
+			let post_info = Multisig::execute_proposal(
+				RuntimeOrigin::signed(3),
+				0,
+				0,
+				Box::new(destroy),
+				Weight::MAX,
+			)
+			.unwrap();
+
+			// Assert
+			let expected = <() as WeightInfo>::execute_proposal(3, 2)
+				.saturating_add(<() as WeightInfo>::destroy_multisig(3, 1));
+			assert_eq!(post_info.actual_weight, Some(expected));
+		});
+	}
+
+	/// Tests that a failed confirmation in a batch is charged in full, and a successful one for
+	/// what it used.
+	#[test]
+	fn batch_confirm_charges_failures_in_full() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2], 2, None));
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+			let max_owners = <Test as crate::Config>::MaxOwners::get();
+
+			// Act
+			let post_info = Multisig::batch_confirm(
+				RuntimeOrigin::signed(2),
+				0,
+				BoundedVec::truncate_from(vec![0, 7]),
+			)
+			.unwrap();
+
+			// Assert
+			let expected = <() as WeightInfo>::confirm_proposal(2, 2)
+				.saturating_add(<() as WeightInfo>::confirm_proposal(max_owners, max_owners));
+			assert_eq!(post_info.actual_weight, Some(expected));
+		});
+	}
+}
+
+/// Tests for the lifecycle status of proposals.
+mod proposal_status {
+	use super::*;
+	use sp_runtime::DispatchError;
+
+	/// Creates a 2-of-3 multisig and submits `call`, approved by the proposer only.
+	fn setup_proposal(call: &RuntimeCall) {
+		System::set_block_number(1);
+		assert_ok!(Multisig::create_multisig(RuntimeOrigin::signed(1), vec![1, 2, 3], 2, None));
+		assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call.clone())));
+	}
+
+	/// Tests that a failed execution leaves the proposal open with its error, and that a
+	/// successful one closes it.
+	#[test]
+	fn failed_execution_stays_open_until_executed() {
+		new_test_ext().execute_with(|| {
+			// Arrange: A call the sovereign account may only dispatch once it is funded.
+			let call: RuntimeCall =
+				pallet_balances::Call::transfer_keep_alive { dest: 7, value: 10 }.into();
+			setup_proposal(&call);
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Pending);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(2), 0, 0));
+
+			// Act: Execute while the sovereign account holds nothing.
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call.clone()),
+				Weight::MAX
+			));
+
+			// Assert: The proposal failed, but may still be confirmed and executed.
+			let error = DispatchError::Arithmetic(sp_runtime::ArithmeticError::Underflow);
+			let failed = ProposalStatus::Failed { error };
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, failed);
+			System::assert_has_event(
+				Event::ProposalStatusChanged { multisig_id: 0, proposal_index: 0, status: failed }
+					.into(),
+			);
+			assert_ok!(Multisig::confirm_proposal(RuntimeOrigin::signed(3), 0, 0));
+
+			// Act: Execute again once the sovereign account is funded.
+			let _ = Balances::deposit_creating(&Multisig::multi_account_id(0), 100);
+			assert_ok!(Multisig::execute_proposal(
+				RuntimeOrigin::signed(1),
+				0,
+				0,
+				Box::new(call),
+				Weight::MAX
+			));
+
+			// Assert
+			assert_eq!(Multisig::proposals(0, 0).unwrap().status, ProposalStatus::Executed);
+			System::assert_has_event(
+				Event::ProposalStatusChanged {
+					multisig_id: 0,
+					proposal_index: 0,
+					status: ProposalStatus::Executed,
+				}
+				.into(),
+			);
+		});
+	}
+
+	/// Tests that removing a proposal announces why it was closed.
+	#[test]
+	fn removal_announces_cancellation_and_expiry() {
+		new_test_ext().execute_with(|| {
+			// Arrange
+			let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			setup_proposal(&call);
+			assert_ok!(Multisig::submit_proposal(RuntimeOrigin::signed(1), 0, Box::new(call)));
+
+			// Act
+			assert_ok!(Multisig::cancel_proposal(RuntimeOrigin::signed(1), 0, 0));
+			System::set_block_number(Multisig::proposals(0, 1).unwrap().expires_at + 1);
+			assert_ok!(Multisig::remove_expired_proposal(RuntimeOrigin::signed(9), 0, 1));
+
+			// Assert
+			for (proposal_index, status) in
+				[(0, ProposalStatus::Cancelled), (1, ProposalStatus::Expired)]
+			{
+				assert!(Multisig::proposals(0, proposal_index).is_none());
+				System::assert_has_event(
+					Event::ProposalStatusChanged { multisig_id: 0, proposal_index, status }.into(),
+				);
+			}
+		});
+	}
 }
@@ -0,0 +1,301 @@
+//! Traits through which a runtime extends the behaviour of the multisig pallet.
+
+use crate::{CallClass, CallHashOf, Config, Multisig, Pallet, TypedProposalOf};
+use core::marker::PhantomData;
+use frame_support::traits::tokens::{nonfungibles, nonfungibles_v2};
+use sp_runtime::{traits::Zero, BoundedVec, DispatchError, DispatchResult};
+use sp_std::prelude::*;
+
+/// Converts a [`crate::TypedProposal`] into the runtime call that the multisig will vote on.
+///
+/// Typed proposals give the pallet a decoded view of what a proposal does, which a raw
+/// `RuntimeCall` hides, so that policies like standing approvals can be applied to them. The
+/// runtime decides which variants it supports by returning `None` for the rest.
+pub trait TypedCallBuilder<T: Config> {
+	/// Builds the runtime call for `proposal`, or returns `None` if it is not supported.
+	///
+	/// `sovereign` is the account the call will be dispatched from, for calls that must also
+	/// name their origin, such as EVM transactions.
+	fn build(
+		sovereign: &T::AccountId,
+		proposal: &TypedProposalOf<T>,
+	) -> Option<<T as Config>::RuntimeCall>;
+}
+
+/// A builder that supports no typed proposals at all.
+impl<T: Config> TypedCallBuilder<T> for () {
+	fn build(
+		_sovereign: &T::AccountId,
+		_proposal: &TypedProposalOf<T>,
+	) -> Option<<T as Config>::RuntimeCall> {
+		None
+	}
+}
+
+/// An external policy consulted before any proposal is executed.
+///
+/// Runtimes use this to require an attestation beyond the owners' own approvals, such as a
+/// risk-engine signer or 2FA service account having approved high-value proposals.
+pub trait PolicyCheck<T: Config> {
+	/// Returns whether `call` may be executed by `multisig_id` with the given `approvals`.
+	fn allows(
+		multisig_id: T::MultisigId,
+		call: &<T as Config>::RuntimeCall,
+		approvals: &[T::AccountId],
+	) -> bool;
+}
+
+/// A policy that allows every proposal which meets the multisig's own rules.
+impl<T: Config> PolicyCheck<T> for () {
+	fn allows(
+		_multisig_id: T::MultisigId,
+		_call: &<T as Config>::RuntimeCall,
+		_approvals: &[T::AccountId],
+	) -> bool {
+		true
+	}
+}
+
+/// Sorts proposed calls into classes, each of which a multisig may give its own threshold.
+pub trait CallClassifier<T: Config> {
+	/// Returns the class of `call`.
+	fn classify(call: &<T as Config>::RuntimeCall) -> CallClass;
+}
+
+/// Every call is of class [`CallClass::Other`].
+impl<T: Config> CallClassifier<T> for () {
+	fn classify(_call: &<T as Config>::RuntimeCall) -> CallClass {
+		CallClass::Other
+	}
+}
+
+/// Looks up proxy relationships, such as those registered with `pallet-proxy`.
+///
+/// The multisig uses this to let a proxy of an owner submit and confirm proposals on the
+/// owner's behalf. A runtime with `pallet-proxy` implements it with `Proxy::find_proxy`,
+/// restricting it to the proxy types it deems appropriate and, if it requires announcements,
+/// to proxies whose call has been announced.
+pub trait OwnerProxies<AccountId> {
+	/// Returns whether `proxy` may act for `owner` in the multisig.
+	fn is_proxy(owner: &AccountId, proxy: &AccountId) -> bool;
+}
+
+/// No account is a proxy of any other.
+impl<AccountId> OwnerProxies<AccountId> for () {
+	fn is_proxy(_owner: &AccountId, _proxy: &AccountId) -> bool {
+		false
+	}
+}
+
+/// Decides whether a proposal has collected enough support to be executed.
+///
+/// Runtimes use this to plug in their own governance math, such as supermajority or weighted
+/// rules, without forking the pallet. [`CountApprovals`] is the pallet's own count-based rule.
+pub trait VoteTally<AccountId> {
+	/// Returns whether a proposal on a multisig with `owners` and `threshold` has passed.
+	///
+	/// `weights` holds the vote weight of each of `owners`, in the same order. `approvals` and
+	/// `rejections` are the owners who voted for and against the proposal.
+	fn has_passed(
+		owners: &[AccountId],
+		weights: &[u32],
+		threshold: u32,
+		approvals: &[AccountId],
+		rejections: &[AccountId],
+	) -> bool;
+}
+
+/// The count-based rule: a proposal passes once the owners who approved it carry a total vote
+/// weight of at least `threshold`. With every weight at one, that is `threshold` owners.
+pub struct CountApprovals;
+impl<AccountId: PartialEq> VoteTally<AccountId> for CountApprovals {
+	fn has_passed(
+		owners: &[AccountId],
+		weights: &[u32],
+		threshold: u32,
+		approvals: &[AccountId],
+		_rejections: &[AccountId],
+	) -> bool {
+		let approved = owners
+			.iter()
+			.zip(weights)
+			.filter(|(owner, _)| approvals.contains(owner))
+			.fold(0u32, |total, (_, weight)| total.saturating_add(*weight));
+		approved >= threshold
+	}
+}
+
+/// Computes the deposits the multisig reserves from its users for the storage they occupy.
+///
+/// Each deposit is refunded once its storage is cleaned up: the creation deposit when the
+/// multisig is destroyed, and proposal and approval deposits when the proposal is executed,
+/// withdrawn or the multisig destroyed. A proposer forfeits the `ExpiredDepositSlash` share of
+/// their deposit if their proposal expires. Runtimes implement this to set their own economic
+/// policy.
+pub trait DepositCalculator<Balance> {
+	/// Returns the deposit reserved from the creator of a multisig with `owner_count` owners.
+	fn creation_deposit(owner_count: u32) -> Balance;
+
+	/// Returns the deposit reserved from the proposer of a call of `call_len` encoded bytes,
+	/// submitted while the multisig already has `open_proposals` pending proposals.
+	fn proposal_deposit(call_len: u32, open_proposals: u32) -> Balance;
+
+	/// Returns the deposit reserved from an owner confirming a proposal on a multisig with
+	/// `owner_count` owners.
+	fn approval_deposit(owner_count: u32) -> Balance;
+
+	/// Returns the deposit reserved from an owner for `len` bytes of call data uploaded in
+	/// chunks.
+	fn call_data_deposit(len: u32) -> Balance;
+}
+
+/// No deposits at all.
+impl<Balance: Zero> DepositCalculator<Balance> for () {
+	fn creation_deposit(_owner_count: u32) -> Balance {
+		Zero::zero()
+	}
+
+	fn proposal_deposit(_call_len: u32, _open_proposals: u32) -> Balance {
+		Zero::zero()
+	}
+
+	fn approval_deposit(_owner_count: u32) -> Balance {
+		Zero::zero()
+	}
+
+	fn call_data_deposit(_len: u32) -> Balance {
+		Zero::zero()
+	}
+}
+
+/// Reports whether an account holds non-fungible items, such as `pallet-nfts` or
+/// `pallet-uniques` items.
+///
+/// The multisig uses this to refuse destroying a wallet that would strand items in its
+/// sovereign account.
+pub trait ItemCustody<AccountId> {
+	/// Returns whether `who` owns at least one item.
+	fn holds_items(who: &AccountId) -> bool;
+}
+
+/// Custody for runtimes without any non-fungible items.
+impl<AccountId> ItemCustody<AccountId> for () {
+	fn holds_items(_who: &AccountId) -> bool {
+		false
+	}
+}
+
+/// Checks item custody through a `nonfungibles_v2` implementation, such as `pallet-nfts`.
+pub struct NonfungiblesV2Custody<Items>(PhantomData<Items>);
+impl<AccountId, Items: nonfungibles_v2::InspectEnumerable<AccountId>> ItemCustody<AccountId>
+	for NonfungiblesV2Custody<Items>
+{
+	fn holds_items(who: &AccountId) -> bool {
+		Items::owned(who).next().is_some()
+	}
+}
+
+/// Checks item custody through a `nonfungibles` implementation, such as `pallet-uniques`.
+pub struct NonfungiblesCustody<Items>(PhantomData<Items>);
+impl<AccountId, Items: nonfungibles::InspectEnumerable<AccountId>> ItemCustody<AccountId>
+	for NonfungiblesCustody<Items>
+{
+	fn holds_items(who: &AccountId) -> bool {
+		Items::owned(who).next().is_some()
+	}
+}
+
+/// Called after a proposal has been executed, whether or not its call succeeded.
+///
+/// Lets other pallets, such as accounting or reputation systems, react to a multisig's
+/// activity without scraping events.
+pub trait OnProposalExecuted<T: Config> {
+	/// Reacts to the proposal `proposal_index` of `multisig_id`, proposing `call_hash`, having
+	/// been executed with `result`.
+	fn on_proposal_executed(
+		multisig_id: T::MultisigId,
+		proposal_index: T::ProposalIndex,
+		call_hash: CallHashOf<T>,
+		result: &DispatchResult,
+	);
+}
+
+impl<T: Config> OnProposalExecuted<T> for () {
+	fn on_proposal_executed(
+		_multisig_id: T::MultisigId,
+		_proposal_index: T::ProposalIndex,
+		_call_hash: CallHashOf<T>,
+		_result: &DispatchResult,
+	) {
+	}
+}
+
+/// Called after a multisig has been created.
+pub trait OnMultisigCreated<T: Config> {
+	/// Reacts to `multisig_id` having been created with the sovereign account `account`.
+	fn on_multisig_created(multisig_id: T::MultisigId, account: &T::AccountId);
+}
+
+impl<T: Config> OnMultisigCreated<T> for () {
+	fn on_multisig_created(_multisig_id: T::MultisigId, _account: &T::AccountId) {}
+}
+
+/// Called after a multisig has been destroyed and its storage cleared.
+pub trait OnMultisigDestroyed<T: Config> {
+	/// Reacts to `multisig_id` having been destroyed.
+	fn on_multisig_destroyed(multisig_id: T::MultisigId);
+}
+
+impl<T: Config> OnMultisigDestroyed<T> for () {
+	fn on_multisig_destroyed(_multisig_id: T::MultisigId) {}
+}
+
+/// Creates and looks up multisigs from other pallets' logic, such as a DAO factory, a bridge
+/// or an escrow, rather than only through extrinsics.
+pub trait MultisigProvider<AccountId> {
+	/// The identifier of a multisig.
+	type MultisigId;
+
+	/// Creates a multisig of `owners` with approval `threshold`, reserving its creation
+	/// deposit from `depositor`, and returns its ID.
+	///
+	/// Fails if there are too many or duplicate owners, or the threshold cannot be met.
+	fn create(
+		depositor: AccountId,
+		owners: Vec<AccountId>,
+		threshold: u32,
+	) -> Result<Self::MultisigId, DispatchError>;
+
+	/// Returns the sovereign account of `multisig_id`, or `None` if it does not exist.
+	fn account_of(multisig_id: Self::MultisigId) -> Option<AccountId>;
+}
+
+impl<T: Config> MultisigProvider<T::AccountId> for Pallet<T> {
+	type MultisigId = T::MultisigId;
+
+	fn create(
+		depositor: T::AccountId,
+		owners: Vec<T::AccountId>,
+		threshold: u32,
+	) -> Result<T::MultisigId, DispatchError> {
+		let owners = Self::bounded_owner_set(owners, threshold)?;
+		let multisig = Multisig {
+			owners,
+			threshold,
+			required_approvers: BoundedVec::new(),
+			approval_order: BoundedVec::new(),
+		};
+		Self::do_create_multisig(depositor, multisig, None)
+	}
+
+	fn account_of(multisig_id: T::MultisigId) -> Option<T::AccountId> {
+		Self::multisigs(multisig_id).map(|_| Self::multi_account_id(multisig_id))
+	}
+}
+
+/// Makes what the benchmarks cannot build generically.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Signer, Signature> {
+	/// Signs `message` with a fresh key, returning the key's public part and the signature.
+	fn sign_message(message: &[u8]) -> (Signer, Signature);
+}
@@ -7,35 +7,2126 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_multisig.
 pub trait WeightInfo {
-    fn create_multisig() -> Weight;
+    fn create_multisig(o: u32) -> Weight;
     fn submit_proposal() -> Weight;
-    fn confirm_proposal() -> Weight;
-    fn execute_proposal() -> Weight;
-    fn destroy_multisig() -> Weight;
+    fn confirm_proposal(o: u32, a: u32) -> Weight;
+    fn execute_proposal(o: u32, a: u32) -> Weight;
+    fn destroy_multisig(o: u32, p: u32) -> Weight;
+    fn pre_approve() -> Weight;
+    fn cancel_pre_approval() -> Weight;
+    fn submit_typed_proposal() -> Weight;
+    fn grant_standing_approval() -> Weight;
+    fn revoke_standing_approval() -> Weight;
+    fn set_required_approvers() -> Weight;
+    fn set_approval_order() -> Weight;
+    fn set_group_quorum() -> Weight;
+    fn clone_multisig() -> Weight;
+    fn register_template() -> Weight;
+    fn remove_template() -> Weight;
+    fn create_from_template() -> Weight;
+    fn submit_proposal_with_content() -> Weight;
+    fn set_contract_limits() -> Weight;
+    fn set_evm_limits() -> Weight;
+    fn track_inbound_spend() -> Weight;
+    fn untrack_inbound_spend() -> Weight;
+    fn set_auditors() -> Weight;
+    fn flag_proposal() -> Weight;
+    fn dispatch_as_multisig() -> Weight;
+    fn set_protected_reserve() -> Weight;
+    fn set_payroll() -> Weight;
+    fn set_payroll_paused() -> Weight;
+    fn cancel_payroll() -> Weight;
+    fn pay_payrolls(n: u32, p: u32) -> Weight;
+    fn grant_session() -> Weight;
+    fn revoke_session() -> Weight;
+    fn operator_execute() -> Weight;
+    fn submit_proposal_with_funds() -> Weight;
+    fn set_owner_label() -> Weight;
+    fn suspend_owner() -> Weight;
+    fn reinstate_owner() -> Weight;
+    fn set_backup_owners() -> Weight;
+    fn clear_backup_owners() -> Weight;
+    fn activate_backup_owners() -> Weight;
+    fn set_deposit_exemption() -> Weight;
+    fn set_velocity_limit() -> Weight;
+    fn bump_signing_nonce(p: u32) -> Weight;
+    fn set_max_active_multisigs() -> Weight;
+    fn note_call_chunk(b: u32) -> Weight;
+    fn finalize_call(b: u32) -> Weight;
+    fn discard_call_upload() -> Weight;
+    fn change_owners(o: u32) -> Weight;
+    fn revoke_approval(o: u32) -> Weight;
+    fn cancel_proposal(o: u32) -> Weight;
+    fn delegate_confirmations(o: u32) -> Weight;
+    fn reject_proposal(o: u32) -> Weight;
+    fn set_owner_weight() -> Weight;
+    fn set_class_threshold() -> Weight;
+    fn set_call_filter() -> Weight;
+    fn set_spending_limit() -> Weight;
+    fn spend_allowance() -> Weight;
+    fn set_execution_delay() -> Weight;
+    fn set_vetoers() -> Weight;
+    fn set_recovery_config() -> Weight;
+    fn remove_recovery_config() -> Weight;
+    fn initiate_recovery() -> Weight;
+    fn approve_recovery() -> Weight;
+    fn claim_recovery() -> Weight;
+    fn cancel_recovery() -> Weight;
+    fn set_threshold_decay() -> Weight;
+    fn revoke_delegation() -> Weight;
+    fn dispatch_as_sub() -> Weight;
+    fn verify_signature() -> Weight;
+    fn batch_execute(n: u32) -> Weight;
 }
 
-/// A dummy implementation for testing purposes.
+/// Weights for pallet_multisig, generated from `benchmarking.rs` against the template runtime.
+///
+/// Generated by `frame-omni-bencher` 0.11 (benchmark CLI 47.2.0) with `--steps 50 --repeat 20`,
+/// using `Compiled` WASM execution on an `Intel(R) Xeon(R) Processor`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `Multisig::ActiveMultisigCount` (r:1 w:1)
+    /// Proof: `Multisig::ActiveMultisigCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MaxActiveMultisigs` (r:1 w:0)
+    /// Proof: `Multisig::MaxActiveMultisigs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::NextMultisigId` (r:1 w:1)
+    /// Proof: `Multisig::NextMultisigId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Parameters::Parameters` (r:2 w:0)
+    /// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptAccounts` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerToMultisigs` (r:100 w:100)
+    /// Proof: `Multisig::OwnerToMultisigs` (`max_values`: None, `max_size`: Some(562), added: 3037, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:0 w:1)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigAccounts` (r:0 w:1)
+    /// Proof: `Multisig::MultisigAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CreationDeposits` (r:0 w:1)
+    /// Proof: `Multisig::CreationDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// The range of component `o` is `[1, 100]`.
+    fn create_multisig(o: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `431`
+        //  Estimated: `6012 + o * (3037 ±0)`
+        // Minimum execution time: 48_326_000 picoseconds.
+        Weight::from_parts(44_045_135, 0)
+            .saturating_add(Weight::from_parts(0, 6012))
+            // Standard Error: 37_909
+            .saturating_add(Weight::from_parts(3_124_922, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(o.into())))
+            .saturating_add(Weight::from_parts(0, 3037).saturating_mul(o.into()))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:0)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CallFilters` (r:1 w:0)
+    /// Proof: `Multisig::CallFilters` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PendingProposalCount` (r:1 w:1)
+    /// Proof: `Multisig::PendingProposalCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::NextProposalIndex` (r:1 w:1)
+    /// Proof: `Multisig::NextProposalIndex` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Parameters::Parameters` (r:4 w:0)
+    /// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptAccounts` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptMultisigs` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptMultisigs` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalDeposits` (r:1 w:1)
+    /// Proof: `Multisig::ProposalDeposits` (`max_values`: None, `max_size`: Some(4846), added: 7321, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PreApprovals` (r:1 w:0)
+    /// Proof: `Multisig::PreApprovals` (`max_values`: None, `max_size`: Some(3254), added: 5729, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:1 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ThresholdDecays` (r:1 w:0)
+    /// Proof: `Multisig::ThresholdDecays` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::UsageMeters` (r:1 w:1)
+    /// Proof: `Multisig::UsageMeters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Proposals` (r:0 w:1)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalsByCallHash` (r:0 w:1)
+    /// Proof: `Multisig::ProposalsByCallHash` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+    fn submit_proposal() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `5257`
+        //  Estimated: `255690`
+        // Minimum execution time: 341_170_000 picoseconds.
+        Weight::from_parts(360_843_000, 0)
+            .saturating_add(Weight::from_parts(0, 255690))
+            .saturating_add(T::DbWeight::get().reads(118))
+            .saturating_add(T::DbWeight::get().writes(8))
+    }
+    /// Storage: `Multisig::Delegations` (r:1 w:0)
+    /// Proof: `Multisig::Delegations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:0)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Proposals` (r:1 w:1)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Parameters::Parameters` (r:1 w:0)
+    /// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptAccounts` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptMultisigs` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptMultisigs` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalDeposits` (r:1 w:1)
+    /// Proof: `Multisig::ProposalDeposits` (`max_values`: None, `max_size`: Some(4846), added: 7321, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PreApprovals` (r:1 w:0)
+    /// Proof: `Multisig::PreApprovals` (`max_values`: None, `max_size`: Some(3254), added: 5729, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:1 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ThresholdDecays` (r:1 w:0)
+    /// Proof: `Multisig::ThresholdDecays` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::GroupQuorums` (r:1 w:0)
+    /// Proof: `Multisig::GroupQuorums` (`max_values`: None, `max_size`: Some(26198), added: 28673, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::UsageMeters` (r:1 w:1)
+    /// Proof: `Multisig::UsageMeters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// The range of component `o` is `[2, 100]`.
+    /// The range of component `a` is `[2, 100]`.
+    fn confirm_proposal(_o: u32, a: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `4184 + a * (80 ±0)`
+        //  Estimated: `255690`
+        // Minimum execution time: 278_828_000 picoseconds.
+        Weight::from_parts(320_855_385, 0)
+            .saturating_add(Weight::from_parts(0, 255690))
+            // Standard Error: 24_925
+            .saturating_add(Weight::from_parts(457_363, 0).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(115))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Proposals` (r:1 w:1)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:0)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:1 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ThresholdDecays` (r:1 w:0)
+    /// Proof: `Multisig::ThresholdDecays` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::GroupQuorums` (r:1 w:0)
+    /// Proof: `Multisig::GroupQuorums` (`max_values`: None, `max_size`: Some(26198), added: 28673, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ExecutionDelays` (r:1 w:0)
+    /// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Earmarks` (r:1 w:0)
+    /// Proof: `Multisig::Earmarks` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:100 w:99)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigOriginProposals` (r:1 w:1)
+    /// Proof: `Multisig::MultisigOriginProposals` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProtectedReserves` (r:1 w:0)
+    /// Proof: `Multisig::ProtectedReserves` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::VelocityLimits` (r:1 w:0)
+    /// Proof: `Multisig::VelocityLimits` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalDeposits` (r:1 w:1)
+    /// Proof: `Multisig::ProposalDeposits` (`max_values`: None, `max_size`: Some(4846), added: 7321, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::UploadedCalls` (r:1 w:0)
+    /// Proof: `Multisig::UploadedCalls` (`max_values`: None, `max_size`: Some(524384), added: 526859, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PendingProposalCount` (r:1 w:1)
+    /// Proof: `Multisig::PendingProposalCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::UsageMeters` (r:1 w:1)
+    /// Proof: `Multisig::UsageMeters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::LastExecutedAt` (r:0 w:1)
+    /// Proof: `Multisig::LastExecutedAt` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalsByCallHash` (r:0 w:1)
+    /// Proof: `Multisig::ProposalsByCallHash` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+    /// The range of component `o` is `[1, 100]`.
+    /// The range of component `a` is `[1, 100]`.
+    fn execute_proposal(o: u32, a: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `5295 + a * (199 ±0)`
+        //  Estimated: `527849 + a * (2603 ±0)`
+        // Minimum execution time: 352_523_000 picoseconds.
+        Weight::from_parts(140_261_131, 0)
+            .saturating_add(Weight::from_parts(0, 527849))
+            // Standard Error: 98_501
+            .saturating_add(Weight::from_parts(1_519_458, 0).saturating_mul(o.into()))
+            // Standard Error: 98_501
+            .saturating_add(Weight::from_parts(17_486_682, 0).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(117))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(7))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+            .saturating_add(Weight::from_parts(0, 2603).saturating_mul(a.into()))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:1)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Proposals` (r:65 w:64)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:1)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:2 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ThresholdDecays` (r:1 w:1)
+    /// Proof: `Multisig::ThresholdDecays` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:101 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::GroupQuorums` (r:1 w:1)
+    /// Proof: `Multisig::GroupQuorums` (`max_values`: None, `max_size`: Some(26198), added: 28673, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ExecutionDelays` (r:1 w:1)
+    /// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:1)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Earmarks` (r:2 w:0)
+    /// Proof: `Multisig::Earmarks` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:100 w:99)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigOriginProposals` (r:2 w:1)
+    /// Proof: `Multisig::MultisigOriginProposals` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProtectedReserves` (r:1 w:1)
+    /// Proof: `Multisig::ProtectedReserves` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CreationDeposits` (r:1 w:1)
+    /// Proof: `Multisig::CreationDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalDeposits` (r:65 w:64)
+    /// Proof: `Multisig::ProposalDeposits` (`max_values`: None, `max_size`: Some(4846), added: 7321, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CallUploads` (r:1 w:0)
+    /// Proof: `Multisig::CallUploads` (`max_values`: None, `max_size`: Some(524408), added: 526883, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::UploadedCalls` (r:2 w:0)
+    /// Proof: `Multisig::UploadedCalls` (`max_values`: None, `max_size`: Some(524384), added: 526859, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PendingProposalCount` (r:1 w:1)
+    /// Proof: `Multisig::PendingProposalCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerToMultisigs` (r:100 w:100)
+    /// Proof: `Multisig::OwnerToMultisigs` (`max_values`: None, `max_size`: Some(562), added: 3037, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ActiveMultisigCount` (r:1 w:1)
+    /// Proof: `Multisig::ActiveMultisigCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PreApprovals` (r:1 w:0)
+    /// Proof: `Multisig::PreApprovals` (`max_values`: None, `max_size`: Some(3254), added: 5729, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PreApprovalCount` (r:1 w:1)
+    /// Proof: `Multisig::PreApprovalCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::StandingApprovals` (r:1 w:0)
+    /// Proof: `Multisig::StandingApprovals` (`max_values`: None, `max_size`: Some(901), added: 3376, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OperatorSessions` (r:1 w:0)
+    /// Proof: `Multisig::OperatorSessions` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::InboundSpends` (r:1 w:0)
+    /// Proof: `Multisig::InboundSpends` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalFlags` (r:1 w:0)
+    /// Proof: `Multisig::ProposalFlags` (`max_values`: None, `max_size`: Some(29046), added: 31521, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerLabels` (r:1 w:0)
+    /// Proof: `Multisig::OwnerLabels` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Delegations` (r:1 w:0)
+    /// Proof: `Multisig::Delegations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CallFilters` (r:1 w:1)
+    /// Proof: `Multisig::CallFilters` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::NextProposalIndex` (r:1 w:1)
+    /// Proof: `Multisig::NextProposalIndex` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigContractLimits` (r:1 w:1)
+    /// Proof: `Multisig::MultisigContractLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigEvmLimits` (r:1 w:1)
+    /// Proof: `Multisig::MultisigEvmLimits` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Payrolls` (r:1 w:1)
+    /// Proof: `Multisig::Payrolls` (`max_values`: None, `max_size`: Some(4831), added: 7306, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Auditors` (r:1 w:1)
+    /// Proof: `Multisig::Auditors` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Vetoers` (r:1 w:1)
+    /// Proof: `Multisig::Vetoers` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::RecoveryConfigs` (r:1 w:1)
+    /// Proof: `Multisig::RecoveryConfigs` (`max_values`: None, `max_size`: Some(3230), added: 5705, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ActiveRecoveries` (r:1 w:1)
+    /// Proof: `Multisig::ActiveRecoveries` (`max_values`: None, `max_size`: Some(6432), added: 8907, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptMultisigs` (r:1 w:1)
+    /// Proof: `Multisig::DepositExemptMultisigs` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::VelocityLimits` (r:1 w:1)
+    /// Proof: `Multisig::VelocityLimits` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ExecutionWindows` (r:1 w:1)
+    /// Proof: `Multisig::ExecutionWindows` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SpendingLimits` (r:1 w:1)
+    /// Proof: `Multisig::SpendingLimits` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::LastExecutedAt` (r:1 w:1)
+    /// Proof: `Multisig::LastExecutedAt` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SpendingWindows` (r:1 w:1)
+    /// Proof: `Multisig::SpendingWindows` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SigningNonces` (r:1 w:1)
+    /// Proof: `Multisig::SigningNonces` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::UsageMeters` (r:1 w:1)
+    /// Proof: `Multisig::UsageMeters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigAccounts` (r:0 w:1)
+    /// Proof: `Multisig::MultisigAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalsByCallHash` (r:0 w:64)
+    /// Proof: `Multisig::ProposalsByCallHash` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+    /// The range of component `o` is `[2, 100]`.
+    /// The range of component `p` is `[1, 64]`.
+    fn destroy_multisig(o: u32, p: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `850 + o * (303 ±0) + p * (221 ±0)`
+        //  Estimated: `1054708 + o * (3037 ±0) + p * (9134 ±0)`
+        // Minimum execution time: 1_982_483_000 picoseconds.
+        Weight::from_parts(481_282_488, 0)
+            .saturating_add(Weight::from_parts(0, 1054708))
+            // Standard Error: 166_923
+            .saturating_add(Weight::from_parts(23_314_569, 0).saturating_mul(o.into()))
+            // Standard Error: 257_639
+            .saturating_add(Weight::from_parts(24_962_697, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(48))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(30))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(p.into())))
+            .saturating_add(Weight::from_parts(0, 3037).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(0, 9134).saturating_mul(p.into()))
+    }
+    /// Storage: `Multisig::CallUploads` (r:1 w:1)
+    /// Proof: `Multisig::CallUploads` (`max_values`: None, `max_size`: Some(524408), added: 526883, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:0)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CallFilters` (r:1 w:0)
+    /// Proof: `Multisig::CallFilters` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PendingProposalCount` (r:1 w:1)
+    /// Proof: `Multisig::PendingProposalCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::NextProposalIndex` (r:1 w:1)
+    /// Proof: `Multisig::NextProposalIndex` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Parameters::Parameters` (r:4 w:0)
+    /// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptAccounts` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptMultisigs` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptMultisigs` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalDeposits` (r:1 w:1)
+    /// Proof: `Multisig::ProposalDeposits` (`max_values`: None, `max_size`: Some(4846), added: 7321, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PreApprovals` (r:1 w:0)
+    /// Proof: `Multisig::PreApprovals` (`max_values`: None, `max_size`: Some(3254), added: 5729, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:1 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ThresholdDecays` (r:1 w:0)
+    /// Proof: `Multisig::ThresholdDecays` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::UsageMeters` (r:1 w:1)
+    /// Proof: `Multisig::UsageMeters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Proposals` (r:0 w:1)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalsByCallHash` (r:0 w:1)
+    /// Proof: `Multisig::ProposalsByCallHash` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::UploadedCalls` (r:0 w:1)
+    /// Proof: `Multisig::UploadedCalls` (`max_values`: None, `max_size`: Some(524384), added: 526859, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[8, 524288]`.
+    fn finalize_call(b: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `5401 + b * (1 ±0)`
+        //  Estimated: `527873`
+        // Minimum execution time: 338_234_000 picoseconds.
+        Weight::from_parts(113_936_835, 0)
+            .saturating_add(Weight::from_parts(0, 527873))
+            // Standard Error: 32
+            .saturating_add(Weight::from_parts(5_225, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(119))
+            .saturating_add(T::DbWeight::get().writes(10))
+    }
+    /// Storage: `Multisig::Proposals` (r:1 w:1)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PendingProposalCount` (r:1 w:1)
+    /// Proof: `Multisig::PendingProposalCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalDeposits` (r:1 w:1)
+    /// Proof: `Multisig::ProposalDeposits` (`max_values`: None, `max_size`: Some(4846), added: 7321, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:98 w:98)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::UploadedCalls` (r:1 w:0)
+    /// Proof: `Multisig::UploadedCalls` (`max_values`: None, `max_size`: Some(524384), added: 526859, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Earmarks` (r:1 w:0)
+    /// Proof: `Multisig::Earmarks` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalFlags` (r:0 w:1)
+    /// Proof: `Multisig::ProposalFlags` (`max_values`: None, `max_size`: Some(29046), added: 31521, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalsByCallHash` (r:0 w:1)
+    /// Proof: `Multisig::ProposalsByCallHash` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigOriginProposals` (r:0 w:1)
+    /// Proof: `Multisig::MultisigOriginProposals` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// The range of component `o` is `[2, 100]`.
+    fn cancel_proposal(o: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `201 + o * (213 ±0)`
+        //  Estimated: `527849 + o * (2448 ±3)`
+        // Minimum execution time: 53_681_000 picoseconds.
+        Weight::from_parts(22_881_700, 0)
+            .saturating_add(Weight::from_parts(0, 527849))
+            // Standard Error: 38_465
+            .saturating_add(Weight::from_parts(14_457_998, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(o.into())))
+            .saturating_add(Weight::from_parts(0, 2448).saturating_mul(o.into()))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:0)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Proposals` (r:1 w:1)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalDeposits` (r:1 w:1)
+    /// Proof: `Multisig::ProposalDeposits` (`max_values`: None, `max_size`: Some(4846), added: 7321, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:98 w:98)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PendingProposalCount` (r:1 w:1)
+    /// Proof: `Multisig::PendingProposalCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::UploadedCalls` (r:1 w:0)
+    /// Proof: `Multisig::UploadedCalls` (`max_values`: None, `max_size`: Some(524384), added: 526859, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Earmarks` (r:1 w:0)
+    /// Proof: `Multisig::Earmarks` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalFlags` (r:0 w:1)
+    /// Proof: `Multisig::ProposalFlags` (`max_values`: None, `max_size`: Some(29046), added: 31521, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalsByCallHash` (r:0 w:1)
+    /// Proof: `Multisig::ProposalsByCallHash` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigOriginProposals` (r:0 w:1)
+    /// Proof: `Multisig::MultisigOriginProposals` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// The range of component `o` is `[2, 100]`.
+    fn reject_proposal(o: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `256 + o * (245 ±0)`
+        //  Estimated: `527849 + o * (2547 ±3)`
+        // Minimum execution time: 77_630_000 picoseconds.
+        Weight::from_parts(110_370_543, 0)
+            .saturating_add(Weight::from_parts(0, 527849))
+            // Standard Error: 64_566
+            .saturating_add(Weight::from_parts(14_490_542, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().writes(5))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(o.into())))
+            .saturating_add(Weight::from_parts(0, 2547).saturating_mul(o.into()))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:1)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:1)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:1 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::GroupQuorums` (r:1 w:1)
+    /// Proof: `Multisig::GroupQuorums` (`max_values`: None, `max_size`: Some(26198), added: 28673, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::VelocityLimits` (r:1 w:0)
+    /// Proof: `Multisig::VelocityLimits` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerToMultisigs` (r:2 w:2)
+    /// Proof: `Multisig::OwnerToMultisigs` (`max_values`: None, `max_size`: Some(562), added: 3037, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerLabels` (r:0 w:1)
+    /// Proof: `Multisig::OwnerLabels` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::StandingApprovals` (r:0 w:1)
+    /// Proof: `Multisig::StandingApprovals` (`max_values`: None, `max_size`: Some(901), added: 3376, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Delegations` (r:0 w:1)
+    /// Proof: `Multisig::Delegations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+    /// The range of component `o` is `[2, 100]`.
+    fn change_owners(o: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `626 + o * (38 ±0)`
+        //  Estimated: `29663 + o * (2547 ±0)`
+        // Minimum execution time: 54_183_000 picoseconds.
+        Weight::from_parts(54_368_545, 0)
+            .saturating_add(Weight::from_parts(0, 29663))
+            // Standard Error: 7_526
+            .saturating_add(Weight::from_parts(2_431_358, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().writes(8))
+            .saturating_add(Weight::from_parts(0, 2547).saturating_mul(o.into()))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SigningNonces` (r:1 w:1)
+    /// Proof: `Multisig::SigningNonces` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PreApprovals` (r:64 w:64)
+    /// Proof: `Multisig::PreApprovals` (`max_values`: None, `max_size`: Some(3254), added: 5729, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PreApprovalCount` (r:0 w:1)
+    /// Proof: `Multisig::PreApprovalCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// The range of component `p` is `[0, 64]`.
+    fn bump_signing_nonce(p: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `506 + p * (69 ±0)`
+        //  Estimated: `13095 + p * (5729 ±0)`
+        // Minimum execution time: 23_660_000 picoseconds.
+        Weight::from_parts(28_556_671, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            // Standard Error: 4_967
+            .saturating_add(Weight::from_parts(1_302_509, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+            .saturating_add(Weight::from_parts(0, 5729).saturating_mul(p.into()))
+    }
+    /// Storage: `Multisig::PayrollAgenda` (r:2 w:2)
+    /// Proof: `Multisig::PayrollAgenda` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Payrolls` (r:16 w:16)
+    /// Proof: `Multisig::Payrolls` (`max_values`: None, `max_size`: Some(4831), added: 7306, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SovereignAccounts` (r:16 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProtectedReserves` (r:16 w:0)
+    /// Proof: `Multisig::ProtectedReserves` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:116 w:116)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[0, 16]`.
+    /// The range of component `p` is `[1, 100]`.
+    fn pay_payrolls(n: u32, p: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0 + n * (4955 ±0) + p * (768 ±0)`
+        //  Estimated: `5199 + n * (7306 ±3) + p * (2603 ±0)`
+        // Minimum execution time: 5_117_000 picoseconds.
+        Weight::from_parts(5_437_000, 0)
+            .saturating_add(Weight::from_parts(0, 5199))
+            // Standard Error: 26_713_100
+            .saturating_add(Weight::from_parts(892_625_174, 0).saturating_mul(n.into()))
+            // Standard Error: 4_236_963
+            .saturating_add(Weight::from_parts(139_893_622, 0).saturating_mul(p.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+            .saturating_add(Weight::from_parts(0, 7306).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Proposals` (r:64 w:64)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:0)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:1 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ThresholdDecays` (r:1 w:0)
+    /// Proof: `Multisig::ThresholdDecays` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::GroupQuorums` (r:1 w:0)
+    /// Proof: `Multisig::GroupQuorums` (`max_values`: None, `max_size`: Some(26198), added: 28673, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ExecutionDelays` (r:1 w:0)
+    /// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Earmarks` (r:64 w:0)
+    /// Proof: `Multisig::Earmarks` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:100 w:99)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigOriginProposals` (r:64 w:64)
+    /// Proof: `Multisig::MultisigOriginProposals` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProtectedReserves` (r:1 w:0)
+    /// Proof: `Multisig::ProtectedReserves` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::VelocityLimits` (r:1 w:0)
+    /// Proof: `Multisig::VelocityLimits` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalDeposits` (r:64 w:64)
+    /// Proof: `Multisig::ProposalDeposits` (`max_values`: None, `max_size`: Some(4846), added: 7321, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::UploadedCalls` (r:64 w:0)
+    /// Proof: `Multisig::UploadedCalls` (`max_values`: None, `max_size`: Some(524384), added: 526859, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PendingProposalCount` (r:1 w:1)
+    /// Proof: `Multisig::PendingProposalCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::UsageMeters` (r:1 w:1)
+    /// Proof: `Multisig::UsageMeters` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::LastExecutedAt` (r:0 w:1)
+    /// Proof: `Multisig::LastExecutedAt` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalsByCallHash` (r:0 w:64)
+    /// Proof: `Multisig::ProposalsByCallHash` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+    /// The range of component `n` is `[1, 64]`.
+    fn batch_execute(n: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `17296 + n * (8148 ±0)`
+        //  Estimated: `261290 + n * (526859 ±0)`
+        // Minimum execution time: 1_714_967_000 picoseconds.
+        Weight::from_parts(404_441_777, 0)
+            .saturating_add(Weight::from_parts(0, 261290))
+            // Standard Error: 2_631_272
+            .saturating_add(Weight::from_parts(1_327_241_181, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(212))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(103))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 526859).saturating_mul(n.into()))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PreApprovals` (r:1 w:1)
+    /// Proof: `Multisig::PreApprovals` (`max_values`: None, `max_size`: Some(3254), added: 5729, mode: `MaxEncodedLen`)
+    fn pre_approve() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `6761`
+        //  Estimated: `13095`
+        // Minimum execution time: 27_313_000 picoseconds.
+        Weight::from_parts(28_523_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::PreApprovals` (r:1 w:1)
+    /// Proof: `Multisig::PreApprovals` (`max_values`: None, `max_size`: Some(3254), added: 5729, mode: `MaxEncodedLen`)
+    fn cancel_pre_approval() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3537`
+        //  Estimated: `6719`
+        // Minimum execution time: 20_546_000 picoseconds.
+        Weight::from_parts(22_552_000, 0)
+            .saturating_add(Weight::from_parts(0, 6719))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:0)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CallFilters` (r:1 w:0)
+    /// Proof: `Multisig::CallFilters` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PendingProposalCount` (r:1 w:1)
+    /// Proof: `Multisig::PendingProposalCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::NextProposalIndex` (r:1 w:1)
+    /// Proof: `Multisig::NextProposalIndex` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Parameters::Parameters` (r:4 w:0)
+    /// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptAccounts` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptMultisigs` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptMultisigs` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalDeposits` (r:1 w:1)
+    /// Proof: `Multisig::ProposalDeposits` (`max_values`: None, `max_size`: Some(4846), added: 7321, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PreApprovals` (r:1 w:0)
+    /// Proof: `Multisig::PreApprovals` (`max_values`: None, `max_size`: Some(3254), added: 5729, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::StandingApprovals` (r:99 w:0)
+    /// Proof: `Multisig::StandingApprovals` (`max_values`: None, `max_size`: Some(901), added: 3376, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:1 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ThresholdDecays` (r:1 w:0)
+    /// Proof: `Multisig::ThresholdDecays` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Earmarks` (r:0 w:1)
+    /// Proof: `Multisig::Earmarks` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Proposals` (r:0 w:1)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalsByCallHash` (r:0 w:1)
+    /// Proof: `Multisig::ProposalsByCallHash` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+    fn submit_typed_proposal() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `5444`
+        //  Estimated: `335214`
+        // Minimum execution time: 533_115_000 picoseconds.
+        Weight::from_parts(542_577_000, 0)
+            .saturating_add(Weight::from_parts(0, 335214))
+            .saturating_add(T::DbWeight::get().reads(218))
+            .saturating_add(T::DbWeight::get().writes(9))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::StandingApprovals` (r:1 w:1)
+    /// Proof: `Multisig::StandingApprovals` (`max_values`: None, `max_size`: Some(901), added: 3376, mode: `MaxEncodedLen`)
+    fn grant_standing_approval() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `4360`
+        //  Estimated: `13095`
+        // Minimum execution time: 28_624_000 picoseconds.
+        Weight::from_parts(36_760_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::StandingApprovals` (r:1 w:1)
+    /// Proof: `Multisig::StandingApprovals` (`max_values`: None, `max_size`: Some(901), added: 3376, mode: `MaxEncodedLen`)
+    fn revoke_standing_approval() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1156`
+        //  Estimated: `4366`
+        // Minimum execution time: 28_539_000 picoseconds.
+        Weight::from_parts(31_939_000, 0)
+            .saturating_add(Weight::from_parts(0, 4366))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:1)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    fn set_required_approvers() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3481`
+        //  Estimated: `13095`
+        // Minimum execution time: 56_292_000 picoseconds.
+        Weight::from_parts(63_814_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:1)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    fn set_approval_order() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3481`
+        //  Estimated: `13095`
+        // Minimum execution time: 56_829_000 picoseconds.
+        Weight::from_parts(60_066_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::GroupQuorums` (r:0 w:1)
+    /// Proof: `Multisig::GroupQuorums` (`max_values`: None, `max_size`: Some(26198), added: 28673, mode: `MaxEncodedLen`)
+    fn set_group_quorum() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3481`
+        //  Estimated: `13095`
+        // Minimum execution time: 270_203_000 picoseconds.
+        Weight::from_parts(280_783_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:1)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ActiveMultisigCount` (r:1 w:1)
+    /// Proof: `Multisig::ActiveMultisigCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MaxActiveMultisigs` (r:1 w:0)
+    /// Proof: `Multisig::MaxActiveMultisigs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::NextMultisigId` (r:1 w:1)
+    /// Proof: `Multisig::NextMultisigId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Parameters::Parameters` (r:2 w:0)
+    /// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptAccounts` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerToMultisigs` (r:100 w:100)
+    /// Proof: `Multisig::OwnerToMultisigs` (`max_values`: None, `max_size`: Some(562), added: 3037, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::GroupQuorums` (r:1 w:1)
+    /// Proof: `Multisig::GroupQuorums` (`max_values`: None, `max_size`: Some(26198), added: 28673, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigContractLimits` (r:1 w:0)
+    /// Proof: `Multisig::MultisigContractLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigEvmLimits` (r:1 w:0)
+    /// Proof: `Multisig::MultisigEvmLimits` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProtectedReserves` (r:1 w:0)
+    /// Proof: `Multisig::ProtectedReserves` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::VelocityLimits` (r:1 w:0)
+    /// Proof: `Multisig::VelocityLimits` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SpendingLimits` (r:1 w:0)
+    /// Proof: `Multisig::SpendingLimits` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ThresholdDecays` (r:1 w:0)
+    /// Proof: `Multisig::ThresholdDecays` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ExecutionDelays` (r:1 w:0)
+    /// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Auditors` (r:1 w:0)
+    /// Proof: `Multisig::Auditors` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Vetoers` (r:1 w:0)
+    /// Proof: `Multisig::Vetoers` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::RecoveryConfigs` (r:1 w:0)
+    /// Proof: `Multisig::RecoveryConfigs` (`max_values`: None, `max_size`: Some(3230), added: 5705, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerLabels` (r:101 w:100)
+    /// Proof: `Multisig::OwnerLabels` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:202 w:100)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:10 w:4)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CallFilters` (r:2 w:0)
+    /// Proof: `Multisig::CallFilters` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigAccounts` (r:0 w:1)
+    /// Proof: `Multisig::MultisigAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CreationDeposits` (r:0 w:1)
+    /// Proof: `Multisig::CreationDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn clone_multisig() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `53953`
+        //  Estimated: `515484`
+        // Minimum execution time: 2_101_754_000 picoseconds.
+        Weight::from_parts(2_130_345_000, 0)
+            .saturating_add(Weight::from_parts(0, 515484))
+            .saturating_add(T::DbWeight::get().reads(434))
+            .saturating_add(T::DbWeight::get().writes(310))
+    }
+    /// Storage: `Multisig::NextTemplateId` (r:1 w:1)
+    /// Proof: `Multisig::NextTemplateId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Templates` (r:0 w:1)
+    /// Proof: `Multisig::Templates` (`max_values`: None, `max_size`: Some(7496), added: 9971, mode: `MaxEncodedLen`)
+    fn register_template() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `225`
+        //  Estimated: `1489`
+        // Minimum execution time: 29_760_000 picoseconds.
+        Weight::from_parts(31_174_000, 0)
+            .saturating_add(Weight::from_parts(0, 1489))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `Multisig::Templates` (r:1 w:1)
+    /// Proof: `Multisig::Templates` (`max_values`: None, `max_size`: Some(7496), added: 9971, mode: `MaxEncodedLen`)
+    fn remove_template() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `321`
+        //  Estimated: `10961`
+        // Minimum execution time: 11_827_000 picoseconds.
+        Weight::from_parts(12_267_000, 0)
+            .saturating_add(Weight::from_parts(0, 10961))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Templates` (r:1 w:0)
+    /// Proof: `Multisig::Templates` (`max_values`: None, `max_size`: Some(7496), added: 9971, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ActiveMultisigCount` (r:1 w:1)
+    /// Proof: `Multisig::ActiveMultisigCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MaxActiveMultisigs` (r:1 w:0)
+    /// Proof: `Multisig::MaxActiveMultisigs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::NextMultisigId` (r:1 w:1)
+    /// Proof: `Multisig::NextMultisigId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    /// Storage: `Parameters::Parameters` (r:2 w:0)
+    /// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptAccounts` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerToMultisigs` (r:100 w:100)
+    /// Proof: `Multisig::OwnerToMultisigs` (`max_values`: None, `max_size`: Some(562), added: 3037, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:0 w:1)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigAccounts` (r:0 w:1)
+    /// Proof: `Multisig::MultisigAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CreationDeposits` (r:0 w:1)
+    /// Proof: `Multisig::CreationDeposits` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+    fn create_from_template() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `7974`
+        //  Estimated: `304690`
+        // Minimum execution time: 343_919_000 picoseconds.
+        Weight::from_parts(354_365_000, 0)
+            .saturating_add(Weight::from_parts(0, 304690))
+            .saturating_add(T::DbWeight::get().reads(108))
+            .saturating_add(T::DbWeight::get().writes(105))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:0)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CallFilters` (r:1 w:0)
+    /// Proof: `Multisig::CallFilters` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PendingProposalCount` (r:1 w:1)
+    /// Proof: `Multisig::PendingProposalCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::NextProposalIndex` (r:1 w:1)
+    /// Proof: `Multisig::NextProposalIndex` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Parameters::Parameters` (r:4 w:0)
+    /// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptAccounts` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptMultisigs` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptMultisigs` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalDeposits` (r:1 w:1)
+    /// Proof: `Multisig::ProposalDeposits` (`max_values`: None, `max_size`: Some(4846), added: 7321, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PreApprovals` (r:1 w:0)
+    /// Proof: `Multisig::PreApprovals` (`max_values`: None, `max_size`: Some(3254), added: 5729, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:1 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ThresholdDecays` (r:1 w:0)
+    /// Proof: `Multisig::ThresholdDecays` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Proposals` (r:0 w:1)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalsByCallHash` (r:0 w:1)
+    /// Proof: `Multisig::ProposalsByCallHash` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+    fn submit_proposal_with_content() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `5183`
+        //  Estimated: `255690`
+        // Minimum execution time: 302_040_000 picoseconds.
+        Weight::from_parts(308_001_000, 0)
+            .saturating_add(Weight::from_parts(0, 255690))
+            .saturating_add(T::DbWeight::get().reads(117))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigContractLimits` (r:0 w:1)
+    /// Proof: `Multisig::MultisigContractLimits` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn set_contract_limits() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 18_145_000 picoseconds.
+        Weight::from_parts(19_427_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::MultisigEvmLimits` (r:0 w:1)
+    /// Proof: `Multisig::MultisigEvmLimits` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+    fn set_evm_limits() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 19_043_000 picoseconds.
+        Weight::from_parts(20_107_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::InboundSpends` (r:1 w:1)
+    /// Proof: `Multisig::InboundSpends` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    fn track_inbound_spend() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3481`
+        //  Estimated: `13095`
+        // Minimum execution time: 23_676_000 picoseconds.
+        Weight::from_parts(26_297_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::InboundSpends` (r:1 w:1)
+    /// Proof: `Multisig::InboundSpends` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    fn untrack_inbound_spend() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3554`
+        //  Estimated: `13095`
+        // Minimum execution time: 24_539_000 picoseconds.
+        Weight::from_parts(26_614_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Auditors` (r:0 w:1)
+    /// Proof: `Multisig::Auditors` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    fn set_auditors() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 31_512_000 picoseconds.
+        Weight::from_parts(32_731_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Auditors` (r:1 w:0)
+    /// Proof: `Multisig::Auditors` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Proposals` (r:1 w:0)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalFlags` (r:1 w:1)
+    /// Proof: `Multisig::ProposalFlags` (`max_values`: None, `max_size`: Some(29046), added: 31521, mode: `MaxEncodedLen`)
+    fn flag_proposal() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `32513`
+        //  Estimated: `32511`
+        // Minimum execution time: 55_380_000 picoseconds.
+        Weight::from_parts(57_717_000, 0)
+            .saturating_add(Weight::from_parts(0, 32511))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    fn dispatch_as_multisig() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `302`
+        //  Estimated: `13095`
+        // Minimum execution time: 16_321_000 picoseconds.
+        Weight::from_parts(17_127_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProtectedReserves` (r:0 w:1)
+    /// Proof: `Multisig::ProtectedReserves` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn set_protected_reserve() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 18_424_000 picoseconds.
+        Weight::from_parts(19_061_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PayrollAgenda` (r:1 w:1)
+    /// Proof: `Multisig::PayrollAgenda` (`max_values`: None, `max_size`: Some(77), added: 2552, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Payrolls` (r:0 w:1)
+    /// Proof: `Multisig::Payrolls` (`max_values`: None, `max_size`: Some(4831), added: 7306, mode: `MaxEncodedLen`)
+    fn set_payroll() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 22_742_000 picoseconds.
+        Weight::from_parts(24_680_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Payrolls` (r:1 w:1)
+    /// Proof: `Multisig::Payrolls` (`max_values`: None, `max_size`: Some(4831), added: 7306, mode: `MaxEncodedLen`)
+    fn set_payroll_paused() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `5203`
+        //  Estimated: `13095`
+        // Minimum execution time: 23_350_000 picoseconds.
+        Weight::from_parts(24_503_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Payrolls` (r:1 w:1)
+    /// Proof: `Multisig::Payrolls` (`max_values`: None, `max_size`: Some(4831), added: 7306, mode: `MaxEncodedLen`)
+    fn cancel_payroll() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `5203`
+        //  Estimated: `13095`
+        // Minimum execution time: 22_871_000 picoseconds.
+        Weight::from_parts(24_307_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OperatorSessions` (r:0 w:1)
+    /// Proof: `Multisig::OperatorSessions` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+    fn grant_session() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 19_083_000 picoseconds.
+        Weight::from_parts(20_308_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OperatorSessions` (r:1 w:1)
+    /// Proof: `Multisig::OperatorSessions` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+    fn revoke_session() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3579`
+        //  Estimated: `13095`
+        // Minimum execution time: 24_497_000 picoseconds.
+        Weight::from_parts(27_378_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OperatorSessions` (r:1 w:0)
+    /// Proof: `Multisig::OperatorSessions` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:0)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProtectedReserves` (r:1 w:0)
+    /// Proof: `Multisig::ProtectedReserves` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::VelocityLimits` (r:1 w:0)
+    /// Proof: `Multisig::VelocityLimits` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ExecutionWindows` (r:1 w:1)
+    /// Proof: `Multisig::ExecutionWindows` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    fn operator_execute() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `527`
+        //  Estimated: `13095`
+        // Minimum execution time: 39_201_000 picoseconds.
+        Weight::from_parts(40_435_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:0)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CallFilters` (r:1 w:0)
+    /// Proof: `Multisig::CallFilters` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PendingProposalCount` (r:1 w:1)
+    /// Proof: `Multisig::PendingProposalCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::NextProposalIndex` (r:1 w:1)
+    /// Proof: `Multisig::NextProposalIndex` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Parameters::Parameters` (r:4 w:0)
+    /// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptAccounts` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptMultisigs` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptMultisigs` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalDeposits` (r:1 w:1)
+    /// Proof: `Multisig::ProposalDeposits` (`max_values`: None, `max_size`: Some(4846), added: 7321, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::PreApprovals` (r:1 w:0)
+    /// Proof: `Multisig::PreApprovals` (`max_values`: None, `max_size`: Some(3254), added: 5729, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:1 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ThresholdDecays` (r:1 w:0)
+    /// Proof: `Multisig::ThresholdDecays` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Proposals` (r:0 w:1)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalsByCallHash` (r:0 w:1)
+    /// Proof: `Multisig::ProposalsByCallHash` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+    fn submit_proposal_with_funds() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `5255`
+        //  Estimated: `255690`
+        // Minimum execution time: 340_317_000 picoseconds.
+        Weight::from_parts(347_279_000, 0)
+            .saturating_add(Weight::from_parts(0, 255690))
+            .saturating_add(T::DbWeight::get().reads(119))
+            .saturating_add(T::DbWeight::get().writes(8))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerLabels` (r:0 w:1)
+    /// Proof: `Multisig::OwnerLabels` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+    fn set_owner_label() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3481`
+        //  Estimated: `13095`
+        // Minimum execution time: 27_189_000 picoseconds.
+        Weight::from_parts(30_884_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:1)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:5 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::GroupQuorums` (r:1 w:0)
+    /// Proof: `Multisig::GroupQuorums` (`max_values`: None, `max_size`: Some(26198), added: 28673, mode: `MaxEncodedLen`)
+    fn suspend_owner() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `33033`
+        //  Estimated: `255690`
+        // Minimum execution time: 418_416_000 picoseconds.
+        Weight::from_parts(428_217_000, 0)
+            .saturating_add(Weight::from_parts(0, 255690))
+            .saturating_add(T::DbWeight::get().reads(109))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:1)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    fn reinstate_owner() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `6699`
+        //  Estimated: `13095`
+        // Minimum execution time: 34_446_000 picoseconds.
+        Weight::from_parts(37_034_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:0 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    fn set_backup_owners() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 32_559_000 picoseconds.
+        Weight::from_parts(34_431_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    fn clear_backup_owners() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3572`
+        //  Estimated: `13095`
+        // Minimum execution time: 22_300_000 picoseconds.
+        Weight::from_parts(23_465_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:1)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerToMultisigs` (r:200 w:200)
+    /// Proof: `Multisig::OwnerToMultisigs` (`max_values`: None, `max_size`: Some(562), added: 3037, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerLabels` (r:100 w:100)
+    /// Proof: `Multisig::OwnerLabels` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:100)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:4 w:4)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::GroupQuorums` (r:0 w:1)
+    /// Proof: `Multisig::GroupQuorums` (`max_values`: None, `max_size`: Some(26198), added: 28673, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:0 w:1)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    fn activate_backup_owners() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `27233`
+        //  Estimated: `608390`
+        // Minimum execution time: 987_789_000 picoseconds.
+        Weight::from_parts(1_016_529_000, 0)
+            .saturating_add(Weight::from_parts(0, 608390))
+            .saturating_add(T::DbWeight::get().reads(406))
+            .saturating_add(T::DbWeight::get().writes(408))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptMultisigs` (r:0 w:1)
+    /// Proof: `Multisig::DepositExemptMultisigs` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    fn set_deposit_exemption() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `302`
+        //  Estimated: `13095`
+        // Minimum execution time: 13_039_000 picoseconds.
+        Weight::from_parts(13_834_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::VelocityLimits` (r:0 w:1)
+    /// Proof: `Multisig::VelocityLimits` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ExecutionWindows` (r:0 w:1)
+    /// Proof: `Multisig::ExecutionWindows` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    fn set_velocity_limit() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3481`
+        //  Estimated: `255690`
+        // Minimum execution time: 219_839_000 picoseconds.
+        Weight::from_parts(223_648_000, 0)
+            .saturating_add(Weight::from_parts(0, 255690))
+            .saturating_add(T::DbWeight::get().reads(102))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `Multisig::MaxActiveMultisigs` (r:0 w:1)
+    /// Proof: `Multisig::MaxActiveMultisigs` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+    fn set_max_active_multisigs() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `0`
+        // Minimum execution time: 4_872_000 picoseconds.
+        Weight::from_parts(5_337_000, 0)
+            .saturating_add(Weight::from_parts(0, 0))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CallUploads` (r:1 w:1)
+    /// Proof: `Multisig::CallUploads` (`max_values`: None, `max_size`: Some(524408), added: 526883, mode: `MaxEncodedLen`)
+    /// Storage: `Parameters::Parameters` (r:1 w:0)
+    /// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptAccounts` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptAccounts` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::DepositExemptMultisigs` (r:1 w:0)
+    /// Proof: `Multisig::DepositExemptMultisigs` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// The range of component `b` is `[1, 524288]`.
+    fn note_call_chunk(b: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3829`
+        //  Estimated: `527873`
+        // Minimum execution time: 50_822_000 picoseconds.
+        Weight::from_parts(54_235_000, 0)
+            .saturating_add(Weight::from_parts(0, 527873))
+            // Standard Error: 11
+            .saturating_add(Weight::from_parts(1_575, 0).saturating_mul(b.into()))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `Multisig::CallUploads` (r:1 w:1)
+    /// Proof: `Multisig::CallUploads` (`max_values`: None, `max_size`: Some(524408), added: 526883, mode: `MaxEncodedLen`)
+    fn discard_call_upload() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `524663`
+        //  Estimated: `527873`
+        // Minimum execution time: 853_581_000 picoseconds.
+        Weight::from_parts(869_474_000, 0)
+            .saturating_add(Weight::from_parts(0, 527873))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Proposals` (r:1 w:1)
+    /// Proof: `Multisig::Proposals` (`max_values`: None, `max_size`: Some(6659), added: 9134, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProposalDeposits` (r:1 w:1)
+    /// Proof: `Multisig::ProposalDeposits` (`max_values`: None, `max_size`: Some(4846), added: 7321, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:1 w:1)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:1 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:0)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ThresholdDecays` (r:1 w:0)
+    /// Proof: `Multisig::ThresholdDecays` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// The range of component `o` is `[2, 100]`.
+    fn revoke_approval(o: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `790 + o * (113 ±0)`
+        //  Estimated: `13095 + o * (2547 ±0)`
+        // Minimum execution time: 55_966_000 picoseconds.
+        Weight::from_parts(52_601_089, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            // Standard Error: 4_470
+            .saturating_add(Weight::from_parts(2_402_004, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().writes(3))
+            .saturating_add(Weight::from_parts(0, 2547).saturating_mul(o.into()))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Delegations` (r:100 w:1)
+    /// Proof: `Multisig::Delegations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+    /// The range of component `o` is `[1, 100]`.
+    fn delegate_confirmations(o: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `241 + o * (123 ±0)`
+        //  Estimated: `13095 + o * (2579 ±0)`
+        // Minimum execution time: 20_116_000 picoseconds.
+        Weight::from_parts(19_281_383, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            // Standard Error: 6_230
+            .saturating_add(Weight::from_parts(3_873_595, 0).saturating_mul(o.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(o.into())))
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(Weight::from_parts(0, 2579).saturating_mul(o.into()))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:99 w:1)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:0)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:5 w:0)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::VelocityLimits` (r:1 w:0)
+    /// Proof: `Multisig::VelocityLimits` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+    fn set_owner_weight() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `5344`
+        //  Estimated: `253143`
+        // Minimum execution time: 460_568_000 picoseconds.
+        Weight::from_parts(466_431_000, 0)
+            .saturating_add(Weight::from_parts(0, 253143))
+            .saturating_add(T::DbWeight::get().reads(108))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:0)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:0 w:1)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    fn set_class_threshold() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3481`
+        //  Estimated: `255690`
+        // Minimum execution time: 210_121_000 picoseconds.
+        Weight::from_parts(213_124_000, 0)
+            .saturating_add(Weight::from_parts(0, 255690))
+            .saturating_add(T::DbWeight::get().reads(102))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::CallFilters` (r:0 w:1)
+    /// Proof: `Multisig::CallFilters` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+    fn set_call_filter() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 18_350_000 picoseconds.
+        Weight::from_parts(19_457_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SpendingLimits` (r:0 w:1)
+    /// Proof: `Multisig::SpendingLimits` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SpendingWindows` (r:0 w:1)
+    /// Proof: `Multisig::SpendingWindows` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    fn set_spending_limit() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 19_443_000 picoseconds.
+        Weight::from_parts(20_327_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:1 w:0)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SpendingLimits` (r:1 w:0)
+    /// Proof: `Multisig::SpendingLimits` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SpendingWindows` (r:1 w:1)
+    /// Proof: `Multisig::SpendingWindows` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ProtectedReserves` (r:1 w:0)
+    /// Proof: `Multisig::ProtectedReserves` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::VelocityLimits` (r:1 w:0)
+    /// Proof: `Multisig::VelocityLimits` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ExecutionWindows` (r:1 w:1)
+    /// Proof: `Multisig::ExecutionWindows` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::BackupOwnerSets` (r:1 w:1)
+    /// Proof: `Multisig::BackupOwnerSets` (`max_values`: None, `max_size`: Some(3234), added: 5709, mode: `MaxEncodedLen`)
+    fn spend_allowance() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `4258`
+        //  Estimated: `13095`
+        // Minimum execution time: 89_860_000 picoseconds.
+        Weight::from_parts(92_471_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(11))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ExecutionDelays` (r:0 w:1)
+    /// Proof: `Multisig::ExecutionDelays` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    fn set_execution_delay() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 17_826_000 picoseconds.
+        Weight::from_parts(18_827_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Vetoers` (r:0 w:1)
+    /// Proof: `Multisig::Vetoers` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    fn set_vetoers() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 32_229_000 picoseconds.
+        Weight::from_parts(33_459_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::RecoveryConfigs` (r:0 w:1)
+    /// Proof: `Multisig::RecoveryConfigs` (`max_values`: None, `max_size`: Some(3230), added: 5705, mode: `MaxEncodedLen`)
+    fn set_recovery_config() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 31_852_000 picoseconds.
+        Weight::from_parts(33_064_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::RecoveryConfigs` (r:1 w:1)
+    /// Proof: `Multisig::RecoveryConfigs` (`max_values`: None, `max_size`: Some(3230), added: 5705, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ActiveRecoveries` (r:0 w:1)
+    /// Proof: `Multisig::ActiveRecoveries` (`max_values`: None, `max_size`: Some(6432), added: 8907, mode: `MaxEncodedLen`)
+    fn remove_recovery_config() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3602`
+        //  Estimated: `13095`
+        // Minimum execution time: 23_645_000 picoseconds.
+        Weight::from_parts(24_811_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::RecoveryConfigs` (r:1 w:0)
+    /// Proof: `Multisig::RecoveryConfigs` (`max_values`: None, `max_size`: Some(3230), added: 5705, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ActiveRecoveries` (r:1 w:1)
+    /// Proof: `Multisig::ActiveRecoveries` (`max_values`: None, `max_size`: Some(6432), added: 8907, mode: `MaxEncodedLen`)
+    fn initiate_recovery() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `3556`
+        //  Estimated: `13095`
+        // Minimum execution time: 34_369_000 picoseconds.
+        Weight::from_parts(35_467_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::RecoveryConfigs` (r:1 w:0)
+    /// Proof: `Multisig::RecoveryConfigs` (`max_values`: None, `max_size`: Some(3230), added: 5705, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ActiveRecoveries` (r:1 w:1)
+    /// Proof: `Multisig::ActiveRecoveries` (`max_values`: None, `max_size`: Some(6432), added: 8907, mode: `MaxEncodedLen`)
+    fn approve_recovery() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `9906`
+        //  Estimated: `9897`
+        // Minimum execution time: 22_031_000 picoseconds.
+        Weight::from_parts(24_256_000, 0)
+            .saturating_add(Weight::from_parts(0, 9897))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:1)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::RecoveryConfigs` (r:1 w:0)
+    /// Proof: `Multisig::RecoveryConfigs` (`max_values`: None, `max_size`: Some(3230), added: 5705, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ActiveRecoveries` (r:1 w:1)
+    /// Proof: `Multisig::ActiveRecoveries` (`max_values`: None, `max_size`: Some(6432), added: 8907, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerToMultisigs` (r:200 w:200)
+    /// Proof: `Multisig::OwnerToMultisigs` (`max_values`: None, `max_size`: Some(562), added: 3037, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerLabels` (r:100 w:100)
+    /// Proof: `Multisig::OwnerLabels` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::OwnerWeights` (r:100 w:100)
+    /// Proof: `Multisig::OwnerWeights` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ClassThresholds` (r:4 w:4)
+    /// Proof: `Multisig::ClassThresholds` (`max_values`: None, `max_size`: Some(41), added: 2516, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::GroupQuorums` (r:0 w:1)
+    /// Proof: `Multisig::GroupQuorums` (`max_values`: None, `max_size`: Some(26198), added: 28673, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::SuspendedOwners` (r:0 w:1)
+    /// Proof: `Multisig::SuspendedOwners` (`max_values`: None, `max_size`: Some(3222), added: 5697, mode: `MaxEncodedLen`)
+    fn claim_recovery() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `33685`
+        //  Estimated: `608390`
+        // Minimum execution time: 1_004_716_000 picoseconds.
+        Weight::from_parts(1_016_990_000, 0)
+            .saturating_add(Weight::from_parts(0, 608390))
+            .saturating_add(T::DbWeight::get().reads(407))
+            .saturating_add(T::DbWeight::get().writes(408))
+    }
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ActiveRecoveries` (r:1 w:1)
+    /// Proof: `Multisig::ActiveRecoveries` (`max_values`: None, `max_size`: Some(6432), added: 8907, mode: `MaxEncodedLen`)
+    fn cancel_recovery() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `6805`
+        //  Estimated: `13095`
+        // Minimum execution time: 25_024_000 picoseconds.
+        Weight::from_parts(27_454_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::ThresholdDecays` (r:0 w:1)
+    /// Proof: `Multisig::ThresholdDecays` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::LastExecutedAt` (r:0 w:1)
+    /// Proof: `Multisig::LastExecutedAt` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+    fn set_threshold_decay() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 19_966_000 picoseconds.
+        Weight::from_parts(20_961_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    /// Storage: `Multisig::Delegations` (r:1 w:1)
+    /// Proof: `Multisig::Delegations` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+    fn revoke_delegation() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `353`
+        //  Estimated: `3569`
+        // Minimum execution time: 13_142_000 picoseconds.
+        Weight::from_parts(13_698_000, 0)
+            .saturating_add(Weight::from_parts(0, 3569))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    /// Storage: `Multisig::SovereignAccounts` (r:1 w:0)
+    /// Proof: `Multisig::SovereignAccounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `Multisig::Multisigs` (r:1 w:0)
+    /// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(9630), added: 12105, mode: `MaxEncodedLen`)
+    fn dispatch_as_sub() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `311`
+        //  Estimated: `13095`
+        // Minimum execution time: 18_646_000 picoseconds.
+        Weight::from_parts(19_849_000, 0)
+            .saturating_add(Weight::from_parts(0, 13095))
+            .saturating_add(T::DbWeight::get().reads(2))
+    }
+    fn verify_signature() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `0`
+        // Minimum execution time: 37_243_000 picoseconds.
+        Weight::from_parts(37_937_000, 0)
+            .saturating_add(Weight::from_parts(0, 0))
+    }
+}
+
+/// A dummy implementation for tests.
+///
+/// These weights are estimates, not benchmark results. The execution times are placeholders,
+/// but the proof sizes are estimated from the storage each call reads: the `MaxEncodedLen` of
+/// every item, at the template runtime's bounds, plus the trie nodes proving it, and the
+/// balances of the accounts whose deposits move. Items read once per owner, approval,
+/// proposal, payee or byte are charged per component.
+///
+/// Runtimes should use `SubstrateWeight` instead.
 impl WeightInfo for () {
-    fn create_multisig() -> Weight {
-        Weight::from_parts(10_000, 0)
+    /// The range of component `o` is `[1, 100]`.
+    fn create_multisig(o: u32) -> Weight {
+        Weight::from_parts(10_000, 11_753)
             .saturating_add(Weight::from_parts(100_000_000, 0))
+            .saturating_add(Weight::from_parts(50_000, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(0, 3_037).saturating_mul(o.into()))
     }
     
     fn submit_proposal() -> Weight {
-        Weight::from_parts(20_000, 0)
+        Weight::from_parts(20_000, 98_929)
             .saturating_add(Weight::from_parts(150_000_000, 0))
     }
 
-    fn confirm_proposal() -> Weight {
-        Weight::from_parts(20_000, 0)
+    /// The range of component `o` is `[2, 100]`.
+    /// The range of component `a` is `[2, 100]`.
+    fn confirm_proposal(o: u32, a: u32) -> Weight {
+        Weight::from_parts(20_000, 97_124)
+            .saturating_add(Weight::from_parts(150_000_000, 0))
+            .saturating_add(Weight::from_parts(100_000, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(50_000, 0).saturating_mul(a.into()))
+            .saturating_add(Weight::from_parts(0, 2_547).saturating_mul(a.into()))
+    }
+    /// The range of component `o` is `[1, 100]`.
+    /// The range of component `a` is `[1, 100]`.
+    fn execute_proposal(o: u32, a: u32) -> Weight {
+        Weight::from_parts(20_000, 628_292)
+            .saturating_add(Weight::from_parts(150_000_000, 0))
+            .saturating_add(Weight::from_parts(150_000, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(50_000, 0).saturating_mul(a.into()))
+            .saturating_add(Weight::from_parts(0, 5_150).saturating_mul(a.into()))
+    }
+    /// The range of component `o` is `[2, 100]`.
+    /// The range of component `p` is `[1, 64]`.
+    fn destroy_multisig(o: u32, p: u32) -> Weight {
+        Weight::from_parts(20_000, 1_251_197)
+            .saturating_add(Weight::from_parts(150_000_000, 0))
+            .saturating_add(Weight::from_parts(50_000, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(Weight::from_parts(0, 5_640).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(0, 19_058).saturating_mul(p.into()))
+    }
+    fn pre_approve() -> Weight {
+        Weight::from_parts(10_000, 20_333)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn cancel_pre_approval() -> Weight {
+        Weight::from_parts(10_000, 8_228)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn submit_typed_proposal() -> Weight {
+        Weight::from_parts(20_000, 103_979)
+            .saturating_add(Weight::from_parts(150_000_000, 0))
+    }
+    fn grant_standing_approval() -> Weight {
+        Weight::from_parts(10_000, 15_481)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn revoke_standing_approval() -> Weight {
+        Weight::from_parts(10_000, 3_376)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_required_approvers() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_approval_order() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_group_quorum() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn clone_multisig() -> Weight {
+        Weight::from_parts(10_000, 97_944)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn register_template() -> Weight {
+        Weight::from_parts(10_000, 499)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn remove_template() -> Weight {
+        Weight::from_parts(10_000, 9_971)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn create_from_template() -> Weight {
+        Weight::from_parts(10_000, 22_158)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn submit_proposal_with_content() -> Weight {
+        Weight::from_parts(10_000, 96_390)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_contract_limits() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_evm_limits() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn track_inbound_spend() -> Weight {
+        Weight::from_parts(10_000, 14_628)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn untrack_inbound_spend() -> Weight {
+        Weight::from_parts(10_000, 14_628)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_auditors() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn flag_proposal() -> Weight {
+        Weight::from_parts(10_000, 46_352)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn dispatch_as_multisig() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_protected_reserve() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_payroll() -> Weight {
+        Weight::from_parts(10_000, 17_184)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_payroll_paused() -> Weight {
+        Weight::from_parts(10_000, 21_938)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn cancel_payroll() -> Weight {
+        Weight::from_parts(10_000, 21_938)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    /// The range of component `n` is `[0, 16]`.
+    /// The range of component `p` is `[1, 100]`.
+    fn pay_payrolls(n: u32, p: u32) -> Weight {
+        Weight::from_parts(10_000, 2_552)
+            .saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(
+                Weight::from_parts(50_000_000, 0).saturating_mul((n * p).into()),
+            )
+            .saturating_add(Weight::from_parts(0, 7_306).saturating_mul(n.into()))
+            .saturating_add(Weight::from_parts(0, 2_603).saturating_mul(p.into()))
+    }
+    fn grant_session() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn revoke_session() -> Weight {
+        Weight::from_parts(10_000, 14_653)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn operator_execute() -> Weight {
+        Weight::from_parts(10_000, 27_338)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn submit_proposal_with_funds() -> Weight {
+        Weight::from_parts(10_000, 101_520)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_owner_label() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn suspend_owner() -> Weight {
+        Weight::from_parts(10_000, 22_876)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn reinstate_owner() -> Weight {
+        Weight::from_parts(10_000, 20_329)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_backup_owners() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn clear_backup_owners() -> Weight {
+        Weight::from_parts(10_000, 20_341)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn activate_backup_owners() -> Weight {
+        Weight::from_parts(10_000, 20_851)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_deposit_exemption() -> Weight {
+        Weight::from_parts(10_000, 12_105)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_velocity_limit() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn bump_signing_nonce(p: u32) -> Weight {
+        Weight::from_parts(10_000, 19_634)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(p.into()))
+            .saturating_add(Weight::from_parts(0, 5_729).saturating_mul(p.into()))
+    }
+    fn set_max_active_multisigs() -> Weight {
+        Weight::from_parts(10_000, 0)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    /// The range of component `b` is `[1, 524288]`.
+    fn note_call_chunk(b: u32) -> Weight {
+        Weight::from_parts(10_000, 22_201)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+            .saturating_add(Weight::from_parts(1_000, 0).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
+    }
+    /// The range of component `b` is `[1, 524288]`.
+    fn finalize_call(b: u32) -> Weight {
+        Weight::from_parts(20_000, 101_404)
             .saturating_add(Weight::from_parts(150_000_000, 0))
+            .saturating_add(Weight::from_parts(2_000, 0).saturating_mul(b.into()))
+            .saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
+    }
+    fn discard_call_upload() -> Weight {
+        Weight::from_parts(10_000, 529_486)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
     }
-    fn execute_proposal() -> Weight {
-        Weight::from_parts(20_000, 0)
+    /// The range of component `o` is `[2, 100]`.
+    fn change_owners(o: u32) -> Weight {
+        Weight::from_parts(20_000, 57_111)
             .saturating_add(Weight::from_parts(150_000_000, 0))
+            .saturating_add(Weight::from_parts(100_000, 0).saturating_mul(o.into()))
     }
-    fn destroy_multisig() -> Weight {
-        Weight::from_parts(20_000, 0)
+    /// The range of component `o` is `[2, 100]`.
+    fn revoke_approval(o: u32) -> Weight {
+        Weight::from_parts(10_000, 75_598)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+            .saturating_add(Weight::from_parts(50_000, 0).saturating_mul(o.into()))
+    }
+    /// The range of component `o` is `[2, 100]`.
+    fn cancel_proposal(o: u32) -> Weight {
+        Weight::from_parts(20_000, 553_478)
             .saturating_add(Weight::from_parts(150_000_000, 0))
+            .saturating_add(Weight::from_parts(50_000, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(0, 2_603).saturating_mul(o.into()))
+    }
+    /// The range of component `o` is `[2, 100]`.
+    fn reject_proposal(o: u32) -> Weight {
+        Weight::from_parts(20_000, 615_727)
+            .saturating_add(Weight::from_parts(150_000_000, 0))
+            .saturating_add(Weight::from_parts(50_000, 0).saturating_mul(o.into()))
+            .saturating_add(Weight::from_parts(0, 2_603).saturating_mul(o.into()))
+    }
+    fn set_owner_weight() -> Weight {
+        Weight::from_parts(10_000, 22_876)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_class_threshold() -> Weight {
+        Weight::from_parts(10_000, 17_179)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_call_filter() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_spending_limit() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn spend_allowance() -> Weight {
+        Weight::from_parts(20_000, 43_829)
+            .saturating_add(Weight::from_parts(150_000_000, 0))
+    }
+    fn set_execution_delay() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_vetoers() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_recovery_config() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn remove_recovery_config() -> Weight {
+        Weight::from_parts(10_000, 20_337)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn initiate_recovery() -> Weight {
+        Weight::from_parts(10_000, 26_717)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn approve_recovery() -> Weight {
+        Weight::from_parts(10_000, 14_612)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn claim_recovery() -> Weight {
+        Weight::from_parts(10_000, 29_754)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn cancel_recovery() -> Weight {
+        Weight::from_parts(10_000, 21_012)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn set_threshold_decay() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn revoke_delegation() -> Weight {
+        Weight::from_parts(10_000, 2_579)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    /// The range of component `o` is `[2, 100]`.
+    fn delegate_confirmations(o: u32) -> Weight {
+        Weight::from_parts(12_000, 14_684)
+            .saturating_add(Weight::from_parts(110_000_000, 0))
+            .saturating_add(Weight::from_parts(50_000, 0).saturating_mul(o.into()))
+    }
+    fn dispatch_as_sub() -> Weight {
+        Weight::from_parts(10_000, 14_632)
+            .saturating_add(Weight::from_parts(100_000_000, 0))
+    }
+    fn verify_signature() -> Weight {
+        Weight::from_parts(50_000_000, 0)
+    }
+    /// The range of component `n` is `[1, 64]`.
+    fn batch_execute(n: u32) -> Weight {
+        Weight::from_parts(10_000, 0)
+            .saturating_add(Weight::from_parts(170_020_000, 1_143_292).saturating_mul(n.into()))
     }
 }
@@ -7,10 +7,12 @@ use frame::{
 	deps::{
 		codec::Compact,
 		frame_support::{
+			dynamic_params::{dynamic_pallet_params, dynamic_params},
 			genesis_builder_helper::{build_state, get_preset},
 			runtime,
 			traits::AsEnsureOriginWithArg,
 			weights::FixedFee,
+			PalletId,
 		},
 	},
 	prelude::*,
@@ -18,9 +20,10 @@ use frame::{
 		apis::{self, impl_runtime_apis},
 		prelude::*,
 	},
-	traits::{FindAuthor, One},
+	traits::{FindAuthor, InstanceFilter, One},
 };
 use pallet_transaction_payment::{ConstFeeMultiplier, FeeDetails, Multiplier, RuntimeDispatchInfo};
+use sp_runtime::traits::ConvertInto;
 
 #[runtime_version]
 const VERSION: RuntimeVersion = RuntimeVersion {
@@ -49,7 +52,8 @@ mod runtime {
 		RuntimeHoldReason,
 		RuntimeSlashReason,
 		RuntimeLockId,
-		RuntimeTask
+		RuntimeTask,
+		RuntimeViewFunction
 	)]
 	pub struct Runtime;
 
@@ -85,6 +89,17 @@ mod runtime {
 	#[runtime::pallet_index(8)]
 	pub type Treasury = pallet_treasury;
 
+	/// Lets signers pay transaction fees in a sufficient asset instead of the native token.
+	#[runtime::pallet_index(9)]
+	pub type AssetTxPayment = pallet_asset_tx_payment;
+
+	#[runtime::pallet_index(10)]
+	pub type Vesting = pallet_vesting;
+
+	/// Stores the runtime parameters governance can tune without a runtime upgrade.
+	#[runtime::pallet_index(11)]
+	pub type Parameters = pallet_parameters;
+
 	#[runtime::pallet_index(99)]
 	pub type Timestamp = pallet_timestamp;
 }
@@ -174,13 +189,376 @@ impl pallet_assets::Config for Runtime {
 	type BenchmarkHelper = ();
 }
 
+parameter_types! {
+	pub const AssetFeePotId: PalletId = PalletId(*b"pba/afee");
+}
+
+/// Collects the fees paid in assets into the account of `AssetFeePotId`, from which governance
+/// can spend them.
+///
+/// Native fees are burned, lowering the token's issuance, but burning an asset fee would shrink
+/// the supply its issuer backs, so asset fees are kept instead. A fee below the asset's minimum
+/// balance, while the pot holds none of the asset, cannot be deposited and is burned.
+pub struct AssetFeePot;
+impl pallet_asset_tx_payment::HandleCredit<AccountId, Assets> for AssetFeePot {
+	fn handle_credit(credit: frame::token::fungibles::Credit<AccountId, Assets>) {
+		use frame::{token::fungibles::Balanced, traits::AccountIdConversion};
+		let pot: AccountId = AssetFeePotId::get().into_account_truncating();
+		let _ = <Assets as Balanced<AccountId>>::resolve(&pot, credit);
+	}
+}
+
+/// Configure fee payment in assets, so multisig owners whose signing keys hold only a stable
+/// asset can still submit and confirm proposals.
+///
+/// This uses `pallet-asset-tx-payment` rather than `pallet-asset-conversion-tx-payment`: the
+/// chain has no asset conversion pools to price an asset against the native token, so the fee is
+/// converted using the ratio between the asset's and the native token's minimum balances instead,
+/// which requires a sufficient asset. The fees are collected by `AssetFeePot`.
+impl pallet_asset_tx_payment::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Fungibles = Assets;
+	type OnChargeAssetTransaction = pallet_asset_tx_payment::FungiblesAdapter<
+		pallet_assets::BalanceToAssetBalance<Balances, Runtime, ConvertInto>,
+		AssetFeePot,
+	>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AssetTxPaymentBenchmarkHelper;
+}
+
+/// Sets up a sufficient fee asset for benchmarking asset fee payment.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetTxPaymentBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_asset_tx_payment::BenchmarkHelperTrait<AccountId, u32, u32>
+	for AssetTxPaymentBenchmarkHelper
+{
+	fn create_asset_id_parameter(id: u32) -> (u32, u32) {
+		(id, id)
+	}
+
+	fn setup_balances_and_pool(asset_id: u32, account: AccountId) {
+		use frame::deps::frame_support::traits::fungibles::Mutate;
+		let _ = Assets::force_create(
+			RuntimeOrigin::root(),
+			asset_id.into(),
+			account.clone().into(),
+			true,
+			1,
+		);
+		let _ = Assets::mint_into(asset_id, &account, 1_000_000);
+	}
+}
+
+/// Signs the multisig pallet's benchmark payloads with a freshly generated sr25519 key.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MultisigBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl
+	pallet_multisig::BenchmarkHelper<
+		frame::deps::sp_runtime::MultiSigner,
+		frame::runtime::types_common::Signature,
+	> for MultisigBenchmarkHelper
+{
+	fn sign_message(
+		message: &[u8],
+	) -> (frame::deps::sp_runtime::MultiSigner, frame::runtime::types_common::Signature) {
+		use frame::deps::{sp_core::crypto::KeyTypeId, sp_io::crypto};
+		const KEY_TYPE: KeyTypeId = KeyTypeId(*b"msig");
+		let public = crypto::sr25519_generate(KEY_TYPE, None);
+		let signature =
+			crypto::sr25519_sign(KEY_TYPE, &public, message).expect("the key was just generated");
+		(public.into(), signature.into())
+	}
+}
+
+parameter_types! {
+	pub const MultisigPalletId: PalletId = PalletId(*b"pba/msig");
+}
+
 /// Configure the pallet-multisig in pallets/multisig.
 impl pallet_multisig::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type RuntimeCall = RuntimeCall;
-	type MaxOwners = ConstU32<100>; 
-	type WeightInfo = (); 
+	type MaxOwners = ConstU32<100>;
+	type MaxPendingProposals = ConstU32<64>;
+	type MaxStandingApprovals = ConstU32<16>;
+	type MaxGroups = ConstU32<8>;
+	type MaxNameLength = ConstU32<64>;
+	type MaxContentRefLength = ConstU32<128>;
+	type PalletId = MultisigPalletId;
+	type TemplateOrigin = EnsureRoot<AccountId>;
+	type MaxContractDataLength = ConstU32<16_384>;
+	type ItemCustody = ();
+	type MaxFlagReasonLength = ConstU32<256>;
+	type PolicyCheck = ();
+	type CallClassifier = MultisigCallClassifier;
+	type OwnerProxies = ();
+	type RecipientGate = frame::traits::Everything;
+	type CriticalCalls = MultisigCriticalCalls;
+	// The runtime has no `pallet-session`, and so no session keys.
+	type SessionKeysLength = ConstU32<0>;
+	type MaxPayees = ConstU32<100>;
+	type MaxPayrollsPerBlock = ConstU32<16>;
+	type SessionScope = MultisigSessionScope;
+	type VoteTally = pallet_multisig::CountApprovals;
+	type DepositCalculator = MultisigDeposits;
+	type DepositExemptionOrigin = EnsureRoot<AccountId>;
+	type WalletCapOrigin = EnsureRoot<AccountId>;
+	type FeeEstimator = MultisigFeeEstimator;
+	type MaxCallSize = ConstU32<{ 512 * 1024 }>;
+	type ProposalLifetime = dynamic_params::multisig::ProposalLifetime;
+	type ExpiredDepositSlash = dynamic_params::multisig::ExpiredDepositSlash;
+	type MaxCallFilterEntries = ConstU32<32>;
+	type DefaultExecutionDelay = ConstU32<0>;
+	type MaxMultisigsPerOwner = ConstU32<128>;
+	type OnProposalExecuted = ();
+	type OnMultisigCreated = ();
+	type OnMultisigDestroyed = ();
+	type OffchainSignature = frame::runtime::types_common::Signature;
+	type SigningPublicKey =
+		<frame::runtime::types_common::Signature as frame::traits::Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MultisigBenchmarkHelper;
+	type CallBuilder = MultisigCallBuilder;
+	type MultisigId = u32;
+	type ProposalIndex = u64;
+	type WeightInfo = pallet_multisig::weight::SubstrateWeight<Runtime>;
+}
+
+/// Builds the calls behind the multisig pallet's typed proposals.
+pub struct MultisigCallBuilder;
+impl pallet_multisig::TypedCallBuilder<Runtime> for MultisigCallBuilder {
+	fn build(
+		_sovereign: &AccountId,
+		proposal: &pallet_multisig::TypedProposalOf<Runtime>,
+	) -> Option<RuntimeCall> {
+		match proposal {
+			pallet_multisig::TypedProposal::Transfer { dest, amount } =>
+				Some(RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+					dest: dest.clone().into(),
+					value: *amount,
+				})),
+			pallet_multisig::TypedProposal::VestedTransfer {
+				dest,
+				locked,
+				per_block,
+				starting_block,
+			} => Some(RuntimeCall::Vesting(pallet_vesting::Call::vested_transfer {
+				target: dest.clone().into(),
+				schedule: pallet_vesting::VestingInfo::new(*locked, *per_block, *starting_block),
+			})),
+			// This runtime includes none of `pallet-contracts`, Frontier's `pallet-evm`,
+			// `pallet-nfts`, `pallet-bounties` or the FRAME `pallet-treasury`; its local treasury
+			// pallet is a stub.
+			pallet_multisig::TypedProposal::InstantiateContract { .. } |
+			pallet_multisig::TypedProposal::TransferItem { .. } |
+			pallet_multisig::TypedProposal::TreasurySpend { .. } |
+			pallet_multisig::TypedProposal::TreasuryPayout { .. } |
+			pallet_multisig::TypedProposal::AcceptCurator { .. } |
+			pallet_multisig::TypedProposal::AwardBounty { .. } |
+			pallet_multisig::TypedProposal::ClaimBounty { .. } |
+			pallet_multisig::TypedProposal::CallContract { .. } |
+			pallet_multisig::TypedProposal::EvmCall { .. } |
+			pallet_multisig::TypedProposal::JoinPool { .. } |
+			pallet_multisig::TypedProposal::BondExtraToPool { .. } |
+			pallet_multisig::TypedProposal::ClaimPoolPayout |
+			pallet_multisig::TypedProposal::SetSessionKeys { .. } |
+			pallet_multisig::TypedProposal::PurgeSessionKeys => None,
+		}
+	}
+}
+
+parameter_types! {
+	pub const MinVestedTransfer: Balance = 100;
+	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+/// Configure vesting, so grants from a team multisig can be proposed as vested transfers.
+impl pallet_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = MinVestedTransfer;
+	type WeightInfo = ();
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type BlockNumberProvider = System;
+	const MAX_VESTING_SCHEDULES: u32 = 28;
+}
+
+/// Sorts proposed calls into the classes a multisig may set separate thresholds for.
+pub struct MultisigCallClassifier;
+impl pallet_multisig::CallClassifier<Runtime> for MultisigCallClassifier {
+	fn classify(call: &RuntimeCall) -> pallet_multisig::CallClass {
+		match call {
+			RuntimeCall::Balances(_) => pallet_multisig::CallClass::Transfer,
+			RuntimeCall::Multisig(
+				pallet_multisig::Call::add_owner { .. } |
+				pallet_multisig::Call::remove_owner { .. } |
+				pallet_multisig::Call::swap_owner { .. } |
+				pallet_multisig::Call::set_owner_weight { .. } |
+				pallet_multisig::Call::set_class_threshold { .. } |
+				pallet_multisig::Call::set_recovery_config { .. } |
+				pallet_multisig::Call::destroy_multisig { .. },
+			) => pallet_multisig::CallClass::Governance,
+			RuntimeCall::System(_) | RuntimeCall::Sudo(_) | RuntimeCall::Parameters(_) =>
+				pallet_multisig::CallClass::Admin,
+			_ => pallet_multisig::CallClass::Other,
+		}
+	}
+}
+
+/// The calls a multisig may only make with the approval of every owner: runtime upgrades,
+/// raw storage writes and anything dispatched through sudo.
+pub struct MultisigCriticalCalls;
+impl Contains<RuntimeCall> for MultisigCriticalCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::System(
+				frame_system::Call::set_code { .. } |
+					frame_system::Call::set_code_without_checks { .. } |
+					frame_system::Call::set_storage { .. } |
+					frame_system::Call::kill_storage { .. } |
+					frame_system::Call::kill_prefix { .. }
+			) | RuntimeCall::Sudo(_)
+		)
+	}
+}
+
+/// Estimates the multisig's metered fees with the transaction payment pallet's weight fee.
+pub struct MultisigFeeEstimator;
+impl sp_runtime::traits::Convert<Weight, Balance> for MultisigFeeEstimator {
+	fn convert(weight: Weight) -> Balance {
+		use frame::deps::frame_support::weights::WeightToFee;
+		<Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(&weight)
+	}
+}
+
+/// The parameters governance can tune through `pallet-parameters`.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	/// The multisig's economics.
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod multisig {
+		/// The base deposit for creating a multisig.
+		#[codec(index = 0)]
+		pub static CreationDepositBase: Balance = 10;
+
+		/// The deposit for creating a multisig per owner.
+		#[codec(index = 1)]
+		pub static CreationDepositPerOwner: Balance = 1;
+
+		/// The base deposit for submitting a proposal.
+		#[codec(index = 2)]
+		pub static ProposalDepositBase: Balance = 10;
+
+		/// The deposit for submitting a proposal per byte of call data.
+		#[codec(index = 3)]
+		pub static ProposalDepositPerByte: Balance = 1;
+
+		/// The deposit for submitting a proposal per proposal already open on the multisig.
+		#[codec(index = 4)]
+		pub static ProposalDepositPerOpenProposal: Balance = 10;
+
+		/// The deposit for confirming a proposal.
+		#[codec(index = 5)]
+		pub static ApprovalDeposit: Balance = 1;
+
+		/// The number of blocks a proposal stays open for by default: a week of 6 second
+		/// blocks.
+		#[codec(index = 6)]
+		pub static ProposalLifetime: u32 = 7 * 24 * 600;
+
+		/// The share of the submission deposit a proposer forfeits to whoever removes their
+		/// expired proposal.
+		#[codec(index = 7)]
+		pub static ExpiredDepositSlash: Perbill = Perbill::from_percent(10);
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Multisig(dynamic_params::multisig::Parameters::ApprovalDeposit(
+			dynamic_params::multisig::ApprovalDeposit,
+			Some(1),
+		))
+	}
+}
+
+/// Configure the parameters pallet, so governance can retune the runtime's economics.
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	type AdminOrigin = AsEnsureOriginWithArg<EnsureRoot<AccountId>>;
+	type WeightInfo = ();
+}
+
+/// The multisig's deposits: a base for each wallet, proposal and approval, plus a rate per
+/// owner or byte of call data, with proposals growing dearer as more are left open.
+///
+/// Every amount is read from [`dynamic_params::multisig`], so governance can adjust them
+/// without a runtime upgrade.
+pub struct MultisigDeposits;
+impl pallet_multisig::DepositCalculator<Balance> for MultisigDeposits {
+	fn creation_deposit(owner_count: u32) -> Balance {
+		use dynamic_params::multisig::*;
+		CreationDepositBase::get()
+			.saturating_add(CreationDepositPerOwner::get().saturating_mul(owner_count.into()))
+	}
+
+	fn proposal_deposit(call_len: u32, open_proposals: u32) -> Balance {
+		use dynamic_params::multisig::*;
+		ProposalDepositBase::get()
+			.saturating_add(ProposalDepositPerByte::get().saturating_mul(call_len.into()))
+			.saturating_add(
+				ProposalDepositPerOpenProposal::get().saturating_mul(open_proposals.into()),
+			)
+	}
+
+	fn approval_deposit(_owner_count: u32) -> Balance {
+		dynamic_params::multisig::ApprovalDeposit::get()
+	}
+
+	fn call_data_deposit(len: u32) -> Balance {
+		dynamic_params::multisig::ProposalDepositPerByte::get().saturating_mul(len.into())
+	}
+}
+
+/// The categories of calls an operator session on a multisig can be scoped to.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	MaxEncodedLen,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+)]
+pub enum MultisigSessionScope {
+	/// Any call.
+	Any,
+	/// Staking management through the DPoS pallet.
+	Staking,
+}
+
+impl InstanceFilter<RuntimeCall> for MultisigSessionScope {
+	fn filter(&self, call: &RuntimeCall) -> bool {
+		match self {
+			MultisigSessionScope::Any => true,
+			MultisigSessionScope::Staking => matches!(call, RuntimeCall::Dpos(..)),
+		}
+	}
 }
 
 /// Configure the pallet-free-tx in pallets/free-tx.
@@ -253,7 +631,7 @@ type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_asset_tx_payment::ChargeAssetTxPayment<Runtime>,
 );
 
 // Many of the types in this runtime are being pulled in from `derive_impl`. We use the almighty
@@ -262,11 +640,21 @@ type SignedExtra = (
 type AccountId = <Runtime as frame_system::Config>::AccountId;
 type Balance = <Runtime as pallet_balances::Config>::Balance;
 type Nonce = <Runtime as frame_system::Config>::Nonce;
+type Hash = <Runtime as frame_system::Config>::Hash;
 type Block = frame::runtime::types_common::BlockOf<Runtime, SignedExtra>;
 type Header = HeaderFor<Runtime>;
 
-type RuntimeExecutive =
-	Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPalletsWithSystem>;
+/// The storage migrations run on the next runtime upgrade.
+type Migrations = (pallet_multisig::migrations::v1::MigrateV0ToV1<Runtime>,);
+
+type RuntimeExecutive = Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	Migrations,
+>;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benches {
@@ -280,6 +668,7 @@ mod benches {
 		[pallet_free_tx, FreeTx]
 		[pallet_treasury, Treasury]
 		[pallet_timestamp, Timestamp]
+		[pallet_parameters, Parameters]
 	);
 }
 
@@ -361,12 +750,63 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame::deps::frame_support::view_functions::runtime_api::RuntimeViewFunction<Block>
+		for Runtime
+	{
+		fn execute_view_function(
+			id: frame::deps::frame_support::view_functions::ViewFunctionId,
+			input: Vec<u8>,
+		) -> Result<Vec<u8>, frame::deps::frame_support::view_functions::ViewFunctionDispatchError>
+		{
+			Runtime::execute_view_function(id, input)
+		}
+	}
+
 	impl apis::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
 		fn account_nonce(account: AccountId) -> Nonce {
 			System::account_nonce(account)
 		}
 	}
 
+	impl pallet_multisig::runtime_api::MultisigApi<Block, AccountId, Balance, u32, u64, Hash>
+		for Runtime
+	{
+		fn account_of(multisig_id: u32) -> Option<AccountId> {
+			Multisig::multisigs(multisig_id).map(|_| Multisig::multi_account_id(multisig_id))
+		}
+
+		fn pending_proposals(multisig_id: u32) -> Vec<(u64, Hash)> {
+			Multisig::pending_proposals(multisig_id)
+		}
+
+		fn executable_proposals(multisig_id: u32) -> Vec<(u64, Hash)> {
+			Multisig::executable_proposals(multisig_id)
+		}
+
+		fn owner_labels(multisig_id: u32) -> Vec<(AccountId, Vec<u8>)> {
+			Multisig::owner_labels(multisig_id)
+				.into_iter()
+				.map(|(owner, label)| (owner, label.into_inner()))
+				.collect()
+		}
+
+		fn export_state(multisig_id: u32) -> Option<Vec<u8>> {
+			Multisig::export_state(multisig_id).map(|snapshot| snapshot.encode())
+		}
+
+		fn confirmation_payload(multisig_id: u32, proposal_index: u64) -> Option<Vec<u8>> {
+			Multisig::confirmation_payload(multisig_id, proposal_index)
+		}
+
+		fn usage_meter(multisig_id: u32) -> Option<pallet_multisig::UsageMeter<Balance>> {
+			Multisig::multisigs(multisig_id).map(|_| Multisig::usage_meter(multisig_id))
+		}
+
+		fn proposals_by_call_hash(call_hash: Hash) -> Vec<(u32, u64)> {
+			Multisig::proposals_by_call_hash(call_hash)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -518,6 +958,17 @@ pub mod genesis_config_presets {
 				accounts: vec![],
 				next_asset_id: Some(2),
 			},
+			multisig: MultisigConfig {
+				multisigs: vec![(
+					vec![
+						AccountKeyring::Alice.to_account_id(),
+						AccountKeyring::Bob.to_account_id(),
+						AccountKeyring::Charlie.to_account_id(),
+					],
+					2,
+					Some(endowment),
+				)],
+			},
 			..Default::default()
 		};
 		serde_json::to_value(config).expect("Could not build genesis config.")
@@ -541,3 +992,6 @@ pub mod genesis_config_presets {
 		vec![PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET)]
 	}
 }
+
+#[cfg(test)]
+mod tests;
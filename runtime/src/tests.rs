@@ -0,0 +1,61 @@
+//! Tests for how the pallets are wired into the runtime.
+
+use crate::*;
+use frame::{
+	deps::frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo},
+	testing_prelude::*,
+	token::fungibles::{Inspect, Mutate},
+	traits::{AccountIdConversion, TransactionExtension},
+};
+use pallet_asset_tx_payment::ChargeAssetTxPayment;
+
+fn new_test_ext() -> TestState {
+	let storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut ext = TestState::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Tests that a signer holding only a sufficient asset pays the transaction fee in it, and that
+/// the fee is collected by the asset fee pot rather than burned.
+#[test]
+fn fee_is_charged_in_an_asset_and_collected() {
+	new_test_ext().execute_with(|| {
+		// Arrange: Alice holds a sufficient asset whose minimum balance is twice the existential
+		// deposit, and no native tokens.
+		let alice = AccountId::new([1; 32]);
+		let asset_id = 1;
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			asset_id.into(),
+			alice.clone().into(),
+			true,
+			20
+		));
+		assert_ok!(Assets::mint_into(asset_id, &alice, 1_000));
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let info = call.get_dispatch_info();
+		let len = call.encoded_size();
+
+		// Act
+		let (pre, _) = ChargeAssetTxPayment::<Runtime>::from(0, Some(asset_id))
+			.validate_and_prepare(RuntimeOrigin::signed(alice.clone()), &call, &info, len, 0)
+			.unwrap();
+		assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch_details(
+			pre,
+			&info,
+			&PostDispatchInfo::default(),
+			len,
+			&Ok(()),
+		));
+
+		// Assert: The native fee is converted at the ratio of the minimum balances.
+		let fee = TransactionPayment::compute_fee(len as u32, &info, 0) * 2;
+		assert!(fee > 0);
+		assert_eq!(Assets::balance(asset_id, &alice), 1_000 - fee);
+		assert_eq!(Balances::free_balance(&alice), 0);
+		let pot: AccountId = AssetFeePotId::get().into_account_truncating();
+		assert_eq!(Assets::balance(asset_id, &pot), fee);
+		assert_eq!(Assets::total_issuance(asset_id), 1_000);
+	});
+}